@@ -0,0 +1,772 @@
+use crate::canonicalization::sha256::Sha256;
+use crate::canonicalization::sha384::Sha384;
+use crate::dataset::{Dataset, Quad};
+use crate::error::{Error, ErrorType};
+use crate::graph::Graph;
+use crate::node::Node;
+use crate::specs::xml_specs::XmlDataTypes;
+use crate::writer::formatter::n_triples_formatter::NTriplesFormatter;
+use crate::writer::formatter::rdf_formatter::RdfFormatter;
+use crate::Result;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+/// Default number of steps after which the canonicalization gives up.
+const DEFAULT_WORK_LIMIT: usize = 1_000_000;
+
+/// Hash algorithm used by the canonicalization.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum HashAlgorithm {
+    /// SHA-256, the default of RDFC-1.0.
+    Sha256,
+    Sha384,
+}
+
+impl HashAlgorithm {
+    /// Returns the hash of the data in lower case hex digits.
+    fn digest_hex(self, data: &[u8]) -> String {
+        match self {
+            HashAlgorithm::Sha256 => Sha256::digest_hex(data),
+            HashAlgorithm::Sha384 => Sha384::digest_hex(data),
+        }
+    }
+}
+
+/// Canonicalizes RDF datasets with the RDF Dataset Canonicalization algorithm (RDFC-1.0).
+///
+/// The canonical form does not depend on the blank node labels of the input or the order
+/// in which quads were added. Blank nodes are relabeled to `c14n0`, `c14n1`, ... and
+/// datasets that are isomorphic produce the same canonical N-Quads.
+///
+/// # Examples
+///
+/// ```
+/// use rdf::canonicalization::rdfc::Canonicalizer;
+/// use rdf::reader::n_triples_parser::NTriplesParser;
+///
+/// let first = NTriplesParser::from_string("_:x <http://example.org/p> _:y .
+///                                          _:y <http://example.org/q> \"z\" .")
+///   .decode_dataset().unwrap();
+/// let second = NTriplesParser::from_string("_:b <http://example.org/q> \"z\" .
+///                                           _:a <http://example.org/p> _:b .")
+///   .decode_dataset().unwrap();
+///
+/// let canonicalizer = Canonicalizer::new();
+/// let canonical = canonicalizer.canonicalize_dataset(&first).unwrap().to_n_quads();
+///
+/// assert_eq!(canonical, canonicalizer.canonicalize_dataset(&second).unwrap().to_n_quads());
+/// assert_eq!(canonical, "_:c14n0 <http://example.org/q> \"z\" .\n\
+///                        _:c14n1 <http://example.org/p> _:c14n0 .\n");
+/// ```
+pub struct Canonicalizer {
+    work_limit: usize,
+    hash_algorithm: HashAlgorithm,
+}
+
+impl Canonicalizer {
+    /// Constructor of `Canonicalizer`.
+    pub fn new() -> Canonicalizer {
+        Canonicalizer {
+            work_limit: DEFAULT_WORK_LIMIT,
+            hash_algorithm: HashAlgorithm::Sha256,
+        }
+    }
+
+    /// Limits the number of steps of the N-degree hashing.
+    ///
+    /// Datasets can be crafted so that the canonicalization takes exponential time, the
+    /// canonicalization fails once the number of recursive hashing calls and examined
+    /// permutations exceeds the limit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::canonicalization::rdfc::Canonicalizer;
+    /// use rdf::reader::n_triples_parser::NTriplesParser;
+    ///
+    /// let input = "_:a <http://example.org/p> _:b .
+    ///              _:b <http://example.org/p> _:a .";
+    /// let dataset = NTriplesParser::from_string(input).decode_dataset().unwrap();
+    ///
+    /// assert!(Canonicalizer::new().work_limit(1).canonicalize_dataset(&dataset).is_err());
+    /// assert!(Canonicalizer::new().canonicalize_dataset(&dataset).is_ok());
+    /// ```
+    pub fn work_limit(mut self, limit: usize) -> Canonicalizer {
+        self.work_limit = limit;
+        self
+    }
+
+    /// Sets the hash algorithm, which changes the canonical labels of blank nodes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::canonicalization::rdfc::{Canonicalizer, HashAlgorithm};
+    /// use rdf::reader::n_triples_parser::NTriplesParser;
+    ///
+    /// let input = "_:x <http://example.org/p> \"a\" .
+    ///              _:y <http://example.org/p> \"b\" .";
+    /// let dataset = NTriplesParser::from_string(input).decode_dataset().unwrap();
+    ///
+    /// let canonical = Canonicalizer::new()
+    ///   .hash_algorithm(HashAlgorithm::Sha384)
+    ///   .canonicalize_dataset(&dataset)
+    ///   .unwrap();
+    ///
+    /// assert_eq!(canonical.issued_identifiers()["x"], "c14n1");
+    /// ```
+    pub fn hash_algorithm(mut self, hash_algorithm: HashAlgorithm) -> Canonicalizer {
+        self.hash_algorithm = hash_algorithm;
+        self
+    }
+
+    /// Canonicalizes the quads of all graphs of the dataset.
+    ///
+    /// # Failures
+    ///
+    /// - The work limit is exceeded.
+    ///
+    pub fn canonicalize_dataset(&self, dataset: &Dataset) -> Result<CanonicalDataset> {
+        self.canonicalize_quads(dataset.quads())
+    }
+
+    /// Canonicalizes the triples of the graph as quads of the default graph.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::canonicalization::rdfc::Canonicalizer;
+    /// use rdf::reader::turtle_parser::TurtleParser;
+    /// use rdf::reader::rdf_parser::RdfParser;
+    ///
+    /// let input = "@prefix ex: <http://example.org/> .
+    ///              ex:a ex:p [ ex:q \"b\" ] .";
+    /// let graph = TurtleParser::from_string(input).decode().unwrap();
+    ///
+    /// let canonical = Canonicalizer::new().canonicalize_graph(&graph).unwrap();
+    ///
+    /// assert_eq!(canonical.to_n_quads(),
+    ///            "<http://example.org/a> <http://example.org/p> _:c14n0 .\n\
+    ///             _:c14n0 <http://example.org/q> \"b\" .\n");
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - The work limit is exceeded.
+    ///
+    pub fn canonicalize_graph(&self, graph: &Graph) -> Result<CanonicalDataset> {
+        self.canonicalize_quads(
+            graph
+                .triples_iter()
+                .map(|triple| Quad::from_triple(triple, None))
+                .collect(),
+        )
+    }
+
+    /// Runs the canonicalization algorithm on the quads.
+    fn canonicalize_quads(&self, quads: Vec<Quad>) -> Result<CanonicalDataset> {
+        // datasets are sets, duplicate quads are serialized once
+        let quads: Vec<Quad> = quads
+            .iter()
+            .map(normalize_quad)
+            .collect::<BTreeSet<Quad>>()
+            .into_iter()
+            .collect();
+
+        let mut state = State::new(&quads, self.work_limit, self.hash_algorithm);
+        let mut hash_to_blank_nodes: BTreeMap<String, Vec<&str>> = BTreeMap::new();
+
+        let blank_nodes: Vec<&str> = state.blank_node_to_quads.keys().cloned().collect();
+
+        for blank_node in blank_nodes {
+            hash_to_blank_nodes
+                .entry(state.hash_first_degree_quads(blank_node))
+                .or_default()
+                .push(blank_node);
+        }
+
+        // blank nodes with a unique first degree hash are labeled in the order of their hashes
+        for blank_nodes in hash_to_blank_nodes.values() {
+            if let [blank_node] = blank_nodes[..] {
+                state.canonical_issuer.issue(blank_node);
+            }
+        }
+
+        for blank_nodes in hash_to_blank_nodes.values().filter(|nodes| nodes.len() > 1) {
+            let mut hash_path_list = Vec::new();
+
+            for &blank_node in blank_nodes {
+                if state.canonical_issuer.get(blank_node).is_some() {
+                    continue;
+                }
+
+                let mut issuer = IdentifierIssuer::new("b");
+                issuer.issue(blank_node);
+
+                hash_path_list.push(state.hash_n_degree_quads(blank_node, issuer)?);
+            }
+
+            hash_path_list.sort_by(|first, second| first.0.cmp(&second.0));
+
+            for (_, issuer) in hash_path_list {
+                for existing in &issuer.issued {
+                    state.canonical_issuer.issue(existing);
+                }
+            }
+        }
+
+        let issuer = state.canonical_issuer;
+        let relabel = |node: &Node| match *node {
+            Node::BlankNode { ref id } => Node::BlankNode {
+                id: issuer.get(id).unwrap_or(id).to_string(),
+            },
+            ref node => node.clone(),
+        };
+
+        let formatter = NTriplesFormatter::new();
+        let mut lines: Vec<(String, Quad)> = quads
+            .iter()
+            .map(|quad| {
+                let quad = Quad::new(
+                    &relabel(quad.subject()),
+                    &relabel(quad.predicate()),
+                    &relabel(quad.object()),
+                    quad.graph_name().map(relabel).as_ref(),
+                );
+
+                (formatter.format_quad(&quad), quad)
+            })
+            .collect();
+
+        lines.sort();
+
+        let issued_identifiers = issuer
+            .issued
+            .iter()
+            .map(|existing| (existing.to_string(), issuer.map[existing].clone()))
+            .collect();
+
+        Ok(CanonicalDataset {
+            quads: lines.into_iter().map(|(_, quad)| quad).collect(),
+            issued_identifiers,
+        })
+    }
+}
+
+impl Default for Canonicalizer {
+    fn default() -> Canonicalizer {
+        Canonicalizer::new()
+    }
+}
+
+/// Result of the canonicalization, the relabeled quads and the issued blank node identifiers.
+#[derive(Debug)]
+pub struct CanonicalDataset {
+    quads: Vec<Quad>,
+    issued_identifiers: BTreeMap<String, String>,
+}
+
+impl CanonicalDataset {
+    /// Returns the quads with canonical blank node labels in canonical order.
+    pub fn quads(&self) -> &[Quad] {
+        &self.quads
+    }
+
+    /// Returns the canonical identifiers of the blank nodes of the input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::canonicalization::rdfc::Canonicalizer;
+    /// use rdf::reader::n_triples_parser::NTriplesParser;
+    ///
+    /// let input = "_:x <http://example.org/p> \"a\" .";
+    /// let dataset = NTriplesParser::from_string(input).decode_dataset().unwrap();
+    ///
+    /// let canonical = Canonicalizer::new().canonicalize_dataset(&dataset).unwrap();
+    ///
+    /// assert_eq!(canonical.issued_identifiers()["x"], "c14n0");
+    /// ```
+    pub fn issued_identifiers(&self) -> &BTreeMap<String, String> {
+        &self.issued_identifiers
+    }
+
+    /// Returns the canonical N-Quads serialization, one quad per line in code point order.
+    pub fn to_n_quads(&self) -> String {
+        let formatter = NTriplesFormatter::new();
+
+        self.quads
+            .iter()
+            .map(|quad| formatter.format_quad(quad) + "\n")
+            .collect()
+    }
+
+    /// Returns a dataset containing the canonical quads.
+    pub fn to_dataset(&self) -> Dataset {
+        let mut dataset = Dataset::new();
+
+        for quad in &self.quads {
+            dataset.add_quad(quad);
+        }
+
+        dataset
+    }
+}
+
+/// Issues identifiers with a prefix and a counter and remembers the order of issuing.
+#[derive(Clone)]
+struct IdentifierIssuer<'a> {
+    prefix: &'static str,
+    issued: Vec<&'a str>,
+    map: HashMap<&'a str, String>,
+}
+
+impl<'a> IdentifierIssuer<'a> {
+    fn new(prefix: &'static str) -> IdentifierIssuer<'a> {
+        IdentifierIssuer {
+            prefix,
+            issued: Vec::new(),
+            map: HashMap::new(),
+        }
+    }
+
+    /// Returns the identifier issued for the existing identifier.
+    fn get(&self, existing: &str) -> Option<&String> {
+        self.map.get(existing)
+    }
+
+    /// Returns the identifier for the existing identifier and issues a new one if needed.
+    fn issue(&mut self, existing: &'a str) -> String {
+        if let Some(issued) = self.map.get(existing) {
+            return issued.clone();
+        }
+
+        let issued = format!("{}{}", self.prefix, self.issued.len());
+        self.issued.push(existing);
+        self.map.insert(existing, issued.clone());
+        issued
+    }
+}
+
+/// State of the canonicalization of a set of quads.
+struct State<'a> {
+    blank_node_to_quads: BTreeMap<&'a str, Vec<&'a Quad>>,
+    first_degree_hashes: HashMap<&'a str, String>,
+    canonical_issuer: IdentifierIssuer<'a>,
+    formatter: NTriplesFormatter,
+    work: usize,
+    work_limit: usize,
+    hash_algorithm: HashAlgorithm,
+}
+
+impl<'a> State<'a> {
+    fn new(quads: &'a [Quad], work_limit: usize, hash_algorithm: HashAlgorithm) -> State<'a> {
+        let mut blank_node_to_quads: BTreeMap<&'a str, Vec<&'a Quad>> = BTreeMap::new();
+
+        for quad in quads {
+            for blank_node in nodes_of(quad).filter_map(blank_id) {
+                let quads = blank_node_to_quads.entry(blank_node).or_default();
+
+                if quads.last() != Some(&quad) {
+                    quads.push(quad);
+                }
+            }
+        }
+
+        State {
+            blank_node_to_quads,
+            first_degree_hashes: HashMap::new(),
+            canonical_issuer: IdentifierIssuer::new("c14n"),
+            formatter: NTriplesFormatter::new(),
+            work: 0,
+            work_limit,
+            hash_algorithm,
+        }
+    }
+
+    /// Counts a step of the N-degree hashing and fails if the work limit is exceeded.
+    fn step(&mut self) -> Result<()> {
+        self.work += 1;
+
+        if self.work > self.work_limit {
+            return Err(Error::new(
+                ErrorType::CanonicalizationLimit,
+                format!(
+                    "Canonicalization exceeded the limit of {} steps.",
+                    self.work_limit
+                ),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Hashes the quads of the blank node with the blank node itself labeled `a` and all
+    /// other blank nodes labeled `z`.
+    fn hash_first_degree_quads(&mut self, reference: &'a str) -> String {
+        if let Some(hash) = self.first_degree_hashes.get(reference) {
+            return hash.clone();
+        }
+
+        let label = |node: &Node| match blank_id(node) {
+            Some(id) if id == reference => Node::BlankNode {
+                id: "a".to_string(),
+            },
+            Some(_) => Node::BlankNode {
+                id: "z".to_string(),
+            },
+            None => node.clone(),
+        };
+
+        let mut lines: Vec<String> = self.blank_node_to_quads[reference]
+            .iter()
+            .map(|quad| {
+                let quad = Quad::new(
+                    &label(quad.subject()),
+                    &label(quad.predicate()),
+                    &label(quad.object()),
+                    quad.graph_name().map(label).as_ref(),
+                );
+
+                self.formatter.format_quad(&quad) + "\n"
+            })
+            .collect();
+
+        lines.sort();
+
+        let hash = self.hash_algorithm.digest_hex(lines.concat().as_bytes());
+        self.first_degree_hashes.insert(reference, hash.clone());
+        hash
+    }
+
+    /// Hashes a blank node that appears in a quad together with the blank node being hashed.
+    fn hash_related_blank_node(
+        &mut self,
+        related: &'a str,
+        quad: &Quad,
+        issuer: &IdentifierIssuer<'a>,
+        position: &str,
+    ) -> String {
+        let mut input = position.to_string();
+
+        if position != "g" {
+            input.push_str(&self.formatter.format_node(quad.predicate()));
+        }
+
+        match self
+            .canonical_issuer
+            .get(related)
+            .or_else(|| issuer.get(related))
+        {
+            Some(identifier) => {
+                input.push_str("_:");
+                input.push_str(identifier);
+            }
+            None => input.push_str(&self.hash_first_degree_quads(related)),
+        }
+
+        self.hash_algorithm.digest_hex(input.as_bytes())
+    }
+
+    /// Hashes the blank node by the paths to all blank nodes it is connected with, returns
+    /// the hash and the issuer with the temporary identifiers of the chosen paths.
+    fn hash_n_degree_quads(
+        &mut self,
+        identifier: &'a str,
+        mut issuer: IdentifierIssuer<'a>,
+    ) -> Result<(String, IdentifierIssuer<'a>)> {
+        self.step()?;
+
+        let mut related_hashes: BTreeMap<String, Vec<&'a str>> = BTreeMap::new();
+        let quads = self.blank_node_to_quads[identifier].clone();
+
+        for quad in quads {
+            let components = [(quad.subject(), "s"), (quad.object(), "o")];
+            let graph_name = quad.graph_name().map(|node| (node, "g"));
+
+            for &(node, position) in components.iter().chain(graph_name.iter()) {
+                match blank_id(node) {
+                    Some(related) if related != identifier => {
+                        let hash = self.hash_related_blank_node(related, quad, &issuer, position);
+                        related_hashes.entry(hash).or_default().push(related);
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        let mut data_to_hash = String::new();
+
+        for (related_hash, blank_nodes) in related_hashes {
+            data_to_hash.push_str(&related_hash);
+
+            let mut chosen_path = String::new();
+            let mut chosen_issuer = None;
+            let mut permutation: Vec<usize> = (0..blank_nodes.len()).collect();
+
+            loop {
+                self.step()?;
+
+                if let Some((path, issuer_copy)) = self.hash_permutation(
+                    permutation.iter().map(|&index| blank_nodes[index]),
+                    &issuer,
+                    &chosen_path,
+                )? {
+                    if chosen_path.is_empty() || path < chosen_path {
+                        chosen_path = path;
+                        chosen_issuer = Some(issuer_copy);
+                    }
+                }
+
+                if !next_permutation(&mut permutation) {
+                    break;
+                }
+            }
+
+            data_to_hash.push_str(&chosen_path);
+
+            if let Some(chosen_issuer) = chosen_issuer {
+                issuer = chosen_issuer;
+            }
+        }
+
+        Ok((
+            self.hash_algorithm.digest_hex(data_to_hash.as_bytes()),
+            issuer,
+        ))
+    }
+
+    /// Builds the path of a permutation of related blank nodes, returns `None` if the path
+    /// can not be smaller than the chosen path.
+    fn hash_permutation<I>(
+        &mut self,
+        permutation: I,
+        issuer: &IdentifierIssuer<'a>,
+        chosen_path: &str,
+    ) -> Result<Option<(String, IdentifierIssuer<'a>)>>
+    where
+        I: Iterator<Item = &'a str>,
+    {
+        let mut issuer_copy = issuer.clone();
+        let mut path = String::new();
+        let mut recursion_list = Vec::new();
+        let is_worse = |path: &str| {
+            !chosen_path.is_empty() && path.len() >= chosen_path.len() && path > chosen_path
+        };
+
+        for related in permutation {
+            path.push_str("_:");
+
+            match self.canonical_issuer.get(related) {
+                Some(identifier) => path.push_str(identifier),
+                None => {
+                    if issuer_copy.get(related).is_none() {
+                        recursion_list.push(related);
+                    }
+
+                    path.push_str(&issuer_copy.issue(related));
+                }
+            }
+
+            if is_worse(&path) {
+                return Ok(None);
+            }
+        }
+
+        for related in recursion_list {
+            let (hash, result_issuer) = self.hash_n_degree_quads(related, issuer_copy.clone())?;
+
+            path.push_str("_:");
+            path.push_str(&issuer_copy.issue(related));
+            path.push('<');
+            path.push_str(&hash);
+            path.push('>');
+
+            issuer_copy = result_issuer;
+
+            if is_worse(&path) {
+                return Ok(None);
+            }
+        }
+
+        Ok(Some((path, issuer_copy)))
+    }
+}
+
+/// Returns the subject, predicate, object and graph name of the quad.
+fn nodes_of(quad: &Quad) -> impl Iterator<Item = &Node> {
+    vec![quad.subject(), quad.predicate(), quad.object()]
+        .into_iter()
+        .chain(quad.graph_name())
+}
+
+/// Returns the label of a blank node.
+fn blank_id(node: &Node) -> Option<&str> {
+    match *node {
+        Node::BlankNode { ref id } => Some(id),
+        _ => None,
+    }
+}
+
+/// Removes the `xsd:string` data type which is implied for literals without language tag in
+/// canonical N-Quads.
+fn normalize_quad(quad: &Quad) -> Quad {
+    let normalize = |node: &Node| match *node {
+        Node::LiteralNode {
+            ref literal,
+            data_type: Some(ref data_type),
+            language: None,
+        } if *data_type.to_string() == XmlDataTypes::String.to_string() => Node::LiteralNode {
+            literal: literal.clone(),
+            data_type: None,
+            language: None,
+        },
+        ref node => node.clone(),
+    };
+
+    Quad::new(
+        quad.subject(),
+        quad.predicate(),
+        &normalize(quad.object()),
+        quad.graph_name(),
+    )
+}
+
+/// Rearranges the indices to the next permutation in lexicographic order, returns `false`
+/// after the last permutation.
+fn next_permutation(indices: &mut [usize]) -> bool {
+    let pivot = match (1..indices.len())
+        .rev()
+        .find(|&i| indices[i - 1] < indices[i])
+    {
+        Some(i) => i - 1,
+        None => return false,
+    };
+
+    let successor = (pivot + 1..indices.len())
+        .rev()
+        .find(|&i| indices[i] > indices[pivot])
+        .unwrap_or(pivot);
+
+    indices.swap(pivot, successor);
+    indices[pivot + 1..].reverse();
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::canonicalization::rdfc::*;
+    use crate::reader::n_triples_parser::NTriplesParser;
+
+    fn canonicalize(input: &str) -> CanonicalDataset {
+        let dataset = NTriplesParser::from_string(input).decode_dataset().unwrap();
+        Canonicalizer::new().canonicalize_dataset(&dataset).unwrap()
+    }
+
+    /// Returns N-Quads of a graph of blank node rings, the labels and order of the
+    /// statements depend on the seed.
+    fn rings(seed: usize) -> String {
+        let sizes = [3, 4, 3, 5];
+        let count: usize = sizes.iter().sum();
+        let label = |node: usize| format!("n{}", (node * 7 + seed * 5) % count);
+
+        let mut lines = Vec::new();
+        let mut start = 0;
+
+        for &size in &sizes {
+            for index in 0..size {
+                let next = start + (index + 1) % size;
+                lines.push(format!(
+                    "_:{} <http://example.org/p> _:{} .",
+                    label(start + index),
+                    label(next)
+                ));
+            }
+
+            start += size;
+        }
+
+        let shift = seed % lines.len();
+        lines.rotate_left(shift);
+        lines.join("\n")
+    }
+
+    #[test]
+    fn independent_of_labels_and_order() {
+        let expected = canonicalize(&rings(0)).to_n_quads();
+
+        for seed in 1..6 {
+            assert_eq!(canonicalize(&rings(seed)).to_n_quads(), expected);
+        }
+
+        assert_eq!(expected.lines().count(), 15);
+        assert!(expected.starts_with("_:c14n0 <http://example.org/p> _:c14n"));
+    }
+
+    #[test]
+    fn issued_identifiers() {
+        let canonical = canonicalize(
+            "_:x <http://example.org/p> _:y .
+             _:y <http://example.org/q> \"z\" _:g .",
+        );
+
+        let issued: Vec<(&str, &str)> = canonical
+            .issued_identifiers()
+            .iter()
+            .map(|(existing, issued)| (existing.as_str(), issued.as_str()))
+            .collect();
+
+        assert_eq!(issued, vec![("g", "c14n0"), ("x", "c14n2"), ("y", "c14n1")]);
+        assert_eq!(canonical.to_dataset().count(), 2);
+    }
+
+    #[test]
+    fn work_limit_exceeded() {
+        let dataset = NTriplesParser::from_string(rings(0))
+            .decode_dataset()
+            .unwrap();
+
+        match Canonicalizer::new()
+            .work_limit(10)
+            .canonicalize_dataset(&dataset)
+        {
+            Err(err) => match *err.error_type() {
+                ErrorType::CanonicalizationLimit => {}
+                _ => panic!("unexpected error type"),
+            },
+            Ok(_) => panic!("work limit was not enforced"),
+        }
+    }
+
+    #[test]
+    fn hash_algorithms() {
+        let dataset = NTriplesParser::from_string(
+            "_:x <http://example.org/p> \"a\" .
+             _:y <http://example.org/p> \"b\" .",
+        )
+        .decode_dataset()
+        .unwrap();
+
+        // the first degree hashes of the blank nodes are ordered differently
+        let sha256 = Canonicalizer::new().canonicalize_dataset(&dataset).unwrap();
+        let sha384 = Canonicalizer::new()
+            .hash_algorithm(HashAlgorithm::Sha384)
+            .canonicalize_dataset(&dataset)
+            .unwrap();
+
+        assert_eq!(sha256.issued_identifiers()["x"], "c14n0");
+        assert_eq!(sha384.issued_identifiers()["x"], "c14n1");
+    }
+
+    #[test]
+    fn next_permutations() {
+        let mut indices = vec![0, 1, 2];
+        let mut count = 1;
+
+        while next_permutation(&mut indices) {
+            count += 1;
+        }
+
+        assert_eq!(count, 6);
+        assert_eq!(indices, vec![2, 1, 0]);
+    }
+}
@@ -0,0 +1,649 @@
+use crate::error::{Error, ErrorType};
+use crate::format::parse_file;
+use crate::graph::Graph;
+use crate::node::Node;
+use crate::specs::rdf_syntax_specs::RdfSyntaxDataTypes;
+use crate::vocab::{owl, rdf, rdfs};
+use crate::Result;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::Path;
+
+/// Types of classes, subjects of these types are turned into constants.
+const CLASS_TYPES: [&str; 3] = [rdfs::CLASS, rdfs::DATATYPE, owl::CLASS];
+
+/// Types of properties, subjects of these types are turned into constants.
+const PROPERTY_TYPES: [&str; 12] = [
+    rdf::PROPERTY,
+    owl::OBJECT_PROPERTY,
+    owl::DATATYPE_PROPERTY,
+    owl::ANNOTATION_PROPERTY,
+    owl::ONTOLOGY_PROPERTY,
+    owl::FUNCTIONAL_PROPERTY,
+    owl::INVERSE_FUNCTIONAL_PROPERTY,
+    owl::TRANSITIVE_PROPERTY,
+    owl::SYMMETRIC_PROPERTY,
+    owl::ASYMMETRIC_PROPERTY,
+    owl::REFLEXIVE_PROPERTY,
+    owl::IRREFLEXIVE_PROPERTY,
+];
+
+/// Names of the constants that every generated module has.
+const RESERVED_NAMES: [&str; 2] = ["DEFAULT_PREFIX", "NAMESPACE_URI"];
+
+/// Rust keywords that can not be used as module names.
+const KEYWORDS: [&str; 51] = [
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "crate",
+    "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "if", "impl", "in",
+    "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref",
+    "return", "self", "Self", "static", "struct", "super", "trait", "true", "try", "type",
+    "typeof", "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+/// Maximum width of the generated code.
+const MAX_WIDTH: usize = 100;
+
+/// Class or property of the vocabulary.
+struct Term {
+    local_name: String,
+    iri: String,
+    is_class: bool,
+    label: Option<String>,
+    comment: Option<String>,
+}
+
+/// Generator of Rust modules with constants for the terms of a vocabulary.
+///
+/// The classes and properties that an ontology declares in the namespace are turned into
+/// `&str` constants of their IRIs, documented with their `rdfs:label` and `rdfs:comment`.
+/// Constant names are the local names in upper snake case, e.g. `SUB_CLASS_OF` for
+/// `subClassOf`. Properties whose names only differ in case from a class end with
+/// `_PROPERTY`. The module is named after the prefix and also contains the constants
+/// `DEFAULT_PREFIX` and `NAMESPACE_URI`, like the modules of `vocab`.
+///
+/// The generated code can be included from a `build.rs` script:
+///
+/// ```ignore
+/// // build.rs
+/// use rdf::codegen::VocabularyGenerator;
+/// use std::env;
+/// use std::path::Path;
+///
+/// fn main() {
+///     let output = Path::new(&env::var("OUT_DIR").unwrap()).join("ex.rs");
+///
+///     VocabularyGenerator::new("ex", "http://example.org/ns#")
+///         .generate_file("ontology.ttl", output)
+///         .unwrap();
+///
+///     println!("cargo:rerun-if-changed=ontology.ttl");
+/// }
+///
+/// // src/lib.rs
+/// include!(concat!(env!("OUT_DIR"), "/ex.rs"));
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use rdf::codegen::VocabularyGenerator;
+/// use rdf::reader::rdf_parser::RdfParser;
+/// use rdf::reader::turtle_parser::TurtleParser;
+///
+/// let input = "@prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+///              @prefix ex: <http://example.org/ns#> .
+///              ex:Person a rdfs:Class ; rdfs:label \"Person\"@en ; rdfs:comment \"A human being.\" .";
+/// let graph = TurtleParser::from_string(input).decode().unwrap();
+///
+/// let code = VocabularyGenerator::new("ex", "http://example.org/ns#")
+///     .generate(&graph)
+///     .unwrap();
+///
+/// assert!(code.contains("    /// Person\n    ///\n    /// A human being.\n    \
+///                        pub const PERSON: &str = \"http://example.org/ns#Person\";\n"));
+/// ```
+#[derive(Debug)]
+pub struct VocabularyGenerator {
+    prefix: String,
+    namespace: String,
+    title: Option<String>,
+}
+
+impl VocabularyGenerator {
+    /// Constructor of `VocabularyGenerator` for the terms in the namespace, the prefix is used
+    /// as module name.
+    pub fn new(prefix: &str, namespace: &str) -> VocabularyGenerator {
+        VocabularyGenerator {
+            prefix: prefix.to_string(),
+            namespace: namespace.to_string(),
+            title: None,
+        }
+    }
+
+    /// Sets the title of the module documentation.
+    ///
+    /// By default, the `rdfs:label` of the ontology is used, if it has one.
+    pub fn title(mut self, title: &str) -> VocabularyGenerator {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    /// Returns the Rust module with the constants for the terms of the ontology.
+    ///
+    /// # Failures
+    ///
+    /// - The prefix is not a valid Rust identifier.
+    /// - Two terms have the same constant name.
+    ///
+    pub fn generate(&self, ontology: &Graph) -> Result<String> {
+        self.check_prefix()?;
+
+        let terms = self.terms(ontology);
+        let names = constant_names(&terms)?;
+
+        let mut code = String::new();
+        code.push_str("// Generated by `rdf::codegen::VocabularyGenerator`, do not edit.\n\n");
+
+        let title = self
+            .title
+            .clone()
+            .or_else(|| {
+                self.ontology_label(ontology)
+                    .map(|label| escape_markdown(&label))
+            })
+            .unwrap_or_else(|| "Vocabulary".to_string());
+
+        push_doc(
+            &mut code,
+            "",
+            &format!("{}, `{}`.", title.trim_end_matches('.'), self.namespace),
+        );
+
+        let renamed = terms
+            .iter()
+            .zip(&names)
+            .find(|&(term, name)| *name != upper_snake_case(&term.local_name));
+
+        if let Some((_, name)) = renamed {
+            code.push_str("///\n");
+            push_doc(
+                &mut code,
+                "",
+                &format!(
+                    "Properties whose names only differ in case from a class end with \
+                     `_PROPERTY`, e.g. `{}`.",
+                    name
+                ),
+            );
+        }
+
+        code.push_str(&format!("pub mod {} {{\n", self.prefix));
+        code.push_str("    /// Prefix that is bound to the namespace by default.\n");
+        push_constant(&mut code, "DEFAULT_PREFIX", &self.prefix);
+        code.push_str("\n    /// Namespace URI of the vocabulary.\n");
+        push_constant(&mut code, "NAMESPACE_URI", &self.namespace);
+
+        for (term, name) in terms.iter().zip(&names) {
+            let label = match term.label {
+                Some(ref label) => escape_markdown(label),
+                None => format!("`{}:{}`", self.prefix, term.local_name),
+            };
+
+            code.push('\n');
+            push_doc(&mut code, "    ", &label);
+
+            if let Some(ref comment) = term.comment {
+                code.push_str("    ///\n");
+                push_doc(&mut code, "    ", &escape_markdown(comment));
+            }
+
+            push_constant(&mut code, name, &term.iri);
+        }
+
+        code.push_str("}\n");
+        Ok(code)
+    }
+
+    /// Parses the ontology file and writes the module with the constants for its terms.
+    ///
+    /// The format of the ontology is determined by the file extension, see
+    /// `format::parse_file`. The output file is only written if its content changes, so
+    /// that `build.rs` scripts do not cause needless recompilation.
+    ///
+    /// # Failures
+    ///
+    /// - The ontology cannot be read or parsed.
+    /// - The module cannot be generated, see `generate`.
+    /// - The output file cannot be written.
+    ///
+    pub fn generate_file<P: AsRef<Path>, Q: AsRef<Path>>(
+        &self,
+        ontology: P,
+        output: Q,
+    ) -> Result<()> {
+        let code = self.generate(&parse_file(ontology)?)?;
+
+        match fs::read_to_string(output.as_ref()) {
+            Ok(ref existing) if *existing == code => Ok(()),
+            _ => Ok(fs::write(output, code)?),
+        }
+    }
+
+    /// Checks that the prefix can be used as module name.
+    fn check_prefix(&self) -> Result<()> {
+        let mut chars = self.prefix.chars();
+        let is_identifier = match chars.next() {
+            Some(c) if c.is_ascii_alphabetic() || c == '_' => {
+                chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+            }
+            _ => false,
+        };
+
+        if is_identifier && self.prefix != "_" && !KEYWORDS.contains(&self.prefix.as_str()) {
+            Ok(())
+        } else {
+            Err(Error::new(
+                ErrorType::InvalidVocabulary,
+                format!("Prefix {} is not a valid module name.", self.prefix),
+            ))
+        }
+    }
+
+    /// Returns the classes and properties of the namespace, ordered by their local names.
+    fn terms(&self, ontology: &Graph) -> Vec<Term> {
+        let mut classes = BTreeSet::new();
+        let mut properties = BTreeSet::new();
+
+        let rdf_type = ontology.create_uri_node(&RdfSyntaxDataTypes::A.to_uri());
+
+        for triple in ontology.get_triples_with_predicate(&rdf_type) {
+            if let Node::UriNode { ref uri } = *triple.object() {
+                if CLASS_TYPES.contains(&uri.to_string().as_str()) {
+                    classes.insert(triple.subject());
+                } else if PROPERTY_TYPES.contains(&uri.to_string().as_str()) {
+                    properties.insert(triple.subject());
+                }
+            }
+        }
+
+        let mut terms = BTreeMap::new();
+
+        for node in classes.iter().chain(properties.iter()) {
+            let iri = match **node {
+                Node::UriNode { ref uri } => uri.to_string(),
+                _ => continue,
+            };
+
+            match iri.strip_prefix(self.namespace.as_str()) {
+                Some(local_name) if !local_name.is_empty() => {
+                    terms.entry(local_name.to_string()).or_insert_with(|| Term {
+                        local_name: local_name.to_string(),
+                        iri: iri.clone(),
+                        is_class: classes.contains(node),
+                        label: text(ontology, node, rdfs::LABEL),
+                        comment: text(ontology, node, rdfs::COMMENT),
+                    });
+                }
+                _ => {}
+            }
+        }
+
+        terms.into_values().collect()
+    }
+
+    /// Returns the label of the ontology, which is identified by the namespace without
+    /// trailing '#' or '/'.
+    fn ontology_label(&self, ontology: &Graph) -> Option<String> {
+        let iri = self.namespace.trim_end_matches(&['#', '/'][..]);
+
+        [iri, self.namespace.as_str()]
+            .iter()
+            .filter_map(|iri| {
+                text(
+                    ontology,
+                    &ontology.create_uri_node_str(iri).ok()?,
+                    rdfs::LABEL,
+                )
+            })
+            .next()
+    }
+}
+
+/// Returns the literal of the node and predicate, English and plain literals are preferred.
+fn text(graph: &Graph, node: &Node, predicate: &str) -> Option<String> {
+    let predicate = graph.create_uri_node_str(predicate).ok()?;
+
+    graph
+        .get_triples_with_subject_and_predicate(node, &predicate)
+        .into_iter()
+        .filter_map(|triple| match *triple.object() {
+            Node::LiteralNode {
+                ref literal,
+                ref language,
+                ..
+            } => {
+                let rank = match *language {
+                    Some(ref language) if language == "en" || language.starts_with("en-") => 0,
+                    None => 1,
+                    Some(_) => 2,
+                };
+                Some((rank, literal.trim()))
+            }
+            _ => None,
+        })
+        .filter(|&(_, literal)| !literal.is_empty())
+        .min()
+        .map(|(_, literal)| literal.to_string())
+}
+
+/// Returns the local name in upper snake case, characters that are not allowed in Rust
+/// identifiers are replaced with '_'.
+pub(crate) fn upper_snake_case(local_name: &str) -> String {
+    let chars: Vec<char> = local_name.chars().collect();
+    let mut name = String::new();
+
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_ascii_uppercase() && i > 0 {
+            let previous = chars[i - 1];
+            let next_is_lowercase = matches!(chars.get(i + 1), Some(c) if c.is_ascii_lowercase());
+
+            if previous.is_ascii_lowercase()
+                || previous.is_ascii_digit()
+                || (previous.is_ascii_uppercase() && next_is_lowercase)
+            {
+                name.push('_');
+            }
+        }
+
+        if c.is_ascii_alphanumeric() {
+            name.push(c.to_ascii_uppercase());
+        } else {
+            name.push('_');
+        }
+    }
+
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        name.insert(0, '_');
+    }
+
+    name
+}
+
+/// Returns the constant names of the terms.
+fn constant_names(terms: &[Term]) -> Result<Vec<String>> {
+    let class_names: BTreeSet<String> = terms
+        .iter()
+        .filter(|term| term.is_class)
+        .map(|term| upper_snake_case(&term.local_name))
+        .collect();
+
+    let names: Vec<String> = terms
+        .iter()
+        .map(|term| {
+            let name = upper_snake_case(&term.local_name);
+
+            if !term.is_class && class_names.contains(&name) {
+                name + "_PROPERTY"
+            } else {
+                name
+            }
+        })
+        .collect();
+
+    let mut used: BTreeMap<&str, &str> = RESERVED_NAMES
+        .iter()
+        .map(|&name| (name, "the module"))
+        .collect();
+
+    for (term, name) in terms.iter().zip(&names) {
+        if name.chars().all(|c| c == '_') {
+            return Err(Error::new(
+                ErrorType::InvalidVocabulary,
+                format!("No constant name for {}.", term.iri),
+            ));
+        }
+
+        if let Some(other) = used.insert(name, &term.iri) {
+            return Err(Error::new(
+                ErrorType::InvalidVocabulary,
+                format!("Constant {} is used for {} and {}.", name, other, term.iri),
+            ));
+        }
+    }
+
+    Ok(names)
+}
+
+/// Appends the text as documentation comment, paragraphs are separated by empty lines.
+fn push_doc(code: &mut String, indent: &str, text: &str) {
+    let paragraphs: Vec<Vec<&str>> = text
+        .split("\n\n")
+        .map(|paragraph| paragraph.split_whitespace().collect::<Vec<_>>())
+        .filter(|words| !words.is_empty())
+        .collect();
+
+    for (i, words) in paragraphs.iter().enumerate() {
+        if i > 0 {
+            code.push_str(indent);
+            code.push_str("///\n");
+        }
+
+        let mut line = String::new();
+
+        for word in words {
+            if !line.is_empty() && indent.len() + 4 + line.len() + 1 + word.len() > MAX_WIDTH {
+                code.push_str(&format!("{}/// {}\n", indent, line));
+                line.clear();
+            }
+
+            if !line.is_empty() {
+                line.push(' ');
+            }
+            line.push_str(word);
+        }
+
+        code.push_str(&format!("{}/// {}\n", indent, line));
+    }
+}
+
+/// Escapes the characters that Markdown would interpret, so that text of the ontology can
+/// neither start code blocks, which rustdoc runs as doctests, nor contain HTML or links.
+fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        if "\\`*_[]<>#|~&".contains(c) {
+            escaped.push('\\');
+        }
+
+        escaped.push(c);
+    }
+
+    escaped
+}
+
+/// Appends a string constant to the module.
+fn push_constant(code: &mut String, name: &str, value: &str) {
+    let declaration = format!("    pub const {}: &str =", name);
+    let literal = format!("{:?};", value);
+
+    if declaration.len() + 1 + literal.len() > MAX_WIDTH {
+        code.push_str(&format!("{}\n        {}\n", declaration, literal));
+    } else {
+        code.push_str(&format!("{} {}\n", declaration, literal));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::codegen::*;
+    use crate::reader::rdf_parser::RdfParser;
+    use crate::reader::turtle_parser::TurtleParser;
+    use std::env;
+
+    const ONTOLOGY: &str = "@prefix rdf: <http://www.w3.org/1999/02/22-rdf-syntax-ns#> .
+        @prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+        @prefix owl: <http://www.w3.org/2002/07/owl#> .
+        @prefix ex: <http://example.org/ns#> .
+
+        <http://example.org/ns> a owl:Ontology ; rdfs:label \"Example ontology\"@en .
+
+        ex:Severity a owl:Class ;
+            rdfs:label \"Schweregrad\"@de, \"severity level\", \"Severity\"@en ;
+            rdfs:comment \"\"\"The class of severities.
+
+                Severities are ordered.\"\"\" .
+        ex:severity a owl:ObjectProperty ; rdfs:range ex:Severity .
+        ex:hasAVeryLongNameThatDoesNotFitIntoASingleLineOfTheGeneratedCode a rdf:Property .
+        ex:XMLValue a rdfs:Datatype .
+        ex:individual a ex:Severity .
+        <http://example.org/other#Thing> a owl:Class .";
+
+    fn ontology() -> Graph {
+        TurtleParser::from_string(ONTOLOGY).decode().unwrap()
+    }
+
+    #[test]
+    fn generate_vocabulary_module() {
+        let code = VocabularyGenerator::new("ex", "http://example.org/ns#")
+            .generate(&ontology())
+            .unwrap();
+
+        let expected = "// Generated by `rdf::codegen::VocabularyGenerator`, do not edit.
+
+/// Example ontology, `http://example.org/ns#`.
+///
+/// Properties whose names only differ in case from a class end with `_PROPERTY`, e.g.
+/// `SEVERITY_PROPERTY`.
+pub mod ex {
+    /// Prefix that is bound to the namespace by default.
+    pub const DEFAULT_PREFIX: &str = \"ex\";
+
+    /// Namespace URI of the vocabulary.
+    pub const NAMESPACE_URI: &str = \"http://example.org/ns#\";
+
+    /// Severity
+    ///
+    /// The class of severities.
+    ///
+    /// Severities are ordered.
+    pub const SEVERITY: &str = \"http://example.org/ns#Severity\";
+
+    /// `ex:XMLValue`
+    pub const XML_VALUE: &str = \"http://example.org/ns#XMLValue\";
+
+    /// `ex:hasAVeryLongNameThatDoesNotFitIntoASingleLineOfTheGeneratedCode`
+    pub const HAS_A_VERY_LONG_NAME_THAT_DOES_NOT_FIT_INTO_A_SINGLE_LINE_OF_THE_GENERATED_CODE: &str =
+        \"http://example.org/ns#hasAVeryLongNameThatDoesNotFitIntoASingleLineOfTheGeneratedCode\";
+
+    /// `ex:severity`
+    pub const SEVERITY_PROPERTY: &str = \"http://example.org/ns#severity\";
+}
+";
+
+        assert_eq!(code, expected);
+
+        let titled = VocabularyGenerator::new("ex", "http://example.org/ns#")
+            .title("Examples")
+            .generate(&Graph::new(None))
+            .unwrap();
+
+        assert!(titled.contains("/// Examples, `http://example.org/ns#`.\npub mod ex {\n"));
+    }
+
+    #[test]
+    fn escape_markdown_of_comments() {
+        let input = "@prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+            @prefix ex: <http://example.org/ns#> .
+            ex:Code a rdfs:Class ; rdfs:label \"<Code>\" ;
+                rdfs:comment \"Example:\\n\\n```\\nassert!(false);\\n```\\n\\nSee [RFC 1] & *more*.\" .";
+        let graph = TurtleParser::from_string(input).decode().unwrap();
+
+        let code = VocabularyGenerator::new("ex", "http://example.org/ns#")
+            .generate(&graph)
+            .unwrap();
+
+        assert!(code.contains(
+            "    /// \\<Code\\>
+    ///
+    /// Example:
+    ///
+    /// \\`\\`\\` assert!(false); \\`\\`\\`
+    ///
+    /// See \\[RFC 1\\] \\& \\*more\\*.
+"
+        ));
+    }
+
+    #[test]
+    fn constant_names_of_local_names() {
+        let names = vec![
+            ("subClassOf", "SUB_CLASS_OF"),
+            ("XMLLiteral", "XML_LITERAL"),
+            ("IRIOrLiteral", "IRI_OR_LITERAL"),
+            ("NCName", "NC_NAME"),
+            ("base64Binary", "BASE64_BINARY"),
+            ("mbox_sha1sum", "MBOX_SHA1SUM"),
+            ("gYearMonth", "G_YEAR_MONTH"),
+            ("content-type", "CONTENT_TYPE"),
+            ("1st", "_1ST"),
+        ];
+
+        for (local_name, name) in names {
+            assert_eq!(upper_snake_case(local_name), name);
+        }
+    }
+
+    #[test]
+    fn reject_invalid_vocabularies() {
+        let input = "@prefix rdf: <http://www.w3.org/1999/02/22-rdf-syntax-ns#> .
+                     @prefix ex: <http://example.org/ns#> .";
+        let cases = vec![
+            (
+                "ex",
+                "ex:familyName a rdf:Property . ex:family_name a rdf:Property .",
+            ),
+            ("ex", "ex:namespaceURI a rdf:Property ."),
+            ("ex", "ex:_ a rdf:Property ."),
+            ("type", ""),
+            ("ex-1", ""),
+        ];
+
+        for (prefix, terms) in cases {
+            let graph = TurtleParser::from_string(input.to_string() + terms)
+                .decode()
+                .unwrap();
+
+            match VocabularyGenerator::new(prefix, "http://example.org/ns#").generate(&graph) {
+                Err(err) => match err.error_type() {
+                    ErrorType::InvalidVocabulary => {}
+                    _ => panic!("Unexpected error type for {}", terms),
+                },
+                Ok(_) => panic!("Vocabulary should be rejected: {} {}", prefix, terms),
+            }
+        }
+    }
+
+    #[test]
+    fn generate_file_from_ontology_file() {
+        let directory = env::temp_dir().join(format!("rdf-codegen-{}", std::process::id()));
+        fs::create_dir_all(&directory).unwrap();
+
+        let ontology_file = directory.join("ontology.ttl");
+        let output = directory.join("ex.rs");
+        fs::write(&ontology_file, ONTOLOGY).unwrap();
+
+        let generator = VocabularyGenerator::new("ex", "http://example.org/ns#");
+        generator.generate_file(&ontology_file, &output).unwrap();
+
+        assert_eq!(
+            fs::read_to_string(&output).unwrap(),
+            generator.generate(&ontology()).unwrap()
+        );
+        assert!(generator
+            .generate_file(directory.join("missing.ttl"), &output)
+            .is_err());
+
+        fs::remove_dir_all(&directory).unwrap();
+    }
+}
@@ -0,0 +1,228 @@
+use crate::graph::Graph;
+use crate::node::Node;
+use crate::triple::Triple;
+use std::collections::BTreeMap;
+
+/// Quad representation, a triple together with the name of the graph it belongs to.
+///
+/// Quads without graph name belong to the default graph.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Debug)]
+pub struct Quad {
+    triple: Triple,
+    graph_name: Option<Node>,
+}
+
+impl Quad {
+    /// Constructor of `Quad`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::dataset::Quad;
+    /// use rdf::node::Node;
+    /// use rdf::uri::Uri;
+    ///
+    /// let subject = Node::BlankNode { id: "a".to_string() };
+    /// let predicate = Node::UriNode { uri: Uri::parse("http://example.org/p").unwrap() };
+    /// let graph_name = Node::UriNode { uri: Uri::parse("http://example.org/g").unwrap() };
+    ///
+    /// let quad = Quad::new(&subject, &predicate, &subject, Some(&graph_name));
+    ///
+    /// assert_eq!(quad.graph_name(), Some(&graph_name));
+    /// ```
+    pub fn new(subject: &Node, predicate: &Node, object: &Node, graph_name: Option<&Node>) -> Quad {
+        Quad::from_triple(&Triple::new(subject, predicate, object), graph_name)
+    }
+
+    /// Creates a quad from a triple of the named graph, or of the default graph.
+    pub fn from_triple(triple: &Triple, graph_name: Option<&Node>) -> Quad {
+        Quad {
+            triple: triple.clone(),
+            graph_name: graph_name.cloned(),
+        }
+    }
+
+    /// Returns a reference to the subject node of the quad.
+    pub fn subject(&self) -> &Node {
+        self.triple.subject()
+    }
+
+    /// Returns a reference to the predicate node of the quad.
+    pub fn predicate(&self) -> &Node {
+        self.triple.predicate()
+    }
+
+    /// Returns a reference to the object node of the quad.
+    pub fn object(&self) -> &Node {
+        self.triple.object()
+    }
+
+    /// Returns the name of the graph of the quad, `None` for the default graph.
+    pub fn graph_name(&self) -> Option<&Node> {
+        self.graph_name.as_ref()
+    }
+
+    /// Returns the triple of the quad.
+    pub fn triple(&self) -> &Triple {
+        &self.triple
+    }
+}
+
+/// Representation of an RDF dataset, a default graph and any number of named graphs.
+#[derive(Debug)]
+pub struct Dataset {
+    default_graph: Graph,
+    named_graphs: BTreeMap<Node, Graph>,
+}
+
+impl Dataset {
+    /// Constructor of an empty dataset.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::dataset::Dataset;
+    ///
+    /// let dataset = Dataset::new();
+    ///
+    /// assert!(dataset.is_empty());
+    /// ```
+    pub fn new() -> Dataset {
+        Dataset::from_graph(Graph::new(None))
+    }
+
+    /// Creates a dataset with the graph as default graph.
+    pub fn from_graph(graph: Graph) -> Dataset {
+        Dataset {
+            default_graph: graph,
+            named_graphs: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the default graph.
+    pub fn default_graph(&self) -> &Graph {
+        &self.default_graph
+    }
+
+    /// Returns the default graph for modification.
+    pub fn default_graph_mut(&mut self) -> &mut Graph {
+        &mut self.default_graph
+    }
+
+    /// Returns the graph with the name, if the dataset contains it.
+    pub fn named_graph(&self, graph_name: &Node) -> Option<&Graph> {
+        self.named_graphs.get(graph_name)
+    }
+
+    /// Returns the graph with the name for modification, an empty graph is added if the dataset
+    /// does not contain it.
+    pub fn named_graph_mut(&mut self, graph_name: &Node) -> &mut Graph {
+        self.named_graphs
+            .entry(graph_name.clone())
+            .or_insert_with(|| Graph::new(None))
+    }
+
+    /// Returns the names of the named graphs.
+    pub fn graph_names(&self) -> Vec<&Node> {
+        self.named_graphs.keys().collect()
+    }
+
+    /// Returns the number of quads in all graphs.
+    pub fn count(&self) -> usize {
+        self.default_graph.count()
+            + self
+                .named_graphs
+                .values()
+                .map(|graph| graph.count())
+                .sum::<usize>()
+    }
+
+    /// Returns `true` if no graph contains any triples.
+    pub fn is_empty(&self) -> bool {
+        self.count() == 0
+    }
+
+    /// Adds the quad to the graph it belongs to.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::dataset::{Dataset, Quad};
+    /// use rdf::node::Node;
+    /// use rdf::uri::Uri;
+    ///
+    /// let node = Node::UriNode { uri: Uri::parse("http://example.org/a").unwrap() };
+    ///
+    /// let mut dataset = Dataset::new();
+    /// dataset.add_quad(&Quad::new(&node, &node, &node, None));
+    /// dataset.add_quad(&Quad::new(&node, &node, &node, Some(&node)));
+    ///
+    /// assert_eq!(dataset.count(), 2);
+    /// assert_eq!(dataset.named_graph(&node).unwrap().count(), 1);
+    /// ```
+    pub fn add_quad(&mut self, quad: &Quad) {
+        match quad.graph_name {
+            Some(ref graph_name) => self.named_graph_mut(graph_name).add_triple(&quad.triple),
+            None => self.default_graph.add_triple(&quad.triple),
+        }
+    }
+
+    /// Returns the quads of all graphs, starting with the default graph.
+    pub fn quads(&self) -> Vec<Quad> {
+        let default_quads = self
+            .default_graph
+            .triples_iter()
+            .map(|triple| Quad::from_triple(triple, None));
+
+        let named_quads = self.named_graphs.iter().flat_map(|(graph_name, graph)| {
+            graph
+                .triples_iter()
+                .map(move |triple| Quad::from_triple(triple, Some(graph_name)))
+        });
+
+        default_quads.chain(named_quads).collect()
+    }
+}
+
+impl Default for Dataset {
+    fn default() -> Dataset {
+        Dataset::new()
+    }
+}
+
+impl From<Graph> for Dataset {
+    fn from(graph: Graph) -> Dataset {
+        Dataset::from_graph(graph)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dataset::*;
+
+    #[test]
+    fn quads_of_all_graphs() {
+        let mut dataset = Dataset::new();
+        let a = dataset
+            .default_graph()
+            .create_uri_node_str("http://example.org/a")
+            .unwrap();
+        let b = dataset
+            .default_graph()
+            .create_uri_node_str("http://example.org/b")
+            .unwrap();
+
+        dataset.add_quad(&Quad::new(&a, &a, &a, Some(&b)));
+        dataset.add_quad(&Quad::new(&a, &a, &b, None));
+        dataset.add_quad(&Quad::new(&b, &a, &a, Some(&a)));
+
+        let graph_names: Vec<Option<Node>> = dataset
+            .quads()
+            .iter()
+            .map(|quad| quad.graph_name().cloned())
+            .collect();
+
+        assert_eq!(graph_names, vec![None, Some(a.clone()), Some(b.clone())]);
+        assert_eq!(dataset.graph_names(), vec![&a, &b]);
+    }
+}
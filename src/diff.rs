@@ -0,0 +1,502 @@
+use crate::error::{Error, ErrorType};
+use crate::graph::Graph;
+use crate::isomorphism::is_isomorphic;
+use crate::node::Node;
+use crate::reader::input_reader::{Position, Span};
+use crate::reader::n_triples_parser::NTriplesParser;
+use crate::reader::rdf_parser::RdfParser;
+use crate::triple::Triple;
+use crate::writer::formatter::n_triples_formatter::NTriplesFormatter;
+use crate::writer::formatter::rdf_formatter::RdfFormatter;
+use crate::Result;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Changes between two versions of a graph, the triples that are removed and the triples that
+/// are added.
+///
+/// Removed triples use the blank node labels of the old graph. Added triples only contain
+/// blank nodes that are new, their labels never clash with labels of the old graph.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct GraphDiff {
+    removed: Vec<Triple>,
+    added: Vec<Triple>,
+}
+
+impl GraphDiff {
+    /// Constructor of an empty `GraphDiff`.
+    pub fn new() -> GraphDiff {
+        GraphDiff::default()
+    }
+
+    /// Returns the triples that are removed, sorted.
+    pub fn removed(&self) -> &[Triple] {
+        &self.removed
+    }
+
+    /// Returns the triples that are added, sorted.
+    pub fn added(&self) -> &[Triple] {
+        &self.added
+    }
+
+    /// Returns `true` if neither triples are removed nor added.
+    pub fn is_empty(&self) -> bool {
+        self.removed.is_empty() && self.added.is_empty()
+    }
+
+    /// Serializes the changes as a single transaction in RDF Patch format.
+    ///
+    /// Deletions are written before additions and all terms are written in N-Triples syntax.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::diff::diff;
+    /// use rdf::reader::rdf_parser::RdfParser;
+    /// use rdf::reader::turtle_parser::TurtleParser;
+    ///
+    /// let old = TurtleParser::from_string("<http://example.org/a> <http://example.org/b> \"c\" .")
+    ///   .decode().unwrap();
+    /// let new = TurtleParser::from_string("<http://example.org/a> <http://example.org/b> \"d\" .")
+    ///   .decode().unwrap();
+    ///
+    /// assert_eq!(diff(&old, &new).to_rdf_patch(),
+    ///            "TX .\n\
+    ///             D <http://example.org/a> <http://example.org/b> \"c\" .\n\
+    ///             A <http://example.org/a> <http://example.org/b> \"d\" .\n\
+    ///             TC .\n");
+    /// ```
+    pub fn to_rdf_patch(&self) -> String {
+        let formatter = NTriplesFormatter::new();
+        let mut output_string = "TX .\n".to_string();
+
+        let changes = self
+            .removed
+            .iter()
+            .map(|triple| ("D", triple))
+            .chain(self.added.iter().map(|triple| ("A", triple)));
+
+        for (operation, triple) in changes {
+            output_string.push_str(&format!(
+                "{} {} {} {} .\n",
+                operation,
+                formatter.format_node(triple.subject()),
+                formatter.format_node(triple.predicate()),
+                formatter.format_node(triple.object())
+            ));
+        }
+
+        output_string.push_str("TC .\n");
+        output_string
+    }
+
+    /// Reads changes from RDF Patch format.
+    ///
+    /// Terms must be written in N-Triples syntax, changes of named graphs are not supported.
+    /// Headers and prefix changes are ignored and the changes of aborted transactions are
+    /// discarded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::diff::GraphDiff;
+    ///
+    /// let patch = "H id <uuid:0b8b8fd9-2b9c-4a4e-9d9b-6d1e0c4f7d11> .
+    ///              TX .
+    ///              D _:b0 <http://example.org/name> \"Alice\" .
+    ///              A _:b0 <http://example.org/name> \"Alicia\" .
+    ///              TC .";
+    ///
+    /// let diff = GraphDiff::from_rdf_patch(patch).unwrap();
+    ///
+    /// assert_eq!(diff.removed().len(), 1);
+    /// assert_eq!(diff.added().len(), 1);
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - Unknown operations or invalid terms.
+    /// - Changes of named graphs.
+    ///
+    pub fn from_rdf_patch(input: &str) -> Result<GraphDiff> {
+        let mut diff = GraphDiff::new();
+        let mut transaction_start = (0, 0);
+        let mut position = Position::default();
+
+        for line in input.split('\n') {
+            let start = position;
+            line.chars().for_each(|c| position.advance(c));
+            let end = position;
+            position.advance('\n');
+
+            let statement = line.trim();
+            let (operation, terms) = match statement.find(char::is_whitespace) {
+                Some(index) => (&statement[..index], statement[index..].trim_start()),
+                None => (statement, ""),
+            };
+
+            let invalid = |message: &str| {
+                Error::new(ErrorType::InvalidReaderInput, message.to_string())
+                    .with_location(Span::new(start, end), statement.to_string())
+            };
+
+            match operation {
+                "" => {}
+                _ if operation.starts_with('#') => {}
+                "H" | "PA" | "PD" | "TC" => {}
+                "TX" => transaction_start = (diff.removed.len(), diff.added.len()),
+                "TA" => {
+                    diff.removed.truncate(transaction_start.0);
+                    diff.added.truncate(transaction_start.1);
+                }
+                "A" | "D" => {
+                    let graph = NTriplesParser::from_string(terms)
+                        .decode()
+                        .map_err(|_| invalid("Invalid triple in RDF Patch."))?;
+
+                    let triple = match graph.triples_iter().next() {
+                        Some(triple) if graph.count() == 1 => triple.clone(),
+                        _ => return Err(invalid("Expected exactly one triple in RDF Patch.")),
+                    };
+
+                    if operation == "A" {
+                        diff.added.push(triple);
+                    } else {
+                        diff.removed.push(triple);
+                    }
+                }
+                _ => return Err(invalid("Unknown operation in RDF Patch.")),
+            }
+        }
+
+        Ok(diff)
+    }
+}
+
+/// Computes the changes that turn the old graph into the new graph.
+///
+/// Graphs are compared as sets of triples. Triples with blank nodes are grouped into
+/// components of triples that share blank nodes, and a component is unchanged if the other
+/// graph contains an isomorphic component, regardless of the blank node labels. Components
+/// that differ are removed and added as a whole.
+///
+/// # Examples
+///
+/// ```
+/// use rdf::diff::diff;
+/// use rdf::reader::rdf_parser::RdfParser;
+/// use rdf::reader::turtle_parser::TurtleParser;
+///
+/// let old = TurtleParser::from_string("@prefix ex: <http://example.org/> .
+///                                      ex:a ex:b [ ex:c \"d\" ] .
+///                                      ex:a ex:e \"f\" .").decode().unwrap();
+/// let new = TurtleParser::from_string("@prefix ex: <http://example.org/> .
+///                                      ex:a ex:b [ ex:c \"d\" ] .
+///                                      ex:a ex:e \"g\" .").decode().unwrap();
+///
+/// let changes = diff(&old, &new);
+///
+/// assert_eq!(changes.removed().len(), 1);
+/// assert_eq!(changes.added().len(), 1);
+/// ```
+pub fn diff(old: &Graph, new: &Graph) -> GraphDiff {
+    let old_triples: BTreeSet<&Triple> = old.triples_iter().collect();
+    let new_triples: BTreeSet<&Triple> = new.triples_iter().collect();
+
+    let (old_ground, old_blank): (Vec<&Triple>, Vec<&Triple>) = old_triples
+        .iter()
+        .partition(|triple| !has_blank_node(triple));
+    let (new_ground, new_blank): (Vec<&Triple>, Vec<&Triple>) = new_triples
+        .iter()
+        .partition(|triple| !has_blank_node(triple));
+
+    let mut removed: Vec<Triple> = old_ground
+        .iter()
+        .filter(|triple| !new_triples.contains(*triple))
+        .map(|&triple| triple.clone())
+        .collect();
+
+    let mut added: Vec<Triple> = new_ground
+        .iter()
+        .filter(|triple| !old_triples.contains(*triple))
+        .map(|&triple| triple.clone())
+        .collect();
+
+    // components of the new graph by their shape, only components with the same shape
+    // can be isomorphic
+    let new_components = components(&new_blank);
+    let mut candidates: BTreeMap<Vec<Triple>, Vec<usize>> = BTreeMap::new();
+
+    for (index, component) in new_components.iter().enumerate() {
+        candidates.entry(shape(component)).or_default().push(index);
+    }
+
+    let mut matched = vec![false; new_components.len()];
+
+    for component in components(&old_blank) {
+        let graph = to_graph(&component);
+        let counterpart = candidates.get(&shape(&component)).and_then(|indices| {
+            indices.iter().cloned().find(|&index| {
+                !matched[index] && is_isomorphic(&graph, &to_graph(&new_components[index])).is_ok()
+            })
+        });
+
+        match counterpart {
+            Some(index) => matched[index] = true,
+            None => removed.extend(component.into_iter().cloned()),
+        }
+    }
+
+    // added blank nodes must not be confused with blank nodes of the old graph
+    let mut used_labels: BTreeSet<String> = old_blank
+        .iter()
+        .chain(new_blank.iter())
+        .flat_map(|triple| blank_labels(triple))
+        .map(|label| label.to_string())
+        .collect();
+
+    let mut labels: BTreeMap<String, String> = BTreeMap::new();
+    let old_labels: BTreeSet<&str> = old_blank
+        .iter()
+        .flat_map(|triple| blank_labels(triple))
+        .collect();
+
+    for (index, component) in new_components.iter().enumerate() {
+        if matched[index] {
+            continue;
+        }
+
+        for triple in component {
+            let mut relabel = |node: &Node| match *node {
+                Node::BlankNode { ref id } => Node::BlankNode {
+                    id: labels
+                        .entry(id.clone())
+                        .or_insert_with(|| fresh_label(id, &old_labels, &mut used_labels))
+                        .clone(),
+                },
+                ref node => node.clone(),
+            };
+
+            let subject = relabel(triple.subject());
+            let predicate = relabel(triple.predicate());
+            let object = relabel(triple.object());
+
+            added.push(Triple::new(&subject, &predicate, &object));
+        }
+    }
+
+    removed.sort();
+    added.sort();
+
+    GraphDiff { removed, added }
+}
+
+/// Returns the label itself if the old graph does not use it, otherwise a new label that
+/// is not used by any graph.
+fn fresh_label(
+    label: &str,
+    old_labels: &BTreeSet<&str>,
+    used_labels: &mut BTreeSet<String>,
+) -> String {
+    if !old_labels.contains(label) {
+        return label.to_string();
+    }
+
+    let mut counter = 1;
+
+    loop {
+        let candidate = format!("{}_{}", label, counter);
+
+        if used_labels.insert(candidate.clone()) {
+            return candidate;
+        }
+
+        counter += 1;
+    }
+}
+
+/// Returns `true` if any node of the triple is a blank node.
+fn has_blank_node(triple: &Triple) -> bool {
+    !blank_labels(triple).is_empty()
+}
+
+/// Returns the labels of the blank nodes of the triple.
+fn blank_labels(triple: &Triple) -> Vec<&str> {
+    [triple.subject(), triple.predicate(), triple.object()]
+        .iter()
+        .filter_map(|node| match **node {
+            Node::BlankNode { ref id } => Some(id.as_str()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Groups triples with blank nodes into components of triples that are connected by
+/// shared blank nodes.
+fn components<'g>(triples: &[&'g Triple]) -> Vec<Vec<&'g Triple>> {
+    let mut parents: BTreeMap<&str, &str> = BTreeMap::new();
+
+    fn root<'a>(parents: &BTreeMap<&'a str, &'a str>, mut label: &'a str) -> &'a str {
+        while let Some(&parent) = parents.get(label) {
+            if parent == label {
+                break;
+            }
+
+            label = parent;
+        }
+
+        label
+    }
+
+    for triple in triples {
+        let labels = blank_labels(triple);
+
+        for label in &labels {
+            parents.entry(label).or_insert(label);
+        }
+
+        for label in &labels[1..] {
+            let first = root(&parents, labels[0]);
+            let other = root(&parents, label);
+            parents.insert(other, first);
+        }
+    }
+
+    let mut components: BTreeMap<&str, Vec<&Triple>> = BTreeMap::new();
+
+    for triple in triples {
+        let label = blank_labels(triple)[0];
+        components
+            .entry(root(&parents, label))
+            .or_default()
+            .push(triple);
+    }
+
+    components.into_values().collect()
+}
+
+/// Returns the triples of the component with all blank nodes replaced by the same blank node.
+fn shape(component: &[&Triple]) -> Vec<Triple> {
+    let anonymize = |node: &Node| match *node {
+        Node::BlankNode { .. } => Node::BlankNode { id: String::new() },
+        ref node => node.clone(),
+    };
+
+    let mut shape: Vec<Triple> = component
+        .iter()
+        .map(|triple| {
+            Triple::new(
+                &anonymize(triple.subject()),
+                &anonymize(triple.predicate()),
+                &anonymize(triple.object()),
+            )
+        })
+        .collect();
+
+    shape.sort();
+    shape
+}
+
+fn to_graph(triples: &[&Triple]) -> Graph {
+    let mut graph = Graph::new(None);
+
+    for triple in triples {
+        graph.add_triple(triple);
+    }
+
+    graph
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::diff::*;
+    use crate::reader::turtle_parser::TurtleParser;
+
+    fn parse(input: &str) -> Graph {
+        TurtleParser::from_string("@prefix ex: <http://example.org/> .\n".to_string() + input)
+            .decode()
+            .unwrap()
+    }
+
+    fn patched(old: &Graph, new: &Graph) -> Graph {
+        let mut graph = parse("");
+        graph.add_triples(&old.triples_iter().cloned().collect::<Vec<_>>());
+        graph.apply_patch(&diff(old, new));
+        graph
+    }
+
+    #[test]
+    fn isomorphic_graphs_have_no_changes() {
+        let old = parse("_:a ex:knows _:b . _:b ex:name \"Bob\" . ex:c ex:d ex:e .");
+        let new = parse("ex:c ex:d ex:e . _:x ex:name \"Bob\" . _:y ex:knows _:x .");
+
+        assert!(diff(&old, &new).is_empty());
+    }
+
+    #[test]
+    fn changed_components_are_replaced() {
+        let old = parse("_:a ex:knows _:b . _:b ex:name \"Bob\" . _:c ex:name \"Carl\" .");
+        let new = parse("_:x ex:knows _:y . _:y ex:name \"Bobby\" . _:z ex:name \"Carl\" .");
+
+        let changes = diff(&old, &new);
+
+        assert_eq!(changes.removed().len(), 2);
+        assert_eq!(changes.added().len(), 2);
+        assert_eq!(patched(&old, &new), new);
+    }
+
+    #[test]
+    fn added_blank_nodes_are_relabeled() {
+        // the label of the new blank node is used for a different blank node in the old graph
+        let old = parse("_:a ex:name \"Alice\" .");
+        let new = parse("_:b ex:name \"Alice\" . _:a ex:name \"Bob\" .");
+
+        let changes = diff(&old, &new);
+
+        assert!(changes.removed().is_empty());
+        assert_eq!(
+            *changes.added()[0].subject(),
+            Node::BlankNode {
+                id: "a_1".to_string()
+            }
+        );
+        assert_eq!(patched(&old, &new), new);
+    }
+
+    #[test]
+    fn rdf_patch_round_trip() {
+        let old = parse("ex:a ex:b \"c\\\"d\" . _:a ex:knows ex:b .");
+        let new = parse("ex:a ex:b \"e\"@en . _:a ex:knows ex:c .");
+
+        let changes = diff(&old, &new);
+        let patch = changes.to_rdf_patch();
+
+        assert_eq!(GraphDiff::from_rdf_patch(&patch).unwrap(), changes);
+    }
+
+    #[test]
+    fn aborted_transactions() {
+        let patch = "TX .
+                     A <http://example.org/a> <http://example.org/b> \"c\" .
+                     TC .
+                     TX .
+                     D <http://example.org/a> <http://example.org/b> \"c\" .
+                     TA .";
+
+        let changes = GraphDiff::from_rdf_patch(patch).unwrap();
+
+        assert_eq!(changes.added().len(), 1);
+        assert!(changes.removed().is_empty());
+    }
+
+    #[test]
+    fn invalid_rdf_patch() {
+        let patch = "TX .\nA <http://example.org/a> \"b\" \"c\" .\nTC .";
+
+        match GraphDiff::from_rdf_patch(patch) {
+            Err(err) => assert_eq!(err.location().unwrap().start().line(), 2),
+            Ok(_) => panic!("invalid triple was accepted"),
+        }
+
+        assert!(GraphDiff::from_rdf_patch("X <http://example.org/a> .").is_err());
+    }
+}
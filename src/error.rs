@@ -1,6 +1,7 @@
-use crate::reader::input_reader::InputChars;
+use crate::reader::input_reader::{InputChars, Span};
 use std::error::Error as StdError;
 use std::fmt;
+use std::io;
 
 /// Different types of errors that can occur.
 #[derive(Debug)]
@@ -23,15 +24,51 @@ pub enum ErrorType {
     /// Incorrect namespace.
     InvalidNamespace,
 
+    /// IRI is malformed (e.g. contains spaces or has an invalid scheme).
+    InvalidUri,
+
+    /// Literal is not valid for its data type (e.g. "abc" as `xsd:integer`).
+    InvalidLiteral,
+
     /// RDF SPARQL reader reads invalid SPARQL input.
     InvalidSparqlInput,
+
+    /// RDF JSON-LD reader reads invalid JSON-LD input.
+    InvalidJsonLdInput,
+
+    /// Writing to or reading from an I/O stream fails.
+    IoError,
+
+    /// RDF format is unknown or not supported.
+    UnsupportedFormat,
+
+    /// RDF collection or container is malformed (e.g. a cyclic list) or an index is out of range.
+    InvalidCollection,
+
+    /// Resource does not have the expected statements (e.g. several objects where one is expected).
+    InvalidResource,
+
+    /// Vocabulary cannot be turned into code (e.g. two terms map to the same constant name).
+    InvalidVocabulary,
+
+    /// Canonicalization exceeds its work limit (e.g. for graphs crafted to be expensive).
+    CanonicalizationLimit,
+
+    /// Literal is valid but its value exceeds the supported precision (e.g. an `xsd:integer`
+    /// beyond the range of `i128`).
+    UnsupportedPrecision,
 }
 
 /// An error related to the rdf-rs module.
+///
+/// Errors that occur while reading input carry the location in the input and a snippet
+/// of the text that was read last.
 #[derive(Debug)]
 pub struct Error {
     error_type: ErrorType,
     error: Box<StdError>,
+    location: Option<Span>,
+    snippet: Option<String>,
 }
 
 impl Error {
@@ -43,6 +80,43 @@ impl Error {
         Error {
             error_type,
             error: error.into(),
+            location: None,
+            snippet: None,
+        }
+    }
+
+    /// Adds the location in the input and a snippet of the offending text to the error.
+    ///
+    /// The location is only added if the error has no location yet, so the most precise
+    /// location is kept when errors are passed on.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::error::{Error, ErrorType};
+    /// use rdf::reader::input_reader::{Position, Span};
+    ///
+    /// let span = Span::new(Position::new(4, 2, 1), Position::new(7, 2, 4));
+    /// let err = Error::new(ErrorType::InvalidToken, "Invalid token.")
+    ///     .with_location(span, "_:a _:b".to_string());
+    ///
+    /// assert_eq!(err.location(), Some(&span));
+    /// assert_eq!(err.to_string(), "Invalid token. (line 2, column 1: `_:a _:b`)");
+    /// ```
+    pub fn with_location(mut self, location: Span, snippet: String) -> Error {
+        if self.location.is_none() {
+            self.location = Some(location);
+            self.snippet = Some(snippet);
+        }
+
+        self
+    }
+
+    /// Copies the location and snippet of another error to this error.
+    pub fn with_location_of(self, other: &Error) -> Error {
+        match (other.location, &other.snippet) {
+            (Some(location), Some(snippet)) => self.with_location(location, snippet.clone()),
+            _ => self,
         }
     }
 
@@ -50,11 +124,29 @@ impl Error {
     pub fn error_type(&self) -> &ErrorType {
         &self.error_type
     }
+
+    /// Returns the location in the input where the error occurred, if known.
+    pub fn location(&self) -> Option<&Span> {
+        self.location.as_ref()
+    }
+
+    /// Returns the text of the input line that was read last before the error occurred.
+    pub fn snippet(&self) -> Option<&str> {
+        self.snippet.as_deref()
+    }
 }
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        self.error.fmt(f)
+        self.error.fmt(f)?;
+
+        match (self.location, &self.snippet) {
+            (Some(location), Some(snippet)) if !snippet.is_empty() => {
+                write!(f, " ({}: `{}`)", location.start(), snippet)
+            }
+            (Some(location), _) => write!(f, " ({})", location.start()),
+            _ => Ok(()),
+        }
     }
 }
 
@@ -63,3 +155,9 @@ impl StdError for Error {
         self.error.description()
     }
 }
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Error {
+        Error::new(ErrorType::IoError, err)
+    }
+}
@@ -0,0 +1,632 @@
+use crate::dataset::Dataset;
+use crate::error::{Error, ErrorType};
+use crate::graph::Graph;
+use crate::json::JsonValue;
+use crate::reader::json_ld_parser::JsonLdParser;
+use crate::reader::n_triples_parser::NTriplesParser;
+use crate::reader::rdf_parser::RdfParser;
+use crate::reader::turtle_parser::TurtleParser;
+use crate::writer::json_ld_writer::JsonLdWriter;
+use crate::writer::n_triples_writer::NTriplesWriter;
+use crate::writer::rdf_writer::RdfWriter;
+use crate::writer::turtle_writer::TurtleWriter;
+use crate::Result;
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Cursor, Read, Write};
+use std::path::Path;
+
+/// RDF serialization formats known to the format registry.
+///
+/// Formats can be looked up by media type, file extension or by sniffing the content
+/// and provide the corresponding parsers and writers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RdfFormat {
+    /// Terse RDF Triple Language.
+    Turtle,
+
+    /// Line-based N-Triples syntax.
+    NTriples,
+
+    /// Line-based N-Quads syntax.
+    NQuads,
+
+    /// RDF/XML syntax.
+    RdfXml,
+
+    /// JSON-LD syntax.
+    JsonLd,
+}
+
+impl RdfFormat {
+    /// Returns all formats of the registry.
+    pub fn all() -> &'static [RdfFormat] {
+        &[
+            RdfFormat::Turtle,
+            RdfFormat::NTriples,
+            RdfFormat::NQuads,
+            RdfFormat::RdfXml,
+            RdfFormat::JsonLd,
+        ]
+    }
+
+    /// Returns the human readable name of the format.
+    pub fn name(&self) -> &'static str {
+        match *self {
+            RdfFormat::Turtle => "Turtle",
+            RdfFormat::NTriples => "N-Triples",
+            RdfFormat::NQuads => "N-Quads",
+            RdfFormat::RdfXml => "RDF/XML",
+            RdfFormat::JsonLd => "JSON-LD",
+        }
+    }
+
+    /// Returns the media types of the format, the preferred media type comes first.
+    pub fn media_types(&self) -> &'static [&'static str] {
+        match *self {
+            RdfFormat::Turtle => &["text/turtle", "application/x-turtle"],
+            RdfFormat::NTriples => &["application/n-triples"],
+            RdfFormat::NQuads => &["application/n-quads"],
+            RdfFormat::RdfXml => &["application/rdf+xml"],
+            RdfFormat::JsonLd => &["application/ld+json"],
+        }
+    }
+
+    /// Returns the file extensions of the format, the preferred extension comes first.
+    pub fn file_extensions(&self) -> &'static [&'static str] {
+        match *self {
+            RdfFormat::Turtle => &["ttl"],
+            RdfFormat::NTriples => &["nt"],
+            RdfFormat::NQuads => &["nq"],
+            RdfFormat::RdfXml => &["rdf", "owl"],
+            RdfFormat::JsonLd => &["jsonld"],
+        }
+    }
+
+    /// Returns the preferred media type of the format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::format::RdfFormat;
+    ///
+    /// assert_eq!(RdfFormat::Turtle.media_type(), "text/turtle");
+    /// ```
+    pub fn media_type(&self) -> &'static str {
+        self.media_types()[0]
+    }
+
+    /// Returns the preferred file extension of the format (without the leading '.').
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::format::RdfFormat;
+    ///
+    /// assert_eq!(RdfFormat::NTriples.file_extension(), "nt");
+    /// ```
+    pub fn file_extension(&self) -> &'static str {
+        self.file_extensions()[0]
+    }
+
+    /// Returns the format for a media type.
+    ///
+    /// Media type parameters (e.g. `charset`) are ignored, the comparison is case-insensitive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::format::RdfFormat;
+    ///
+    /// assert_eq!(RdfFormat::from_media_type("text/turtle; charset=utf-8"),
+    ///            Some(RdfFormat::Turtle));
+    /// assert_eq!(RdfFormat::from_media_type("text/html"), None);
+    /// ```
+    pub fn from_media_type(media_type: &str) -> Option<RdfFormat> {
+        let essence = media_type
+            .split(';')
+            .next()
+            .unwrap_or("")
+            .trim()
+            .to_lowercase();
+
+        RdfFormat::all()
+            .iter()
+            .find(|format| format.media_types().contains(&essence.as_str()))
+            .cloned()
+    }
+
+    /// Returns the format for a file extension, with or without the leading '.'.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::format::RdfFormat;
+    ///
+    /// assert_eq!(RdfFormat::from_extension(".jsonld"), Some(RdfFormat::JsonLd));
+    /// assert_eq!(RdfFormat::from_extension("TTL"), Some(RdfFormat::Turtle));
+    /// ```
+    pub fn from_extension(extension: &str) -> Option<RdfFormat> {
+        let extension = extension.trim_start_matches('.').to_lowercase();
+
+        RdfFormat::all()
+            .iter()
+            .find(|format| format.file_extensions().contains(&extension.as_str()))
+            .cloned()
+    }
+
+    /// Returns the format for the extension of a file path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::format::RdfFormat;
+    ///
+    /// assert_eq!(RdfFormat::from_path("data/people.nt"), Some(RdfFormat::NTriples));
+    /// assert_eq!(RdfFormat::from_path("data/people"), None);
+    /// ```
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Option<RdfFormat> {
+        path.as_ref()
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .and_then(RdfFormat::from_extension)
+    }
+
+    /// Guesses the format of the content.
+    ///
+    /// Content is only considered JSON-LD if it is a JSON object or array, so that Turtle
+    /// starting with a blank node property list is not mistaken for JSON-LD. Returns `None`
+    /// if the content does not look like any of the formats.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::format::RdfFormat;
+    ///
+    /// assert_eq!(RdfFormat::sniff("@prefix ex: <http://example.org/> ."),
+    ///            Some(RdfFormat::Turtle));
+    /// assert_eq!(RdfFormat::sniff("<http://example.org/a> <http://example.org/b> \"c\" ."),
+    ///            Some(RdfFormat::NTriples));
+    /// assert_eq!(RdfFormat::sniff("{\"@id\": \"http://example.org/a\"}"),
+    ///            Some(RdfFormat::JsonLd));
+    /// assert_eq!(RdfFormat::sniff("[ <http://example.org/b> \"c\" ] ."),
+    ///            Some(RdfFormat::Turtle));
+    /// ```
+    pub fn sniff(content: &str) -> Option<RdfFormat> {
+        let content = content.trim_start_matches('\u{feff}').trim_start();
+
+        if content.starts_with('{') || content.starts_with('[') {
+            match JsonValue::parse(content) {
+                Ok(JsonValue::Object(_)) | Ok(JsonValue::Array(_)) => {
+                    return Some(RdfFormat::JsonLd)
+                }
+                _ => {}
+            }
+        }
+
+        if content.starts_with("<?xml") || content.starts_with("<rdf:RDF") {
+            return Some(RdfFormat::RdfXml);
+        }
+
+        let mut line_based_format = None;
+
+        for line in content.lines().map(|line| line.trim()) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            match sniff_line(line) {
+                Some(format) if line_based_format.is_none() => line_based_format = Some(format),
+                Some(format) if line_based_format == Some(format) => {}
+                Some(RdfFormat::NQuads) => line_based_format = Some(RdfFormat::NQuads),
+                Some(_) => {}
+                // statements spanning multiple lines or using abbreviations are Turtle
+                None => return Some(RdfFormat::Turtle),
+            }
+        }
+
+        line_based_format
+    }
+
+    /// Returns `true` if the crate provides a parser for the format that decodes into a graph.
+    ///
+    /// N-Quads can only be decoded into a dataset, see `has_dataset_parser`.
+    pub fn has_parser(&self) -> bool {
+        match *self {
+            RdfFormat::Turtle | RdfFormat::NTriples | RdfFormat::JsonLd => true,
+            RdfFormat::NQuads | RdfFormat::RdfXml => false,
+        }
+    }
+
+    /// Returns `true` if the crate provides a parser for the format that decodes into a dataset.
+    pub fn has_dataset_parser(&self) -> bool {
+        match *self {
+            RdfFormat::Turtle | RdfFormat::NTriples | RdfFormat::NQuads | RdfFormat::JsonLd => true,
+            RdfFormat::RdfXml => false,
+        }
+    }
+
+    /// Returns `true` if the crate provides a writer for the format.
+    pub fn has_writer(&self) -> bool {
+        match *self {
+            RdfFormat::Turtle | RdfFormat::NTriples | RdfFormat::NQuads | RdfFormat::JsonLd => true,
+            RdfFormat::RdfXml => false,
+        }
+    }
+
+    /// Returns a parser of the format that reads from the provided input.
+    ///
+    /// N-Quads statements may belong to named graphs, so N-Quads are parsed with
+    /// `parse_dataset` instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::format::RdfFormat;
+    ///
+    /// let input = "<http://example.org/a> <http://example.org/b> \"c\" .";
+    ///
+    /// let mut parser = RdfFormat::NTriples.parser(input.as_bytes()).unwrap();
+    /// assert_eq!(parser.decode().unwrap().count(), 1);
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - The crate does not provide a parser for the format.
+    ///
+    pub fn parser<'a, R: Read + 'a>(&self, input: R) -> Result<Box<dyn RdfParser + 'a>> {
+        match *self {
+            RdfFormat::Turtle => Ok(Box::new(TurtleParser::from_reader(input))),
+            RdfFormat::NTriples => Ok(Box::new(NTriplesParser::from_reader(input))),
+            RdfFormat::JsonLd => Ok(Box::new(JsonLdParser::from_reader(input))),
+            RdfFormat::NQuads => Err(Error::new(
+                ErrorType::UnsupportedFormat,
+                "N-Quads can only be parsed into a dataset.",
+            )),
+            RdfFormat::RdfXml => Err(self.unsupported("Parsing")),
+        }
+    }
+
+    /// Parses the input of the format into a dataset.
+    ///
+    /// Statements of the formats for graphs are added to the default graph.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::format::RdfFormat;
+    ///
+    /// let input = "<http://example.org/a> <http://example.org/b> \"c\" <http://example.org/g> .";
+    ///
+    /// let dataset = RdfFormat::NQuads.parse_dataset(input.as_bytes()).unwrap();
+    /// assert_eq!(dataset.graph_names().len(), 1);
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - The crate does not provide a parser for the format.
+    /// - Invalid input for the format.
+    ///
+    pub fn parse_dataset<R: Read>(&self, input: R) -> Result<Dataset> {
+        match *self {
+            RdfFormat::NQuads => NTriplesParser::from_reader(input).decode_dataset(),
+            _ => Ok(Dataset::from_graph(self.parser(input)?.decode()?)),
+        }
+    }
+
+    /// Returns a writer of the format that uses the namespaces of the provided graph.
+    ///
+    /// N-Quads output only consists of triples in the default graph.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::format::RdfFormat;
+    /// use rdf::graph::Graph;
+    ///
+    /// let graph = Graph::new(None);
+    ///
+    /// let writer = RdfFormat::Turtle.writer(&graph).unwrap();
+    /// assert_eq!(writer.write_to_string(&graph).unwrap(), "");
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - The crate does not provide a writer for the format.
+    ///
+    pub fn writer<'a>(&self, graph: &'a Graph) -> Result<Box<dyn RdfWriter + 'a>> {
+        match *self {
+            RdfFormat::Turtle => Ok(Box::new(TurtleWriter::new(graph.namespaces()))),
+            RdfFormat::NTriples | RdfFormat::NQuads => Ok(Box::new(NTriplesWriter::new())),
+            RdfFormat::JsonLd => Ok(Box::new(JsonLdWriter::new())),
+            RdfFormat::RdfXml => Err(self.unsupported("Writing")),
+        }
+    }
+
+    /// Parses a string of the format into a graph.
+    ///
+    /// # Failures
+    ///
+    /// - The crate does not provide a parser for the format.
+    /// - Invalid input for the format.
+    ///
+    pub fn parse_string<S: Into<String>>(&self, input: S) -> Result<Graph> {
+        self.parser(Cursor::new(input.into().into_bytes()))?
+            .decode()
+    }
+
+    /// Generates the syntax of the format for a graph.
+    ///
+    /// # Failures
+    ///
+    /// - The crate does not provide a writer for the format.
+    /// - The graph cannot be represented in the format.
+    ///
+    pub fn write_to_string(&self, graph: &Graph) -> Result<String> {
+        self.writer(graph)?.write_to_string(graph)
+    }
+
+    /// Creates the error for formats that are known but not supported.
+    fn unsupported(&self, operation: &str) -> Error {
+        Error::new(
+            ErrorType::UnsupportedFormat,
+            format!("{} {} is not supported.", operation, self.name()),
+        )
+    }
+}
+
+/// Parses a file into a graph.
+///
+/// The format is determined by the file extension. Files with unknown extensions are
+/// sniffed to guess the format.
+///
+/// # Failures
+///
+/// - The file cannot be read.
+/// - The format cannot be determined or is not supported.
+/// - Invalid input for the format.
+///
+pub fn parse_file<P: AsRef<Path>>(path: P) -> Result<Graph> {
+    let path = path.as_ref();
+
+    match RdfFormat::from_path(path) {
+        Some(format) => format.parser(BufReader::new(File::open(path)?))?.decode(),
+        None => {
+            let mut content = String::new();
+            File::open(path)?.read_to_string(&mut content)?;
+
+            match RdfFormat::sniff(&content) {
+                Some(format) => format.parse_string(content),
+                None => Err(Error::new(
+                    ErrorType::UnsupportedFormat,
+                    format!("Unable to determine the RDF format of {}.", path.display()),
+                )),
+            }
+        }
+    }
+}
+
+/// Writes a graph to a file in the format that corresponds to the file extension.
+///
+/// # Failures
+///
+/// - The format of the file extension is unknown or not supported.
+/// - The graph cannot be represented in the format.
+/// - The file cannot be written.
+///
+pub fn write_file<P: AsRef<Path>>(path: P, graph: &Graph) -> Result<()> {
+    let path = path.as_ref();
+
+    let format = RdfFormat::from_path(path).ok_or_else(|| {
+        Error::new(
+            ErrorType::UnsupportedFormat,
+            format!("Unknown RDF file extension of {}.", path.display()),
+        )
+    })?;
+
+    let writer = format.writer(graph)?;
+    let mut output = BufWriter::new(File::create(path)?);
+    writer.write_to(graph, &mut output)?;
+    output.flush()?;
+    Ok(())
+}
+
+/// Guesses whether a single line is an N-Triples or N-Quads statement.
+///
+/// Returns `None` if the line is not a complete line-based statement.
+fn sniff_line(line: &str) -> Option<RdfFormat> {
+    let mut chars = line.chars().peekable();
+    let mut terms = 0;
+
+    loop {
+        while chars.peek().is_some_and(|c| c.is_whitespace()) {
+            chars.next();
+        }
+
+        match chars.next()? {
+            '.' => {
+                let rest: String = chars.collect();
+                let rest = rest.trim();
+
+                if !rest.is_empty() && !rest.starts_with('#') {
+                    return None;
+                }
+
+                return match terms {
+                    3 => Some(RdfFormat::NTriples),
+                    4 => Some(RdfFormat::NQuads),
+                    _ => None,
+                };
+            }
+            '<' => {
+                // IRI
+                if !chars.any(|c| c == '>') {
+                    return None;
+                }
+            }
+            '_' => {
+                // blank node label
+                if chars.next()? != ':' {
+                    return None;
+                }
+
+                while chars.peek().is_some_and(|c| !c.is_whitespace()) {
+                    chars.next();
+                }
+            }
+            '"' => {
+                // literal with optional language tag or data type
+                let mut escaped = false;
+
+                loop {
+                    match chars.next()? {
+                        '\\' if !escaped => escaped = true,
+                        '"' if !escaped => break,
+                        _ => escaped = false,
+                    }
+                }
+
+                while chars.peek().is_some_and(|c| !c.is_whitespace()) {
+                    chars.next();
+                }
+            }
+            _ => return None,
+        }
+
+        terms += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::ErrorType;
+    use crate::format::{self, RdfFormat};
+    use crate::graph::Graph;
+    use crate::triple::Triple;
+    use crate::uri::Uri;
+    use std::env;
+    use std::fs;
+
+    #[test]
+    fn lookup_formats() {
+        for format in RdfFormat::all() {
+            assert_eq!(
+                RdfFormat::from_media_type(format.media_type()),
+                Some(*format)
+            );
+            assert_eq!(
+                RdfFormat::from_extension(format.file_extension()),
+                Some(*format)
+            );
+        }
+
+        assert_eq!(
+            RdfFormat::from_media_type("Application/LD+JSON;profile=expanded"),
+            Some(RdfFormat::JsonLd)
+        );
+        assert_eq!(RdfFormat::from_path("a/b.c/d.nq"), Some(RdfFormat::NQuads));
+        assert_eq!(RdfFormat::from_path("a/b.txt"), None);
+    }
+
+    #[test]
+    fn sniff_formats() {
+        assert_eq!(
+            RdfFormat::sniff("<?xml version=\"1.0\"?><rdf:RDF></rdf:RDF>"),
+            Some(RdfFormat::RdfXml)
+        );
+        assert_eq!(
+            RdfFormat::sniff("\u{feff}  [{\"@id\": \"http://example.org/a\"}]"),
+            Some(RdfFormat::JsonLd)
+        );
+        assert_eq!(
+            RdfFormat::sniff(
+                "# comment\n\
+                 _:a <http://example.org/b> \"c \\\" .\"@en .\n\
+                 _:a <http://example.org/b> \"d\"^^<http://example.org/e> . # comment"
+            ),
+            Some(RdfFormat::NTriples)
+        );
+        assert_eq!(
+            RdfFormat::sniff(
+                "_:a <http://example.org/b> _:c .\n\
+                 _:a <http://example.org/b> _:c <http://example.org/g> ."
+            ),
+            Some(RdfFormat::NQuads)
+        );
+        assert_eq!(
+            RdfFormat::sniff("_:a <http://example.org/b> _:c ;\n    <http://example.org/d> _:e ."),
+            Some(RdfFormat::Turtle)
+        );
+        assert_eq!(
+            RdfFormat::sniff("[ <http://example.org/b> <http://example.org/c> ] ."),
+            Some(RdfFormat::Turtle)
+        );
+        assert_eq!(
+            RdfFormat::sniff("[] <http://example.org/b> [ <http://example.org/c> \"d\" ] ."),
+            Some(RdfFormat::Turtle)
+        );
+        assert_eq!(RdfFormat::sniff(""), None);
+    }
+
+    #[test]
+    fn unsupported_formats() {
+        let graph = Graph::new(None);
+
+        match RdfFormat::RdfXml.writer(&graph) {
+            Err(err) => match *err.error_type() {
+                ErrorType::UnsupportedFormat => {}
+                _ => panic!("unexpected error type"),
+            },
+            Ok(_) => panic!("RDF/XML writer is not supported"),
+        }
+
+        assert!(RdfFormat::NQuads.parse_string("").is_err());
+        assert!(RdfFormat::RdfXml.parse_dataset("".as_bytes()).is_err());
+        assert!(!RdfFormat::RdfXml.has_parser());
+        assert!(!RdfFormat::NQuads.has_parser());
+        assert!(RdfFormat::NQuads.has_dataset_parser());
+        assert!(RdfFormat::NQuads.has_writer());
+    }
+
+    #[test]
+    fn parse_datasets() {
+        let input = "<http://example.org/a> <http://example.org/b> \"c\" .
+                     <http://example.org/a> <http://example.org/b> \"d\" <http://example.org/g> .";
+
+        let dataset = RdfFormat::NQuads.parse_dataset(input.as_bytes()).unwrap();
+        assert_eq!(dataset.count(), 2);
+        assert_eq!(dataset.default_graph().count(), 1);
+
+        let dataset = RdfFormat::Turtle
+            .parse_dataset("<http://example.org/a> <http://example.org/b> \"c\" .".as_bytes())
+            .unwrap();
+        assert_eq!(dataset.default_graph().count(), 1);
+        assert!(dataset.graph_names().is_empty());
+    }
+
+    #[test]
+    fn write_and_parse_files() {
+        let mut graph = Graph::new(None);
+        let subject = graph.create_uri_node(&Uri::parse("http://example.org/a").unwrap());
+        let predicate = graph.create_uri_node(&Uri::parse("http://example.org/b").unwrap());
+        let object = graph.create_literal_node("c".to_string());
+        graph.add_triple(&Triple::new(&subject, &predicate, &object));
+
+        let directory = env::temp_dir().join(format!("rdf-format-{}", std::process::id()));
+        fs::create_dir_all(&directory).unwrap();
+
+        for format in RdfFormat::all().iter().filter(|format| format.has_parser()) {
+            let path = directory.join(format!("graph.{}", format.file_extension()));
+            format::write_file(&path, &graph).unwrap();
+            assert_eq!(format::parse_file(&path).unwrap().count(), 1);
+
+            // unknown extensions are sniffed
+            let unknown_path = directory.join(format!("{}.data", format.file_extension()));
+            fs::rename(&path, &unknown_path).unwrap();
+            assert_eq!(format::parse_file(&unknown_path).unwrap().count(), 1);
+        }
+
+        assert!(format::write_file(directory.join("graph.data"), &graph).is_err());
+
+        fs::remove_dir_all(&directory).unwrap();
+    }
+}
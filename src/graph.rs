@@ -1,12 +1,51 @@
+use crate::diff::GraphDiff;
+use crate::error::{Error, ErrorType};
+use crate::isomorphism::is_isomorphic;
 use crate::namespace::*;
 use crate::node::*;
-use std::collections::HashMap;
+use crate::reader::blank_node_scope::BlankNodeScope;
+use crate::reader::rdf_sink::MergeSink;
+use crate::resource::Resource;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::slice::Iter;
 use crate::triple::*;
 use crate::uri::Uri;
 use crate::Result;
+use crate::specs::rdf_syntax_specs::RdfSyntaxDataTypes;
 use crate::specs::xml_specs::XmlDataTypes;
 
+/// Types of RDF containers.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ContainerType {
+    /// Unordered container, `rdf:Bag`.
+    Bag,
+
+    /// Ordered container, `rdf:Seq`.
+    Seq,
+
+    /// Container of alternatives, `rdf:Alt`.
+    Alt,
+}
+
+impl ContainerType {
+    /// Returns the URI of the container type.
+    pub fn to_uri(&self) -> Uri {
+        match *self {
+            ContainerType::Bag => RdfSyntaxDataTypes::Bag.to_uri(),
+            ContainerType::Seq => RdfSyntaxDataTypes::Seq.to_uri(),
+            ContainerType::Alt => RdfSyntaxDataTypes::Alt.to_uri(),
+        }
+    }
+
+    /// Returns the container type of the URI, if it is one.
+    pub fn from_uri(uri: &Uri) -> Option<ContainerType> {
+        [ContainerType::Bag, ContainerType::Seq, ContainerType::Alt]
+            .iter()
+            .find(|container_type| container_type.to_uri() == *uri)
+            .cloned()
+    }
+}
+
 /// Representation of an RDF graph.
 #[derive(Debug)]
 pub struct Graph {
@@ -85,7 +124,7 @@ impl Graph {
     /// use rdf::uri::Uri;
     /// use rdf::graph::Graph;
     ///
-    /// let base_uri = Uri::new("http://example.org/".to_string());
+    /// let base_uri = Uri::parse("http://example.org/").unwrap();
     /// let graph = Graph::new(Some(&base_uri));
     ///
     /// assert_eq!(graph.base_uri(), &Some(base_uri));
@@ -102,7 +141,7 @@ impl Graph {
     /// use rdf::uri::Uri;
     /// use rdf::graph::Graph;
     ///
-    /// let base_uri = Uri::new("http://base.example.org/".to_string());
+    /// let base_uri = Uri::parse("http://base.example.org/").unwrap();
     /// let mut graph = Graph::new(None);
     ///
     /// graph.set_base_uri(&base_uri);
@@ -129,7 +168,7 @@ impl Graph {
     ///
     /// let mut graph = Graph::new(None);
     /// graph.add_namespace(&Namespace::new("example".to_string(),
-    ///                                     Uri::new("http://example.org/".to_string())));
+    ///                                     Uri::parse("http://example.org/").unwrap()));
     ///
     /// assert_eq!(graph.namespaces().len(), 1);
     /// ```
@@ -147,7 +186,7 @@ impl Graph {
     /// use rdf::namespace::Namespace;
     ///
     /// let mut graph = Graph::new(None);
-    /// let uri = Uri::new("http://example.org/".to_string());
+    /// let uri = Uri::parse("http://example.org/").unwrap();
     /// graph.add_namespace(&Namespace::new("example".to_string(), uri.to_owned()));
     ///
     /// assert_eq!(graph.get_namespace_uri_by_prefix("example").unwrap(), &uri);
@@ -204,11 +243,11 @@ impl Graph {
     /// use rdf::uri::Uri;
     ///
     /// let graph = Graph::new(None);
-    /// let literal_node = graph.create_literal_node_with_data_type("literal".to_string(), &Uri::new("http://example.org/show/localName".to_string()));
+    /// let literal_node = graph.create_literal_node_with_data_type("literal".to_string(), &Uri::parse("http://example.org/show/localName").unwrap());
     ///
     /// assert_eq!(literal_node, Node::LiteralNode {
     ///   literal: "literal".to_string(),
-    ///   data_type: Some(Uri::new("http://example.org/show/localName".to_string())),
+    ///   data_type: Some(Uri::parse("http://example.org/show/localName").unwrap()),
     ///   language: None
     /// });
     /// ```
@@ -250,8 +289,25 @@ impl Graph {
         self.next_id
     }
 
+    /// Ensures that blank nodes created later on do not reuse generated IDs of the triple.
+    fn reserve_blank_node_ids(&mut self, triple: &Triple) {
+        for node in &[triple.subject(), triple.predicate(), triple.object()] {
+            if let Node::BlankNode { ref id } = **node {
+                // the largest ID can not be followed by another one and is not reserved
+                if let Some(Ok(n)) = id.strip_prefix("auto").map(|n| n.parse::<u64>()) {
+                    if let Some(next_id) = n.checked_add(1) {
+                        self.next_id = self.next_id.max(next_id);
+                    }
+                }
+            }
+        }
+    }
+
     /// Creates a blank node with a unique ID.
     ///
+    /// The ID differs from the IDs of all blank nodes that were added to the graph, as well as
+    /// from the IDs of blank nodes that were created before.
+    ///
     /// # Examples
     ///
     /// ```
@@ -304,10 +360,10 @@ impl Graph {
     /// use rdf::uri::Uri;
     ///
     /// let graph = Graph::new(None);
-    /// let uri_node = graph.create_uri_node(&Uri::new("http://example.org/show/localName".to_string()));
+    /// let uri_node = graph.create_uri_node(&Uri::parse("http://example.org/show/localName").unwrap());
     ///
     /// assert_eq!(uri_node, Node::UriNode {
-    ///   uri: Uri::new("http://example.org/show/localName".to_string())
+    ///   uri: Uri::parse("http://example.org/show/localName").unwrap()
     /// });
     /// ```
     pub fn create_uri_node(&self, uri: &Uri) -> Node {
@@ -315,7 +371,9 @@ impl Graph {
     }
 
     /// Creates a new URI node from a string slice.
-    /// 
+    ///
+    /// IRIs that start with '#' are appended to the base URI of the graph, if there is one.
+    ///
     /// # Examples
     ///
     /// ```
@@ -324,29 +382,32 @@ impl Graph {
     /// use rdf::uri::Uri;
     ///
     /// let graph = Graph::new(None);
-    /// let uri_node = graph.create_uri_node_str("http://example.org/show/localName");
+    /// let uri_node = graph.create_uri_node_str("http://example.org/show/localName").unwrap();
     ///
     /// assert_eq!(uri_node, Node::UriNode {
-    ///   uri: Uri::new("http://example.org/show/localName".to_string())
+    ///   uri: Uri::parse("http://example.org/show/localName").unwrap()
     /// });
-    /// 
-    /// let graph = Graph::new(Some(&Uri::new("http://www.w3.org/2006/vcard/ns".to_string())));
-    /// let uri_node = graph.create_uri_node_str("#fn");
+    ///
+    /// let graph = Graph::new(Some(&Uri::parse("http://www.w3.org/2006/vcard/ns").unwrap()));
+    /// let uri_node = graph.create_uri_node_str("#fn").unwrap();
     /// assert_eq!(uri_node, Node::UriNode {
-    ///   uri: Uri::new("http://www.w3.org/2006/vcard/ns#fn".to_string())
+    ///   uri: Uri::parse("http://www.w3.org/2006/vcard/ns#fn").unwrap()
     /// });
+    ///
+    /// assert!(graph.create_uri_node_str("http://example.org/a b").is_err());
     /// ```
-    pub fn create_uri_node_str(&self, uri: &str) -> Node {
-        let uri = match (uri.starts_with("#"),self.base_uri()){
-            (true, Some(base))=> {
-                let mut s = base.to_string().clone();
-                s.push_str(uri);
-                Uri::new(s)
-            },
-            (_,_) =>  Uri::new(uri.to_string())
+    ///
+    /// # Failures
+    ///
+    /// - The IRI is invalid according to RFC 3987.
+    ///
+    pub fn create_uri_node_str(&self, uri: &str) -> Result<Node> {
+        let uri = match (uri.starts_with('#'), self.base_uri()) {
+            (true, Some(base)) => Uri::parse(&(base.to_string().clone() + uri))?,
+            (_, _) => Uri::parse(uri)?,
         };
 
-        Node::UriNode { uri: uri }
+        Ok(Node::UriNode { uri })
     }
 
     /// Adds a triple to the graph.
@@ -361,7 +422,7 @@ impl Graph {
     /// let mut graph = Graph::new(None);
     ///
     /// let subject = graph.create_blank_node();
-    /// let predicate = graph.create_uri_node(&Uri::new("http://example.org/show/localName".to_string()));
+    /// let predicate = graph.create_uri_node(&Uri::parse("http://example.org/show/localName").unwrap());
     /// let object = graph.create_blank_node();
     /// let triple = Triple::new(&subject, &predicate, &object);
     ///
@@ -370,6 +431,8 @@ impl Graph {
     /// assert_eq!(graph.count(), 1);
     /// ```
     pub fn add_triple(&mut self, triple: &Triple) {
+        // blank nodes created later on must not clash with generated IDs of the triple
+        self.reserve_blank_node_ids(triple);
         self.triples.add_triple(triple);
     }
 
@@ -385,7 +448,7 @@ impl Graph {
     /// let mut graph = Graph::new(None);
     ///
     /// let subject = graph.create_blank_node();
-    /// let predicate = graph.create_uri_node(&Uri::new("http://example.org/show/localName".to_string()));
+    /// let predicate = graph.create_uri_node(&Uri::parse("http://example.org/show/localName").unwrap());
     /// let object = graph.create_blank_node();
     ///
     /// let triple1 = Triple::new(&subject, &predicate, &object);
@@ -401,6 +464,37 @@ impl Graph {
         }
     }
 
+    /// Adds the triples of another graph, blank nodes whose labels are already used in this
+    /// graph are renamed.
+    ///
+    /// Blank nodes are local to their graph, so blank nodes of the other graph are never
+    /// identified with blank nodes of this graph, even if they have the same label.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::graph::Graph;
+    /// use rdf::reader::rdf_parser::RdfParser;
+    /// use rdf::reader::turtle_parser::TurtleParser;
+    ///
+    /// let mut graph = TurtleParser::from_string("_:b1 <http://example.org/name> \"Alice\" .").decode().unwrap();
+    /// let other = TurtleParser::from_string("_:b1 <http://example.org/name> \"Bob\" .").decode().unwrap();
+    ///
+    /// graph.merge(&other);
+    ///
+    /// let subjects: Vec<_> = graph.triples_iter().map(|triple| triple.subject()).collect();
+    ///
+    /// assert_eq!(subjects.len(), 2);
+    /// assert_ne!(subjects[0], subjects[1]);
+    /// ```
+    pub fn merge(&mut self, other: &Graph) {
+        let mut sink = MergeSink::new(self);
+
+        for triple in other.triples_iter() {
+            sink.add_triple(triple);
+        }
+    }
+
     /// Deletes the triple from the graph.
     ///
     /// # Examples
@@ -412,7 +506,7 @@ impl Graph {
     /// let mut graph = Graph::new(None);
     ///
     /// let subject = graph.create_blank_node();
-    /// let predicate = graph.create_uri_node(&Uri::new("http://example.org/show/localName".to_string()));
+    /// let predicate = graph.create_uri_node(&Uri::parse("http://example.org/show/localName").unwrap());
     /// let object = graph.create_blank_node();
     /// let triple = Triple::new(&subject, &predicate, &object);
     ///
@@ -425,6 +519,26 @@ impl Graph {
         self.triples.remove_triple(triple);
     }
 
+    /// Returns a view of the node that gives access to the statements about it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::graph::Graph;
+    /// use rdf::reader::rdf_parser::RdfParser;
+    /// use rdf::reader::turtle_parser::TurtleParser;
+    ///
+    /// let graph = TurtleParser::from_string("<http://example.org/a> a <http://example.org/T> .")
+    ///   .decode().unwrap();
+    ///
+    /// let a = graph.resource(&graph.create_uri_node_str("http://example.org/a").unwrap());
+    ///
+    /// assert!(a.has_type("http://example.org/T"));
+    /// ```
+    pub fn resource(&self, node: &Node) -> Resource<'_> {
+        Resource::new(self, node)
+    }
+
     /// Returns all triples from the store that have the specified subject node.
     ///
     /// # Examples
@@ -438,7 +552,7 @@ impl Graph {
     ///
     /// let subject1 = graph.create_blank_node();
     /// let subject2 = graph.create_blank_node();
-    /// let predicate = graph.create_uri_node(&Uri::new("http://example.org/show/localName".to_string()));
+    /// let predicate = graph.create_uri_node(&Uri::parse("http://example.org/show/localName").unwrap());
     /// let object = graph.create_blank_node();
     ///
     /// let triple1 = Triple::new(&subject1, &predicate, &object);
@@ -465,7 +579,7 @@ impl Graph {
     ///
     /// let subject1 = graph.create_blank_node();
     /// let subject2 = graph.create_blank_node();
-    /// let predicate = graph.create_uri_node(&Uri::new("http://example.org/show/localName".to_string()));
+    /// let predicate = graph.create_uri_node(&Uri::parse("http://example.org/show/localName").unwrap());
     /// let object = graph.create_blank_node();
     ///
     /// let triple1 = Triple::new(&subject1, &predicate, &object);
@@ -492,7 +606,7 @@ impl Graph {
     ///
     /// let subject1 = graph.create_blank_node();
     /// let subject2 = graph.create_blank_node();
-    /// let predicate = graph.create_uri_node(&Uri::new("http://example.org/show/localName".to_string()));
+    /// let predicate = graph.create_uri_node(&Uri::parse("http://example.org/show/localName").unwrap());
     /// let object = graph.create_blank_node();
     ///
     /// let triple1 = Triple::new(&subject1, &predicate, &object);
@@ -519,7 +633,7 @@ impl Graph {
     ///
     /// let subject1 = graph.create_blank_node();
     /// let subject2 = graph.create_blank_node();
-    /// let predicate = graph.create_uri_node(&Uri::new("http://example.org/show/localName".to_string()));
+    /// let predicate = graph.create_uri_node(&Uri::parse("http://example.org/show/localName").unwrap());
     /// let object = graph.create_blank_node();
     ///
     /// let triple1 = Triple::new(&subject1, &predicate, &object);
@@ -551,7 +665,7 @@ impl Graph {
     ///
     /// let subject1 = graph.create_blank_node();
     /// let subject2 = graph.create_blank_node();
-    /// let predicate = graph.create_uri_node(&Uri::new("http://example.org/show/localName".to_string()));
+    /// let predicate = graph.create_uri_node(&Uri::parse("http://example.org/show/localName").unwrap());
     /// let object = graph.create_blank_node();
     ///
     /// let triple1 = Triple::new(&subject1, &predicate, &object);
@@ -583,7 +697,7 @@ impl Graph {
     ///
     /// let subject1 = graph.create_blank_node();
     /// let subject2 = graph.create_blank_node();
-    /// let predicate = graph.create_uri_node(&Uri::new("http://example.org/show/localName".to_string()));
+    /// let predicate = graph.create_uri_node(&Uri::parse("http://example.org/show/localName").unwrap());
     /// let object = graph.create_blank_node();
     ///
     /// let triple1 = Triple::new(&subject1, &predicate, &object);
@@ -602,57 +716,1292 @@ impl Graph {
             .get_triples_with_predicate_and_object(predicate_node, object_node)
     }
 
-    /// Returns an iterator over the triples of the graph.
-    pub fn triples_iter(&self) -> Iter<Triple> {
-        self.triples.iter()
+    /// Replaces all literals of the graph by their canonical form.
+    ///
+    /// Literals with a supported XML schema data type are written in canonical lexical form and
+    /// language tags are converted to lower case, see `Node::canonicalize`. Triples that become
+    /// equal by canonicalization are only kept once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::graph::Graph;
+    /// use rdf::reader::rdf_parser::RdfParser;
+    /// use rdf::reader::turtle_parser::TurtleParser;
+    ///
+    /// let input = "@prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+    ///              <http://example.org/a> <http://example.org/b> \"01\"^^xsd:integer, 1 .";
+    ///
+    /// let mut graph = TurtleParser::from_string(input).decode().unwrap();
+    /// assert_eq!(graph.count(), 2);
+    ///
+    /// graph.canonicalize_literals();
+    /// assert_eq!(graph.count(), 1);
+    /// ```
+    pub fn canonicalize_literals(&mut self) {
+        let canonical: Vec<(Triple, bool)> = self
+            .triples
+            .iter()
+            .map(|triple| {
+                let canonical = triple.canonicalize();
+                let changed = canonical != *triple;
+                (canonical, changed)
+            })
+            .collect();
+
+        // only duplicates that are caused by canonicalization are removed
+        let changed: BTreeSet<&Triple> = canonical
+            .iter()
+            .filter(|&&(_, changed)| changed)
+            .map(|(triple, _)| triple)
+            .collect();
+
+        let mut added = BTreeSet::new();
+        let mut triples = TripleStore::new();
+
+        for (triple, _) in canonical.iter() {
+            if !changed.contains(triple) || added.insert(triple) {
+                triples.add_triple(triple);
+            }
+        }
+
+        self.triples = triples;
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use crate::graph::Graph;
-    use crate::node::*;
+    /// Replaces every blank node with a Skolem IRI of the authority, so that the graph can be
+    /// stored where blank nodes are not supported.
+    ///
+    /// The Skolem IRIs have the form `{scheme}://{authority}/.well-known/genid/{label}` where
+    /// the label is the ID of the blank node. If the graph already contains the Skolem IRI of
+    /// a label, the blank node gets a different label. `deskolemize` turns the IRIs back into
+    /// blank nodes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::graph::Graph;
+    /// use rdf::node::Node;
+    /// use rdf::reader::rdf_parser::RdfParser;
+    /// use rdf::reader::turtle_parser::TurtleParser;
+    /// use rdf::uri::Uri;
+    ///
+    /// let mut graph = TurtleParser::from_string("_:alice <http://example.org/name> \"Alice\" .")
+    ///   .decode().unwrap();
+    /// let authority = Uri::parse("http://example.org/").unwrap();
+    ///
+    /// graph.skolemize(&authority).unwrap();
+    ///
+    /// let subject = graph.triples_iter().next().unwrap().subject();
+    /// assert_eq!(*subject, Node::UriNode {
+    ///   uri: Uri::parse("http://example.org/.well-known/genid/alice").unwrap()
+    /// });
+    ///
+    /// graph.deskolemize(&authority).unwrap();
+    ///
+    /// let subject = graph.triples_iter().next().unwrap().subject();
+    /// assert_eq!(*subject, Node::BlankNode { id: "alice".to_string() });
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - The authority is not an IRI with scheme and authority, e.g. `http://example.org`.
+    ///
+    pub fn skolemize(&mut self, authority: &Uri) -> Result<()> {
+        let prefix = Graph::skolem_prefix(authority)?;
 
-    #[test]
-    fn empty_graph() {
-        let graph = Graph::new(None);
-        assert_eq!(graph.is_empty(), true);
+        let used_labels = self.triples.iter().flat_map(|triple| {
+            Graph::skolem_labels(triple, &prefix).map(|label| label.to_string())
+        });
+        let mut labels = BlankNodeScope::with_used_ids(used_labels);
+
+        self.map_nodes(|node| match *node {
+            Node::BlankNode { ref id } => match labels.labeled(id) {
+                Node::BlankNode { id } => Node::UriNode {
+                    uri: Uri::new_unchecked(prefix.clone() + &id),
+                },
+                other => other,
+            },
+            _ => node.clone(),
+        });
+
+        Ok(())
     }
 
-    #[test]
-    fn create_literal_node() {
-        let graph = Graph::new(None);
-        let literal_node = graph.create_literal_node("literal".to_string());
+    /// Replaces the Skolem IRIs of the authority with blank nodes, the inverse of `skolemize`.
+    ///
+    /// The blank nodes are labeled with the last segment of the Skolem IRIs, unless the label
+    /// is already used by a blank node of the graph. IRIs of other authorities are kept.
+    ///
+    /// # Failures
+    ///
+    /// - The authority is not an IRI with scheme and authority, e.g. `http://example.org`.
+    ///
+    pub fn deskolemize(&mut self, authority: &Uri) -> Result<()> {
+        let prefix = Graph::skolem_prefix(authority)?;
 
-        assert_eq!(
-            literal_node,
-            Node::LiteralNode {
-                literal: "literal".to_string(),
-                data_type: None,
-                language: None,
+        let used_ids = self
+            .triples
+            .get_blank_nodes()
+            .into_iter()
+            .filter_map(|node| match *node {
+                Node::BlankNode { ref id } => Some(id.clone()),
+                _ => None,
+            });
+        let mut blank_nodes = BlankNodeScope::with_used_ids(used_ids);
+
+        self.map_nodes(|node| match Graph::skolem_label(node, &prefix) {
+            Some(label) => blank_nodes.labeled(label),
+            None => node.clone(),
+        });
+
+        Ok(())
+    }
+
+    /// Returns the start of the Skolem IRIs of the authority.
+    fn skolem_prefix(authority: &Uri) -> Result<String> {
+        match (authority.scheme(), authority.authority()) {
+            (Some(scheme), Some(host)) if authority.is_valid() => {
+                Ok(scheme.to_string() + "://" + host + "/.well-known/genid/")
             }
-        );
+            _ => Err(Error::new(
+                ErrorType::InvalidUri,
+                "Skolem IRIs need an authority with scheme: ".to_string() + authority.to_string(),
+            )),
+        }
     }
 
-    #[test]
-    fn create_multiple_blank_nodes() {
-        let mut graph = Graph::new(None);
+    /// Returns the label of the node if it is a Skolem IRI with the prefix.
+    fn skolem_label<'a>(node: &'a Node, prefix: &str) -> Option<&'a str> {
+        match *node {
+            Node::UriNode { ref uri } => uri
+                .to_string()
+                .strip_prefix(prefix)
+                .filter(|label| !label.is_empty()),
+            _ => None,
+        }
+    }
 
-        let blank_node_0 = graph.create_blank_node();
-        let blank_node_1 = graph.create_blank_node();
+    /// Returns the labels of the Skolem IRIs with the prefix in the triple.
+    fn skolem_labels<'a>(triple: &'a Triple, prefix: &'a str) -> impl Iterator<Item = &'a str> {
+        vec![triple.subject(), triple.predicate(), triple.object()]
+            .into_iter()
+            .filter_map(move |node| Graph::skolem_label(node, prefix))
+    }
 
-        assert_eq!(
-            blank_node_0,
-            Node::BlankNode {
-                id: "auto0".to_string(),
+    /// Replaces every node of the graph.
+    fn map_nodes<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&Node) -> Node,
+    {
+        let triples: Vec<Triple> = self
+            .triples
+            .iter()
+            .map(|triple| {
+                Triple::new(
+                    &f(triple.subject()),
+                    &f(triple.predicate()),
+                    &f(triple.object()),
+                )
+            })
+            .collect();
+
+        self.triples = TripleStore::new();
+        self.add_triples(&triples);
+    }
+
+    /// Returns the elements of the RDF collection (list) that starts with the node.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::graph::Graph;
+    /// use rdf::reader::rdf_parser::RdfParser;
+    /// use rdf::reader::turtle_parser::TurtleParser;
+    ///
+    /// let graph = TurtleParser::from_string("<http://example.org/a> <http://example.org/b> ( 1 2 3 ) .")
+    ///   .decode().unwrap();
+    /// let head = graph.triples_iter()
+    ///   .find(|triple| triple.predicate() == &graph.create_uri_node_str("http://example.org/b").unwrap())
+    ///   .unwrap()
+    ///   .object();
+    ///
+    /// let elements = graph.read_list(head).unwrap();
+    ///
+    /// assert_eq!(elements, vec![graph.create_integer_node(1), graph.create_integer_node(2),
+    ///                           graph.create_integer_node(3)]);
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - A node of the list does not have exactly one `rdf:first` and one `rdf:rest`.
+    /// - A node of the list is a literal.
+    /// - The list is cyclic.
+    ///
+    pub fn read_list(&self, head: &Node) -> Result<Vec<Node>> {
+        Ok(self
+            .list_cells(head)?
+            .into_iter()
+            .map(|(_, element)| element)
+            .collect())
+    }
+
+    /// Adds an RDF collection (list) of the elements and returns its first node, `rdf:nil` for
+    /// an empty list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::graph::Graph;
+    ///
+    /// let mut graph = Graph::new(None);
+    /// let elements = vec![graph.create_literal_node("a".to_string()),
+    ///                     graph.create_literal_node("b".to_string())];
+    ///
+    /// let head = graph.add_list(&elements);
+    ///
+    /// assert_eq!(graph.count(), 4);
+    /// assert_eq!(graph.read_list(&head).unwrap(), elements);
+    /// ```
+    pub fn add_list(&mut self, elements: &[Node]) -> Node {
+        self.write_list(&[], elements)
+    }
+
+    /// Appends the element to the list and returns the first node of the list.
+    ///
+    /// Lists are changed in place, so the first node stays the same unless the list was empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::graph::Graph;
+    ///
+    /// let mut graph = Graph::new(None);
+    /// let a = graph.create_literal_node("a".to_string());
+    /// let b = graph.create_literal_node("b".to_string());
+    ///
+    /// let head = graph.add_list(&[a.clone()]);
+    ///
+    /// assert_eq!(graph.push_list_item(&head, &b).unwrap(), head);
+    /// assert_eq!(graph.read_list(&head).unwrap(), vec![a, b]);
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - The list is malformed, see `read_list`.
+    ///
+    pub fn push_list_item(&mut self, head: &Node, element: &Node) -> Result<Node> {
+        self.edit_list(head, |elements| {
+            elements.push(element.clone());
+            Ok(())
+        })
+    }
+
+    /// Inserts the element at the index of the list and returns the first node of the list.
+    ///
+    /// # Failures
+    ///
+    /// - The list is malformed, see `read_list`.
+    /// - The index is greater than the length of the list.
+    ///
+    pub fn insert_list_item(&mut self, head: &Node, index: usize, element: &Node) -> Result<Node> {
+        self.edit_list(head, |elements| {
+            Graph::check_index(index, elements.len() + 1)?;
+            elements.insert(index, element.clone());
+            Ok(())
+        })
+    }
+
+    /// Replaces the element at the index of the list and returns the first node of the list.
+    ///
+    /// # Failures
+    ///
+    /// - The list is malformed, see `read_list`.
+    /// - The index is out of range.
+    ///
+    pub fn set_list_item(&mut self, head: &Node, index: usize, element: &Node) -> Result<Node> {
+        self.edit_list(head, |elements| {
+            Graph::check_index(index, elements.len())?;
+            elements[index] = element.clone();
+            Ok(())
+        })
+    }
+
+    /// Removes the element at the index of the list and returns the first node of the list,
+    /// which is `rdf:nil` if the list became empty.
+    ///
+    /// # Failures
+    ///
+    /// - The list is malformed, see `read_list`.
+    /// - The index is out of range.
+    ///
+    pub fn remove_list_item(&mut self, head: &Node, index: usize) -> Result<Node> {
+        self.edit_list(head, |elements| {
+            Graph::check_index(index, elements.len())?;
+            elements.remove(index);
+            Ok(())
+        })
+    }
+
+    /// Returns the nodes of the list together with their elements.
+    fn list_cells(&self, head: &Node) -> Result<Vec<(Node, Node)>> {
+        let first = RdfSyntaxDataTypes::ListFirst.to_uri();
+        let rest = RdfSyntaxDataTypes::ListRest.to_uri();
+        let nil = self.create_uri_node(&RdfSyntaxDataTypes::ListNil.to_uri());
+
+        let mut cells = Vec::new();
+        let mut visited = BTreeSet::new();
+        let mut cell = head.clone();
+
+        while cell != nil {
+            if let Node::LiteralNode { .. } = cell {
+                return Err(Error::new(
+                    ErrorType::InvalidCollection,
+                    "Literal is used as list node.",
+                ));
             }
-        );
 
-        assert_eq!(
-            blank_node_1,
-            Node::BlankNode {
-                id: "auto1".to_string(),
+            if !visited.insert(cell.clone()) {
+                return Err(Error::new(ErrorType::InvalidCollection, "List is cyclic."));
             }
-        );
+
+            let element = self.single_object(&cell, &first)?;
+            let next = self.single_object(&cell, &rest)?;
+
+            cells.push((cell, element));
+            cell = next;
+        }
+
+        Ok(cells)
+    }
+
+    /// Returns the only object of the subject and predicate.
+    fn single_object(&self, subject: &Node, predicate: &Uri) -> Result<Node> {
+        match self
+            .triples
+            .get_triples_with_subject_and_predicate(subject, &self.create_uri_node(predicate))[..]
+        {
+            [triple] => Ok(triple.object().clone()),
+            [] => Err(Error::new(
+                ErrorType::InvalidCollection,
+                "List node has no ".to_string() + predicate.to_string(),
+            )),
+            _ => Err(Error::new(
+                ErrorType::InvalidCollection,
+                "List node has more than one ".to_string() + predicate.to_string(),
+            )),
+        }
+    }
+
+    /// Replaces the elements of the list and returns its first node.
+    fn edit_list<F>(&mut self, head: &Node, edit: F) -> Result<Node>
+    where
+        F: FnOnce(&mut Vec<Node>) -> Result<()>,
+    {
+        let (cells, mut elements): (Vec<Node>, Vec<Node>) =
+            self.list_cells(head)?.into_iter().unzip();
+
+        edit(&mut elements)?;
+
+        Ok(self.write_list(&cells, &elements))
+    }
+
+    /// Writes the list of the elements and returns its first node.
+    ///
+    /// The nodes of the list are reused in order, so the first node of a non-empty list is
+    /// kept. Missing nodes are created as blank nodes.
+    fn write_list(&mut self, cells: &[Node], elements: &[Node]) -> Node {
+        let first = self.create_uri_node(&RdfSyntaxDataTypes::ListFirst.to_uri());
+        let rest = self.create_uri_node(&RdfSyntaxDataTypes::ListRest.to_uri());
+        let nil = self.create_uri_node(&RdfSyntaxDataTypes::ListNil.to_uri());
+
+        for cell in cells {
+            for predicate in &[&first, &rest] {
+                let triples: Vec<Triple> = self
+                    .get_triples_with_subject_and_predicate(cell, predicate)
+                    .into_iter()
+                    .cloned()
+                    .collect();
+
+                for triple in &triples {
+                    self.remove_triple(triple);
+                }
+            }
+        }
+
+        let mut cells: Vec<Node> = cells.iter().take(elements.len()).cloned().collect();
+
+        while cells.len() < elements.len() {
+            let cell = self.create_blank_node();
+            cells.push(cell);
+        }
+
+        let mut next = nil;
+
+        for (cell, element) in cells.iter().zip(elements).rev() {
+            self.add_triple(&Triple::new(cell, &first, element));
+            self.add_triple(&Triple::new(cell, &rest, &next));
+            next = cell.clone();
+        }
+
+        next
+    }
+
+    /// Adds a container of the type with the elements as members and returns the container.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::graph::{ContainerType, Graph};
+    ///
+    /// let mut graph = Graph::new(None);
+    /// let elements = vec![graph.create_literal_node("a".to_string()),
+    ///                     graph.create_literal_node("b".to_string())];
+    ///
+    /// let container = graph.add_container(ContainerType::Seq, &elements);
+    ///
+    /// assert_eq!(graph.count(), 3);
+    /// assert_eq!(graph.container_type(&container), Some(ContainerType::Seq));
+    /// assert_eq!(graph.read_container(&container).unwrap(), elements);
+    /// ```
+    pub fn add_container(&mut self, container_type: ContainerType, elements: &[Node]) -> Node {
+        let container = self.create_blank_node();
+        let rdf_type = self.create_uri_node(&RdfSyntaxDataTypes::A.to_uri());
+        let type_node = self.create_uri_node(&container_type.to_uri());
+
+        self.add_triple(&Triple::new(&container, &rdf_type, &type_node));
+        self.write_container(&container, elements);
+
+        container
+    }
+
+    /// Returns the type of the container, `None` if the node is not typed as `rdf:Bag`,
+    /// `rdf:Seq` or `rdf:Alt`.
+    pub fn container_type(&self, container: &Node) -> Option<ContainerType> {
+        let rdf_type = self.create_uri_node(&RdfSyntaxDataTypes::A.to_uri());
+
+        self.get_triples_with_subject_and_predicate(container, &rdf_type)
+            .into_iter()
+            .filter_map(|triple| match *triple.object() {
+                Node::UriNode { ref uri } => ContainerType::from_uri(uri),
+                _ => None,
+            })
+            .next()
+    }
+
+    /// Returns the members of the container ordered by their `rdf:_n` properties.
+    ///
+    /// Gaps in the numbering are skipped.
+    ///
+    /// # Failures
+    ///
+    /// - The container has more than one member with the same number.
+    ///
+    pub fn read_container(&self, container: &Node) -> Result<Vec<Node>> {
+        let mut members = BTreeMap::new();
+
+        for triple in self.get_triples_with_subject(container) {
+            let index = match *triple.predicate() {
+                Node::UriNode { ref uri } => RdfSyntaxDataTypes::member_index(uri),
+                _ => None,
+            };
+
+            if let Some(index) = index {
+                if members.insert(index, triple.object().clone()).is_some() {
+                    return Err(Error::new(
+                        ErrorType::InvalidCollection,
+                        "Container has more than one member ".to_string()
+                            + RdfSyntaxDataTypes::member_uri(index).to_string(),
+                    ));
+                }
+            }
+        }
+
+        Ok(members.into_values().collect())
+    }
+
+    /// Appends the element to the members of the container.
+    ///
+    /// Members of containers are renumbered from `rdf:_1` when the container is changed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::graph::{ContainerType, Graph};
+    ///
+    /// let mut graph = Graph::new(None);
+    /// let a = graph.create_literal_node("a".to_string());
+    /// let b = graph.create_literal_node("b".to_string());
+    ///
+    /// let container = graph.add_container(ContainerType::Bag, &[a.clone()]);
+    /// graph.push_container_item(&container, &b).unwrap();
+    ///
+    /// assert_eq!(graph.read_container(&container).unwrap(), vec![a, b]);
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - The container is malformed, see `read_container`.
+    ///
+    pub fn push_container_item(&mut self, container: &Node, element: &Node) -> Result<()> {
+        self.edit_container(container, |elements| {
+            elements.push(element.clone());
+            Ok(())
+        })
+    }
+
+    /// Inserts the element at the index of the members of the container.
+    ///
+    /// # Failures
+    ///
+    /// - The container is malformed, see `read_container`.
+    /// - The index is greater than the number of members.
+    ///
+    pub fn insert_container_item(
+        &mut self,
+        container: &Node,
+        index: usize,
+        element: &Node,
+    ) -> Result<()> {
+        self.edit_container(container, |elements| {
+            Graph::check_index(index, elements.len() + 1)?;
+            elements.insert(index, element.clone());
+            Ok(())
+        })
+    }
+
+    /// Replaces the member at the index of the container.
+    ///
+    /// # Failures
+    ///
+    /// - The container is malformed, see `read_container`.
+    /// - The index is out of range.
+    ///
+    pub fn set_container_item(
+        &mut self,
+        container: &Node,
+        index: usize,
+        element: &Node,
+    ) -> Result<()> {
+        self.edit_container(container, |elements| {
+            Graph::check_index(index, elements.len())?;
+            elements[index] = element.clone();
+            Ok(())
+        })
+    }
+
+    /// Removes the member at the index of the container.
+    ///
+    /// # Failures
+    ///
+    /// - The container is malformed, see `read_container`.
+    /// - The index is out of range.
+    ///
+    pub fn remove_container_item(&mut self, container: &Node, index: usize) -> Result<()> {
+        self.edit_container(container, |elements| {
+            Graph::check_index(index, elements.len())?;
+            elements.remove(index);
+            Ok(())
+        })
+    }
+
+    /// Replaces the members of the container.
+    fn edit_container<F>(&mut self, container: &Node, edit: F) -> Result<()>
+    where
+        F: FnOnce(&mut Vec<Node>) -> Result<()>,
+    {
+        let mut elements = self.read_container(container)?;
+
+        edit(&mut elements)?;
+
+        self.write_container(container, &elements);
+        Ok(())
+    }
+
+    /// Replaces the membership triples of the container with members `rdf:_1`, `rdf:_2`, ...
+    fn write_container(&mut self, container: &Node, elements: &[Node]) {
+        let members: Vec<Triple> = self
+            .get_triples_with_subject(container)
+            .into_iter()
+            .filter(|triple| match *triple.predicate() {
+                Node::UriNode { ref uri } => RdfSyntaxDataTypes::member_index(uri).is_some(),
+                _ => false,
+            })
+            .cloned()
+            .collect();
+
+        for triple in &members {
+            self.remove_triple(triple);
+        }
+
+        for (n, element) in elements.iter().enumerate() {
+            let member = self.create_uri_node(&RdfSyntaxDataTypes::member_uri(n + 1));
+            self.add_triple(&Triple::new(container, &member, element));
+        }
+    }
+
+    /// Checks that the index is less than the length.
+    fn check_index(index: usize, len: usize) -> Result<()> {
+        if index < len {
+            Ok(())
+        } else {
+            Err(Error::new(
+                ErrorType::InvalidCollection,
+                format!("Index {} is out of range for {} elements.", index, len),
+            ))
+        }
+    }
+
+    /// Applies the changes to the graph, the removed triples are deleted before the added
+    /// triples are inserted.
+    ///
+    /// Triples that are added and already in the graph are not inserted again, see
+    /// `diff::diff` for computing the changes between two graphs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::diff::{diff, GraphDiff};
+    /// use rdf::reader::rdf_parser::RdfParser;
+    /// use rdf::reader::turtle_parser::TurtleParser;
+    ///
+    /// let mut old = TurtleParser::from_string("_:a <http://example.org/name> \"Alice\" .").decode().unwrap();
+    /// let new = TurtleParser::from_string("_:b <http://example.org/name> \"Alicia\" .").decode().unwrap();
+    ///
+    /// let patch = GraphDiff::from_rdf_patch(&diff(&old, &new).to_rdf_patch()).unwrap();
+    /// old.apply_patch(&patch);
+    ///
+    /// assert_eq!(old, new);
+    /// ```
+    pub fn apply_patch(&mut self, patch: &GraphDiff) {
+        for triple in patch.removed() {
+            self.remove_triple(triple);
+        }
+
+        for triple in patch.added() {
+            if !self.contains_triple(triple) {
+                self.add_triple(triple);
+            }
+        }
+    }
+
+    /// Returns `true` if the graph contains the triple.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::graph::Graph;
+    /// use rdf::triple::Triple;
+    ///
+    /// let mut graph = Graph::new(None);
+    /// let node = graph.create_uri_node_str("http://example.org/a").unwrap();
+    /// let triple = Triple::new(&node, &node, &node);
+    ///
+    /// assert!(!graph.contains_triple(&triple));
+    ///
+    /// graph.add_triple(&triple);
+    ///
+    /// assert!(graph.contains_triple(&triple));
+    /// ```
+    pub fn contains_triple(&self, triple: &Triple) -> bool {
+        self.triples.contains(triple)
+    }
+
+    /// Returns a graph with the triples of both graphs.
+    ///
+    /// The graphs are treated as sets of triples, so the result contains no duplicates.
+    /// Blank nodes with the same label are the same blank node in both graphs, use `merge`
+    /// to keep them apart. The namespaces of both graphs are merged, prefixes that are bound
+    /// to different URIs are handled as specified. The base URI of this graph is kept, if it
+    /// has one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::namespace::PrefixConflict;
+    /// use rdf::reader::rdf_parser::RdfParser;
+    /// use rdf::reader::turtle_parser::TurtleParser;
+    ///
+    /// let first = TurtleParser::from_string("@prefix ex: <http://example.org/> . ex:a ex:b ex:c, ex:d .")
+    ///   .decode().unwrap();
+    /// let second = TurtleParser::from_string("@prefix ex: <http://example.com/> . ex:a ex:b ex:c .")
+    ///   .decode().unwrap();
+    ///
+    /// let union = first.union(&second, PrefixConflict::Rename).unwrap();
+    ///
+    /// assert_eq!(union.count(), 3);
+    /// assert_eq!(union.namespaces().len(), 2);
+    /// assert!(first.union(&second, PrefixConflict::Fail).is_err());
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - A prefix is bound to different URIs and conflicts are handled with `PrefixConflict::Fail`.
+    ///
+    pub fn union(&self, other: &Graph, conflict: PrefixConflict) -> Result<Graph> {
+        self.combine(
+            other,
+            conflict,
+            self.triples_iter().chain(other.triples_iter()),
+        )
+    }
+
+    /// Returns a graph with the triples that are in both graphs.
+    ///
+    /// Namespaces and base URI are combined like for `union`.
+    ///
+    /// # Failures
+    ///
+    /// - A prefix is bound to different URIs and conflicts are handled with `PrefixConflict::Fail`.
+    ///
+    pub fn intersection(&self, other: &Graph, conflict: PrefixConflict) -> Result<Graph> {
+        self.combine(
+            other,
+            conflict,
+            self.triples_iter()
+                .filter(|triple| other.contains_triple(triple)),
+        )
+    }
+
+    /// Returns a graph with the triples of this graph that are not in the other graph.
+    ///
+    /// Namespaces and base URI are combined like for `union`.
+    ///
+    /// # Failures
+    ///
+    /// - A prefix is bound to different URIs and conflicts are handled with `PrefixConflict::Fail`.
+    ///
+    pub fn difference(&self, other: &Graph, conflict: PrefixConflict) -> Result<Graph> {
+        self.combine(
+            other,
+            conflict,
+            self.triples_iter()
+                .filter(|triple| !other.contains_triple(triple)),
+        )
+    }
+
+    /// Returns a graph with the triples that are in exactly one of the graphs.
+    ///
+    /// Namespaces and base URI are combined like for `union`.
+    ///
+    /// # Failures
+    ///
+    /// - A prefix is bound to different URIs and conflicts are handled with `PrefixConflict::Fail`.
+    ///
+    pub fn symmetric_difference(&self, other: &Graph, conflict: PrefixConflict) -> Result<Graph> {
+        let only_in_self = self
+            .triples_iter()
+            .filter(|triple| !other.contains_triple(triple));
+        let only_in_other = other
+            .triples_iter()
+            .filter(|triple| !self.contains_triple(triple));
+
+        self.combine(other, conflict, only_in_self.chain(only_in_other))
+    }
+
+    /// Creates a graph with the triples, the namespaces of both graphs and the base URI of
+    /// this graph, or else of the other graph.
+    fn combine<'a, I>(&self, other: &Graph, conflict: PrefixConflict, triples: I) -> Result<Graph>
+    where
+        I: Iterator<Item = &'a Triple>,
+    {
+        let base_uri = self.base_uri.as_ref().or(other.base_uri.as_ref());
+        let mut graph = Graph::new(base_uri);
+
+        graph.namespaces.merge(&self.namespaces, conflict)?;
+        graph.namespaces.merge(&other.namespaces, conflict)?;
+
+        for triple in triples {
+            if !graph.contains_triple(triple) {
+                graph.add_triple(triple);
+            }
+        }
+
+        Ok(graph)
+    }
+
+    /// Returns an iterator over the triples of the graph.
+    pub fn triples_iter(&self) -> Iter<Triple> {
+        self.triples.iter()
+    }
+}
+
+impl PartialEq for Graph {
+    /// Graphs are equal if they are isomorphic, see `isomorphism::is_isomorphic`.
+    ///
+    /// Blank node labels, duplicate triples, namespaces and the base URI are not compared.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::graph::Graph;
+    /// use rdf::triple::Triple;
+    /// use rdf::reader::rdf_parser::RdfParser;
+    /// use rdf::reader::turtle_parser::TurtleParser;
+    ///
+    /// let mut graph = Graph::new(None);
+    /// let subject = graph.create_blank_node();
+    /// let predicate = graph.create_uri_node_str("http://xmlns.com/foaf/0.1/name").unwrap();
+    /// let object = graph.create_literal_node("Art Barstow".to_string());
+    /// graph.add_triple(&Triple::new(&subject, &predicate, &object));
+    ///
+    /// let input = "_:art <http://xmlns.com/foaf/0.1/name> \"Art Barstow\" .";
+    ///
+    /// assert_eq!(graph, TurtleParser::from_string(input).decode().unwrap());
+    /// ```
+    fn eq(&self, other: &Graph) -> bool {
+        is_isomorphic(self, other).is_ok()
+    }
+}
+
+impl Eq for Graph {}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::ErrorType;
+    use crate::graph::{ContainerType, Graph};
+    use crate::namespace::{Namespace, PrefixConflict};
+    use crate::node::*;
+    use crate::reader::n_triples_parser::NTriplesParser;
+    use crate::reader::rdf_parser::RdfParser;
+    use crate::reader::turtle_parser::TurtleParser;
+    use crate::specs::rdf_syntax_specs::RdfSyntaxDataTypes;
+    use crate::specs::xml_specs::XmlDataTypes;
+    use crate::triple::Triple;
+    use crate::uri::Uri;
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn empty_graph() {
+        let graph = Graph::new(None);
+        assert_eq!(graph.is_empty(), true);
+    }
+
+    #[test]
+    fn create_literal_node() {
+        let graph = Graph::new(None);
+        let literal_node = graph.create_literal_node("literal".to_string());
+
+        assert_eq!(
+            literal_node,
+            Node::LiteralNode {
+                literal: "literal".to_string(),
+                data_type: None,
+                language: None,
+            }
+        );
+    }
+
+    #[test]
+    fn canonicalize_literals() {
+        let mut graph = Graph::new(None);
+        let subject = graph.create_uri_node_str("http://example.org/a").unwrap();
+        let predicate = graph.create_uri_node_str("http://example.org/b").unwrap();
+        let integer = XmlDataTypes::Integer.to_uri();
+
+        let objects = [
+            graph.create_literal_node_with_data_type("01".to_string(), &integer),
+            graph.create_literal_node_with_data_type("1".to_string(), &integer),
+            graph.create_literal_node_with_language("chat".to_string(), "FR".to_string()),
+            graph.create_literal_node("x".to_string()),
+            graph.create_literal_node("x".to_string()),
+        ];
+
+        for object in objects.iter() {
+            graph.add_triple(&Triple::new(&subject, &predicate, object));
+        }
+
+        graph.canonicalize_literals();
+
+        let objects: Vec<&Node> = graph.triples_iter().map(|t| t.object()).collect();
+
+        assert_eq!(
+            objects,
+            vec![
+                &graph.create_literal_node_with_data_type("1".to_string(), &integer),
+                &graph.create_literal_node_with_language("chat".to_string(), "fr".to_string()),
+                &graph.create_literal_node("x".to_string()),
+                &graph.create_literal_node("x".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn created_blank_nodes_do_not_clash_with_added_blank_nodes() {
+        let mut graph = Graph::new(None);
+        let predicate = graph.create_uri_node_str("http://example.org/p").unwrap();
+        let added = graph.create_blank_node_with_id("auto0".to_string());
+
+        graph.add_triple(&Triple::new(&added, &predicate, &added));
+
+        assert_eq!(
+            graph.create_blank_node(),
+            Node::BlankNode {
+                id: "auto1".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn add_blank_nodes_with_largest_id() {
+        let graph = NTriplesParser::from_string(
+            "_:auto18446744073709551615 <http://example.org/p> <http://example.org/o> .",
+        )
+        .decode()
+        .unwrap();
+
+        assert_eq!(graph.count(), 1);
+    }
+
+    #[test]
+    fn merge_graphs_with_same_blank_node_labels() {
+        let mut graph = Graph::new(None);
+        let predicate = graph.create_uri_node_str("http://example.org/p").unwrap();
+        let a = graph.create_blank_node_with_id("a".to_string());
+        let generated = graph.create_blank_node();
+        graph.add_triple(&Triple::new(&a, &predicate, &generated));
+
+        let mut other = Graph::new(None);
+        let b = other.create_blank_node_with_id("b".to_string());
+        other.add_triple(&Triple::new(&a, &predicate, &b));
+        other.add_triple(&Triple::new(&b, &predicate, &generated));
+
+        graph.merge(&other);
+
+        let blank_nodes: BTreeSet<&Node> = graph
+            .triples_iter()
+            .flat_map(|triple| vec![triple.subject(), triple.object()])
+            .collect();
+
+        assert_eq!(graph.count(), 3);
+        assert_eq!(blank_nodes.len(), 5);
+        assert!(blank_nodes.contains(&b));
+    }
+
+    #[test]
+    fn create_multiple_blank_nodes() {
+        let mut graph = Graph::new(None);
+
+        let blank_node_0 = graph.create_blank_node();
+        let blank_node_1 = graph.create_blank_node();
+
+        assert_eq!(
+            blank_node_0,
+            Node::BlankNode {
+                id: "auto0".to_string(),
+            }
+        );
+
+        assert_eq!(
+            blank_node_1,
+            Node::BlankNode {
+                id: "auto1".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn set_operations_on_graphs() {
+        let mut first = Graph::new(None);
+        let mut second = Graph::new(None);
+        let a = first.create_uri_node_str("http://example.org/a").unwrap();
+        let b = first.create_uri_node_str("http://example.org/b").unwrap();
+        let c = first.create_uri_node_str("http://example.org/c").unwrap();
+
+        let both = Triple::new(&a, &b, &c);
+        let only_first = Triple::new(&a, &b, &a);
+        let only_second = Triple::new(&c, &b, &a);
+
+        first.add_triple(&both);
+        first.add_triple(&only_first);
+        second.add_triple(&both);
+        second.add_triple(&only_second);
+
+        let triples = |graph: Graph| -> Vec<Triple> { graph.triples_iter().cloned().collect() };
+        let conflict = PrefixConflict::Fail;
+
+        assert_eq!(
+            triples(first.union(&second, conflict).unwrap()),
+            vec![both.clone(), only_first.clone(), only_second.clone()]
+        );
+        assert_eq!(
+            triples(first.intersection(&second, conflict).unwrap()),
+            vec![both.clone()]
+        );
+        assert_eq!(
+            triples(first.difference(&second, conflict).unwrap()),
+            vec![only_first.clone()]
+        );
+        assert_eq!(
+            triples(first.symmetric_difference(&second, conflict).unwrap()),
+            vec![only_first, only_second]
+        );
+    }
+
+    #[test]
+    fn set_operations_merge_namespaces_and_base_uri() {
+        let mut first = Graph::new(None);
+        first.add_namespace(&Namespace::new(
+            "ex".to_string(),
+            Uri::parse("http://example.org/").unwrap(),
+        ));
+
+        let mut second = Graph::new(Some(&Uri::parse("http://example.com/").unwrap()));
+        second.add_namespace(&Namespace::new(
+            "ex".to_string(),
+            Uri::parse("http://example.com/").unwrap(),
+        ));
+        second.add_namespace(&Namespace::new(
+            "foaf".to_string(),
+            Uri::parse("http://xmlns.com/foaf/0.1/").unwrap(),
+        ));
+
+        let kept = first.union(&second, PrefixConflict::KeepFirst).unwrap();
+        assert_eq!(
+            kept.get_namespace_uri_by_prefix("ex").unwrap().to_string(),
+            "http://example.org/"
+        );
+        assert_eq!(kept.namespaces().len(), 2);
+        assert_eq!(
+            kept.base_uri().as_ref().unwrap().to_string(),
+            "http://example.com/"
+        );
+
+        let replaced = first
+            .difference(&second, PrefixConflict::KeepSecond)
+            .unwrap();
+        assert_eq!(
+            replaced
+                .get_namespace_uri_by_prefix("ex")
+                .unwrap()
+                .to_string(),
+            "http://example.com/"
+        );
+
+        let renamed = first.intersection(&second, PrefixConflict::Rename).unwrap();
+        assert_eq!(renamed.namespaces().len(), 3);
+
+        assert!(first.union(&second, PrefixConflict::Fail).is_err());
+    }
+
+    #[test]
+    fn skolemize_and_deskolemize_blank_nodes() {
+        let mut graph = Graph::new(None);
+        let authority = Uri::parse("https://example.org/data/").unwrap();
+        let skolem = |id: &str| Node::UriNode {
+            uri: Uri::parse(&("https://example.org/.well-known/genid/".to_string() + id)).unwrap(),
+        };
+        let a = Node::BlankNode {
+            id: "a".to_string(),
+        };
+        let p = graph.create_uri_node_str("http://example.org/p").unwrap();
+
+        graph.add_triple(&Triple::new(&a, &p, &skolem("a")));
+
+        graph.skolemize(&authority).unwrap();
+
+        let triple = graph.triples_iter().next().unwrap().clone();
+        assert_eq!(*triple.object(), skolem("a"));
+        assert_eq!(*triple.subject(), skolem("auto0"));
+        assert!(graph.triples.get_blank_nodes().is_empty());
+
+        // the skolemized graph is unchanged by other authorities
+        graph
+            .deskolemize(&Uri::parse("https://example.com").unwrap())
+            .unwrap();
+        assert!(graph.contains_triple(&triple));
+
+        graph.deskolemize(&authority).unwrap();
+
+        let triple = graph.triples_iter().next().unwrap();
+        assert_eq!(
+            *triple.subject(),
+            Node::BlankNode {
+                id: "auto0".to_string()
+            }
+        );
+        assert_eq!(*triple.object(), a);
+        assert_eq!(
+            graph.create_blank_node(),
+            Node::BlankNode {
+                id: "auto1".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn deskolemize_keeps_blank_nodes_apart() {
+        let mut graph = Graph::new(None);
+        let authority = Uri::parse("http://example.org").unwrap();
+        let a = Node::BlankNode {
+            id: "a".to_string(),
+        };
+        let skolem_a = graph
+            .create_uri_node_str("http://example.org/.well-known/genid/a")
+            .unwrap();
+
+        graph.add_triple(&Triple::new(&a, &a, &skolem_a));
+        graph.deskolemize(&authority).unwrap();
+
+        let triple = graph.triples_iter().next().unwrap();
+        assert_eq!(*triple.subject(), a);
+        assert_ne!(*triple.object(), a);
+        assert!(matches!(*triple.object(), Node::BlankNode { .. }));
+    }
+
+    #[test]
+    fn skolemize_requires_authority() {
+        let mut graph = Graph::new(None);
+
+        for authority in &["urn:example:a", "/relative", "http://exa mple.org/"] {
+            match graph.skolemize(&Uri::new_unchecked(authority.to_string())) {
+                Err(err) => match err.error_type() {
+                    ErrorType::InvalidUri => {}
+                    _ => panic!("Unexpected error type for {}", authority),
+                },
+                Ok(_) => panic!("Authority {} should be rejected", authority),
+            }
+        }
+    }
+
+    #[test]
+    fn read_lists_of_turtle_documents() {
+        let input = "<http://example.org/a> <http://example.org/b> ( 1 ( ) ( 2 ) ) .";
+        let graph = TurtleParser::from_string(input.to_string())
+            .decode()
+            .unwrap();
+        let nil = graph.create_uri_node(&RdfSyntaxDataTypes::ListNil.to_uri());
+        let b = graph.create_uri_node_str("http://example.org/b").unwrap();
+        let head = graph.get_triples_with_predicate(&b)[0].object();
+
+        let elements = graph.read_list(head).unwrap();
+
+        assert_eq!(elements.len(), 3);
+        assert_eq!(elements[0], graph.create_integer_node(1));
+        assert_eq!(elements[1], nil);
+        assert_eq!(
+            graph.read_list(&elements[2]).unwrap(),
+            vec![graph.create_integer_node(2)]
+        );
+        assert_eq!(graph.read_list(&nil).unwrap(), vec![]);
+    }
+
+    #[test]
+    fn detect_malformed_lists() {
+        let inputs = vec![
+            // missing rdf:rest
+            "_:l rdf:first 1 .",
+            // two elements
+            "_:l rdf:first 1, 2 ; rdf:rest rdf:nil .",
+            // cycle
+            "_:l rdf:first 1 ; rdf:rest [ rdf:first 2 ; rdf:rest _:l ] .",
+            // literal as list node
+            "_:l rdf:first 1 ; rdf:rest \"rest\" .",
+        ];
+
+        for input in inputs {
+            let input =
+                "@prefix rdf: <http://www.w3.org/1999/02/22-rdf-syntax-ns#> . ".to_string() + input;
+            let graph = TurtleParser::from_string(input.clone()).decode().unwrap();
+            let head = Node::BlankNode {
+                id: "l".to_string(),
+            };
+
+            match graph.read_list(&head) {
+                Err(err) => match err.error_type() {
+                    ErrorType::InvalidCollection => {}
+                    _ => panic!("Unexpected error type for {}", input),
+                },
+                Ok(_) => panic!("List should be malformed: {}", input),
+            }
+        }
+    }
+
+    #[test]
+    fn edit_lists_in_place() {
+        let mut graph = Graph::new(None);
+        let nil = graph.create_uri_node(&RdfSyntaxDataTypes::ListNil.to_uri());
+        let node = |n| graph.create_integer_node(n);
+        let (one, two, three) = (node(1), node(2), node(3));
+
+        assert_eq!(graph.add_list(&[]), nil);
+
+        let head = graph.push_list_item(&nil, &two).unwrap();
+        assert_ne!(head, nil);
+
+        assert_eq!(graph.insert_list_item(&head, 0, &one).unwrap(), head);
+        assert_eq!(graph.push_list_item(&head, &three).unwrap(), head);
+        assert_eq!(
+            graph.read_list(&head).unwrap(),
+            vec![one.clone(), two.clone(), three.clone()]
+        );
+        assert_eq!(graph.count(), 6);
+
+        graph.set_list_item(&head, 2, &one).unwrap();
+        graph.remove_list_item(&head, 0).unwrap();
+        assert_eq!(graph.read_list(&head).unwrap(), vec![two, one]);
+        assert_eq!(graph.count(), 4);
+
+        assert!(graph.insert_list_item(&head, 3, &three).is_err());
+        assert!(graph.remove_list_item(&head, 2).is_err());
+
+        graph.remove_list_item(&head, 0).unwrap();
+        assert_eq!(graph.remove_list_item(&head, 0).unwrap(), nil);
+        assert!(graph.is_empty());
+    }
+
+    #[test]
+    fn read_and_edit_containers() {
+        let input = "@prefix rdf: <http://www.w3.org/1999/02/22-rdf-syntax-ns#> .
+                     _:c a rdf:Alt ; rdf:_3 \"c\" ; rdf:_1 \"a\" ; rdf:_01 \"x\" ;
+                         rdf:_ \"y\" ; rdf:first \"z\" .";
+        let mut graph = TurtleParser::from_string(input.to_string())
+            .decode()
+            .unwrap();
+        let container = Node::BlankNode {
+            id: "c".to_string(),
+        };
+        let literal = |value: &str| Node::LiteralNode {
+            literal: value.to_string(),
+            data_type: None,
+            language: None,
+        };
+
+        assert_eq!(graph.container_type(&container), Some(ContainerType::Alt));
+        assert_eq!(
+            graph.read_container(&container).unwrap(),
+            vec![literal("a"), literal("c")]
+        );
+
+        graph
+            .insert_container_item(&container, 1, &literal("b"))
+            .unwrap();
+        graph
+            .push_container_item(&container, &literal("d"))
+            .unwrap();
+        graph.remove_container_item(&container, 0).unwrap();
+        graph
+            .set_container_item(&container, 2, &literal("e"))
+            .unwrap();
+
+        assert_eq!(
+            graph.read_container(&container).unwrap(),
+            vec![literal("b"), literal("c"), literal("e")]
+        );
+        assert!(graph
+            .set_container_item(&container, 3, &literal("f"))
+            .is_err());
+
+        let member = graph.create_uri_node(&RdfSyntaxDataTypes::member_uri(3));
+        assert_eq!(
+            graph.get_triples_with_predicate(&member)[0].object(),
+            &literal("e")
+        );
+
+        graph.add_triple(&Triple::new(&container, &member, &literal("f")));
+        assert!(graph.read_container(&container).is_err());
+
+        let other = graph.create_blank_node();
+        assert_eq!(graph.container_type(&other), None);
+        assert_eq!(graph.read_container(&other).unwrap(), vec![]);
     }
 }
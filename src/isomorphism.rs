@@ -0,0 +1,837 @@
+use crate::graph::Graph;
+use crate::node::Node;
+use crate::triple::Triple;
+use crate::writer::formatter::n_triples_formatter::NTriplesFormatter;
+use crate::writer::formatter::rdf_formatter::RdfFormatter;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+/// Triple that shows that two graphs are not isomorphic.
+#[derive(Clone, PartialEq, Debug)]
+pub enum Mismatch {
+    /// Triple of the first graph that has no counterpart in the second graph.
+    OnlyInFirst(Triple),
+
+    /// Triple of the second graph that has no counterpart in the first graph.
+    OnlyInSecond(Triple),
+}
+
+impl Mismatch {
+    /// Returns the triple without counterpart in the other graph.
+    pub fn triple(&self) -> &Triple {
+        match *self {
+            Mismatch::OnlyInFirst(ref triple) | Mismatch::OnlyInSecond(ref triple) => triple,
+        }
+    }
+}
+
+impl fmt::Display for Mismatch {
+    /// Writes the triple in N-Triples syntax together with the graph it belongs to.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let graph = match *self {
+            Mismatch::OnlyInFirst(_) => "first",
+            Mismatch::OnlyInSecond(_) => "second",
+        };
+
+        let formatter = NTriplesFormatter::new();
+        let triple = self.triple();
+
+        write!(
+            f,
+            "Triple only in {} graph: {} {} {} .",
+            graph,
+            formatter.format_node(triple.subject()),
+            formatter.format_node(triple.predicate()),
+            formatter.format_node(triple.object())
+        )
+    }
+}
+
+/// Checks if two graphs are isomorphic, which means they are equal up to the labels of their
+/// blank nodes.
+///
+/// Returns the bijection from the blank nodes of the first graph to the blank nodes of the
+/// second graph. If the graphs are not isomorphic, a triple of one graph that has no
+/// counterpart in the other graph is returned. Duplicate triples, namespaces and the base URI
+/// are not compared.
+///
+/// Blank nodes are distinguished by iteratively hashing their neighbourhood, so a search for
+/// the bijection is only needed for blank nodes that cannot be distinguished this way. The
+/// blank nodes of both graphs are refined together, and after a choice only the blank nodes
+/// that are affected by it are refined again.
+///
+/// # Examples
+///
+/// ```
+/// use rdf::isomorphism::{is_isomorphic, Mismatch};
+/// use rdf::reader::rdf_parser::RdfParser;
+/// use rdf::reader::turtle_parser::TurtleParser;
+///
+/// let first = TurtleParser::from_string("_:a <http://example.org/knows> _:b .").decode().unwrap();
+/// let second = TurtleParser::from_string("_:x <http://example.org/knows> _:y .").decode().unwrap();
+/// let third = TurtleParser::from_string("_:x <http://example.org/knows> _:x .").decode().unwrap();
+///
+/// let bijection = is_isomorphic(&first, &second).unwrap();
+/// assert_eq!(bijection.len(), 2);
+///
+/// match is_isomorphic(&first, &third) {
+///   Err(Mismatch::OnlyInFirst(triple)) => assert_eq!(first.get_triples_with_subject(triple.subject()).len(), 1),
+///   _ => assert!(false),
+/// }
+/// ```
+#[allow(clippy::result_large_err)]
+pub fn is_isomorphic(first: &Graph, second: &Graph) -> Result<BTreeMap<Node, Node>, Mismatch> {
+    let first = IndexedGraph::new(first);
+    let second = IndexedGraph::new(second);
+
+    if let Some(triple) = first.ground.difference(&second.ground).next() {
+        return Err(Mismatch::OnlyInFirst((*triple).clone()));
+    }
+
+    if let Some(triple) = second.ground.difference(&first.ground).next() {
+        return Err(Mismatch::OnlyInSecond((*triple).clone()));
+    }
+
+    let bijection = search(&first, &second).map_err(|witness| match witness {
+        Witness::First(triple) => Mismatch::OnlyInFirst(triple.clone()),
+        Witness::Second(triple) => Mismatch::OnlyInSecond(triple.clone()),
+    })?;
+
+    Ok(bijection
+        .iter()
+        .enumerate()
+        .map(|(index, &other)| {
+            (
+                first.blank_nodes[index].clone(),
+                second.blank_nodes[other].clone(),
+            )
+        })
+        .collect())
+}
+
+/// Triple without counterpart that is found while searching the bijection.
+enum Witness<'g> {
+    First(&'g Triple),
+    Second(&'g Triple),
+}
+
+/// Term of a triple with blank nodes.
+#[derive(Clone, Copy)]
+enum Term {
+    /// Hash of a node that is not a blank node.
+    Ground(u64),
+
+    /// Index of a blank node.
+    Blank(usize),
+}
+
+/// Triples of a graph, where the triples with blank nodes refer to them by index.
+struct IndexedGraph<'g> {
+    blank_nodes: Vec<&'g Node>,
+
+    /// Triples without blank nodes.
+    ground: BTreeSet<&'g Triple>,
+
+    /// Triples with blank nodes.
+    triples: Vec<(&'g Triple, [Term; 3])>,
+
+    /// Indices of the triples that contain each blank node.
+    occurrences: Vec<Vec<usize>>,
+}
+
+impl<'g> IndexedGraph<'g> {
+    fn new(graph: &'g Graph) -> IndexedGraph<'g> {
+        let mut indexed = IndexedGraph {
+            blank_nodes: Vec::new(),
+            ground: BTreeSet::new(),
+            triples: Vec::new(),
+            occurrences: Vec::new(),
+        };
+
+        let mut indices = BTreeMap::new();
+        let triples: BTreeSet<&Triple> = graph.triples_iter().collect();
+
+        for triple in triples {
+            let nodes = [triple.subject(), triple.predicate(), triple.object()];
+
+            if !nodes.iter().any(|node| is_blank(node)) {
+                indexed.ground.insert(triple);
+                continue;
+            }
+
+            let triple_index = indexed.triples.len();
+            let mut terms = [Term::Ground(0); 3];
+
+            for (term, &node) in terms.iter_mut().zip(nodes.iter()) {
+                *term = if is_blank(node) {
+                    let index = *indices.entry(node).or_insert_with(|| {
+                        indexed.blank_nodes.push(node);
+                        indexed.occurrences.push(Vec::new());
+                        indexed.blank_nodes.len() - 1
+                    });
+
+                    // a blank node may occur multiple times in the same triple
+                    if indexed.occurrences[index].last() != Some(&triple_index) {
+                        indexed.occurrences[index].push(triple_index);
+                    }
+
+                    Term::Blank(index)
+                } else {
+                    Term::Ground(hash_node(node))
+                };
+            }
+
+            indexed.triples.push((triple, terms));
+        }
+
+        indexed
+    }
+
+    /// Returns a triple that contains the blank node.
+    fn occurrence(&self, index: usize) -> &'g Triple {
+        self.triples[self.occurrences[index][0]].0
+    }
+
+    /// Returns the triple of the graph with the blank nodes of the other graph.
+    fn map_triple(&self, triple_index: usize, other: &IndexedGraph, bijection: &[usize]) -> Triple {
+        let (triple, ref terms) = self.triples[triple_index];
+        let nodes = [triple.subject(), triple.predicate(), triple.object()];
+
+        let mapped: Vec<&Node> = terms
+            .iter()
+            .zip(nodes.iter())
+            .map(|(term, &node)| match *term {
+                Term::Blank(index) => other.blank_nodes[bijection[index]],
+                Term::Ground(_) => node,
+            })
+            .collect();
+
+        Triple::new(mapped[0], mapped[1], mapped[2])
+    }
+}
+
+/// Blank nodes of both graphs, where the blank nodes of the second graph follow the blank nodes
+/// of the first graph, so that the classes of both graphs are refined together.
+struct Union<'a, 'g> {
+    first: &'a IndexedGraph<'g>,
+    second: &'a IndexedGraph<'g>,
+}
+
+impl<'a, 'g> Union<'a, 'g> {
+    fn len(&self) -> usize {
+        self.first.blank_nodes.len() + self.second.blank_nodes.len()
+    }
+
+    /// Returns the graph of a blank node, `0` for the first and `1` for the second graph,
+    /// together with the index of the blank node in the graph.
+    fn locate(&self, node: usize) -> (usize, usize) {
+        let first_len = self.first.blank_nodes.len();
+
+        if node < first_len {
+            (0, node)
+        } else {
+            (1, node - first_len)
+        }
+    }
+
+    /// Returns the blank node of the union for the index of a blank node in a graph.
+    fn node(&self, graph: usize, index: usize) -> usize {
+        if graph == 0 {
+            index
+        } else {
+            index + self.first.blank_nodes.len()
+        }
+    }
+
+    fn graph(&self, graph: usize) -> &'a IndexedGraph<'g> {
+        if graph == 0 {
+            self.first
+        } else {
+            self.second
+        }
+    }
+
+    /// Returns the hash of the triples that contain the blank node, which only depends on the
+    /// classes of the other blank nodes.
+    fn signature(&self, node: usize, classes: &[usize]) -> u64 {
+        let (graph_index, index) = self.locate(node);
+        let graph = self.graph(graph_index);
+
+        let mut neighbourhood: Vec<u64> = graph.occurrences[index]
+            .iter()
+            .map(|&triple_index| {
+                let mut hasher = DefaultHasher::new();
+
+                for term in graph.triples[triple_index].1.iter() {
+                    match *term {
+                        Term::Blank(other) if other == index => 2u8.hash(&mut hasher),
+                        Term::Blank(other) => {
+                            (1u8, classes[self.node(graph_index, other)]).hash(&mut hasher)
+                        }
+                        Term::Ground(hash) => (0u8, hash).hash(&mut hasher),
+                    }
+                }
+
+                hasher.finish()
+            })
+            .collect();
+
+        neighbourhood.sort_unstable();
+        hash(&neighbourhood)
+    }
+
+    /// Returns the other blank nodes of the triples that contain the blank node.
+    fn neighbours(&self, node: usize) -> Vec<usize> {
+        let (graph_index, index) = self.locate(node);
+        let graph = self.graph(graph_index);
+
+        graph.occurrences[index]
+            .iter()
+            .flat_map(|&triple_index| graph.triples[triple_index].1.iter())
+            .filter_map(|term| match *term {
+                Term::Blank(other) if other != index => Some(self.node(graph_index, other)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Returns a triple of the blank node as witness that the graphs are not isomorphic.
+    fn witness(&self, node: usize) -> Witness<'g> {
+        match self.locate(node) {
+            (0, index) => Witness::First(self.first.occurrence(index)),
+            (_, index) => Witness::Second(self.second.occurrence(index)),
+        }
+    }
+}
+
+/// Partition of the blank nodes of both graphs into classes of blank nodes that are not
+/// distinguished yet.
+#[derive(Clone)]
+struct Partition {
+    /// Class of each blank node.
+    classes: Vec<usize>,
+
+    /// Signature of each blank node when it was last computed.
+    signatures: Vec<u64>,
+
+    /// Signature that all blank nodes of each class share.
+    class_signatures: Vec<u64>,
+
+    /// Number of blank nodes of each class in the first and in the second graph.
+    counts: Vec<[usize; 2]>,
+}
+
+impl Partition {
+    /// Creates a partition with a single class.
+    fn new(union: &Union) -> Partition {
+        Partition {
+            classes: vec![0; union.len()],
+            signatures: vec![0; union.len()],
+            class_signatures: vec![0],
+            counts: vec![[
+                union.first.blank_nodes.len(),
+                union.second.blank_nodes.len(),
+            ]],
+        }
+    }
+
+    fn add_class(&mut self, signature: u64) -> usize {
+        self.class_signatures.push(signature);
+        self.counts.push([0, 0]);
+        self.counts.len() - 1
+    }
+
+    fn move_node(&mut self, union: &Union, node: usize, class: usize) {
+        let (graph, _) = union.locate(node);
+
+        self.counts[self.classes[node]][graph] -= 1;
+        self.counts[class][graph] += 1;
+        self.classes[node] = class;
+    }
+
+    /// Gives a blank node of each graph a class of their own and refines the classes of the
+    /// affected blank nodes.
+    fn individualize(
+        &mut self,
+        union: &Union,
+        first_node: usize,
+        second_node: usize,
+    ) -> Result<(), usize> {
+        let class = self.add_class(self.signatures[first_node]);
+        self.move_node(union, first_node, class);
+        self.move_node(union, second_node, class);
+
+        let mut affected = union.neighbours(first_node);
+        affected.extend(union.neighbours(second_node));
+
+        self.refine(union, affected)
+    }
+
+    /// Splits the classes until all blank nodes of a class have the same signature.
+    ///
+    /// Only the signatures of the affected blank nodes are computed, and afterwards the
+    /// signatures of the blank nodes next to blank nodes that changed their class. Returns a
+    /// blank node of a class that has more blank nodes in one graph than in the other.
+    fn refine(&mut self, union: &Union, mut affected: Vec<usize>) -> Result<(), usize> {
+        while !affected.is_empty() {
+            affected.sort_unstable();
+            affected.dedup();
+
+            // the signatures are computed before any blank node changes its class
+            let mut groups: BTreeMap<usize, BTreeMap<u64, Vec<usize>>> = BTreeMap::new();
+
+            for &node in affected.iter() {
+                let signature = union.signature(node, &self.classes);
+                self.signatures[node] = signature;
+
+                groups
+                    .entry(self.classes[node])
+                    .or_default()
+                    .entry(signature)
+                    .or_default()
+                    .push(node);
+            }
+
+            let mut moved = Vec::new();
+
+            for (class, groups) in groups {
+                let [first_count, second_count] = self.counts[class];
+                let group_sizes: usize = groups.values().map(|nodes| nodes.len()).sum();
+
+                // blank nodes that are not affected keep their class and signature
+                let kept = if group_sizes < first_count + second_count {
+                    self.class_signatures[class]
+                } else {
+                    *groups.keys().next().unwrap()
+                };
+
+                self.class_signatures[class] = kept;
+
+                for (signature, nodes) in groups {
+                    if signature == kept {
+                        continue;
+                    }
+
+                    let new_class = self.add_class(signature);
+
+                    for &node in nodes.iter() {
+                        self.move_node(union, node, new_class);
+                    }
+
+                    // the class that was split is balanced if all new classes are
+                    let [first_count, second_count] = self.counts[new_class];
+
+                    if first_count != second_count {
+                        let graph = if first_count > second_count { 0 } else { 1 };
+
+                        return Err(*nodes
+                            .iter()
+                            .find(|&&node| union.locate(node).0 == graph)
+                            .unwrap());
+                    }
+
+                    moved.extend(nodes);
+                }
+            }
+
+            affected = moved
+                .iter()
+                .flat_map(|&node| union.neighbours(node))
+                .collect();
+        }
+
+        Ok(())
+    }
+
+    /// Returns a blank node of the first graph of the smallest class with several blank nodes,
+    /// together with the blank nodes of the second graph of the class.
+    fn choice(&self, union: &Union) -> Option<(usize, Vec<usize>)> {
+        let class = (0..self.counts.len())
+            .filter(|&class| self.counts[class][0] > 1)
+            .min_by_key(|&class| self.counts[class][0])?;
+
+        let (first, second): (Vec<usize>, Vec<usize>) = (0..union.len())
+            .filter(|&node| self.classes[node] == class)
+            .partition(|&node| union.locate(node).0 == 0);
+
+        Some((first[0], second))
+    }
+
+    /// Returns the bijection, if each class has one blank node of each graph.
+    fn bijection(&self, union: &Union) -> Vec<usize> {
+        let first_len = union.first.blank_nodes.len();
+        let mut second_nodes = vec![0; self.counts.len()];
+
+        for node in first_len..union.len() {
+            second_nodes[self.classes[node]] = node - first_len;
+        }
+
+        (0..first_len)
+            .map(|node| second_nodes[self.classes[node]])
+            .collect()
+    }
+}
+
+/// Blank node of the first graph that shares its class with other blank nodes, together with
+/// the blank nodes of the second graph it can be mapped to.
+struct Choice {
+    partition: Partition,
+    node: usize,
+    candidates: Vec<usize>,
+    next: usize,
+}
+
+/// Searches a bijection of the blank nodes.
+///
+/// If blank nodes share a class after refinement, each possible choice for one of them is
+/// tried by giving it a class of its own. The choices are kept on a stack instead of recursing,
+/// since graphs may contain many blank nodes that cannot be distinguished.
+fn search<'g>(
+    first: &IndexedGraph<'g>,
+    second: &IndexedGraph<'g>,
+) -> Result<Vec<usize>, Witness<'g>> {
+    let union = Union { first, second };
+    let mut partition = Partition::new(&union);
+
+    match partition.counts[0] {
+        [0, 0] => return Ok(Vec::new()),
+        [first_count, second_count] if first_count > second_count => return Err(union.witness(0)),
+        [first_count, second_count] if first_count < second_count => {
+            return Err(union.witness(first_count))
+        }
+        _ => {}
+    }
+
+    partition
+        .refine(&union, (0..union.len()).collect())
+        .and_then(|()| compare_components(&union, &partition))
+        .map_err(|node| union.witness(node))?;
+
+    let mut choices: Vec<Choice> = Vec::new();
+
+    loop {
+        let mut witness = match partition.choice(&union) {
+            Some((node, candidates)) => {
+                choices.push(Choice {
+                    partition: partition.clone(),
+                    node,
+                    candidates,
+                    next: 0,
+                });
+                None
+            }
+            None => {
+                let bijection = partition.bijection(&union);
+
+                match verify(first, second, &bijection) {
+                    Ok(()) => return Ok(bijection),
+                    Err(witness) => Some(witness),
+                }
+            }
+        };
+
+        // backtrack to the last choice with remaining candidates
+        loop {
+            match choices.last_mut() {
+                Some(choice) if choice.next < choice.candidates.len() => {
+                    let candidate = choice.candidates[choice.next];
+                    choice.next += 1;
+
+                    partition = choice.partition.clone();
+
+                    match partition.individualize(&union, choice.node, candidate) {
+                        Ok(()) => break,
+                        Err(node) => witness = Some(union.witness(node)),
+                    }
+                }
+                Some(_) => {
+                    choices.pop();
+                }
+                None => return Err(witness.unwrap_or_else(|| Witness::First(first.triples[0].0))),
+            }
+        }
+    }
+}
+
+/// Checks that both graphs have connected components of blank nodes with the same classes,
+/// so that graphs that only differ in the way their components are connected are not searched.
+/// Returns a blank node of a component without counterpart.
+fn compare_components(union: &Union, partition: &Partition) -> Result<(), usize> {
+    fn root(parents: &mut [usize], mut node: usize) -> usize {
+        while parents[node] != node {
+            parents[node] = parents[parents[node]];
+            node = parents[node];
+        }
+
+        node
+    }
+
+    let mut parents: Vec<usize> = (0..union.len()).collect();
+
+    for node in 0..union.len() {
+        for neighbour in union.neighbours(node) {
+            let (a, b) = (root(&mut parents, node), root(&mut parents, neighbour));
+            parents[a.max(b)] = a.min(b);
+        }
+    }
+
+    let mut components: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+
+    for node in 0..union.len() {
+        let component = root(&mut parents, node);
+
+        components
+            .entry(component)
+            .or_default()
+            .push(partition.classes[node]);
+    }
+
+    // components of both graphs with the same classes of blank nodes
+    let mut matching: BTreeMap<Vec<usize>, [Vec<usize>; 2]> = BTreeMap::new();
+
+    for (component, mut classes) in components {
+        classes.sort_unstable();
+
+        matching.entry(classes).or_default()[union.locate(component).0].push(component);
+    }
+
+    for [first, second] in matching.values() {
+        if first.len() > second.len() {
+            return Err(first[0]);
+        }
+
+        if second.len() > first.len() {
+            return Err(second[0]);
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks that the bijection maps all triples of the first graph to triples of the second graph.
+fn verify<'g>(
+    first: &IndexedGraph<'g>,
+    second: &IndexedGraph<'g>,
+    bijection: &[usize],
+) -> Result<(), Witness<'g>> {
+    let second_triples: BTreeSet<&Triple> =
+        second.triples.iter().map(|&(triple, _)| triple).collect();
+
+    for (triple_index, &(triple, _)) in first.triples.iter().enumerate() {
+        if !second_triples.contains(&first.map_triple(triple_index, second, bijection)) {
+            return Err(Witness::First(triple));
+        }
+    }
+
+    Ok(())
+}
+
+fn is_blank(node: &Node) -> bool {
+    matches!(*node, Node::BlankNode { .. })
+}
+
+fn hash<T: Hash>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Returns the hash of a node that is not a blank node.
+fn hash_node(node: &Node) -> u64 {
+    match *node {
+        Node::UriNode { ref uri } => hash(&(0u8, uri.to_string())),
+        Node::LiteralNode {
+            ref literal,
+            ref data_type,
+            ref language,
+        } => hash(&(
+            1u8,
+            literal,
+            data_type.as_ref().map(|data_type| data_type.to_string()),
+            language,
+        )),
+        Node::BlankNode { ref id } => hash(&(2u8, id)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::graph::Graph;
+    use crate::isomorphism::*;
+    use crate::reader::rdf_parser::RdfParser;
+    use crate::reader::turtle_parser::TurtleParser;
+    use crate::triple::Triple;
+
+    fn parse(input: &str) -> Graph {
+        TurtleParser::from_string("@prefix : <http://example.org/> .\n".to_string() + input)
+            .decode()
+            .unwrap()
+    }
+
+    #[test]
+    fn isomorphic_graphs() {
+        let examples = [
+            ("", ""),
+            (":a :b :c .", ":a :b :c ."),
+            (":a :b _:x .", ":a :b _:y ."),
+            (
+                ":a :b [ :c [ :d 1 ] ] .",
+                ":a :b _:b1 . _:b1 :c _:b2 . _:b2 :d 1 .",
+            ),
+            (":a :b ( 1 2 3 ) .", ":a :b ( 1 2 3 ) ."),
+            // cycles of blank nodes that cannot be distinguished by hashing
+            (
+                "_:a :p _:b . _:b :p _:c . _:c :p _:a . _:d :p _:e . _:e :p _:f . _:f :p _:d .",
+                "_:u :p _:v . _:v :p _:w . _:w :p _:u . _:x :p _:y . _:y :p _:z . _:z :p _:x .",
+            ),
+            (
+                "_:a :p _:b . _:b :p _:a . _:a :q _:a .",
+                "_:y :p _:x . _:x :p _:y . _:x :q _:x .",
+            ),
+        ];
+
+        for &(first, second) in examples.iter() {
+            let first = parse(first);
+            let second = parse(second);
+
+            assert!(
+                is_isomorphic(&first, &second).is_ok(),
+                "{:?} != {:?}",
+                first,
+                second
+            );
+        }
+    }
+
+    #[test]
+    fn many_indistinguishable_blank_nodes() {
+        let mut first = String::new();
+        let mut second = String::new();
+
+        for index in 0..200 {
+            first.push_str(&format!(":a :p _:b{} . _:b{} :q :c .\n", index, index));
+            second.push_str(&format!(
+                ":a :p _:x{} . _:x{} :q :c .\n",
+                499 - index,
+                499 - index
+            ));
+        }
+
+        let bijection = is_isomorphic(&parse(&first), &parse(&second)).unwrap();
+
+        assert_eq!(bijection.len(), 200);
+    }
+
+    #[test]
+    fn not_isomorphic_graphs() {
+        let examples = [
+            (":a :b :c .", ""),
+            (":a :b _:x .", ":a :b _:x . :a :b _:y ."),
+            (":a :b _:x . _:x :c 1 .", ":a :b _:x . _:x :c 2 ."),
+            // two cycles of three against one cycle of six
+            (
+                "_:a :p _:b . _:b :p _:c . _:c :p _:a . _:d :p _:e . _:e :p _:f . _:f :p _:d .",
+                "_:u :p _:v . _:v :p _:w . _:w :p _:x . _:x :p _:y . _:y :p _:z . _:z :p _:u .",
+            ),
+        ];
+
+        for &(first, second) in examples.iter() {
+            let first = parse(first);
+            let second = parse(second);
+
+            assert!(
+                is_isomorphic(&first, &second).is_err(),
+                "{:?} == {:?}",
+                first,
+                second
+            );
+            assert!(
+                is_isomorphic(&second, &first).is_err(),
+                "{:?} == {:?}",
+                second,
+                first
+            );
+        }
+    }
+
+    /// Returns cycles of blank nodes with the given lengths.
+    fn cycles(prefix: &str, lengths: &[usize]) -> String {
+        let mut input = String::new();
+        let mut start = 0;
+
+        for &length in lengths.iter() {
+            for index in 0..length {
+                input.push_str(&format!(
+                    "_:{}{} :p _:{}{} .\n",
+                    prefix,
+                    start + index,
+                    prefix,
+                    start + (index + 1) % length
+                ));
+            }
+
+            start += length;
+        }
+
+        input
+    }
+
+    #[test]
+    fn large_cycles_of_blank_nodes() {
+        let one_cycle = parse(&cycles("a", &[10000]));
+
+        assert!(is_isomorphic(&parse(&cycles("b", &[5000, 5000])), &one_cycle).is_err());
+        assert!(is_isomorphic(&one_cycle, &parse(&cycles("c", &[10000]))).is_ok());
+
+        // cycles with chords of different lengths
+        let first = parse(&(cycles("a", &[6000]) + "_:a0 :q _:a3000 ."));
+        let second = parse(&(cycles("b", &[6000]) + "_:b0 :q _:b2999 ."));
+
+        assert!(is_isomorphic(&first, &second).is_err());
+    }
+
+    #[test]
+    fn bijection_of_blank_nodes() {
+        let mut first = Graph::new(None);
+        let subject = first.create_blank_node();
+        let predicate = first.create_uri_node_str("http://example.org/p").unwrap();
+        let object = first.create_blank_node();
+        first.add_triple(&Triple::new(&subject, &predicate, &object));
+
+        let second = parse("_:art :p _:bob .");
+        let bijection = is_isomorphic(&first, &second).unwrap();
+
+        assert_eq!(
+            bijection[&subject],
+            second.create_blank_node_with_id("art".to_string())
+        );
+        assert_eq!(
+            bijection[&object],
+            second.create_blank_node_with_id("bob".to_string())
+        );
+    }
+
+    #[test]
+    fn mismatch_witness() {
+        let first = parse(":a :b _:x . _:x :c 1 .");
+        let second = parse(":a :b _:y . _:y :c 2 .");
+
+        match is_isomorphic(&first, &second) {
+            Err(Mismatch::OnlyInFirst(triple)) => {
+                assert!(first.triples_iter().any(|t| *t == triple))
+            }
+            other => panic!("unexpected result {:?}", other),
+        }
+
+        match is_isomorphic(&parse(":a :b :c ."), &parse(":a :b :c . :a :b 2 .")) {
+            Err(mismatch) => assert_eq!(
+                mismatch.to_string(),
+                "Triple only in second graph: <http://example.org/a> <http://example.org/b> \
+                 \"2\"^^<http://www.w3.org/2001/XMLSchema#integer> ."
+            ),
+            Ok(_) => panic!("graphs are isomorphic"),
+        }
+    }
+}
@@ -0,0 +1,511 @@
+use crate::error::{Error, ErrorType};
+use crate::reader::input_reader::{Position, Span};
+use crate::Result;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::iter::Peekable;
+use std::str::Chars;
+
+/// Members of a JSON object, ordered by key.
+pub type JsonObject = BTreeMap<String, JsonValue>;
+
+/// Representation of a JSON value.
+///
+/// Numbers keep their lexical form so that integers of arbitrary size survive a round trip.
+#[derive(Clone, PartialEq, Debug)]
+pub enum JsonValue {
+    Null,
+    Boolean(bool),
+    Number(String),
+    String(String),
+    Array(Vec<JsonValue>),
+    Object(JsonObject),
+}
+
+impl JsonValue {
+    /// Parses a JSON document.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::json::JsonValue;
+    ///
+    /// let value = JsonValue::parse("{\"name\": [\"a\", 1, true, null]}").unwrap();
+    ///
+    /// assert_eq!(value.get("name").unwrap().as_array().unwrap().len(), 4);
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - The input is not a valid JSON document.
+    ///
+    pub fn parse(input: &str) -> Result<JsonValue> {
+        let mut reader = JsonReader {
+            chars: input.chars().peekable(),
+        };
+
+        reader.read_document().map_err(|err| {
+            // the error is located behind the last character that was read
+            let remaining: usize = reader.chars.map(|c| c.len_utf8()).sum();
+            let read = &input[..input.len() - remaining];
+
+            let mut position = Position::default();
+            read.chars().for_each(|c| position.advance(c));
+
+            let line = read.rsplit('\n').next().unwrap_or("");
+            let snippet: String = line.chars().rev().take(60).collect();
+            let snippet: String = snippet.chars().rev().collect();
+
+            err.with_location(Span::new(position, position), snippet.trim().to_string())
+        })
+    }
+
+    /// Returns `true` if the value is `null`.
+    pub fn is_null(&self) -> bool {
+        *self == JsonValue::Null
+    }
+
+    /// Returns the string content if the value is a string.
+    pub fn as_str(&self) -> Option<&str> {
+        match *self {
+            JsonValue::String(ref s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// Returns the boolean if the value is a boolean.
+    pub fn as_bool(&self) -> Option<bool> {
+        match *self {
+            JsonValue::Boolean(b) => Some(b),
+            _ => None,
+        }
+    }
+
+    /// Returns the elements if the value is an array.
+    pub fn as_array(&self) -> Option<&Vec<JsonValue>> {
+        match *self {
+            JsonValue::Array(ref a) => Some(a),
+            _ => None,
+        }
+    }
+
+    /// Returns the members if the value is an object.
+    pub fn as_object(&self) -> Option<&JsonObject> {
+        match *self {
+            JsonValue::Object(ref o) => Some(o),
+            _ => None,
+        }
+    }
+
+    /// Returns the member with the provided key if the value is an object.
+    pub fn get(&self, key: &str) -> Option<&JsonValue> {
+        self.as_object().and_then(|o| o.get(key))
+    }
+
+    /// Returns the value as array, wrapping non-array values.
+    pub fn to_vec(&self) -> Vec<JsonValue> {
+        match *self {
+            JsonValue::Array(ref a) => a.clone(),
+            ref v => vec![v.clone()],
+        }
+    }
+
+    /// Serializes the value using the provided number of spaces for indentation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::json::JsonValue;
+    ///
+    /// let value = JsonValue::parse("{\"a\": [1]}").unwrap();
+    ///
+    /// assert_eq!(value.to_pretty_string(2), "{\n  \"a\": [\n    1\n  ]\n}");
+    /// ```
+    pub fn to_pretty_string(&self, indentation: usize) -> String {
+        let mut output_string = String::new();
+        self.write_pretty(&mut output_string, indentation, 0);
+        output_string
+    }
+
+    fn write_pretty(&self, output_string: &mut String, indentation: usize, level: usize) {
+        let inner = " ".repeat(indentation * (level + 1));
+        let outer = " ".repeat(indentation * level);
+
+        match *self {
+            JsonValue::Array(ref elements) if !elements.is_empty() => {
+                output_string.push_str("[\n");
+
+                for (i, element) in elements.iter().enumerate() {
+                    if i > 0 {
+                        output_string.push_str(",\n");
+                    }
+
+                    output_string.push_str(&inner);
+                    element.write_pretty(output_string, indentation, level + 1);
+                }
+
+                output_string.push('\n');
+                output_string.push_str(&outer);
+                output_string.push(']');
+            }
+            JsonValue::Object(ref members) if !members.is_empty() => {
+                output_string.push_str("{\n");
+
+                for (i, (key, value)) in members.iter().enumerate() {
+                    if i > 0 {
+                        output_string.push_str(",\n");
+                    }
+
+                    output_string.push_str(&inner);
+                    output_string.push_str(&escape_json_string(key));
+                    output_string.push_str(": ");
+                    value.write_pretty(output_string, indentation, level + 1);
+                }
+
+                output_string.push('\n');
+                output_string.push_str(&outer);
+                output_string.push('}');
+            }
+            ref value => output_string.push_str(&value.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for JsonValue {
+    /// Serializes the value without insignificant whitespace.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            JsonValue::Null => write!(f, "null"),
+            JsonValue::Boolean(b) => write!(f, "{}", b),
+            JsonValue::Number(ref n) => write!(f, "{}", n),
+            JsonValue::String(ref s) => write!(f, "{}", escape_json_string(s)),
+            JsonValue::Array(ref elements) => {
+                write!(f, "[")?;
+
+                for (i, element) in elements.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+
+                    write!(f, "{}", element)?;
+                }
+
+                write!(f, "]")
+            }
+            JsonValue::Object(ref members) => {
+                write!(f, "{{")?;
+
+                for (i, (key, value)) in members.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+
+                    write!(f, "{}:{}", escape_json_string(key), value)?;
+                }
+
+                write!(f, "}}")
+            }
+        }
+    }
+}
+
+/// Returns the quoted and escaped JSON representation of a string.
+fn escape_json_string(s: &str) -> String {
+    let mut output_string = "\"".to_string();
+
+    for c in s.chars() {
+        match c {
+            '"' => output_string.push_str("\\\""),
+            '\\' => output_string.push_str("\\\\"),
+            '\n' => output_string.push_str("\\n"),
+            '\r' => output_string.push_str("\\r"),
+            '\t' => output_string.push_str("\\t"),
+            '\u{8}' => output_string.push_str("\\b"),
+            '\u{c}' => output_string.push_str("\\f"),
+            c if (c as u32) < 0x20 => output_string.push_str(&format!("\\u{:04x}", c as u32)),
+            c => output_string.push(c),
+        }
+    }
+
+    output_string.push('"');
+    output_string
+}
+
+/// Recursive descent reader for JSON documents.
+struct JsonReader<'a> {
+    chars: Peekable<Chars<'a>>,
+}
+
+impl<'a> JsonReader<'a> {
+    fn read_document(&mut self) -> Result<JsonValue> {
+        let value = self.read_value()?;
+        self.skip_whitespaces();
+
+        match self.chars.next() {
+            None => Ok(value),
+            Some(c) => Err(Error::new(
+                ErrorType::InvalidReaderInput,
+                "Unexpected character after JSON document: ".to_string() + &c.to_string(),
+            )),
+        }
+    }
+
+    fn skip_whitespaces(&mut self) {
+        while let Some(&c) = self.chars.peek() {
+            if c == ' ' || c == '\n' || c == '\r' || c == '\t' {
+                self.chars.next();
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<()> {
+        match self.chars.next() {
+            Some(c) if c == expected => Ok(()),
+            Some(c) => Err(Error::new(
+                ErrorType::InvalidReaderInput,
+                format!("Expected '{}' in JSON input but found '{}'.", expected, c),
+            )),
+            None => Err(Error::new(
+                ErrorType::InvalidReaderInput,
+                format!("Expected '{}' but reached end of JSON input.", expected),
+            )),
+        }
+    }
+
+    fn read_value(&mut self) -> Result<JsonValue> {
+        self.skip_whitespaces();
+
+        match self.chars.peek() {
+            Some('{') => self.read_object(),
+            Some('[') => self.read_array(),
+            Some('"') => Ok(JsonValue::String(self.read_string()?)),
+            Some('t') => self.read_keyword("true", JsonValue::Boolean(true)),
+            Some('f') => self.read_keyword("false", JsonValue::Boolean(false)),
+            Some('n') => self.read_keyword("null", JsonValue::Null),
+            Some(&c) if c == '-' || c.is_ascii_digit() => self.read_number(),
+            Some(&c) => Err(Error::new(
+                ErrorType::InvalidReaderInput,
+                "Invalid character in JSON input: ".to_string() + &c.to_string(),
+            )),
+            None => Err(Error::new(
+                ErrorType::InvalidReaderInput,
+                "Unexpected end of JSON input.",
+            )),
+        }
+    }
+
+    fn read_keyword(&mut self, keyword: &str, value: JsonValue) -> Result<JsonValue> {
+        for expected in keyword.chars() {
+            self.expect(expected)?;
+        }
+
+        Ok(value)
+    }
+
+    fn read_object(&mut self) -> Result<JsonValue> {
+        self.expect('{')?;
+        let mut members = JsonObject::new();
+
+        self.skip_whitespaces();
+        if self.chars.peek() == Some(&'}') {
+            self.chars.next();
+            return Ok(JsonValue::Object(members));
+        }
+
+        loop {
+            self.skip_whitespaces();
+            let key = self.read_string()?;
+            self.skip_whitespaces();
+            self.expect(':')?;
+            let value = self.read_value()?;
+            members.insert(key, value);
+
+            self.skip_whitespaces();
+            match self.chars.next() {
+                Some(',') => {}
+                Some('}') => return Ok(JsonValue::Object(members)),
+                _ => {
+                    return Err(Error::new(
+                        ErrorType::InvalidReaderInput,
+                        "Expected ',' or '}' in JSON object.",
+                    ))
+                }
+            }
+        }
+    }
+
+    fn read_array(&mut self) -> Result<JsonValue> {
+        self.expect('[')?;
+        let mut elements = Vec::new();
+
+        self.skip_whitespaces();
+        if self.chars.peek() == Some(&']') {
+            self.chars.next();
+            return Ok(JsonValue::Array(elements));
+        }
+
+        loop {
+            elements.push(self.read_value()?);
+
+            self.skip_whitespaces();
+            match self.chars.next() {
+                Some(',') => {}
+                Some(']') => return Ok(JsonValue::Array(elements)),
+                _ => {
+                    return Err(Error::new(
+                        ErrorType::InvalidReaderInput,
+                        "Expected ',' or ']' in JSON array.",
+                    ))
+                }
+            }
+        }
+    }
+
+    fn read_number(&mut self) -> Result<JsonValue> {
+        let mut number = String::new();
+
+        while let Some(&c) = self.chars.peek() {
+            if c.is_ascii_digit() || c == '-' || c == '+' || c == '.' || c == 'e' || c == 'E' {
+                number.push(c);
+                self.chars.next();
+            } else {
+                break;
+            }
+        }
+
+        match number.parse::<f64>() {
+            Ok(_) if !number.starts_with('+') && !number.ends_with('.') => {
+                Ok(JsonValue::Number(number))
+            }
+            _ => Err(Error::new(
+                ErrorType::InvalidReaderInput,
+                "Invalid JSON number: ".to_string() + &number,
+            )),
+        }
+    }
+
+    fn read_hex_code(&mut self) -> Result<u32> {
+        let mut code = 0;
+
+        for _ in 0..4 {
+            match self.chars.next().and_then(|c| c.to_digit(16)) {
+                Some(digit) => code = code * 16 + digit,
+                None => {
+                    return Err(Error::new(
+                        ErrorType::InvalidReaderInput,
+                        "Invalid unicode escape sequence in JSON string.",
+                    ))
+                }
+            }
+        }
+
+        Ok(code)
+    }
+
+    fn read_string(&mut self) -> Result<String> {
+        self.expect('"')?;
+        let mut s = String::new();
+
+        loop {
+            match self.chars.next() {
+                Some('"') => return Ok(s),
+                Some('\\') => match self.chars.next() {
+                    Some('"') => s.push('"'),
+                    Some('\\') => s.push('\\'),
+                    Some('/') => s.push('/'),
+                    Some('b') => s.push('\u{8}'),
+                    Some('f') => s.push('\u{c}'),
+                    Some('n') => s.push('\n'),
+                    Some('r') => s.push('\r'),
+                    Some('t') => s.push('\t'),
+                    Some('u') => {
+                        let mut code = self.read_hex_code()?;
+
+                        // combine UTF-16 surrogate pairs
+                        if (0xD800..0xDC00).contains(&code) {
+                            self.expect('\\')?;
+                            self.expect('u')?;
+                            let low = self.read_hex_code()?;
+                            code = 0x10000 + ((code - 0xD800) << 10) + (low.wrapping_sub(0xDC00));
+                        }
+
+                        match std::char::from_u32(code) {
+                            Some(c) => s.push(c),
+                            None => {
+                                return Err(Error::new(
+                                    ErrorType::InvalidReaderInput,
+                                    "Invalid unicode code point in JSON string.",
+                                ))
+                            }
+                        }
+                    }
+                    _ => {
+                        return Err(Error::new(
+                            ErrorType::InvalidReaderInput,
+                            "Invalid escape sequence in JSON string.",
+                        ))
+                    }
+                },
+                Some(c) => s.push(c),
+                None => {
+                    return Err(Error::new(
+                        ErrorType::InvalidReaderInput,
+                        "Unterminated JSON string.",
+                    ))
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::json::JsonValue;
+
+    #[test]
+    fn parse_nested_json() {
+        let value = JsonValue::parse("{ \"a\": { \"b\": [1.5, -2e3, \"x\\u00e9\\n\"] } }").unwrap();
+        let elements = value
+            .get("a")
+            .unwrap()
+            .get("b")
+            .unwrap()
+            .as_array()
+            .unwrap();
+
+        assert_eq!(elements[0], JsonValue::Number("1.5".to_string()));
+        assert_eq!(elements[1], JsonValue::Number("-2e3".to_string()));
+        assert_eq!(elements[2], JsonValue::String("xé\n".to_string()));
+    }
+
+    #[test]
+    fn reject_invalid_json() {
+        assert!(JsonValue::parse("{\"a\": }").is_err());
+        assert!(JsonValue::parse("[1, 2").is_err());
+        assert!(JsonValue::parse("{} {}").is_err());
+    }
+
+    #[test]
+    fn serialize_json() {
+        let input = "{\"b\":[true,null,\"q\\\"uote\"],\"a\":1}";
+        let value = JsonValue::parse(input).unwrap();
+
+        assert_eq!(
+            value.to_string(),
+            "{\"a\":1,\"b\":[true,null,\"q\\\"uote\"]}"
+        );
+        assert_eq!(JsonValue::parse(&value.to_string()).unwrap(), value);
+    }
+
+    #[test]
+    fn locate_json_errors() {
+        let err = JsonValue::parse("{\n  \"a\": [1,\n  \"b\": 2]\n}").unwrap_err();
+        let location = err.location().unwrap();
+
+        assert_eq!(location.start().line(), 3);
+        assert_eq!(location.start().column(), 7);
+        assert_eq!(err.snippet(), Some("\"b\":"));
+    }
+}
@@ -0,0 +1,420 @@
+use crate::json::{JsonObject, JsonValue};
+use crate::json_ld::context::{Context, TermDefinition};
+use crate::json_ld::expansion::{is_list_object, is_value_object};
+
+/// Compacts expanded node objects using the terms and prefixes of the provided context.
+///
+/// Returns an array of compacted node objects, without the `@context` entry.
+///
+/// # Examples
+///
+/// ```
+/// use rdf::json::JsonValue;
+/// use rdf::json_ld::compaction::compact;
+/// use rdf::json_ld::context::Context;
+///
+/// let mut context = Context::new(None);
+/// context.add_term("foaf", "http://xmlns.com/foaf/0.1/");
+///
+/// let expanded = JsonValue::parse("[{
+///   \"@id\": \"http://example.org/alice\",
+///   \"http://xmlns.com/foaf/0.1/name\": [{\"@value\": \"Alice\"}]
+/// }]").unwrap();
+///
+/// assert_eq!(compact(&expanded, &context).to_string(),
+///            "[{\"@id\":\"http://example.org/alice\",\"foaf:name\":\"Alice\"}]");
+/// ```
+pub fn compact(expanded: &JsonValue, context: &Context) -> JsonValue {
+    JsonValue::Array(
+        expanded
+            .to_vec()
+            .iter()
+            .filter_map(|node| node.as_object())
+            .map(|node| JsonValue::Object(compact_node(context, node)))
+            .collect(),
+    )
+}
+
+/// Compacts an absolute IRI to a term, a compact IRI or a vocabulary-relative IRI.
+///
+/// Terms and vocabulary-relative IRIs are only used if `vocab` is `true`, which is
+/// the case for properties and types. If several compact forms exist, the shortest one wins.
+///
+/// # Examples
+///
+/// ```
+/// use rdf::json_ld::compaction::compact_iri;
+/// use rdf::json_ld::context::Context;
+///
+/// let mut context = Context::new(None);
+/// context.add_term("foaf", "http://xmlns.com/foaf/0.1/");
+/// context.add_term("name", "http://xmlns.com/foaf/0.1/name");
+///
+/// assert_eq!(compact_iri(&context, "http://xmlns.com/foaf/0.1/name", true), "name");
+/// assert_eq!(compact_iri(&context, "http://xmlns.com/foaf/0.1/name", false), "foaf:name");
+/// assert_eq!(compact_iri(&context, "http://example.org/", true), "http://example.org/");
+/// ```
+pub fn compact_iri(context: &Context, iri: &str, vocab: bool) -> String {
+    if vocab {
+        let term = context
+            .terms()
+            .iter()
+            .filter(|(_, definition)| !definition.is_reverse() && definition.iri() == Some(iri))
+            .map(|(term, _)| term)
+            .min_by(|a, b| a.len().cmp(&b.len()).then(a.cmp(b)));
+
+        if let Some(term) = term {
+            return term.clone();
+        }
+
+        if let Some(suffix) = context.vocab().and_then(|v| iri.strip_prefix(v)) {
+            if !suffix.is_empty() && !suffix.contains(':') && context.term(suffix).is_none() {
+                return suffix.to_string();
+            }
+        }
+    }
+
+    let compact_iri = context
+        .terms()
+        .iter()
+        .filter(|(_, definition)| definition.is_prefix())
+        .filter_map(|(term, definition)| {
+            let suffix = iri.strip_prefix(definition.iri()?)?;
+
+            if suffix.is_empty() || suffix.starts_with("//") {
+                return None;
+            }
+
+            let candidate = term.clone() + ":" + suffix;
+
+            // the compact IRI must not be confused with a term of another IRI
+            match context.term(&candidate) {
+                Some(definition) if definition.iri() != Some(iri) => None,
+                _ => Some(candidate),
+            }
+        })
+        .min_by(|a, b| a.len().cmp(&b.len()).then(a.cmp(b)));
+
+    compact_iri.unwrap_or_else(|| iri.to_string())
+}
+
+/// Compacts a single expanded node object.
+fn compact_node(context: &Context, node: &JsonObject) -> JsonObject {
+    let mut result = JsonObject::new();
+
+    // properties that might be a list of values depending on their container
+    let mut multiple_values: Vec<String> = Vec::new();
+
+    for (key, value) in node {
+        match key.as_str() {
+            "@id" => {
+                if let Some(id) = value.as_str() {
+                    result.insert(
+                        key.clone(),
+                        JsonValue::String(compact_iri(context, id, false)),
+                    );
+                }
+            }
+            "@type" => {
+                let mut types: Vec<JsonValue> = value
+                    .to_vec()
+                    .iter()
+                    .filter_map(|t| t.as_str())
+                    .map(|t| JsonValue::String(compact_iri(context, t, true)))
+                    .collect();
+
+                let types = if types.len() == 1 {
+                    types.remove(0)
+                } else {
+                    JsonValue::Array(types)
+                };
+
+                result.insert(key.clone(), types);
+            }
+            "@graph" => {
+                result.insert(key.clone(), compact(value, context));
+            }
+            "@reverse" => {
+                if let Some(reverse) = value.as_object() {
+                    result.insert(
+                        key.clone(),
+                        JsonValue::Object(compact_node(context, reverse)),
+                    );
+                }
+            }
+            property if property.starts_with('@') => {
+                result.insert(key.clone(), value.clone());
+            }
+            property => {
+                for value in value.to_vec() {
+                    let (term, definition) = select_term(context, property, &value);
+                    let compacted = compact_value(context, definition, &value);
+
+                    let is_array_container = definition.is_some_and(|d| {
+                        d.has_container("@set") || d.has_container("@list")
+                    });
+
+                    if !is_array_container && !multiple_values.contains(&term) {
+                        multiple_values.push(term.clone());
+                    }
+
+                    let entry = result
+                        .entry(term)
+                        .or_insert_with(|| JsonValue::Array(Vec::new()));
+
+                    if let JsonValue::Array(ref mut values) = *entry {
+                        match compacted {
+                            // values of list containers are the list items
+                            JsonValue::Array(items) => values.extend(items),
+                            compacted => values.push(compacted),
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    // single values are not wrapped in an array unless the container requires it
+    for term in multiple_values {
+        if let Some(JsonValue::Array(values)) = result.get_mut(&term) {
+            if values.len() == 1 {
+                let value = values.remove(0);
+                result.insert(term, value);
+            }
+        }
+    }
+
+    result
+}
+
+/// Selects the term that is used for a value of the provided property.
+///
+/// Terms with type or language mappings that match the value are preferred over terms
+/// without mappings. Returns a compact IRI if no term matches.
+fn select_term<'a>(
+    context: &'a Context,
+    property: &str,
+    value: &JsonValue,
+) -> (String, Option<&'a TermDefinition>) {
+    let is_list = is_list_object(value);
+
+    let term = context
+        .terms()
+        .iter()
+        .filter(|(_, definition)| !definition.is_reverse() && definition.iri() == Some(property))
+        .filter(|(_, definition)| definition.has_container("@list") == is_list)
+        .map(|(term, definition)| {
+            let has_mapping =
+                definition.type_mapping().is_some() || definition.language_mapping().is_some();
+
+            let rank = match (has_mapping, matches_mapping(context, definition, value)) {
+                (true, true) => 0,
+                (false, _) => 1,
+                (true, false) => 2,
+            };
+
+            (rank, term, definition)
+        })
+        .min_by(|a, b| {
+            a.0.cmp(&b.0)
+                .then(a.1.len().cmp(&b.1.len()))
+                .then(a.1.cmp(b.1))
+        });
+
+    match term {
+        Some((_, term, definition)) => (term.clone(), Some(definition)),
+        None => (compact_iri(context, property, true), None),
+    }
+}
+
+/// Returns `true` if the value can be represented without its value object or node reference.
+fn matches_mapping(context: &Context, definition: &TermDefinition, value: &JsonValue) -> bool {
+    let type_mapping = definition.type_mapping();
+
+    if is_value_object(value) {
+        let data_type = value.get("@type").and_then(|t| t.as_str());
+        let language = value.get("@language").and_then(|l| l.as_str());
+
+        match value.get("@value") {
+            Some(JsonValue::String(_)) => match (data_type, type_mapping) {
+                (Some(data_type), Some(type_mapping)) => data_type == type_mapping,
+                (None, None) => {
+                    // bare strings get the language of the term or the default language
+                    let term_language = match definition.language_mapping() {
+                        Some(language) => language,
+                        None => context.default_language(),
+                    };
+
+                    language == term_language
+                }
+                _ => false,
+            },
+            Some(_) => data_type.is_none() && type_mapping.is_none(),
+            None => false,
+        }
+    } else if is_list_object(value) {
+        true
+    } else {
+        let is_reference = value
+            .as_object()
+            .is_some_and(|o| o.len() == 1 && o.contains_key("@id"));
+        is_reference && (type_mapping == Some("@id") || type_mapping == Some("@vocab"))
+    }
+}
+
+/// Compacts a value of a property that uses the provided term definition.
+fn compact_value(
+    context: &Context,
+    definition: Option<&TermDefinition>,
+    value: &JsonValue,
+) -> JsonValue {
+    let object = match value.as_object() {
+        Some(object) => object,
+        None => return value.clone(),
+    };
+
+    let matches = definition.map_or_else(
+        || {
+            // without a term definition, only plain strings of the default language are bare
+            matches_mapping(context, &TermDefinition::default(), value)
+        },
+        |d| matches_mapping(context, d, value),
+    );
+
+    if let Some(items) = object.get("@list") {
+        let items: Vec<JsonValue> = items
+            .to_vec()
+            .iter()
+            .map(|item| compact_value(context, None, item))
+            .collect();
+
+        return if definition.is_some_and(|d| d.has_container("@list")) {
+            JsonValue::Array(items)
+        } else {
+            let mut list = JsonObject::new();
+            list.insert("@list".to_string(), JsonValue::Array(items));
+            JsonValue::Object(list)
+        };
+    }
+
+    if is_value_object(value) {
+        if matches {
+            return object["@value"].clone();
+        }
+
+        let mut result = JsonObject::new();
+
+        for (key, v) in object {
+            match (key.as_str(), v.as_str()) {
+                ("@type", Some(data_type)) => {
+                    result.insert(
+                        key.clone(),
+                        JsonValue::String(compact_iri(context, data_type, true)),
+                    );
+                }
+                _ => {
+                    result.insert(key.clone(), v.clone());
+                }
+            }
+        }
+
+        return JsonValue::Object(result);
+    }
+
+    if let (true, Some(id)) = (matches, object.get("@id").and_then(|id| id.as_str())) {
+        let vocab = definition.and_then(|d| d.type_mapping()) == Some("@vocab");
+        return JsonValue::String(compact_iri(context, id, vocab));
+    }
+
+    JsonValue::Object(compact_node(context, object))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::json::JsonValue;
+    use crate::json_ld::compaction::{compact, compact_iri};
+    use crate::json_ld::context::Context;
+    use crate::json_ld::loader::NoContextLoader;
+
+    fn context(local: &str) -> Context {
+        Context::new(None)
+            .process(&JsonValue::parse(local).unwrap(), &NoContextLoader::new())
+            .unwrap()
+    }
+
+    #[test]
+    fn compact_iris_with_vocab_and_prefixes() {
+        let context = context(
+            "{\"@vocab\": \"http://schema.org/\", \"ex\": \"http://example.org/\", \"exa\": \"http://example.org/a\", \"h\": \"http:\"}",
+        );
+
+        assert_eq!(
+            compact_iri(&context, "http://schema.org/name", true),
+            "name"
+        );
+        assert_eq!(
+            compact_iri(&context, "http://schema.org/name", false),
+            "http://schema.org/name"
+        );
+        assert_eq!(
+            compact_iri(&context, "http://example.org/abc", false),
+            "ex:abc"
+        );
+        assert_eq!(
+            compact_iri(&context, "http://other.org/", false),
+            "http://other.org/"
+        );
+    }
+
+    #[test]
+    fn compact_with_type_and_language_mappings() {
+        let context = context(
+            "{
+              \"xsd\": \"http://www.w3.org/2001/XMLSchema#\",
+              \"@language\": \"en\",
+              \"age\": {\"@id\": \"http://schema.org/age\", \"@type\": \"xsd:integer\"},
+              \"knows\": {\"@id\": \"http://schema.org/knows\", \"@type\": \"@id\"},
+              \"name\": \"http://schema.org/name\",
+              \"tags\": {\"@id\": \"http://schema.org/tags\", \"@container\": \"@set\"}
+            }",
+        );
+
+        let expanded = JsonValue::parse(
+            "[{
+              \"@id\": \"http://example.org/alice\",
+              \"http://schema.org/age\": [{\"@value\": \"30\", \"@type\": \"http://www.w3.org/2001/XMLSchema#integer\"}],
+              \"http://schema.org/knows\": [{\"@id\": \"http://example.org/bob\"}],
+              \"http://schema.org/name\": [{\"@value\": \"Alice\", \"@language\": \"en\"}, {\"@value\": \"Alicia\"}],
+              \"http://schema.org/tags\": [{\"@value\": \"a\", \"@language\": \"en\"}]
+            }]",
+        )
+        .unwrap();
+
+        assert_eq!(
+            compact(&expanded, &context).to_string(),
+            "[{\"@id\":\"http://example.org/alice\",\"age\":\"30\",\"knows\":\"http://example.org/bob\",\
+             \"name\":[\"Alice\",{\"@value\":\"Alicia\"}],\"tags\":[\"a\"]}]"
+        );
+    }
+
+    #[test]
+    fn compact_lists() {
+        let context = context(
+            "{\"ex\": \"http://example.org/\", \"items\": {\"@id\": \"ex:items\", \"@container\": \"@list\"}}",
+        );
+
+        let expanded = JsonValue::parse(
+            "[{
+              \"@id\": \"http://example.org/a\",
+              \"http://example.org/items\": [{\"@list\": [{\"@value\": \"x\"}, {\"@id\": \"http://example.org/b\"}]}],
+              \"http://example.org/other\": [{\"@list\": []}]
+            }]",
+        )
+        .unwrap();
+
+        assert_eq!(
+            compact(&expanded, &context).to_string(),
+            "[{\"@id\":\"ex:a\",\"ex:other\":{\"@list\":[]},\"items\":[\"x\",{\"@id\":\"ex:b\"}]}]"
+        );
+    }
+}
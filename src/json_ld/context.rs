@@ -0,0 +1,808 @@
+use crate::error::{Error, ErrorType};
+use crate::json::{JsonObject, JsonValue};
+use crate::json_ld::loader::ContextLoader;
+use crate::specs::json_ld_specs::JsonLdSpecs;
+use crate::uri::Uri;
+use crate::Result;
+use std::collections::HashMap;
+
+/// Definition of a term within a JSON-LD context.
+#[derive(Clone, Debug, Default)]
+pub struct TermDefinition {
+    /// IRI the term expands to, `None` if the term is explicitly mapped to `null`.
+    iri: Option<String>,
+
+    /// `true` if the term is a reverse property.
+    reverse: bool,
+
+    /// Type coercion of values (an IRI, `@id`, `@vocab`, `@json` or `@none`).
+    type_mapping: Option<String>,
+
+    /// Language of string values, `Some(None)` if the default language is reset.
+    language_mapping: Option<Option<String>>,
+
+    /// Container mappings like `@list` or `@language`.
+    container: Vec<String>,
+
+    /// Term-scoped context.
+    context: Option<JsonValue>,
+
+    /// `true` if the term can be used as prefix of compact IRIs.
+    prefix: bool,
+}
+
+impl TermDefinition {
+    /// Returns the IRI the term expands to.
+    pub fn iri(&self) -> Option<&str> {
+        self.iri.as_deref()
+    }
+
+    /// Returns `true` if the term is a reverse property.
+    pub fn is_reverse(&self) -> bool {
+        self.reverse
+    }
+
+    /// Returns the type coercion of values.
+    pub fn type_mapping(&self) -> Option<&str> {
+        self.type_mapping.as_deref()
+    }
+
+    /// Returns the language mapping of string values.
+    pub fn language_mapping(&self) -> Option<Option<&str>> {
+        self.language_mapping.as_ref().map(|l| l.as_deref())
+    }
+
+    /// Returns `true` if the term has the provided container mapping.
+    pub fn has_container(&self, container: &str) -> bool {
+        self.container.iter().any(|c| c == container)
+    }
+
+    /// Returns the term-scoped context.
+    pub fn context(&self) -> Option<&JsonValue> {
+        self.context.as_ref()
+    }
+
+    /// Returns `true` if the term can be used as prefix of compact IRIs.
+    pub fn is_prefix(&self) -> bool {
+        self.prefix
+    }
+}
+
+/// An active JSON-LD context that is used for expanding and compacting terms and IRIs.
+#[derive(Clone, Debug, Default)]
+pub struct Context {
+    /// Base IRI against which relative IRIs are resolved.
+    base_uri: Option<String>,
+
+    /// Base IRI of the document, restored if the context is reset.
+    original_base_uri: Option<String>,
+
+    /// Vocabulary mapping for terms without definition.
+    vocab: Option<String>,
+
+    /// Default language of string values.
+    default_language: Option<String>,
+
+    /// Term definitions of the context.
+    terms: HashMap<String, TermDefinition>,
+}
+
+impl Context {
+    /// Constructor of an empty `Context`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::json_ld::context::Context;
+    ///
+    /// let context = Context::new(Some("http://example.org/"));
+    ///
+    /// assert_eq!(context.expand_iri("a/b", true, false), Some("http://example.org/a/b".to_string()));
+    /// ```
+    pub fn new(base_uri: Option<&str>) -> Context {
+        Context {
+            base_uri: base_uri.map(|b| b.to_string()),
+            original_base_uri: base_uri.map(|b| b.to_string()),
+            vocab: None,
+            default_language: None,
+            terms: HashMap::new(),
+        }
+    }
+
+    /// Returns the base IRI of the context.
+    pub fn base_uri(&self) -> Option<&str> {
+        self.base_uri.as_deref()
+    }
+
+    /// Returns the vocabulary mapping of the context.
+    pub fn vocab(&self) -> Option<&str> {
+        self.vocab.as_deref()
+    }
+
+    /// Returns the default language of the context.
+    pub fn default_language(&self) -> Option<&str> {
+        self.default_language.as_deref()
+    }
+
+    /// Returns the definition of a term.
+    pub fn term(&self, term: &str) -> Option<&TermDefinition> {
+        self.terms.get(term)
+    }
+
+    /// Returns all term definitions.
+    pub fn terms(&self) -> &HashMap<String, TermDefinition> {
+        &self.terms
+    }
+
+    /// Adds a simple term definition that maps the term to the provided IRI.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::json_ld::context::Context;
+    ///
+    /// let mut context = Context::new(None);
+    /// context.add_term("foaf", "http://xmlns.com/foaf/0.1/");
+    ///
+    /// assert_eq!(context.expand_iri("foaf:name", false, true),
+    ///            Some("http://xmlns.com/foaf/0.1/name".to_string()));
+    /// ```
+    pub fn add_term(&mut self, term: &str, iri: &str) {
+        let definition = TermDefinition {
+            iri: Some(iri.to_string()),
+            prefix: JsonLdSpecs::ends_with_gen_delim(iri),
+            ..Default::default()
+        };
+
+        self.terms.insert(term.to_string(), definition);
+    }
+
+    /// Processes a local context and returns the resulting active context.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::json::JsonValue;
+    /// use rdf::json_ld::context::Context;
+    /// use rdf::json_ld::loader::NoContextLoader;
+    ///
+    /// let local = JsonValue::parse("{\"@vocab\": \"http://schema.org/\", \"knows\": {\"@type\": \"@id\"}}").unwrap();
+    /// let context = Context::new(None).process(&local, &NoContextLoader::new()).unwrap();
+    ///
+    /// assert_eq!(context.expand_iri("name", false, true), Some("http://schema.org/name".to_string()));
+    /// assert_eq!(context.term("knows").unwrap().type_mapping(), Some("@id"));
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - Invalid context definitions.
+    /// - Remote contexts that cannot be loaded or are included recursively.
+    ///
+    pub fn process(&self, local: &JsonValue, loader: &dyn ContextLoader) -> Result<Context> {
+        self.process_with_remotes(local, loader, &mut Vec::new(), false)
+    }
+
+    /// Processes a local context and keeps track of the remote contexts that are being loaded.
+    fn process_with_remotes(
+        &self,
+        local: &JsonValue,
+        loader: &dyn ContextLoader,
+        remote_contexts: &mut Vec<String>,
+        is_remote: bool,
+    ) -> Result<Context> {
+        let mut result = self.clone();
+
+        for context in local.to_vec() {
+            match context {
+                JsonValue::Null => {
+                    result = Context::new(self.original_base_uri.as_deref());
+                }
+                JsonValue::String(ref iri) => {
+                    let iri = match result.base_uri {
+                        Some(ref base) => resolve_iri(base, iri),
+                        None => iri.clone(),
+                    };
+
+                    if remote_contexts.contains(&iri) {
+                        return Err(Error::new(
+                            ErrorType::InvalidJsonLdInput,
+                            "Recursive inclusion of remote context: ".to_string() + &iri,
+                        ));
+                    }
+
+                    let document = loader.load_context(&iri)?;
+                    let remote_context = match document.get("@context") {
+                        Some(c) => c.clone(),
+                        None => {
+                            return Err(Error::new(
+                                ErrorType::InvalidJsonLdInput,
+                                "Remote document does not contain a context: ".to_string() + &iri,
+                            ))
+                        }
+                    };
+
+                    remote_contexts.push(iri);
+                    result = result.process_with_remotes(
+                        &remote_context,
+                        loader,
+                        remote_contexts,
+                        true,
+                    )?;
+                    remote_contexts.pop();
+                }
+                JsonValue::Object(ref definition) => {
+                    result.process_definition(definition, loader, remote_contexts, is_remote)?;
+                }
+                _ => {
+                    return Err(Error::new(
+                        ErrorType::InvalidJsonLdInput,
+                        "Invalid local context.",
+                    ))
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Applies a context definition object to the context.
+    fn process_definition(
+        &mut self,
+        definition: &JsonObject,
+        loader: &dyn ContextLoader,
+        remote_contexts: &mut Vec<String>,
+        is_remote: bool,
+    ) -> Result<()> {
+        let mut definition = definition.clone();
+
+        if let Some(version) = definition.get("@version") {
+            if *version != JsonValue::Number("1.1".to_string()) {
+                return Err(Error::new(
+                    ErrorType::InvalidJsonLdInput,
+                    "Invalid @version value.",
+                ));
+            }
+        }
+
+        // properties of imported contexts are overridden by the importing context
+        if let Some(import) = definition.remove("@import") {
+            let iri = match (import.as_str(), self.base_uri.as_ref()) {
+                (Some(i), Some(base)) => resolve_iri(base, i),
+                (Some(i), None) => i.to_string(),
+                (None, _) => {
+                    return Err(Error::new(
+                        ErrorType::InvalidJsonLdInput,
+                        "Invalid @import value.",
+                    ))
+                }
+            };
+
+            match loader.load_context(&iri)?.get("@context") {
+                Some(JsonValue::Object(imported)) => {
+                    for (key, value) in imported {
+                        if !definition.contains_key(key) {
+                            definition.insert(key.clone(), value.clone());
+                        }
+                    }
+                }
+                _ => {
+                    return Err(Error::new(
+                        ErrorType::InvalidJsonLdInput,
+                        "Imported context is not a single object: ".to_string() + &iri,
+                    ))
+                }
+            }
+        }
+
+        // the base of remote contexts is ignored
+        if let (Some(base), false) = (definition.get("@base"), is_remote) {
+            match *base {
+                JsonValue::Null => self.base_uri = None,
+                JsonValue::String(ref b) => {
+                    self.base_uri = match self.base_uri {
+                        Some(ref current) => Some(resolve_iri(current, b)),
+                        None => Some(b.clone()),
+                    }
+                }
+                _ => {
+                    return Err(Error::new(
+                        ErrorType::InvalidJsonLdInput,
+                        "Invalid @base value.",
+                    ))
+                }
+            }
+        }
+
+        if let Some(vocab) = definition.get("@vocab") {
+            match *vocab {
+                JsonValue::Null => self.vocab = None,
+                JsonValue::String(ref v) => self.vocab = self.expand_iri(v, true, true),
+                _ => {
+                    return Err(Error::new(
+                        ErrorType::InvalidJsonLdInput,
+                        "Invalid @vocab value.",
+                    ))
+                }
+            }
+        }
+
+        if let Some(language) = definition.get("@language") {
+            match *language {
+                JsonValue::Null => self.default_language = None,
+                JsonValue::String(ref l) => self.default_language = Some(l.clone()),
+                _ => {
+                    return Err(Error::new(
+                        ErrorType::InvalidJsonLdInput,
+                        "Invalid default language.",
+                    ))
+                }
+            }
+        }
+
+        let mut defined = HashMap::new();
+
+        for term in definition.keys() {
+            match term.as_str() {
+                "@base" | "@direction" | "@import" | "@language" | "@propagate" | "@protected"
+                | "@version" | "@vocab" => {}
+                _ => self.create_term_definition(
+                    &definition,
+                    term,
+                    &mut defined,
+                    loader,
+                    remote_contexts,
+                )?,
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Creates the definition of a term of a local context.
+    ///
+    /// `defined` tracks which terms have already been processed to detect cyclic definitions.
+    fn create_term_definition(
+        &mut self,
+        local: &JsonObject,
+        term: &str,
+        defined: &mut HashMap<String, bool>,
+        loader: &dyn ContextLoader,
+        remote_contexts: &mut Vec<String>,
+    ) -> Result<()> {
+        match defined.get(term) {
+            Some(true) => return Ok(()),
+            Some(false) => {
+                return Err(Error::new(
+                    ErrorType::InvalidJsonLdInput,
+                    "Cyclic IRI mapping for term: ".to_string() + term,
+                ))
+            }
+            None => {}
+        }
+
+        if term.is_empty() || JsonLdSpecs::is_keyword(term) {
+            return Err(Error::new(
+                ErrorType::InvalidJsonLdInput,
+                "Invalid term definition: ".to_string() + term,
+            ));
+        }
+
+        defined.insert(term.to_string(), false);
+
+        // terms having the form of a keyword are ignored
+        if JsonLdSpecs::has_keyword_form(term) {
+            defined.insert(term.to_string(), true);
+            return Ok(());
+        }
+
+        self.terms.remove(term);
+
+        let value = match local.get(term) {
+            Some(JsonValue::Null) => {
+                let mut id = JsonObject::new();
+                id.insert("@id".to_string(), JsonValue::Null);
+                id
+            }
+            Some(JsonValue::String(iri)) => {
+                let mut id = JsonObject::new();
+                id.insert("@id".to_string(), JsonValue::String(iri.clone()));
+                id
+            }
+            Some(JsonValue::Object(definition)) => definition.clone(),
+            _ => {
+                return Err(Error::new(
+                    ErrorType::InvalidJsonLdInput,
+                    "Invalid term definition: ".to_string() + term,
+                ))
+            }
+        };
+
+        let mut definition = TermDefinition::default();
+
+        if let Some(type_mapping) = value.get("@type") {
+            let type_mapping = match type_mapping.as_str() {
+                Some(t) => self.expand_iri_with_local(
+                    t,
+                    false,
+                    true,
+                    local,
+                    defined,
+                    loader,
+                    remote_contexts,
+                )?,
+                None => None,
+            };
+
+            match type_mapping {
+                Some(ref t)
+                    if t == "@id"
+                        || t == "@vocab"
+                        || t == "@json"
+                        || t == "@none"
+                        || t.contains(':') =>
+                {
+                    definition.type_mapping = type_mapping.clone();
+                }
+                _ => {
+                    return Err(Error::new(
+                        ErrorType::InvalidJsonLdInput,
+                        "Invalid type mapping for term: ".to_string() + term,
+                    ))
+                }
+            }
+        }
+
+        if let Some(reverse) = value.get("@reverse") {
+            if value.contains_key("@id") {
+                return Err(Error::new(
+                    ErrorType::InvalidJsonLdInput,
+                    "Reverse property must not have an @id: ".to_string() + term,
+                ));
+            }
+
+            definition.iri = match reverse.as_str() {
+                Some(r) => self.expand_iri_with_local(
+                    r,
+                    false,
+                    true,
+                    local,
+                    defined,
+                    loader,
+                    remote_contexts,
+                )?,
+                None => None,
+            };
+
+            match definition.iri {
+                Some(ref iri) if iri.contains(':') => {}
+                _ => {
+                    return Err(Error::new(
+                        ErrorType::InvalidJsonLdInput,
+                        "Invalid IRI mapping for reverse property: ".to_string() + term,
+                    ))
+                }
+            }
+
+            definition.reverse = true;
+        } else if let Some(id) = value.get("@id").filter(|id| id.as_str() != Some(term)) {
+            match *id {
+                JsonValue::Null => definition.iri = None,
+                JsonValue::String(ref id) => {
+                    if !JsonLdSpecs::is_keyword(id) && JsonLdSpecs::has_keyword_form(id) {
+                        defined.insert(term.to_string(), true);
+                        return Ok(());
+                    }
+
+                    definition.iri = self.expand_iri_with_local(
+                        id,
+                        false,
+                        true,
+                        local,
+                        defined,
+                        loader,
+                        remote_contexts,
+                    )?;
+
+                    match definition.iri {
+                        Some(ref iri) if iri == "@context" => {
+                            return Err(Error::new(
+                                ErrorType::InvalidJsonLdInput,
+                                "Term must not be mapped to @context: ".to_string() + term,
+                            ))
+                        }
+                        Some(ref iri) if JsonLdSpecs::is_keyword(iri) || iri.contains(':') => {}
+                        _ => {
+                            return Err(Error::new(
+                                ErrorType::InvalidJsonLdInput,
+                                "Invalid IRI mapping for term: ".to_string() + term,
+                            ))
+                        }
+                    }
+
+                    // simple terms mapping to IRIs ending with a delimiter can be used as prefixes
+                    if !term.contains(':') && !term.contains('/') {
+                        definition.prefix = local.get(term).is_some_and(|v| v.as_str().is_some())
+                            && definition.iri.as_ref().is_some_and(|iri| {
+                                JsonLdSpecs::ends_with_gen_delim(iri) || iri.starts_with("_:")
+                            });
+                    }
+                }
+                _ => {
+                    return Err(Error::new(
+                        ErrorType::InvalidJsonLdInput,
+                        "Invalid IRI mapping for term: ".to_string() + term,
+                    ))
+                }
+            }
+        } else if let Some(position) = prefix_separator(term) {
+            // compact IRI or absolute IRI as term
+            let (prefix, suffix) = (&term[..position], &term[position + 1..]);
+
+            if local.contains_key(prefix) {
+                self.create_term_definition(local, prefix, defined, loader, remote_contexts)?;
+            }
+
+            definition.iri = match self.terms.get(prefix).and_then(|d| d.iri.clone()) {
+                Some(prefix_iri) => Some(prefix_iri + suffix),
+                None => Some(term.to_string()),
+            };
+        } else if term.contains('/') {
+            definition.iri = self.expand_iri(term, false, true);
+        } else if term == "@type" {
+            definition.iri = Some("@type".to_string());
+        } else if let Some(ref vocab) = self.vocab {
+            definition.iri = Some(vocab.clone() + term);
+        } else {
+            return Err(Error::new(
+                ErrorType::InvalidJsonLdInput,
+                "Invalid IRI mapping for term without vocabulary mapping: ".to_string() + term,
+            ));
+        }
+
+        if let Some(container) = value.get("@container") {
+            for c in container.to_vec() {
+                match c.as_str() {
+                    Some(c) if JsonLdSpecs::is_container(c) => {
+                        definition.container.push(c.to_string())
+                    }
+                    _ => {
+                        return Err(Error::new(
+                            ErrorType::InvalidJsonLdInput,
+                            "Invalid container mapping for term: ".to_string() + term,
+                        ))
+                    }
+                }
+            }
+        }
+
+        if let Some(context) = value.get("@context") {
+            definition.context = Some(context.clone());
+        }
+
+        if let Some(language) = value.get("@language") {
+            definition.language_mapping = match *language {
+                JsonValue::Null => Some(None),
+                JsonValue::String(ref l) => Some(Some(l.clone())),
+                _ => {
+                    return Err(Error::new(
+                        ErrorType::InvalidJsonLdInput,
+                        "Invalid language mapping for term: ".to_string() + term,
+                    ))
+                }
+            }
+        }
+
+        if let Some(prefix) = value.get("@prefix") {
+            match prefix.as_bool() {
+                Some(p) => definition.prefix = p,
+                None => {
+                    return Err(Error::new(
+                        ErrorType::InvalidJsonLdInput,
+                        "Invalid @prefix value for term: ".to_string() + term,
+                    ))
+                }
+            }
+        }
+
+        self.terms.insert(term.to_string(), definition);
+        defined.insert(term.to_string(), true);
+
+        Ok(())
+    }
+
+    /// Expands an IRI while a local context is processed.
+    ///
+    /// Terms of the local context the value depends on are defined first.
+    #[allow(clippy::too_many_arguments)]
+    fn expand_iri_with_local(
+        &mut self,
+        value: &str,
+        document_relative: bool,
+        vocab: bool,
+        local: &JsonObject,
+        defined: &mut HashMap<String, bool>,
+        loader: &dyn ContextLoader,
+        remote_contexts: &mut Vec<String>,
+    ) -> Result<Option<String>> {
+        if local.contains_key(value) && defined.get(value) != Some(&true) {
+            self.create_term_definition(local, value, defined, loader, remote_contexts)?;
+        }
+
+        if let Some(position) = value.find(':') {
+            let prefix = &value[..position];
+
+            if local.contains_key(prefix) && defined.get(prefix) != Some(&true) {
+                self.create_term_definition(local, prefix, defined, loader, remote_contexts)?;
+            }
+        }
+
+        Ok(self.expand_iri(value, document_relative, vocab))
+    }
+
+    /// Expands a term, compact IRI or relative IRI to an absolute IRI or keyword.
+    ///
+    /// Returns `None` if the value is mapped to `null` or has the form of a keyword.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::json_ld::context::Context;
+    ///
+    /// let mut context = Context::new(Some("http://example.org/base/"));
+    /// context.add_term("ex", "http://example.org/vocab#");
+    ///
+    /// assert_eq!(context.expand_iri("ex:name", false, true), Some("http://example.org/vocab#name".to_string()));
+    /// assert_eq!(context.expand_iri("../other", true, false), Some("http://example.org/other".to_string()));
+    /// assert_eq!(context.expand_iri("_:b0", true, false), Some("_:b0".to_string()));
+    /// assert_eq!(context.expand_iri("@id", false, true), Some("@id".to_string()));
+    /// ```
+    pub fn expand_iri(&self, value: &str, document_relative: bool, vocab: bool) -> Option<String> {
+        if JsonLdSpecs::is_keyword(value) {
+            return Some(value.to_string());
+        }
+
+        if JsonLdSpecs::has_keyword_form(value) {
+            return None;
+        }
+
+        if vocab {
+            if let Some(definition) = self.terms.get(value) {
+                return definition.iri.clone();
+            }
+        }
+
+        if let Some(position) = prefix_separator(value) {
+            let (prefix, suffix) = (&value[..position], &value[position + 1..]);
+
+            if prefix == "_" || suffix.starts_with("//") {
+                return Some(value.to_string());
+            }
+
+            if let Some(definition) = self.terms.get(prefix) {
+                if let (Some(iri), true) = (definition.iri.as_ref(), definition.prefix) {
+                    return Some(iri.clone() + suffix);
+                }
+            }
+
+            if is_absolute_iri(value) {
+                return Some(value.to_string());
+            }
+        }
+
+        if vocab {
+            if let Some(ref v) = self.vocab {
+                return Some(v.clone() + value);
+            }
+        }
+
+        if document_relative {
+            if let Some(ref base) = self.base_uri {
+                return Some(resolve_iri(base, value));
+            }
+        }
+
+        Some(value.to_string())
+    }
+}
+
+/// Returns the position of the first `:` that is not the first character.
+fn prefix_separator(value: &str) -> Option<usize> {
+    value
+        .char_indices()
+        .skip(1)
+        .find(|&(_, c)| c == ':')
+        .map(|(position, _)| position)
+}
+
+/// Returns `true` if the provided IRI starts with a scheme.
+pub(crate) fn is_absolute_iri(iri: &str) -> bool {
+    match iri.find(':') {
+        Some(position) if position > 0 => {
+            let scheme = &iri[..position];
+            scheme
+                .chars()
+                .next()
+                .is_some_and(|c| c.is_ascii_alphabetic())
+                && scheme
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '+' || c == '-' || c == '.')
+        }
+        _ => false,
+    }
+}
+
+/// Resolves a relative IRI reference against a base IRI (RFC 3986, section 5.2).
+pub(crate) fn resolve_iri(base: &str, reference: &str) -> String {
+    Uri::new_unchecked(base.to_string())
+        .resolve(&Uri::new_unchecked(reference.to_string()))
+        .to_string()
+        .clone()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::json::JsonValue;
+    use crate::json_ld::context::*;
+    use crate::json_ld::loader::NoContextLoader;
+
+    #[test]
+    fn resolve_relative_iris() {
+        let base = "http://a/b/c/d;p?q";
+
+        assert_eq!(resolve_iri(base, "g"), "http://a/b/c/g");
+        assert_eq!(resolve_iri(base, "./g"), "http://a/b/c/g");
+        assert_eq!(resolve_iri(base, "g/"), "http://a/b/c/g/");
+        assert_eq!(resolve_iri(base, "/g"), "http://a/g");
+        assert_eq!(resolve_iri(base, "//g"), "http://g");
+        assert_eq!(resolve_iri(base, "?y"), "http://a/b/c/d;p?y");
+        assert_eq!(resolve_iri(base, "#s"), "http://a/b/c/d;p?q#s");
+        assert_eq!(resolve_iri(base, ""), "http://a/b/c/d;p?q");
+        assert_eq!(resolve_iri(base, ".."), "http://a/b/");
+        assert_eq!(resolve_iri(base, "../.."), "http://a/");
+        assert_eq!(resolve_iri(base, "../../../g"), "http://a/g");
+        assert_eq!(resolve_iri(base, "g;x=1/../y"), "http://a/b/c/y");
+    }
+
+    #[test]
+    fn process_cyclic_context() {
+        let local = JsonValue::parse("{\"a\": \"b:x\", \"b\": \"a:y\"}").unwrap();
+
+        assert!(Context::new(None)
+            .process(&local, &NoContextLoader::new())
+            .is_err());
+    }
+
+    #[test]
+    fn process_compact_iri_terms() {
+        let local = JsonValue::parse(
+            "{\"ex\": \"http://example.org/\", \"name\": \"ex:name\", \"ex:age\": {\"@type\": \"ex:int\"}}",
+        )
+        .unwrap();
+        let context = Context::new(None)
+            .process(&local, &NoContextLoader::new())
+            .unwrap();
+
+        assert_eq!(
+            context.term("name").unwrap().iri(),
+            Some("http://example.org/name")
+        );
+        assert_eq!(
+            context.term("ex:age").unwrap().iri(),
+            Some("http://example.org/age")
+        );
+        assert_eq!(
+            context.term("ex:age").unwrap().type_mapping(),
+            Some("http://example.org/int")
+        );
+    }
+
+    #[test]
+    fn reset_context_with_null() {
+        let local = JsonValue::parse("[{\"@vocab\": \"http://example.org/\"}, null]").unwrap();
+        let context = Context::new(None)
+            .process(&local, &NoContextLoader::new())
+            .unwrap();
+
+        assert_eq!(context.vocab(), None);
+    }
+}
@@ -0,0 +1,688 @@
+use crate::error::{Error, ErrorType};
+use crate::json::{JsonObject, JsonValue};
+use crate::json_ld::context::Context;
+use crate::json_ld::loader::ContextLoader;
+use crate::specs::json_ld_specs::JsonLdSpecs;
+use crate::Result;
+
+/// Expands a JSON-LD document so that all terms and compact IRIs are replaced by absolute IRIs.
+///
+/// The result is always an array of expanded node objects.
+///
+/// # Examples
+///
+/// ```
+/// use rdf::json::JsonValue;
+/// use rdf::json_ld::context::Context;
+/// use rdf::json_ld::expansion::expand;
+/// use rdf::json_ld::loader::NoContextLoader;
+///
+/// let document = JsonValue::parse("{
+///   \"@context\": {\"name\": \"http://xmlns.com/foaf/0.1/name\"},
+///   \"@id\": \"http://example.org/alice\",
+///   \"name\": \"Alice\"
+/// }").unwrap();
+///
+/// let expanded = expand(&document, &Context::new(None), &NoContextLoader::new()).unwrap();
+///
+/// assert_eq!(expanded.to_string(),
+///            "[{\"@id\":\"http://example.org/alice\",\"http://xmlns.com/foaf/0.1/name\":[{\"@value\":\"Alice\"}]}]");
+/// ```
+///
+/// # Failures
+///
+/// - Invalid contexts or invalid usage of JSON-LD keywords.
+///
+pub fn expand(
+    document: &JsonValue,
+    context: &Context,
+    loader: &dyn ContextLoader,
+) -> Result<JsonValue> {
+    let expander = Expander { loader };
+
+    let expanded = match expander.expand_element(context, None, document)? {
+        JsonValue::Object(ref object) if object.len() == 1 && object.contains_key("@graph") => {
+            object["@graph"].clone()
+        }
+        JsonValue::Null => JsonValue::Array(Vec::new()),
+        expanded => expanded,
+    };
+
+    Ok(JsonValue::Array(expanded.to_vec()))
+}
+
+/// Returns `true` if the expanded value is a value object.
+pub fn is_value_object(value: &JsonValue) -> bool {
+    value.get("@value").is_some()
+}
+
+/// Returns `true` if the expanded value is a list object.
+pub fn is_list_object(value: &JsonValue) -> bool {
+    value.get("@list").is_some()
+}
+
+/// Implements the JSON-LD expansion algorithm.
+struct Expander<'a> {
+    loader: &'a dyn ContextLoader,
+}
+
+impl<'a> Expander<'a> {
+    /// Expands an arbitrary element of a JSON-LD document.
+    fn expand_element(
+        &self,
+        context: &Context,
+        active_property: Option<&str>,
+        element: &JsonValue,
+    ) -> Result<JsonValue> {
+        match *element {
+            JsonValue::Null => Ok(JsonValue::Null),
+            JsonValue::Array(ref elements) => {
+                let is_list = active_property
+                    .and_then(|p| context.term(p))
+                    .is_some_and(|d| d.has_container("@list"));
+                let mut result = Vec::new();
+
+                for element in elements {
+                    match self.expand_element(context, active_property, element)? {
+                        JsonValue::Array(ref nested) if is_list => {
+                            let mut list = JsonObject::new();
+                            list.insert("@list".to_string(), JsonValue::Array(nested.clone()));
+                            result.push(JsonValue::Object(list));
+                        }
+                        JsonValue::Array(nested) => result.extend(nested),
+                        JsonValue::Null => {}
+                        expanded => result.push(expanded),
+                    }
+                }
+
+                Ok(JsonValue::Array(result))
+            }
+            JsonValue::Object(ref object) => self.expand_object(context, active_property, object),
+            ref scalar => match active_property {
+                // free-floating scalars are dropped
+                None | Some("@graph") => Ok(JsonValue::Null),
+                Some(property) => Ok(self.expand_value(context, property, scalar)),
+            },
+        }
+    }
+
+    /// Expands a JSON object that is either a node, value or list object.
+    fn expand_object(
+        &self,
+        context: &Context,
+        active_property: Option<&str>,
+        object: &JsonObject,
+    ) -> Result<JsonValue> {
+        let mut context = context.clone();
+
+        // apply property-scoped and embedded contexts
+        if let Some(scoped) = active_property
+            .and_then(|p| context.term(p))
+            .and_then(|d| d.context())
+            .cloned()
+        {
+            context = context.process(&scoped, self.loader)?;
+        }
+
+        if let Some(local) = object.get("@context") {
+            context = context.process(local, self.loader)?;
+        }
+
+        // apply type-scoped contexts
+        let type_scoped_context = context.clone();
+        for (key, value) in object {
+            if context.expand_iri(key, false, true).as_deref() != Some("@type") {
+                continue;
+            }
+
+            let mut types: Vec<String> = value
+                .to_vec()
+                .iter()
+                .filter_map(|t| t.as_str().map(|s| s.to_string()))
+                .collect();
+            types.sort();
+
+            for t in types {
+                if let Some(scoped) = type_scoped_context.term(&t).and_then(|d| d.context()) {
+                    context = context.process(scoped, self.loader)?;
+                }
+            }
+        }
+
+        let mut result = JsonObject::new();
+
+        for (key, value) in object {
+            if key == "@context" {
+                continue;
+            }
+
+            let expanded_property = match context.expand_iri(key, false, true) {
+                Some(ref p) if p.contains(':') || JsonLdSpecs::is_keyword(p) => p.clone(),
+                _ => continue,
+            };
+
+            if JsonLdSpecs::is_keyword(&expanded_property) {
+                self.expand_keyword(
+                    &context,
+                    active_property,
+                    &expanded_property,
+                    value,
+                    &mut result,
+                )?;
+                continue;
+            }
+
+            let definition = context.term(key).cloned().unwrap_or_default();
+
+            let mut expanded_value = if definition.type_mapping() == Some("@json") {
+                let mut json_value = JsonObject::new();
+                json_value.insert("@value".to_string(), value.clone());
+                json_value.insert("@type".to_string(), JsonValue::String("@json".to_string()));
+                JsonValue::Object(json_value)
+            } else if definition.has_container("@language") && value.as_object().is_some() {
+                self.expand_language_map(value.as_object().unwrap_or(&JsonObject::new()))?
+            } else if (definition.has_container("@index")
+                || definition.has_container("@id")
+                || definition.has_container("@type"))
+                && value.as_object().is_some()
+            {
+                self.expand_index_map(
+                    &context,
+                    key,
+                    value.as_object().unwrap_or(&JsonObject::new()),
+                )?
+            } else {
+                self.expand_element(&context, Some(key), value)?
+            };
+
+            if expanded_value.is_null() {
+                continue;
+            }
+
+            if definition.has_container("@list") && !is_list_object(&expanded_value) {
+                let mut list = JsonObject::new();
+                list.insert(
+                    "@list".to_string(),
+                    JsonValue::Array(expanded_value.to_vec()),
+                );
+                expanded_value = JsonValue::Object(list);
+            }
+
+            if definition.has_container("@graph") {
+                let graphs = expanded_value
+                    .to_vec()
+                    .into_iter()
+                    .map(|item| {
+                        let mut graph = JsonObject::new();
+                        graph.insert("@graph".to_string(), JsonValue::Array(item.to_vec()));
+                        JsonValue::Object(graph)
+                    })
+                    .collect();
+                expanded_value = JsonValue::Array(graphs);
+            }
+
+            if definition.is_reverse() {
+                let reverse_map = result
+                    .entry("@reverse".to_string())
+                    .or_insert_with(|| JsonValue::Object(JsonObject::new()));
+
+                for item in expanded_value.to_vec() {
+                    if is_value_object(&item) || is_list_object(&item) {
+                        return Err(Error::new(
+                            ErrorType::InvalidJsonLdInput,
+                            "Invalid reverse property value for: ".to_string() + key,
+                        ));
+                    }
+
+                    add_value(reverse_map, &expanded_property, item);
+                }
+            } else {
+                for item in expanded_value.to_vec() {
+                    add_value_to_object(&mut result, &expanded_property, item);
+                }
+            }
+        }
+
+        self.post_process(active_property, result)
+    }
+
+    /// Expands a keyword of a node, value or list object and adds it to the result.
+    fn expand_keyword(
+        &self,
+        context: &Context,
+        active_property: Option<&str>,
+        keyword: &str,
+        value: &JsonValue,
+        result: &mut JsonObject,
+    ) -> Result<()> {
+        if active_property == Some("@reverse") {
+            return Err(Error::new(
+                ErrorType::InvalidJsonLdInput,
+                "Keywords are not allowed in reverse property maps.",
+            ));
+        }
+
+        let expanded_value = match keyword {
+            "@id" => match value.as_str() {
+                Some(id) => context
+                    .expand_iri(id, true, false)
+                    .map_or(JsonValue::Null, JsonValue::String),
+                None => {
+                    return Err(Error::new(
+                        ErrorType::InvalidJsonLdInput,
+                        "Invalid @id value.",
+                    ))
+                }
+            },
+            "@type" => {
+                let mut types = Vec::new();
+
+                for t in value.to_vec() {
+                    match t.as_str() {
+                        Some(t) => {
+                            if let Some(expanded) = context.expand_iri(t, true, true) {
+                                types.push(JsonValue::String(expanded));
+                            }
+                        }
+                        None => {
+                            return Err(Error::new(
+                                ErrorType::InvalidJsonLdInput,
+                                "Invalid @type value.",
+                            ))
+                        }
+                    }
+                }
+
+                match value {
+                    JsonValue::Array(_) => JsonValue::Array(types),
+                    _ => types.pop().unwrap_or(JsonValue::Null),
+                }
+            }
+            "@graph" => JsonValue::Array(
+                self.expand_element(context, Some("@graph"), value)?
+                    .to_vec(),
+            ),
+            "@value" => match *value {
+                JsonValue::Array(_) | JsonValue::Object(_)
+                    if result.get("@type").and_then(|t| t.as_str()) != Some("@json") =>
+                {
+                    return Err(Error::new(
+                        ErrorType::InvalidJsonLdInput,
+                        "Invalid @value value.",
+                    ))
+                }
+                ref v => {
+                    result.insert("@value".to_string(), v.clone());
+                    return Ok(());
+                }
+            },
+            "@language" | "@index" | "@direction" => match value.as_str() {
+                Some(_) => value.clone(),
+                None => {
+                    return Err(Error::new(
+                        ErrorType::InvalidJsonLdInput,
+                        "Invalid ".to_string() + keyword + " value.",
+                    ))
+                }
+            },
+            "@list" => match active_property {
+                None | Some("@graph") => return Ok(()),
+                Some(_) => JsonValue::Array(
+                    self.expand_element(context, active_property, value)?
+                        .to_vec(),
+                ),
+            },
+            "@set" => self.expand_element(context, active_property, value)?,
+            "@reverse" => {
+                if value.as_object().is_none() {
+                    return Err(Error::new(
+                        ErrorType::InvalidJsonLdInput,
+                        "Invalid @reverse value.",
+                    ));
+                }
+
+                if let JsonValue::Object(expanded) =
+                    self.expand_element(context, Some("@reverse"), value)?
+                {
+                    // a reverse of a reverse property is a forward property
+                    if let Some(JsonValue::Object(reversed)) = expanded.get("@reverse") {
+                        for (property, items) in reversed {
+                            for item in items.to_vec() {
+                                add_value_to_object(result, property, item);
+                            }
+                        }
+                    }
+
+                    for (property, items) in expanded.iter().filter(|(p, _)| *p != "@reverse") {
+                        let reverse_map = result
+                            .entry("@reverse".to_string())
+                            .or_insert_with(|| JsonValue::Object(JsonObject::new()));
+
+                        for item in items.to_vec() {
+                            if is_value_object(&item) || is_list_object(&item) {
+                                return Err(Error::new(
+                                    ErrorType::InvalidJsonLdInput,
+                                    "Invalid reverse property value.",
+                                ));
+                            }
+
+                            add_value(reverse_map, property, item);
+                        }
+                    }
+                }
+
+                return Ok(());
+            }
+            // other keywords like @nest or @included are not supported and ignored
+            _ => return Ok(()),
+        };
+
+        if !expanded_value.is_null() {
+            result.insert(keyword.to_string(), expanded_value);
+        }
+
+        Ok(())
+    }
+
+    /// Expands a scalar value using the type and language coercion of the active property.
+    fn expand_value(
+        &self,
+        context: &Context,
+        active_property: &str,
+        value: &JsonValue,
+    ) -> JsonValue {
+        let definition = context.term(active_property);
+        let type_mapping = definition.and_then(|d| d.type_mapping());
+
+        match (type_mapping, value.as_str()) {
+            (Some("@id"), Some(id)) => {
+                return node_reference(context.expand_iri(id, true, false));
+            }
+            (Some("@vocab"), Some(id)) => {
+                return node_reference(context.expand_iri(id, true, true));
+            }
+            _ => {}
+        }
+
+        let mut result = JsonObject::new();
+        result.insert("@value".to_string(), value.clone());
+
+        match type_mapping {
+            Some("@id") | Some("@vocab") | Some("@none") => {}
+            Some(t) => {
+                result.insert("@type".to_string(), JsonValue::String(t.to_string()));
+            }
+            None if value.as_str().is_some() => {
+                let language = match definition.and_then(|d| d.language_mapping()) {
+                    Some(language) => language,
+                    None => context.default_language(),
+                };
+
+                if let Some(language) = language {
+                    result.insert(
+                        "@language".to_string(),
+                        JsonValue::String(language.to_string()),
+                    );
+                }
+            }
+            None => {}
+        }
+
+        JsonValue::Object(result)
+    }
+
+    /// Expands a language map to an array of language-tagged value objects.
+    fn expand_language_map(&self, map: &JsonObject) -> Result<JsonValue> {
+        let mut result = Vec::new();
+
+        for (language, values) in map {
+            for value in values.to_vec() {
+                match value {
+                    JsonValue::Null => {}
+                    JsonValue::String(s) => {
+                        let mut item = JsonObject::new();
+                        item.insert("@value".to_string(), JsonValue::String(s));
+
+                        if language != "@none" {
+                            item.insert(
+                                "@language".to_string(),
+                                JsonValue::String(language.clone()),
+                            );
+                        }
+
+                        result.push(JsonValue::Object(item));
+                    }
+                    _ => {
+                        return Err(Error::new(
+                            ErrorType::InvalidJsonLdInput,
+                            "Invalid language map value.",
+                        ))
+                    }
+                }
+            }
+        }
+
+        Ok(JsonValue::Array(result))
+    }
+
+    /// Expands maps with `@index`, `@id` or `@type` containers.
+    fn expand_index_map(
+        &self,
+        context: &Context,
+        key: &str,
+        map: &JsonObject,
+    ) -> Result<JsonValue> {
+        let definition = context.term(key).cloned().unwrap_or_default();
+        let mut result = Vec::new();
+
+        for (index, values) in map {
+            let expanded =
+                self.expand_element(context, Some(key), &JsonValue::Array(values.to_vec()))?;
+
+            for item in expanded.to_vec() {
+                let mut item = match item {
+                    JsonValue::Object(item) => item,
+                    _ => continue,
+                };
+
+                if definition.has_container("@index")
+                    && !item.contains_key("@index")
+                    && index != "@none"
+                {
+                    item.insert("@index".to_string(), JsonValue::String(index.clone()));
+                } else if definition.has_container("@id")
+                    && !item.contains_key("@id")
+                    && index != "@none"
+                {
+                    if let Some(id) = context.expand_iri(index, true, false) {
+                        item.insert("@id".to_string(), JsonValue::String(id));
+                    }
+                } else if definition.has_container("@type") && index != "@none" {
+                    if let Some(t) = context.expand_iri(index, true, true) {
+                        let mut types = vec![JsonValue::String(t)];
+                        if let Some(existing) = item.get("@type") {
+                            types.extend(existing.to_vec());
+                        }
+                        item.insert("@type".to_string(), JsonValue::Array(types));
+                    }
+                }
+
+                result.push(JsonValue::Object(item));
+            }
+        }
+
+        Ok(JsonValue::Array(result))
+    }
+
+    /// Validates and simplifies an expanded object.
+    fn post_process(
+        &self,
+        active_property: Option<&str>,
+        mut result: JsonObject,
+    ) -> Result<JsonValue> {
+        if result.contains_key("@value") {
+            if result.keys().any(|k| {
+                !matches!(
+                    k.as_str(),
+                    "@value" | "@language" | "@type" | "@index" | "@direction"
+                )
+            }) || (result.contains_key("@language") && result.contains_key("@type"))
+            {
+                return Err(Error::new(
+                    ErrorType::InvalidJsonLdInput,
+                    "Invalid value object.",
+                ));
+            }
+
+            let is_json = result.get("@type").and_then(|t| t.as_str()) == Some("@json");
+
+            if result["@value"].is_null() && !is_json {
+                return Ok(JsonValue::Null);
+            }
+
+            if result.contains_key("@language") && result["@value"].as_str().is_none() {
+                return Err(Error::new(
+                    ErrorType::InvalidJsonLdInput,
+                    "Language-tagged value must be a string.",
+                ));
+            }
+
+            if let Some(t) = result.get("@type") {
+                match t.as_str() {
+                    Some(t) if t.contains(':') || t == "@json" => {}
+                    _ => {
+                        return Err(Error::new(
+                            ErrorType::InvalidJsonLdInput,
+                            "Invalid typed value.",
+                        ))
+                    }
+                }
+            }
+        } else if let Some(t) = result.get("@type").cloned() {
+            if t.as_array().is_none() {
+                result.insert("@type".to_string(), JsonValue::Array(vec![t]));
+            }
+        } else if result.contains_key("@set") || result.contains_key("@list") {
+            if result
+                .keys()
+                .any(|k| k != "@set" && k != "@list" && k != "@index")
+            {
+                return Err(Error::new(
+                    ErrorType::InvalidJsonLdInput,
+                    "Invalid set or list object.",
+                ));
+            }
+
+            if let Some(set) = result.remove("@set") {
+                return Ok(set);
+            }
+        }
+
+        if result.len() == 1 && result.contains_key("@language") {
+            return Ok(JsonValue::Null);
+        }
+
+        // drop free-floating values at the top level and in graphs
+        if active_property.is_none() || active_property == Some("@graph") {
+            if result.is_empty() || result.contains_key("@value") || result.contains_key("@list") {
+                return Ok(JsonValue::Null);
+            }
+
+            if result.len() == 1 && result.contains_key("@id") {
+                return Ok(JsonValue::Null);
+            }
+        }
+
+        Ok(JsonValue::Object(result))
+    }
+}
+
+/// Creates a node reference from an expanded IRI.
+fn node_reference(id: Option<String>) -> JsonValue {
+    match id {
+        Some(id) => {
+            let mut reference = JsonObject::new();
+            reference.insert("@id".to_string(), JsonValue::String(id));
+            JsonValue::Object(reference)
+        }
+        None => JsonValue::Null,
+    }
+}
+
+/// Appends a value to the array stored at the provided key of an object value.
+fn add_value(object: &mut JsonValue, key: &str, value: JsonValue) {
+    if let JsonValue::Object(ref mut object) = *object {
+        add_value_to_object(object, key, value);
+    }
+}
+
+/// Appends a value to the array stored at the provided key.
+fn add_value_to_object(object: &mut JsonObject, key: &str, value: JsonValue) {
+    let entry = object
+        .entry(key.to_string())
+        .or_insert_with(|| JsonValue::Array(Vec::new()));
+
+    if let JsonValue::Array(ref mut values) = *entry {
+        values.push(value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::json::JsonValue;
+    use crate::json_ld::context::Context;
+    use crate::json_ld::expansion::expand;
+    use crate::json_ld::loader::NoContextLoader;
+
+    fn expand_str(input: &str) -> String {
+        let document = JsonValue::parse(input).unwrap();
+        expand(&document, &Context::new(None), &NoContextLoader::new())
+            .unwrap()
+            .to_string()
+    }
+
+    #[test]
+    fn expand_type_coercion() {
+        let expanded = expand_str(
+            "{\"@context\": {\"@vocab\": \"http://ex/\", \"knows\": {\"@type\": \"@id\"}, \"age\": {\"@type\": \"http://ex/int\"}},
+              \"@id\": \"http://ex/a\", \"knows\": \"http://ex/b\", \"age\": \"5\"}",
+        );
+
+        assert_eq!(
+            expanded,
+            "[{\"@id\":\"http://ex/a\",\"http://ex/age\":[{\"@type\":\"http://ex/int\",\"@value\":\"5\"}],\"http://ex/knows\":[{\"@id\":\"http://ex/b\"}]}]"
+        );
+    }
+
+    #[test]
+    fn expand_language_and_list_containers() {
+        let expanded = expand_str(
+            "{\"@context\": {\"@language\": \"en\", \"label\": {\"@id\": \"http://ex/label\", \"@container\": \"@language\"},
+              \"items\": {\"@id\": \"http://ex/items\", \"@container\": \"@list\"}, \"note\": \"http://ex/note\"},
+              \"label\": {\"de\": \"Hallo\"}, \"items\": [1, 2], \"note\": \"hi\"}",
+        );
+
+        assert_eq!(
+            expanded,
+            "[{\"http://ex/items\":[{\"@list\":[{\"@value\":1},{\"@value\":2}]}],\"http://ex/label\":[{\"@language\":\"de\",\"@value\":\"Hallo\"}],\"http://ex/note\":[{\"@language\":\"en\",\"@value\":\"hi\"}]}]"
+        );
+    }
+
+    #[test]
+    fn expand_reverse_properties() {
+        let expanded = expand_str(
+            "{\"@context\": {\"parent\": {\"@reverse\": \"http://ex/child\"}},
+              \"@id\": \"http://ex/a\", \"parent\": {\"@id\": \"http://ex/b\"}}",
+        );
+
+        assert_eq!(
+            expanded,
+            "[{\"@id\":\"http://ex/a\",\"@reverse\":{\"http://ex/child\":[{\"@id\":\"http://ex/b\"}]}}]"
+        );
+    }
+
+    #[test]
+    fn drop_terms_without_mapping() {
+        assert_eq!(expand_str("{\"unknown\": \"value\"}"), "[]");
+    }
+}
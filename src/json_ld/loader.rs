@@ -0,0 +1,226 @@
+use crate::error::{Error, ErrorType};
+use crate::json::JsonValue;
+use crate::Result;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+/// Trait implemented by loaders that resolve remote JSON-LD contexts.
+///
+/// Closures of the form `Fn(&str) -> Result<JsonValue>` can be used as loaders as well.
+pub trait ContextLoader {
+    /// Returns the JSON document that is referenced by the provided context IRI.
+    fn load_context(&self, iri: &str) -> Result<JsonValue>;
+}
+
+impl<F> ContextLoader for F
+where
+    F: Fn(&str) -> Result<JsonValue>,
+{
+    fn load_context(&self, iri: &str) -> Result<JsonValue> {
+        self(iri)
+    }
+}
+
+/// Loader that refuses to load any remote context.
+///
+/// This is the default loader of `JsonLdParser`.
+#[derive(Default)]
+pub struct NoContextLoader {}
+
+impl NoContextLoader {
+    /// Constructor of `NoContextLoader`.
+    pub fn new() -> NoContextLoader {
+        NoContextLoader {}
+    }
+}
+
+impl ContextLoader for NoContextLoader {
+    fn load_context(&self, iri: &str) -> Result<JsonValue> {
+        Err(Error::new(
+            ErrorType::InvalidJsonLdInput,
+            "Loading remote contexts is not supported: ".to_string() + iri,
+        ))
+    }
+}
+
+/// Loader that serves remote contexts from local files.
+#[derive(Default)]
+pub struct FileContextLoader {
+    /// Context IRIs that are associated with a specific file.
+    files: HashMap<String, PathBuf>,
+
+    /// IRI prefixes that are associated with a directory.
+    directories: Vec<(String, PathBuf)>,
+}
+
+impl FileContextLoader {
+    /// Constructor of `FileContextLoader`.
+    pub fn new() -> FileContextLoader {
+        FileContextLoader {
+            files: HashMap::new(),
+            directories: Vec::new(),
+        }
+    }
+
+    /// Serves the context with the provided IRI from a file.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::json_ld::loader::FileContextLoader;
+    ///
+    /// let mut loader = FileContextLoader::new();
+    /// loader.add_file("http://schema.org/", "tests/contexts/schema.jsonld");
+    /// ```
+    pub fn add_file<P: AsRef<Path>>(&mut self, iri: &str, path: P) {
+        self.files
+            .insert(iri.to_string(), path.as_ref().to_path_buf());
+    }
+
+    /// Serves all contexts whose IRIs start with the provided prefix from a directory.
+    ///
+    /// The remainder of the IRI is used as path relative to the directory, contexts outside
+    /// of the directory are not served.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::json_ld::loader::FileContextLoader;
+    ///
+    /// let mut loader = FileContextLoader::new();
+    /// loader.add_directory("https://example.org/contexts/", "tests/contexts");
+    /// ```
+    pub fn add_directory<P: AsRef<Path>>(&mut self, iri_prefix: &str, path: P) {
+        self.directories
+            .push((iri_prefix.to_string(), path.as_ref().to_path_buf()));
+    }
+
+    /// Returns the file that is associated with the provided IRI.
+    ///
+    /// # Failures
+    ///
+    /// - No file or directory is associated with the IRI.
+    /// - The path relative to the directory is absolute or leaves the directory.
+    ///
+    fn path_for(&self, iri: &str) -> Result<PathBuf> {
+        if let Some(path) = self.files.get(iri) {
+            return Ok(path.clone());
+        }
+
+        // prefer the most specific directory mapping
+        let (prefix, dir) = match self
+            .directories
+            .iter()
+            .filter(|(prefix, _)| iri.starts_with(prefix.as_str()))
+            .max_by_key(|(prefix, _)| prefix.len())
+        {
+            Some(mapping) => mapping,
+            None => {
+                return Err(Error::new(
+                    ErrorType::InvalidJsonLdInput,
+                    "No local file for remote context: ".to_string() + iri,
+                ))
+            }
+        };
+
+        let relative_path = Path::new(&iri[prefix.len()..]);
+        let inside_directory = relative_path
+            .components()
+            .all(|component| matches!(component, Component::Normal(_) | Component::CurDir));
+
+        if !inside_directory {
+            return Err(Error::new(
+                ErrorType::InvalidJsonLdInput,
+                format!(
+                    "Remote context {} is outside of the directory {}.",
+                    iri,
+                    dir.display()
+                ),
+            ));
+        }
+
+        Ok(dir.join(relative_path))
+    }
+}
+
+impl ContextLoader for FileContextLoader {
+    fn load_context(&self, iri: &str) -> Result<JsonValue> {
+        let path = self.path_for(iri)?;
+
+        match fs::read_to_string(&path) {
+            Ok(content) => JsonValue::parse(&content),
+            Err(err) => Err(Error::new(
+                ErrorType::InvalidJsonLdInput,
+                format!(
+                    "Unable to read context {} from {}: {}",
+                    iri,
+                    path.display(),
+                    err
+                ),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::json::JsonValue;
+    use crate::json_ld::loader::*;
+
+    #[test]
+    fn closure_context_loader() {
+        let loader =
+            |iri: &str| JsonValue::parse(&format!("{{\"@context\": {{\"iri\": \"{}\"}}}}", iri));
+
+        let context = loader.load_context("http://example.org/").unwrap();
+
+        assert_eq!(
+            context
+                .get("@context")
+                .unwrap()
+                .get("iri")
+                .unwrap()
+                .as_str(),
+            Some("http://example.org/")
+        );
+    }
+
+    #[test]
+    fn file_context_loader_without_mapping() {
+        let loader = FileContextLoader::new();
+
+        assert!(loader.load_context("http://example.org/context").is_err());
+        assert!(NoContextLoader::new()
+            .load_context("http://example.org/")
+            .is_err());
+    }
+
+    #[test]
+    fn file_context_loader_stays_in_directory() {
+        let mut loader = FileContextLoader::new();
+        loader.add_directory("https://example.org/contexts/", "tests/conformance");
+
+        assert_eq!(
+            loader
+                .path_for("https://example.org/contexts/turtle/./manifest.ttl")
+                .unwrap(),
+            Path::new("tests/conformance/turtle/./manifest.ttl")
+        );
+
+        for iri in [
+            "https://example.org/contexts/../../Cargo.toml",
+            "https://example.org/contexts/turtle/../../../Cargo.toml",
+            "https://example.org/contexts//etc/passwd",
+        ] {
+            match loader.load_context(iri) {
+                Err(err) => assert!(
+                    err.to_string().contains("outside of the directory"),
+                    "{}",
+                    iri
+                ),
+                Ok(_) => panic!("{} was loaded", iri),
+            }
+        }
+    }
+}
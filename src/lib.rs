@@ -11,6 +11,7 @@
 //!
 //! * Turtle
 //! * N-Triples
+//! * JSON-LD
 //!
 //!
 //! ## Usage
@@ -28,7 +29,7 @@
 //! let mut graph = Graph::new(None);
 //!
 //! let subject = graph.create_blank_node();
-//! let predicate = graph.create_uri_node(&Uri::new("http://example.org/show/localName".to_string()));
+//! let predicate = graph.create_uri_node(&Uri::parse("http://example.org/show/localName").unwrap());
 //! let object = graph.create_blank_node();
 //! let triple = Triple::new(&subject, &predicate, &object);
 //!
@@ -48,7 +49,7 @@
 //!
 //! let mut graph = Graph::new(None);
 //! let subject = graph.create_blank_node();
-//! let predicate = graph.create_uri_node(&Uri::new("http://example.org/show/localName".to_string()));
+//! let predicate = graph.create_uri_node(&Uri::parse("http://example.org/show/localName").unwrap());
 //! let object = graph.create_blank_node();
 //! let triple = Triple::new(&subject, &predicate, &object);
 //!
@@ -79,23 +80,56 @@
 //!   Ok(graph) => {
 //!     assert_eq!(graph.count(), 3);
 //!     assert_eq!(graph.namespaces().len(), 2);
-//!     assert_eq!(graph.base_uri(), &Some(Uri::new("http://example.org/".to_string())))
+//!     assert_eq!(graph.base_uri(), &Some(Uri::parse("http://example.org/").unwrap()))
 //!   },
 //!   Err(_) => assert!(false)
 //! }
 //! ```
+//!
+//! The format registry selects parsers and writers by media type or file extension.
+//!
+//! ```
+//! use rdf::format::RdfFormat;
+//!
+//! let format = RdfFormat::from_media_type("application/n-triples").unwrap();
+//! let graph = format.parse_string("<http://example.org/a> <http://example.org/b> \"c\" .").unwrap();
+//!
+//! assert_eq!(graph.count(), 1);
+//! ```
 
 use std::result;
 
+pub mod codegen;
+pub mod dataset;
+pub mod diff;
 pub mod error;
+pub mod format;
 pub mod graph;
+pub mod isomorphism;
+pub mod json;
 pub mod namespace;
 pub mod node;
+pub mod resource;
 pub mod triple;
 pub mod uri;
 
+pub mod canonicalization {
+    pub mod rdfc;
+    pub mod sha256;
+    pub mod sha384;
+}
+
+pub mod json_ld {
+    pub mod compaction;
+    pub mod context;
+    pub mod expansion;
+    pub mod loader;
+}
+
 pub type Result<T> = result::Result<T, error::Error>;
 
+pub mod vocab;
+
 pub mod writer {
     pub mod formatter {
         pub mod n_triples_formatter;
@@ -103,6 +137,7 @@ pub mod writer {
         pub mod turtle_formatter;
     }
 
+    pub mod json_ld_writer;
     pub mod n_triples_writer;
     pub mod rdf_writer;
     pub mod turtle_writer;
@@ -117,14 +152,19 @@ pub mod reader {
         pub mod turtle_lexer;
     }
 
+    pub mod blank_node_scope;
     pub mod input_reader;
+    pub mod json_ld_parser;
     pub mod n_triples_parser;
     pub mod rdf_parser;
+    pub mod rdf_sink;
     pub mod sparql_parser;
     pub mod turtle_parser;
 }
 
 pub mod specs {
+    pub mod json_ld_specs;
+    pub mod n_triples_specs;
     pub mod rdf_syntax_specs;
     pub mod sparql_specs;
     pub mod turtle_specs;
@@ -138,6 +178,14 @@ pub mod sparql {
     pub mod result;
 }
 
+pub mod xsd {
+    pub mod binary;
+    pub mod date_time;
+    pub mod decimal;
+    pub mod duration;
+    pub mod value;
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
@@ -1,6 +1,7 @@
 use crate::error::{Error, ErrorType};
 use std::collections::HashMap;
 use crate::uri::Uri;
+use crate::vocab::{dc, dcterms, foaf, owl, rdf, rdfs, sh, skos, xsd};
 use crate::Result;
 
 /// Representation of a specific namespace.
@@ -20,7 +21,7 @@ impl Namespace {
     /// use rdf::uri::Uri;
     ///
     /// let ns = Namespace::new("example".to_string(),
-    ///                         Uri::new("http://example.org/".to_string()));
+    ///                         Uri::parse("http://example.org/").unwrap());
     /// ```
     pub fn new(prefix: String, uri: Uri) -> Namespace {
         Namespace { prefix, uri }
@@ -35,7 +36,7 @@ impl Namespace {
     /// use rdf::uri::Uri;
     ///
     /// let ns = Namespace::new("example".to_string(),
-    ///                         Uri::new("http://example.org/".to_string()));
+    ///                         Uri::parse("http://example.org/").unwrap());
     ///
     /// assert_eq!(ns.prefix(), "example");
     /// ```
@@ -52,15 +53,46 @@ impl Namespace {
     /// use rdf::uri::Uri;
     ///
     /// let ns = Namespace::new("example".to_string(),
-    ///                         Uri::new("http://example.org/".to_string()));
+    ///                         Uri::parse("http://example.org/").unwrap());
     ///
-    /// assert_eq!(ns.uri(), &Uri::new("http://example.org/".to_string()));
+    /// assert_eq!(ns.uri(), &Uri::parse("http://example.org/").unwrap());
     /// ```
     pub fn uri(&self) -> &Uri {
         &self.uri
     }
 }
 
+/// Prefixes and namespace URIs of the built-in vocabularies.
+const DEFAULT_PREFIXES: [(&str, &str); 9] = [
+    (rdf::DEFAULT_PREFIX, rdf::NAMESPACE_URI),
+    (rdfs::DEFAULT_PREFIX, rdfs::NAMESPACE_URI),
+    (owl::DEFAULT_PREFIX, owl::NAMESPACE_URI),
+    (xsd::DEFAULT_PREFIX, xsd::NAMESPACE_URI),
+    (skos::DEFAULT_PREFIX, skos::NAMESPACE_URI),
+    (dc::DEFAULT_PREFIX, dc::NAMESPACE_URI),
+    (dcterms::DEFAULT_PREFIX, dcterms::NAMESPACE_URI),
+    (foaf::DEFAULT_PREFIX, foaf::NAMESPACE_URI),
+    (sh::DEFAULT_PREFIX, sh::NAMESPACE_URI),
+];
+
+/// Handling of a prefix that two namespace stores bind to different URIs.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum PrefixConflict {
+    /// The binding of the first store is kept.
+    #[default]
+    KeepFirst,
+
+    /// The binding of the second store replaces the binding of the first store.
+    KeepSecond,
+
+    /// The URI of the second store is bound to the prefix with a number appended, unless
+    /// the first store already has a prefix for it.
+    Rename,
+
+    /// Merging the namespace stores fails.
+    Fail,
+}
+
 /// Storage for multiple namespaces.
 #[derive(PartialEq, Debug, Default)]
 pub struct NamespaceStore {
@@ -84,6 +116,49 @@ impl NamespaceStore {
         }
     }
 
+    /// Creates a store with the prefixes of the built-in vocabularies in `vocab`, e.g. `rdfs`
+    /// and `foaf`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::namespace::NamespaceStore;
+    /// use rdf::vocab::rdfs;
+    ///
+    /// let nss = NamespaceStore::with_default_prefixes();
+    ///
+    /// assert_eq!(nss.get_uri_by_prefix("rdfs").unwrap().to_string(), rdfs::NAMESPACE_URI);
+    /// ```
+    pub fn with_default_prefixes() -> NamespaceStore {
+        let mut nss = NamespaceStore::new();
+        nss.add_default_prefixes();
+        nss
+    }
+
+    /// Adds the prefixes of the built-in vocabularies that are not bound yet.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::namespace::{Namespace, NamespaceStore};
+    /// use rdf::uri::Uri;
+    ///
+    /// let mut nss = NamespaceStore::new();
+    /// nss.add(&Namespace::new("sh".to_string(), Uri::parse("http://example.org/sh#").unwrap()));
+    ///
+    /// nss.add_default_prefixes();
+    ///
+    /// assert_eq!(nss.get_uri_by_prefix("sh").unwrap().to_string(), "http://example.org/sh#");
+    /// assert_eq!(nss.get_uri_by_prefix("foaf").unwrap().to_string(), "http://xmlns.com/foaf/0.1/");
+    /// ```
+    pub fn add_default_prefixes(&mut self) {
+        for &(prefix, uri) in DEFAULT_PREFIXES.iter() {
+            self.namespaces
+                .entry(prefix.to_string())
+                .or_insert_with(|| Uri::new_unchecked(uri.to_string()));
+        }
+    }
+
     /// Returns the stored namespaces with prefixes.
     pub fn namespaces(&self) -> &HashMap<String, Uri> {
         &self.namespaces
@@ -101,7 +176,7 @@ impl NamespaceStore {
     /// let mut nss = NamespaceStore::new();
     ///
     /// let ns = Namespace::new("example".to_string(),
-    ///                         Uri::new("http://example.org/".to_string()));
+    ///                         Uri::parse("http://example.org/").unwrap());
     ///
     /// nss.add(&ns);
     /// ```
@@ -109,6 +184,97 @@ impl NamespaceStore {
         self.namespaces.insert(ns.prefix().clone(), ns.uri.clone());
     }
 
+    /// Adds the namespaces of another store, prefixes that are bound to different URIs are
+    /// handled as specified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::namespace::{Namespace, NamespaceStore, PrefixConflict};
+    /// use rdf::uri::Uri;
+    ///
+    /// let mut nss = NamespaceStore::new();
+    /// nss.add(&Namespace::new("ex".to_string(), Uri::parse("http://example.org/").unwrap()));
+    ///
+    /// let mut other = NamespaceStore::new();
+    /// other.add(&Namespace::new("ex".to_string(), Uri::parse("http://example.com/").unwrap()));
+    ///
+    /// assert!(nss.merge(&other, PrefixConflict::Fail).is_err());
+    ///
+    /// nss.merge(&other, PrefixConflict::Rename).unwrap();
+    ///
+    /// assert_eq!(nss.get_uri_by_prefix("ex1").unwrap(),
+    ///            &Uri::parse("http://example.com/").unwrap());
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - A prefix is bound to different URIs and conflicts are handled with `PrefixConflict::Fail`.
+    ///
+    pub fn merge(&mut self, other: &NamespaceStore, conflict: PrefixConflict) -> Result<()> {
+        let mut namespaces: Vec<(&String, &Uri)> = other.namespaces.iter().collect();
+        namespaces.sort();
+
+        // the store is not changed if merging fails
+        if conflict == PrefixConflict::Fail {
+            for &(prefix, uri) in &namespaces {
+                match self.namespaces.get(prefix) {
+                    Some(bound_uri) if bound_uri != uri => {
+                        return Err(Error::new(
+                            ErrorType::InvalidNamespace,
+                            format!(
+                                "Prefix {} is bound to {} and {}.",
+                                prefix,
+                                bound_uri.to_string(),
+                                uri.to_string()
+                            ),
+                        ))
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        for (prefix, uri) in namespaces {
+            match self.namespaces.get(prefix) {
+                Some(bound_uri) if bound_uri != uri => {}
+                _ => {
+                    self.namespaces.insert(prefix.clone(), uri.clone());
+                    continue;
+                }
+            }
+
+            match conflict {
+                PrefixConflict::KeepFirst | PrefixConflict::Fail => {}
+                PrefixConflict::KeepSecond => {
+                    self.namespaces.insert(prefix.clone(), uri.clone());
+                }
+                PrefixConflict::Rename => {
+                    if self.namespaces.values().all(|bound_uri| bound_uri != uri) {
+                        let renamed = self.unused_prefix(prefix);
+                        self.namespaces.insert(renamed, uri.clone());
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the prefix with the smallest number appended that is not bound, a trailing
+    /// ':' is kept at the end.
+    fn unused_prefix(&self, prefix: &str) -> String {
+        let (name, colon) = match prefix.strip_suffix(':') {
+            Some(name) => (name, ":"),
+            None => (prefix, ""),
+        };
+
+        (1..)
+            .map(|number| format!("{}{}{}", name, number, colon))
+            .find(|renamed| !self.namespaces.contains_key(renamed))
+            .unwrap()
+    }
+
     /// Returns the URI of a specific namespace.
     ///
     /// # Examples
@@ -121,12 +287,12 @@ impl NamespaceStore {
     /// let mut nss = NamespaceStore::new();
     ///
     /// let ns = Namespace::new("example".to_string(),
-    ///                         Uri::new("http://example.org/".to_string()));
+    ///                         Uri::parse("http://example.org/").unwrap());
     ///
     /// nss.add(&ns);
     ///
     /// assert_eq!(nss.get_uri_by_prefix("example").unwrap(),
-    ///            &Uri::new("http://example.org/".to_string()))
+    ///            &Uri::parse("http://example.org/").unwrap())
     /// ```
     pub fn get_uri_by_prefix(&self, prefix: &str) -> Result<&Uri> {
         match self.namespaces.get(prefix) {
@@ -1,7 +1,10 @@
+use crate::specs::xml_specs::XmlDataTypes;
 use crate::uri::Uri;
+use crate::xsd::value::XsdValue;
+use crate::Result;
 
 /// Node representation.
-#[derive(Clone, PartialEq, PartialOrd, Eq, Ord, Debug)]
+#[derive(Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Debug)]
 pub enum Node {
     /// Node for representing a URI.
     UriNode { uri: Uri },
@@ -17,6 +20,135 @@ pub enum Node {
     BlankNode { id: String },
 }
 
+impl Node {
+    /// Parses the value of a literal node according to its XML schema data type.
+    ///
+    /// Literals without data type, including literals with language, are strings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::node::Node;
+    /// use rdf::uri::Uri;
+    ///
+    /// let node = Node::LiteralNode {
+    ///   literal: "1.50".to_string(),
+    ///   data_type: Some(Uri::parse("http://www.w3.org/2001/XMLSchema#decimal").unwrap()),
+    ///   language: None,
+    /// };
+    ///
+    /// assert_eq!(node.literal_value().unwrap().as_f64(), Some(1.5));
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - The node is not a literal.
+    /// - The data type of the literal is not supported.
+    /// - The lexical form is not valid for the data type.
+    ///
+    pub fn literal_value(&self) -> Result<XsdValue> {
+        XsdValue::from_node(self)
+    }
+
+    /// Returns the node with the literal in canonical form.
+    ///
+    /// The lexical form of literals with a supported XML schema data type is replaced by its
+    /// canonical form and language tags are converted to lower case. Other nodes and literals
+    /// that are not valid for their data type are returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::node::Node;
+    /// use rdf::specs::xml_specs::XmlDataTypes;
+    ///
+    /// let node = Node::LiteralNode {
+    ///   literal: "01".to_string(),
+    ///   data_type: Some(XmlDataTypes::Integer.to_uri()),
+    ///   language: None,
+    /// };
+    ///
+    /// assert_eq!(node.canonicalize(), Node::LiteralNode {
+    ///   literal: "1".to_string(),
+    ///   data_type: Some(XmlDataTypes::Integer.to_uri()),
+    ///   language: None,
+    /// });
+    /// ```
+    pub fn canonicalize(&self) -> Node {
+        match *self {
+            Node::LiteralNode {
+                ref literal,
+                ref data_type,
+                ref language,
+            } => {
+                let canonical = data_type
+                    .as_ref()
+                    .and_then(XmlDataTypes::from_uri)
+                    .and_then(|data_type| XsdValue::canonicalize(literal, data_type).ok());
+
+                Node::LiteralNode {
+                    literal: canonical.unwrap_or_else(|| literal.clone()),
+                    data_type: data_type.clone(),
+                    language: language.as_ref().map(|language| language.to_lowercase()),
+                }
+            }
+            _ => self.clone(),
+        }
+    }
+
+    /// Checks if two nodes are equal, comparing literals by their value.
+    ///
+    /// Literals with supported XML schema data types are equal if their values are equal
+    /// according to `XsdValue::value_eq`, e.g. `"01"^^xsd:integer` and `"1.0"^^xsd:decimal`.
+    /// Language tags are compared case-insensitively. All other nodes are compared as terms.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::node::Node;
+    /// use rdf::specs::xml_specs::XmlDataTypes;
+    ///
+    /// let integer = Node::LiteralNode {
+    ///   literal: "01".to_string(),
+    ///   data_type: Some(XmlDataTypes::Integer.to_uri()),
+    ///   language: None,
+    /// };
+    ///
+    /// let decimal = Node::LiteralNode {
+    ///   literal: "1.0".to_string(),
+    ///   data_type: Some(XmlDataTypes::Decimal.to_uri()),
+    ///   language: None,
+    /// };
+    ///
+    /// assert!(integer.value_eq(&decimal));
+    /// assert!(integer != decimal);
+    /// ```
+    pub fn value_eq(&self, other: &Node) -> bool {
+        match (self, other) {
+            (
+                Node::LiteralNode {
+                    literal: a,
+                    language: Some(a_language),
+                    ..
+                },
+                Node::LiteralNode {
+                    literal: b,
+                    language: Some(b_language),
+                    ..
+                },
+            ) => a == b && a_language.eq_ignore_ascii_case(b_language),
+            (
+                Node::LiteralNode { language: None, .. },
+                Node::LiteralNode { language: None, .. },
+            ) => match (self.literal_value(), other.literal_value()) {
+                (Ok(a), Ok(b)) => a.value_eq(&b),
+                _ => self == other,
+            },
+            _ => self == other,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::node::*;
@@ -38,4 +170,106 @@ mod tests {
             _ => assert!(false),
         }
     }
+
+    #[test]
+    fn canonicalize_literals() {
+        let literal = |literal: &str, data_type: Option<XmlDataTypes>, language: Option<&str>| {
+            Node::LiteralNode {
+                literal: literal.to_string(),
+                data_type: data_type.map(|data_type| data_type.to_uri()),
+                language: language.map(|language| language.to_string()),
+            }
+        };
+
+        let examples = [
+            (literal("+010", Some(XmlDataTypes::Int), None), "10"),
+            (literal("1.50", Some(XmlDataTypes::Decimal), None), "1.5"),
+            (literal("1", Some(XmlDataTypes::Boolean), None), "true"),
+            (literal("15e-1", Some(XmlDataTypes::Double), None), "1.5E0"),
+            (literal("abc", Some(XmlDataTypes::Integer), None), "abc"),
+            (literal(" a ", None, None), " a "),
+        ];
+
+        for (node, canonical) in examples.iter() {
+            if let Node::LiteralNode { literal, .. } = node.canonicalize() {
+                assert_eq!(literal, *canonical);
+            }
+        }
+
+        assert_eq!(
+            literal("chat", None, Some("fr-CA")).canonicalize(),
+            literal("chat", None, Some("fr-ca"))
+        );
+    }
+
+    #[test]
+    fn compare_literal_values() {
+        let typed = |literal: &str, data_type: XmlDataTypes| Node::LiteralNode {
+            literal: literal.to_string(),
+            data_type: Some(data_type.to_uri()),
+            language: None,
+        };
+
+        let language = |literal: &str, language: &str| Node::LiteralNode {
+            literal: literal.to_string(),
+            data_type: None,
+            language: Some(language.to_string()),
+        };
+
+        let simple = Node::LiteralNode {
+            literal: "1".to_string(),
+            data_type: None,
+            language: None,
+        };
+
+        let equal = [
+            (
+                typed("01", XmlDataTypes::Integer),
+                typed("1", XmlDataTypes::Integer),
+            ),
+            (
+                typed("1", XmlDataTypes::Int),
+                typed("1.0", XmlDataTypes::Decimal),
+            ),
+            (
+                typed("1", XmlDataTypes::Integer),
+                typed("1E0", XmlDataTypes::Float),
+            ),
+            (typed("1", XmlDataTypes::String), simple.clone()),
+            (
+                typed("2002-10-10T12:00:00-05:00", XmlDataTypes::DateTime),
+                typed("2002-10-10T17:00:00Z", XmlDataTypes::DateTime),
+            ),
+            (language("chat", "fr"), language("chat", "FR")),
+            (
+                typed("x", XmlDataTypes::Integer),
+                typed("x", XmlDataTypes::Integer),
+            ),
+        ];
+
+        for (a, b) in equal.iter() {
+            assert!(a.value_eq(b), "{:?} != {:?}", a, b);
+        }
+
+        let unequal = [
+            (typed("1", XmlDataTypes::Integer), simple.clone()),
+            (
+                typed("NaN", XmlDataTypes::Double),
+                typed("NaN", XmlDataTypes::Double),
+            ),
+            (
+                typed("1", XmlDataTypes::Boolean),
+                typed("1", XmlDataTypes::Integer),
+            ),
+            (
+                typed("2002-10-10T12:00:00", XmlDataTypes::DateTime),
+                typed("2002-10-10T12:00:00Z", XmlDataTypes::DateTime),
+            ),
+            (language("1", "en"), simple.clone()),
+        ];
+
+        for (a, b) in unequal.iter() {
+            assert!(!a.value_eq(b), "{:?} == {:?}", a, b);
+        }
+    }
 }
@@ -0,0 +1,101 @@
+use crate::node::Node;
+use std::collections::{HashMap, HashSet};
+
+/// Blank nodes of a single document.
+///
+/// Labels of the document are kept, unless the label was already used for a generated blank
+/// node. Generated blank nodes are labeled `auto0`, `auto1`, ... and skip labels that are used
+/// by the document, so that labeled and generated blank nodes never clash.
+#[derive(Default)]
+pub(crate) struct BlankNodeScope {
+    /// Blank nodes by their label in the document.
+    labels: HashMap<String, Node>,
+
+    /// IDs of all blank nodes of the document.
+    used_ids: HashSet<String>,
+
+    /// Next number that may be used for a generated blank node.
+    next_id: u64,
+}
+
+impl BlankNodeScope {
+    /// Constructor of `BlankNodeScope`.
+    pub(crate) fn new() -> BlankNodeScope {
+        BlankNodeScope::default()
+    }
+
+    /// Creates a scope where the IDs are already taken, e.g. by blank nodes of a graph that
+    /// the document is merged into.
+    pub(crate) fn with_used_ids<I>(ids: I) -> BlankNodeScope
+    where
+        I: IntoIterator<Item = String>,
+    {
+        BlankNodeScope {
+            used_ids: ids.into_iter().collect(),
+            ..BlankNodeScope::default()
+        }
+    }
+
+    /// Returns the blank node for a label of the document.
+    pub(crate) fn labeled(&mut self, label: &str) -> Node {
+        if let Some(node) = self.labels.get(label) {
+            return node.clone();
+        }
+
+        let node = if self.used_ids.insert(label.to_string()) {
+            Node::BlankNode {
+                id: label.to_string(),
+            }
+        } else {
+            self.generate()
+        };
+
+        self.labels.insert(label.to_string(), node.clone());
+        node
+    }
+
+    /// Returns a new blank node that is not labeled in the document.
+    pub(crate) fn generate(&mut self) -> Node {
+        loop {
+            let id = "auto".to_string() + &self.next_id.to_string();
+            self.next_id += 1;
+
+            if self.used_ids.insert(id.clone()) {
+                return Node::BlankNode { id };
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::node::Node;
+    use crate::reader::blank_node_scope::BlankNodeScope;
+
+    fn blank(id: &str) -> Node {
+        Node::BlankNode { id: id.to_string() }
+    }
+
+    #[test]
+    fn labeled_and_generated_blank_nodes_do_not_clash() {
+        let mut scope = BlankNodeScope::new();
+
+        assert_eq!(scope.labeled("auto1"), blank("auto1"));
+        assert_eq!(scope.generate(), blank("auto0"));
+        assert_eq!(scope.generate(), blank("auto2"));
+
+        // the label was already used for a generated blank node
+        assert_eq!(scope.labeled("auto0"), blank("auto3"));
+        assert_eq!(scope.labeled("auto0"), blank("auto3"));
+        assert_eq!(scope.labeled("auto1"), blank("auto1"));
+    }
+
+    #[test]
+    fn used_ids_are_renamed() {
+        let mut scope = BlankNodeScope::with_used_ids(vec!["a".to_string(), "auto0".to_string()]);
+
+        assert_eq!(scope.labeled("a"), blank("auto1"));
+        assert_eq!(scope.labeled("b"), blank("b"));
+        assert_eq!(scope.generate(), blank("auto2"));
+    }
+}
@@ -1,16 +1,21 @@
 use crate::error::{Error, ErrorType};
+use std::collections::VecDeque;
+use std::fmt;
 use std::io::Read;
 use std::ops::Index;
 use std::str;
 use crate::Result;
 
+/// Maximum number of characters that are kept for error snippets.
+const SNIPPET_LENGTH: usize = 60;
+
 /// Collection of several helper methods that can be used when reading input.
 pub struct InputReaderHelper {}
 
 impl InputReaderHelper {
     /// Returns `true` if the provided character is a whitespace.
     pub fn whitespace(c: char) -> bool {
-        c == '\n' || c == '\r' || c == ' '
+        c == '\n' || c == '\r' || c == ' ' || c == '\t'
     }
 
     /// Returns `true` if the provided character is a line break.
@@ -20,7 +25,7 @@ impl InputReaderHelper {
 
     /// Returns `true` if the provided character can be used to separate two nodes.
     pub fn node_delimiter(c: char) -> bool {
-        c == '\n' || c == '\r' || c == ' ' || c == '.'
+        InputReaderHelper::whitespace(c) || c == '.'
     }
 
     /// Returns `true` if the provided character is a digit.
@@ -96,10 +101,116 @@ impl InputChars {
     }
 }
 
+/// Position of a character in the input.
+///
+/// Lines and columns start at 1, the column counts characters and the offset counts bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Position {
+    offset: usize,
+    line: usize,
+    column: usize,
+}
+
+impl Default for Position {
+    fn default() -> Position {
+        Position::new(0, 1, 1)
+    }
+}
+
+impl fmt::Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
+}
+
+impl Position {
+    /// Constructor of `Position`.
+    pub fn new(offset: usize, line: usize, column: usize) -> Position {
+        Position {
+            offset,
+            line,
+            column,
+        }
+    }
+
+    /// Returns the byte offset from the beginning of the input.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the line number.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Returns the column number.
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    /// Moves the position behind the provided character.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::input_reader::Position;
+    ///
+    /// let mut position = Position::default();
+    /// position.advance('a');
+    /// position.advance('\n');
+    /// position.advance('é');
+    ///
+    /// assert_eq!(position, Position::new(4, 2, 2));
+    /// ```
+    pub fn advance(&mut self, c: char) {
+        self.offset += c.len_utf8();
+
+        if c == '\n' {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+    }
+}
+
+/// Range of the input between two positions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Span {
+    start: Position,
+    end: Position,
+}
+
+impl Span {
+    /// Constructor of `Span`.
+    pub fn new(start: Position, end: Position) -> Span {
+        Span { start, end }
+    }
+
+    /// Returns the position of the first character.
+    pub fn start(&self) -> &Position {
+        &self.start
+    }
+
+    /// Returns the position behind the last character.
+    pub fn end(&self) -> &Position {
+        &self.end
+    }
+}
+
 /// Reads input and transforms it to `InputChars`.
+///
+/// The reader keeps track of the position of the next character that is consumed.
 pub struct InputReader<R: Read> {
     input: R,
     peeked_chars: InputChars,
+
+    /// Position of the next character and of the last consumed character.
+    position: Position,
+    previous_position: Position,
+
+    /// Last consumed characters that are used for error snippets.
+    recent_chars: VecDeque<char>,
 }
 
 impl<R: Read> InputReader<R> {
@@ -116,9 +227,74 @@ impl<R: Read> InputReader<R> {
         InputReader {
             input,
             peeked_chars: InputChars::new(Vec::new()),
+            position: Position::default(),
+            previous_position: Position::default(),
+            recent_chars: VecDeque::new(),
         }
     }
 
+    /// Returns the position of the next character that is consumed.
+    ///
+    /// Peeked characters are not considered as consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::input_reader::{InputReader, Position};
+    ///
+    /// let mut reader = InputReader::new("_:a\n_:b".as_bytes());
+    /// let _ = reader.get_until(|c| c == '\n');
+    /// let _ = reader.get_next_char();
+    /// let _ = reader.peek_next_k_chars(2);
+    ///
+    /// assert_eq!(reader.position(), &Position::new(4, 2, 1));
+    /// ```
+    pub fn position(&self) -> &Position {
+        &self.position
+    }
+
+    /// Returns the consumed text of the current line, shortened to the last characters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::input_reader::InputReader;
+    ///
+    /// let mut reader = InputReader::new("_:a .\n  _:b _:c".as_bytes());
+    /// let _ = reader.get_until(|c| c == 'c');
+    ///
+    /// assert_eq!(reader.snippet(), "_:b _:");
+    /// ```
+    pub fn snippet(&self) -> String {
+        let line: String = self
+            .recent_chars
+            .iter()
+            .rev()
+            .take_while(|c| !InputReaderHelper::line_break(**c))
+            .collect();
+
+        line.chars().rev().collect::<String>().trim().to_string()
+    }
+
+    /// Saves the position, so that consumed characters can be put back.
+    fn save_position(&self) -> (Position, VecDeque<char>) {
+        (self.position, self.recent_chars.clone())
+    }
+
+    /// Restores a position that was saved before characters were put back.
+    fn restore_position(&mut self, saved: (Position, VecDeque<char>)) {
+        self.position = saved.0;
+        self.previous_position = saved.0;
+        self.recent_chars = saved.1;
+    }
+
+    /// Puts the last consumed character back.
+    fn unread_char(&mut self, c: char) {
+        self.peeked_chars.insert(0, Some(c));
+        self.position = self.previous_position;
+        self.recent_chars.pop_back();
+    }
+
     /// Returns the next `k` characters but does not consume them.
     ///
     /// # Examples
@@ -138,11 +314,17 @@ impl<R: Read> InputReader<R> {
     ///
     pub fn peek_next_k_chars(&mut self, k: usize) -> Result<InputChars> {
         if self.peeked_chars.len() >= k {
-            Ok(InputChars::new(self.peeked_chars.to_vec()[0..k].to_vec()))
+            Ok(InputChars::new(
+                (0..k).map(|i| self.peeked_chars[i]).collect(),
+            ))
         } else {
-            let next_k_chars = self.get_next_k_chars(k)?;
-            self.peeked_chars = next_k_chars.clone();
-            Ok(next_k_chars)
+            let saved_position = self.save_position();
+            let mut next_k_chars = self.get_next_k_chars(k)?;
+            let result = next_k_chars.clone();
+            next_k_chars.append(&mut self.peeked_chars);
+            self.peeked_chars = next_k_chars;
+            self.restore_position(saved_position);
+            Ok(result)
         }
     }
 
@@ -186,16 +368,13 @@ impl<R: Read> InputReader<R> {
     /// - End of input reached.
     ///
     pub fn peek_next_char_discard_leading_spaces(&mut self) -> Result<InputChar> {
-        match self.get_next_char_discard_leading_spaces() {
-            Ok(Some(next_char)) => {
-                if self.peeked_chars.is_empty() {
-                    self.peeked_chars.push(Some(next_char));
+        loop {
+            match self.peek_next_char()? {
+                Some(' ') | Some('\n') | Some('\t') | Some('\r') => {
+                    let _ = self.get_next_char()?;
                 }
-
-                Ok(Some(next_char))
+                c => return Ok(c),
             }
-            Ok(None) => Ok(None),
-            Err(err) => Err(err),
         }
     }
 
@@ -217,6 +396,24 @@ impl<R: Read> InputReader<R> {
     /// - End of input reached.
     ///
     pub fn get_next_char(&mut self) -> Result<InputChar> {
+        let next_char = self.read_next_char()?;
+
+        if let Some(c) = next_char {
+            self.previous_position = self.position;
+            self.position.advance(c);
+
+            if self.recent_chars.len() == SNIPPET_LENGTH {
+                self.recent_chars.pop_front();
+            }
+
+            self.recent_chars.push_back(c);
+        }
+
+        Ok(next_char)
+    }
+
+    /// Returns the next peeked character or reads the next character from the input source.
+    fn read_next_char(&mut self) -> Result<InputChar> {
         if !self.peeked_chars.is_empty() {
             return Ok(self.peeked_chars.remove(0));
         }
@@ -337,10 +534,12 @@ impl<R: Read> InputReader<R> {
     /// - End of input reached.
     ///
     pub fn peek_until<F: Fn(char) -> bool>(&mut self, delimiter: F) -> Result<InputChars> {
+        let saved_position = self.save_position();
         let mut chars = self.get_until(delimiter)?;
         let result = chars.clone();
         chars.append(&mut self.peeked_chars);
         self.peeked_chars = chars;
+        self.restore_position(saved_position);
         Ok(result)
     }
 
@@ -369,11 +568,8 @@ impl<R: Read> InputReader<R> {
         &mut self,
         delimiter: F,
     ) -> Result<InputChars> {
-        let mut chars = self.get_until_discard_leading_spaces(delimiter)?;
-        let result = chars.clone();
-        chars.append(&mut self.peeked_chars);
-        self.peeked_chars = chars;
-        Ok(result)
+        self.discard_leading_spaces()?;
+        self.peek_until(delimiter)
     }
 
     /// Returns all characters of a input source until a certain delimiter occurs.
@@ -402,7 +598,7 @@ impl<R: Read> InputReader<R> {
         loop {
             match self.get_next_char()? {
                 Some(c) if delimiter(c) => {
-                    self.peeked_chars.insert(0, Some(c));
+                    self.unread_char(c);
 
                     return Ok(InputChars::new(buf.into_iter().collect()));
                 }
@@ -441,13 +637,18 @@ impl<R: Read> InputReader<R> {
         &mut self,
         delimiter: F,
     ) -> Result<InputChars> {
+        self.discard_leading_spaces()?;
+        self.get_until(delimiter)
+    }
+
+    /// Consumes leading whitespaces.
+    fn discard_leading_spaces(&mut self) -> Result<()> {
         let whitespaces = InputReaderHelper::whitespace;
 
-        // consume leading whitespaces
         while whitespaces(self.peek_next_char()?.unwrap_or('x')) {
             let _ = self.get_next_char();
         }
 
-        self.get_until(delimiter)
+        Ok(())
     }
 }
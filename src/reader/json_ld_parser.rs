@@ -0,0 +1,634 @@
+use crate::error::{Error, ErrorType};
+use crate::graph::Graph;
+use crate::json::{JsonObject, JsonValue};
+use crate::json_ld::context::Context;
+use crate::json_ld::expansion::{expand, is_list_object, is_value_object};
+use crate::json_ld::loader::{ContextLoader, NoContextLoader};
+use crate::node::Node;
+use crate::reader::blank_node_scope::BlankNodeScope;
+use crate::reader::rdf_parser::RdfParser;
+use crate::specs::rdf_syntax_specs::RdfSyntaxDataTypes;
+use crate::specs::xml_specs::XmlDataTypes;
+use crate::triple::Triple;
+use crate::uri::Uri;
+use crate::xsd::value::XsdValue;
+use crate::Result;
+use std::io::Cursor;
+use std::io::Read;
+
+/// RDF parser to generate an RDF graph from JSON-LD.
+///
+/// Named graphs are merged into the resulting graph, since `Graph` only represents a single graph.
+pub struct JsonLdParser<R: Read> {
+    input: R,
+    base_uri: Option<Uri>,
+    context_loader: Box<dyn ContextLoader>,
+    canonical_literals: bool,
+}
+
+impl<R: Read> RdfParser for JsonLdParser<R> {
+    /// Generates an RDF graph from a string containing JSON-LD.
+    ///
+    /// Returns an error in case invalid JSON-LD is provided.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::json_ld_parser::JsonLdParser;
+    /// use rdf::reader::rdf_parser::RdfParser;
+    ///
+    /// let input = "{
+    ///   \"@context\": {\"foaf\": \"http://xmlns.com/foaf/0.1/\"},
+    ///   \"@id\": \"http://www.w3.org/2001/sw/RDFCore/ntriples/\",
+    ///   \"foaf:maker\": {\"@id\": \"_:art\", \"foaf:name\": \"Art Barstow\"}
+    /// }";
+    ///
+    /// let mut reader = JsonLdParser::from_string(input.to_string());
+    ///
+    /// match reader.decode() {
+    ///   Ok(graph) => assert_eq!(graph.count(), 2),
+    ///   Err(_) => assert!(false)
+    /// }
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - Invalid JSON input.
+    /// - Invalid JSON-LD contexts or keyword usages.
+    /// - Remote contexts that cannot be loaded.
+    ///
+    fn decode(&mut self) -> Result<Graph> {
+        let expanded = self.expand()?;
+        let mut graph = Graph::new(self.base_uri.as_ref());
+
+        let mut converter = RdfConverter {
+            graph: &mut graph,
+            blank_nodes: BlankNodeScope::new(),
+        };
+
+        for node in expanded.to_vec() {
+            converter.node_to_rdf(&node)?;
+        }
+
+        if self.canonical_literals {
+            graph.canonicalize_literals();
+        }
+
+        Ok(graph)
+    }
+}
+
+impl JsonLdParser<Cursor<Vec<u8>>> {
+    /// Constructor of `JsonLdParser` from input string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::json_ld_parser::JsonLdParser;
+    ///
+    /// let input = "{\"@id\": \"http://example.org/a\", \"http://example.org/b\": \"c\"}";
+    ///
+    /// let reader = JsonLdParser::from_string(input.to_string());
+    /// ```
+    pub fn from_string<S>(input: S) -> JsonLdParser<Cursor<Vec<u8>>>
+    where
+        S: Into<String>,
+    {
+        JsonLdParser::from_reader(Cursor::new(input.into().into_bytes()))
+    }
+}
+
+impl<R: Read> JsonLdParser<R> {
+    /// Constructor of `JsonLdParser` from input reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::json_ld_parser::JsonLdParser;
+    ///
+    /// let input = "{\"@id\": \"http://example.org/a\", \"http://example.org/b\": \"c\"}";
+    ///
+    /// let reader = JsonLdParser::from_reader(input.as_bytes());
+    /// ```
+    pub fn from_reader(input: R) -> JsonLdParser<R> {
+        JsonLdParser {
+            input,
+            base_uri: None,
+            context_loader: Box::new(NoContextLoader::new()),
+            canonical_literals: false,
+        }
+    }
+
+    /// Sets the base IRI of the document that relative IRIs are resolved against.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::json_ld_parser::JsonLdParser;
+    /// use rdf::reader::rdf_parser::RdfParser;
+    /// use rdf::node::Node;
+    /// use rdf::uri::Uri;
+    ///
+    /// let input = "{\"@id\": \"alice\", \"http://xmlns.com/foaf/0.1/name\": \"Alice\"}";
+    ///
+    /// let mut reader = JsonLdParser::from_string(input.to_string())
+    ///   .with_base_uri(&Uri::parse("http://example.org/people/").unwrap());
+    /// let graph = reader.decode().unwrap();
+    ///
+    /// assert_eq!(graph.triples_iter().next().unwrap().subject(),
+    ///            &Node::UriNode { uri: Uri::parse("http://example.org/people/alice").unwrap() });
+    /// ```
+    pub fn with_base_uri(mut self, base_uri: &Uri) -> JsonLdParser<R> {
+        self.base_uri = Some(base_uri.clone());
+        self
+    }
+
+    /// Sets the loader that is used to retrieve remote contexts.
+    ///
+    /// By default, remote contexts are not loaded and cause an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::json::JsonValue;
+    /// use rdf::reader::json_ld_parser::JsonLdParser;
+    /// use rdf::reader::rdf_parser::RdfParser;
+    ///
+    /// let input = "{\"@context\": \"http://example.org/context.jsonld\", \"name\": \"Alice\"}";
+    /// let loader = |_: &str| JsonValue::parse("{\"@context\": {\"name\": \"http://xmlns.com/foaf/0.1/name\"}}");
+    ///
+    /// let mut reader = JsonLdParser::from_string(input.to_string()).with_context_loader(loader);
+    ///
+    /// assert_eq!(reader.decode().unwrap().count(), 1);
+    /// ```
+    pub fn with_context_loader<L>(mut self, loader: L) -> JsonLdParser<R>
+    where
+        L: ContextLoader + 'static,
+    {
+        self.context_loader = Box::new(loader);
+        self
+    }
+
+    /// Converts literals of the generated graph to canonical form.
+    ///
+    /// See `Graph::canonicalize_literals`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::json_ld_parser::JsonLdParser;
+    /// use rdf::reader::rdf_parser::RdfParser;
+    /// use rdf::node::Node;
+    ///
+    /// let input = "{\"@id\": \"http://example.org/a\",
+    ///               \"http://example.org/b\": {\"@value\": \"chat\", \"@language\": \"fr-CA\"}}";
+    ///
+    /// let mut reader = JsonLdParser::from_string(input.to_string()).canonicalize_literals();
+    /// let graph = reader.decode().unwrap();
+    ///
+    /// match *graph.triples_iter().next().unwrap().object() {
+    ///   Node::LiteralNode { ref language, .. } => assert_eq!(language.as_ref().unwrap(), "fr-ca"),
+    ///   _ => assert!(false),
+    /// }
+    /// ```
+    pub fn canonicalize_literals(mut self) -> JsonLdParser<R> {
+        self.canonical_literals = true;
+        self
+    }
+
+    /// Reads the input and returns it in expanded JSON-LD form.
+    ///
+    /// # Failures
+    ///
+    /// - Invalid JSON input.
+    /// - Invalid JSON-LD contexts or keyword usages.
+    ///
+    pub fn expand(&mut self) -> Result<JsonValue> {
+        let mut input = String::new();
+
+        if self.input.read_to_string(&mut input).is_err() {
+            return Err(Error::new(
+                ErrorType::InvalidByteEncoding,
+                "Invalid byte encoding of JSON-LD input.",
+            ));
+        }
+
+        let document = JsonValue::parse(&input)?;
+        let base_uri = self.base_uri.as_ref().map(|b| b.to_string().as_str());
+
+        expand(
+            &document,
+            &Context::new(base_uri),
+            self.context_loader.as_ref(),
+        )
+    }
+}
+
+/// Converts expanded JSON-LD to triples.
+struct RdfConverter<'a> {
+    graph: &'a mut Graph,
+
+    /// Blank nodes of the document.
+    blank_nodes: BlankNodeScope,
+}
+
+impl<'a> RdfConverter<'a> {
+    /// Creates the node for an IRI or blank node identifier.
+    ///
+    /// Returns `None` for relative or malformed IRIs, which cannot be represented in RDF.
+    fn create_node(&mut self, id: &str) -> Option<Node> {
+        if let Some(label) = id.strip_prefix("_:") {
+            Some(self.blank_nodes.labeled(label))
+        } else {
+            RdfConverter::create_uri(id).map(|uri| Node::UriNode { uri })
+        }
+    }
+
+    /// Creates a URI if the IRI is well-formed and absolute.
+    fn create_uri(iri: &str) -> Option<Uri> {
+        Uri::parse(iri).ok().filter(|uri| uri.is_absolute())
+    }
+
+    /// Adds the triples of an expanded node object and returns its subject.
+    fn node_to_rdf(&mut self, node: &JsonValue) -> Result<Option<Node>> {
+        let object = match node.as_object() {
+            Some(object) => object,
+            None => return Ok(None),
+        };
+
+        let subject = match object.get("@id").and_then(|id| id.as_str()) {
+            Some(id) => self.create_node(id),
+            None => Some(self.blank_nodes.generate()),
+        };
+
+        // triples of named graphs are merged into the graph
+        if let Some(graph) = object.get("@graph") {
+            for node in graph.to_vec() {
+                self.node_to_rdf(&node)?;
+            }
+        }
+
+        let subject = match subject {
+            Some(subject) => subject,
+            None => return Ok(None),
+        };
+
+        if let Some(types) = object.get("@type") {
+            let predicate = Node::UriNode {
+                uri: RdfSyntaxDataTypes::A.to_uri(),
+            };
+
+            for t in types.to_vec() {
+                if let Some(object) = t.as_str().and_then(|t| self.create_node(t)) {
+                    self.graph
+                        .add_triple(&Triple::new(&subject, &predicate, &object));
+                }
+            }
+        }
+
+        for (property, values) in object.iter().filter(|(p, _)| !p.starts_with('@')) {
+            let predicate = match self.create_node(property) {
+                Some(predicate @ Node::UriNode { .. }) => predicate,
+                _ => continue, // blank node predicates are not valid RDF
+            };
+
+            for value in values.to_vec() {
+                if let Some(object) = self.object_to_rdf(&value)? {
+                    self.graph
+                        .add_triple(&Triple::new(&subject, &predicate, &object));
+                }
+            }
+        }
+
+        if let Some(JsonValue::Object(reverse)) = object.get("@reverse") {
+            for (property, values) in reverse {
+                let predicate = match self.create_node(property) {
+                    Some(predicate @ Node::UriNode { .. }) => predicate,
+                    _ => continue,
+                };
+
+                for value in values.to_vec() {
+                    if let Some(reverse_subject) = self.node_to_rdf(&value)? {
+                        self.graph
+                            .add_triple(&Triple::new(&reverse_subject, &predicate, &subject));
+                    }
+                }
+            }
+        }
+
+        Ok(Some(subject))
+    }
+
+    /// Returns the node for a value, list or node object.
+    fn object_to_rdf(&mut self, value: &JsonValue) -> Result<Option<Node>> {
+        if is_value_object(value) {
+            self.value_to_rdf(value)
+        } else if is_list_object(value) {
+            let items = value.get("@list").map(|l| l.to_vec()).unwrap_or_default();
+            self.list_to_rdf(&items).map(Some)
+        } else {
+            self.node_to_rdf(value)
+        }
+    }
+
+    /// Converts a value object to a literal node.
+    fn value_to_rdf(&mut self, value: &JsonValue) -> Result<Option<Node>> {
+        let data_type = value.get("@type").and_then(|t| t.as_str());
+        let language = value
+            .get("@language")
+            .and_then(|l| l.as_str())
+            .map(|l| l.to_string());
+
+        let (literal, default_data_type) = match (value.get("@value"), data_type) {
+            (Some(json), Some("@json")) => (
+                canonical_json(json),
+                Some("http://www.w3.org/1999/02/22-rdf-syntax-ns#JSON".to_string()),
+            ),
+            (Some(JsonValue::Boolean(b)), _) => {
+                (b.to_string(), Some(XmlDataTypes::Boolean.to_string()))
+            }
+            (Some(JsonValue::Number(n)), dt) => {
+                let is_double = n.contains(['.', 'e', 'E'])
+                    || n.trim_start_matches('-').len() > 21
+                    || dt == Some(XmlDataTypes::Double.to_string().as_str());
+
+                if is_double {
+                    (canonical_double(n), Some(XmlDataTypes::Double.to_string()))
+                } else {
+                    (n.clone(), Some(XmlDataTypes::Integer.to_string()))
+                }
+            }
+            (Some(JsonValue::String(s)), _) => (s.clone(), None),
+            _ => return Ok(None),
+        };
+
+        let data_type = match data_type {
+            Some("@json") => default_data_type.map(Uri::new_unchecked),
+            Some(dt) => match RdfConverter::create_uri(dt) {
+                Some(uri) => Some(uri),
+                None => return Ok(None),
+            },
+            None if language.is_some() => None,
+            None => default_data_type.map(Uri::new_unchecked),
+        };
+
+        Ok(Some(Node::LiteralNode {
+            literal,
+            data_type,
+            language,
+        }))
+    }
+
+    /// Adds the triples of an RDF collection and returns its head.
+    fn list_to_rdf(&mut self, items: &[JsonValue]) -> Result<Node> {
+        let nil = Node::UriNode {
+            uri: RdfSyntaxDataTypes::ListNil.to_uri(),
+        };
+
+        if items.is_empty() {
+            return Ok(nil);
+        }
+
+        let first = Node::UriNode {
+            uri: RdfSyntaxDataTypes::ListFirst.to_uri(),
+        };
+        let rest = Node::UriNode {
+            uri: RdfSyntaxDataTypes::ListRest.to_uri(),
+        };
+
+        let head = self.blank_nodes.generate();
+        let mut current = head.clone();
+
+        for (i, item) in items.iter().enumerate() {
+            if let Some(object) = self.object_to_rdf(item)? {
+                self.graph
+                    .add_triple(&Triple::new(&current, &first, &object));
+            }
+
+            let next = if i + 1 < items.len() {
+                self.blank_nodes.generate()
+            } else {
+                nil.clone()
+            };
+
+            self.graph.add_triple(&Triple::new(&current, &rest, &next));
+            current = next;
+        }
+
+        Ok(head)
+    }
+}
+
+/// Returns the canonical lexical form of a JSON number as `xsd:double` (e.g. `1.5E1`).
+fn canonical_double(number: &str) -> String {
+    XsdValue::Double(number.parse::<f64>().unwrap_or(0.0)).to_string()
+}
+
+/// Returns the canonical JSON serialization used for `rdf:JSON` literals.
+fn canonical_json(value: &JsonValue) -> String {
+    match *value {
+        JsonValue::Object(ref members) => {
+            let sorted: JsonObject = members
+                .iter()
+                .map(|(k, v)| (k.clone(), JsonValue::String(canonical_json(v))))
+                .collect();
+
+            let members: Vec<String> = sorted
+                .iter()
+                .map(|(k, v)| {
+                    JsonValue::String(k.clone()).to_string() + ":" + v.as_str().unwrap_or("")
+                })
+                .collect();
+
+            "{".to_string() + &members.join(",") + "}"
+        }
+        JsonValue::Array(ref elements) => {
+            let elements: Vec<String> = elements.iter().map(canonical_json).collect();
+            "[".to_string() + &elements.join(",") + "]"
+        }
+        ref v => v.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::json::JsonValue;
+    use crate::json_ld::loader::FileContextLoader;
+    use crate::node::Node;
+    use crate::reader::json_ld_parser::JsonLdParser;
+    use crate::reader::rdf_parser::RdfParser;
+    use crate::specs::rdf_syntax_specs::RdfSyntaxDataTypes;
+    use crate::specs::xml_specs::XmlDataTypes;
+    use crate::uri::Uri;
+    use std::env;
+    use std::fs;
+
+    fn uri_node(uri: &str) -> Node {
+        Node::UriNode {
+            uri: Uri::parse(uri).unwrap(),
+        }
+    }
+
+    #[test]
+    fn read_json_ld_with_types_and_literals() {
+        let input = "{
+          \"@context\": {
+            \"@vocab\": \"http://schema.org/\",
+            \"xsd\": \"http://www.w3.org/2001/XMLSchema#\",
+            \"born\": {\"@id\": \"birthDate\", \"@type\": \"xsd:date\"}
+          },
+          \"@id\": \"http://example.org/alice\",
+          \"@type\": \"Person\",
+          \"name\": {\"@value\": \"Alice\", \"@language\": \"en\"},
+          \"born\": \"1990-01-01\",
+          \"age\": 30,
+          \"height\": 1.7,
+          \"member\": true
+        }";
+
+        let graph = JsonLdParser::from_string(input).decode().unwrap();
+        let subject = uri_node("http://example.org/alice");
+
+        assert_eq!(graph.count(), 6);
+        assert_eq!(
+            graph.get_triples_with_subject_and_predicate(
+                &subject,
+                &uri_node("http://schema.org/height")
+            )[0]
+            .object(),
+            &Node::LiteralNode {
+                literal: "1.7E0".to_string(),
+                data_type: Some(XmlDataTypes::Double.to_uri()),
+                language: None,
+            }
+        );
+        assert_eq!(
+            graph.get_triples_with_subject_and_predicate(
+                &subject,
+                &uri_node("http://schema.org/birthDate")
+            )[0]
+            .object(),
+            &Node::LiteralNode {
+                literal: "1990-01-01".to_string(),
+                data_type: Some(XmlDataTypes::Date.to_uri()),
+                language: None,
+            }
+        );
+        assert_eq!(
+            graph
+                .get_triples_with_predicate_and_object(
+                    &Node::UriNode {
+                        uri: RdfSyntaxDataTypes::A.to_uri()
+                    },
+                    &uri_node("http://schema.org/Person")
+                )
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn read_json_ld_lists_graphs_and_reverse_properties() {
+        let input = "{
+          \"@context\": {
+            \"ex\": \"http://example.org/\",
+            \"items\": {\"@id\": \"ex:items\", \"@container\": \"@list\"},
+            \"parentOf\": {\"@reverse\": \"ex:child\"}
+          },
+          \"@graph\": [
+            {\"@id\": \"ex:a\", \"items\": [\"x\", \"y\"]},
+            {\"@id\": \"ex:b\", \"parentOf\": {\"@id\": \"ex:c\"}}
+          ]
+        }";
+
+        let graph = JsonLdParser::from_string(input).decode().unwrap();
+
+        // 1 items triple, 4 list triples and 1 reversed triple
+        assert_eq!(graph.count(), 6);
+        assert_eq!(
+            graph
+                .get_triples_with_subject_and_object(
+                    &uri_node("http://example.org/c"),
+                    &uri_node("http://example.org/b")
+                )
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn labeled_blank_nodes_do_not_clash_with_generated_blank_nodes() {
+        let input = "[
+          {\"http://example.org/name\": \"generated\"},
+          {\"@id\": \"_:auto0\", \"http://example.org/name\": \"labeled\"}
+        ]";
+
+        let graph = JsonLdParser::from_string(input.to_string())
+            .decode()
+            .unwrap();
+        let subjects: Vec<&Node> = graph.triples_iter().map(|t| t.subject()).collect();
+
+        assert_eq!(subjects.len(), 2);
+        assert_ne!(subjects[0], subjects[1]);
+    }
+
+    #[test]
+    fn read_json_ld_with_embedded_contexts() {
+        let input = "{
+          \"@context\": {\"@vocab\": \"http://example.org/\"},
+          \"@id\": \"http://example.org/a\",
+          \"knows\": {
+            \"@context\": {\"@vocab\": \"http://xmlns.com/foaf/0.1/\"},
+            \"name\": \"Bob\"
+          }
+        }";
+
+        let graph = JsonLdParser::from_string(input).decode().unwrap();
+
+        assert_eq!(graph.count(), 2);
+        assert_eq!(
+            graph
+                .get_triples_with_predicate(&uri_node("http://xmlns.com/foaf/0.1/name"))
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn read_json_ld_with_remote_context_from_file() {
+        let directory = env::temp_dir().join("rdf_json_ld_parser_contexts");
+        fs::create_dir_all(&directory).unwrap();
+        fs::write(
+            directory.join("person.jsonld"),
+            "{\"@context\": {\"name\": \"http://xmlns.com/foaf/0.1/name\"}}",
+        )
+        .unwrap();
+
+        let mut loader = FileContextLoader::new();
+        loader.add_directory("https://example.org/contexts/", &directory);
+
+        let input =
+            "{\"@context\": \"https://example.org/contexts/person.jsonld\", \"name\": \"Alice\"}";
+        let graph = JsonLdParser::from_string(input)
+            .with_context_loader(loader)
+            .decode()
+            .unwrap();
+
+        assert_eq!(graph.count(), 1);
+    }
+
+    #[test]
+    fn reject_remote_context_without_loader() {
+        let input = "{\"@context\": \"https://example.org/context.jsonld\", \"name\": \"Alice\"}";
+
+        assert!(JsonLdParser::from_string(input).decode().is_err());
+    }
+
+    #[test]
+    fn reject_invalid_json_ld() {
+        let input = "{\"@id\": \"http://example.org/a\", \"http://example.org/p\": {\"@value\": \"x\", \"@language\": \"en\", \"@type\": \"http://example.org/t\"}}";
+
+        assert!(JsonLdParser::from_string(input).decode().is_err());
+        assert!(JsonValue::parse("{").is_err());
+    }
+}
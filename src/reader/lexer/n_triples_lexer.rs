@@ -1,7 +1,8 @@
 use crate::error::{Error, ErrorType};
-use crate::reader::input_reader::{InputReader, InputReaderHelper};
+use crate::reader::input_reader::{InputReader, InputReaderHelper, Span};
 use crate::reader::lexer::rdf_lexer::{RdfLexer, TokensFromRdf};
 use crate::reader::lexer::token::Token;
+use crate::specs::n_triples_specs::NTriplesSpecs;
 use std::io::Read;
 use crate::Result;
 
@@ -9,6 +10,16 @@ use crate::Result;
 pub struct NTriplesLexer<R: Read> {
     input_reader: InputReader<R>,
     peeked_token: Option<Token>,
+
+    /// Spans of the last returned token and of the peeked token.
+    token_span: Span,
+    peeked_span: Span,
+
+    /// `true` if the token that was read last from the input is a triple delimiter.
+    at_statement_end: bool,
+
+    /// Line of the current statement, statements must not span several lines.
+    statement_line: Option<usize>,
 }
 
 /// Contains all implemented rules for creating tokens from NTriples syntax.
@@ -34,23 +45,43 @@ pub trait TokensFromNTriples<R: Read>: TokensFromRdf<R> {
 
     /// Parses the language specification from the input and returns it as token.
     fn get_language_specification(input_reader: &mut InputReader<R>) -> Result<String> {
-        match input_reader.get_until(InputReaderHelper::node_delimiter) {
-            Ok(chars) => Ok(chars.to_string()),
-            Err(err) => match err.error_type() {
-                &ErrorType::EndOfInput(ref chars) => Ok(chars.to_string()),
-                _ => Err(Error::new(
-                    ErrorType::InvalidReaderInput,
-                    "Invalid input for while parsing language specification.",
-                )),
-            },
+        let mut language = String::new();
+        let mut subtag_length = 0;
+
+        // the first subtag only consists of letters, the following subtags may contain digits
+        loop {
+            let c = match input_reader.peek_next_char()? {
+                Some(c) if c.is_ascii_alphabetic() => c,
+                Some(c) if c.is_ascii_digit() && language.contains('-') => c,
+                Some('-') if subtag_length > 0 => {
+                    subtag_length = 0;
+                    '-'
+                }
+                _ => break,
+            };
+
+            if c != '-' {
+                subtag_length += 1;
+            }
+
+            Self::consume_next_char(input_reader);
+            language.push(c);
+        }
+
+        if subtag_length == 0 {
+            return Err(Error::new(
+                ErrorType::InvalidReaderInput,
+                "Invalid language specification: ".to_string() + &language,
+            ));
         }
+
+        Ok(language)
     }
 
     /// Parses a literal from the input and returns it as token.
     fn get_literal(input_reader: &mut InputReader<R>) -> Result<Token> {
         Self::consume_next_char(input_reader); // consume '"'
-        let literal = input_reader.get_until(|c| c == '"')?.to_string();
-        Self::consume_next_char(input_reader); // consume '"'
+        let literal = Self::get_quoted_string(input_reader, '"')?;
 
         match input_reader.peek_next_char()? {
             Some('@') => {
@@ -60,7 +91,7 @@ pub trait TokensFromNTriples<R: Read>: TokensFromRdf<R> {
             }
             Some('^') => {
                 Self::consume_next_char(input_reader); // consume '^'
-                Self::consume_next_char(input_reader); // consume '^'
+                Self::consume_data_type_delimiter(input_reader)?; // consume second '^'
 
                 match input_reader.peek_next_char()? {
                     Some('<') => {
@@ -82,9 +113,141 @@ pub trait TokensFromNTriples<R: Read>: TokensFromRdf<R> {
                     None => Err(Error::new(ErrorType::InvalidReaderInput, "Invalid input.")),
                 }
             }
-            _ => {
-                Self::consume_next_char(input_reader); // consume '"'
-                Ok(Token::Literal(literal))
+            _ => Ok(Token::Literal(literal)),
+        }
+    }
+
+    /// Consumes the second '^' of a data type delimiter.
+    fn consume_data_type_delimiter(input_reader: &mut InputReader<R>) -> Result<()> {
+        match input_reader.get_next_char()? {
+            Some('^') => Ok(()),
+            _ => Err(Error::new(
+                ErrorType::InvalidReaderInput,
+                "Invalid data type delimiter, expected '^^'.",
+            )),
+        }
+    }
+
+    /// Returns the number of following '.' characters that are part of a name.
+    ///
+    /// Names must not end with '.', so the characters are only part of the name if they are
+    /// followed by a character that continues the name.
+    fn peek_inner_dots<F: Fn(char) -> bool>(
+        input_reader: &mut InputReader<R>,
+        continues_name: F,
+    ) -> Result<usize> {
+        let mut k = 1;
+
+        loop {
+            match input_reader.peek_next_k_chars(k)?[k - 1] {
+                Some('.') => k += 1,
+                Some(c) if k > 1 && continues_name(c) => return Ok(k - 1),
+                _ => return Ok(0),
+            }
+        }
+    }
+
+    /// Parses a string up to the closing quote and decodes its escape sequences.
+    ///
+    /// The opening quote has to be consumed already, the closing quote is consumed.
+    fn get_quoted_string(input_reader: &mut InputReader<R>, quote: char) -> Result<String> {
+        let mut string = String::new();
+
+        loop {
+            match input_reader.get_next_char()? {
+                Some('\\') => string.push(Self::get_escaped_char(input_reader, true)?),
+                Some(c) if c == quote => return Ok(string),
+                Some('\n') | Some('\r') | None => {
+                    return Err(Error::new(
+                        ErrorType::InvalidReaderInput,
+                        "Unterminated string literal.",
+                    ))
+                }
+                Some(c) => string.push(c),
+            }
+        }
+    }
+
+    /// Parses an escape sequence whose '\' has already been consumed and returns the
+    /// escaped character.
+    ///
+    /// Unicode escapes (`\uXXXX` and `\UXXXXXXXX`) are always allowed, string escapes
+    /// (e.g. `\n`) only if `allow_string_escapes` is `true`.
+    fn get_escaped_char(
+        input_reader: &mut InputReader<R>,
+        allow_string_escapes: bool,
+    ) -> Result<char> {
+        let escaped_char = match input_reader.get_next_char()? {
+            Some('u') => return Self::get_unicode_char(input_reader, 4),
+            Some('U') => return Self::get_unicode_char(input_reader, 8),
+            Some(c) if allow_string_escapes => NTriplesSpecs::unescape_character(c),
+            _ => None,
+        };
+
+        escaped_char
+            .ok_or_else(|| Error::new(ErrorType::InvalidReaderInput, "Invalid escape sequence."))
+    }
+
+    /// Parses the hexadecimal digits of a unicode escape sequence and returns the character.
+    fn get_unicode_char(input_reader: &mut InputReader<R>, digits: usize) -> Result<char> {
+        let mut code_point = 0;
+
+        for _ in 0..digits {
+            match input_reader.get_next_char()?.and_then(|c| c.to_digit(16)) {
+                Some(digit) => code_point = code_point * 16 + digit,
+                None => {
+                    return Err(Error::new(
+                        ErrorType::InvalidReaderInput,
+                        "Invalid hexadecimal digit in unicode escape sequence.",
+                    ))
+                }
+            }
+        }
+
+        std::char::from_u32(code_point).ok_or_else(|| {
+            Error::new(
+                ErrorType::InvalidReaderInput,
+                "Invalid code point in unicode escape sequence.",
+            )
+        })
+    }
+
+    /// Consumes the input until a triple delimiter that is not part of a literal or URI.
+    ///
+    /// The delimiter has to be followed by a whitespace, a comment or the end of the input.
+    fn skip_to_statement_end(input_reader: &mut InputReader<R>) -> Result<()> {
+        let mut quote = None;
+
+        loop {
+            match (input_reader.get_next_char()?, quote) {
+                (None, _) => return Ok(()),
+                (Some('\\'), Some(_)) => {
+                    let _ = input_reader.get_next_char()?; // skip escaped character
+                }
+                (Some(c), Some(q)) if c == q => quote = None,
+                (Some(_), Some(_)) => {}
+                (Some('"'), None) => quote = Some('"'),
+                (Some('\''), None) => quote = Some('\''),
+                (Some('<'), None) => quote = Some('>'),
+                (Some('#'), None) => {
+                    let _ = input_reader.get_until(InputReaderHelper::line_break);
+                }
+                (Some('.'), None) => match input_reader.peek_next_char()? {
+                    None | Some('#') => return Ok(()),
+                    Some(c) if InputReaderHelper::whitespace(c) => return Ok(()),
+                    Some(_) => {}
+                },
+                (Some(_), None) => {}
+            }
+        }
+    }
+
+    /// Consumes the input until the end of the line.
+    fn skip_to_line_end(input_reader: &mut InputReader<R>) -> Result<()> {
+        loop {
+            match input_reader.get_next_char()? {
+                None | Some('\n') | Some('\r') => return Ok(()),
+                Some(_) => {}
             }
         }
     }
@@ -92,9 +255,22 @@ pub trait TokensFromNTriples<R: Read>: TokensFromRdf<R> {
     /// Parses a URI from the input and returns it as token.
     fn get_uri(input_reader: &mut InputReader<R>) -> Result<Token> {
         Self::consume_next_char(input_reader); // consume '<'
-        let chars = input_reader.get_until(|c| c == '>')?;
-        Self::consume_next_char(input_reader); // consume '>'
-        Ok(Token::Uri(chars.to_string()))
+        let mut uri = String::new();
+
+        loop {
+            match input_reader.get_next_char()? {
+                Some('\\') => uri.push(Self::get_escaped_char(input_reader, false)?),
+                Some('>') => return Ok(Token::Uri(uri)),
+                Some(c) if NTriplesSpecs::is_allowed_in_uri(c) => uri.push(c),
+                Some(c) => {
+                    return Err(Error::new(
+                        ErrorType::InvalidReaderInput,
+                        "Invalid character in URI: ".to_string() + &c.escape_debug().to_string(),
+                    ))
+                }
+                None => return Err(Error::new(ErrorType::InvalidReaderInput, "Unterminated URI.")),
+            }
+        }
     }
 
     /// Parses a blank node ID from the input and returns it as token.
@@ -118,16 +294,41 @@ pub trait TokensFromNTriples<R: Read>: TokensFromRdf<R> {
             }
         }
 
-        match input_reader.get_until(InputReaderHelper::node_delimiter) {
-            Ok(chars) => Ok(Token::BlankNode(chars.to_string())),
-            Err(err) => match err.error_type() {
-                &ErrorType::EndOfInput(ref chars) => Ok(Token::BlankNode(chars.to_string())),
-                _ => Err(Error::new(
+        match input_reader.peek_next_char()? {
+            Some(c) if NTriplesSpecs::is_pn_chars_u(c) || InputReaderHelper::digit(c) => {}
+            _ => {
+                return Err(Error::new(
                     ErrorType::InvalidReaderInput,
-                    "Invalid input for lexer while parsing blank node.",
-                )),
-            },
+                    "Invalid first character of blank node label.",
+                ))
+            }
         }
+
+        let mut id = String::new();
+
+        loop {
+            match input_reader.peek_next_char()? {
+                Some(c) if NTriplesSpecs::is_pn_chars(c) => {
+                    Self::consume_next_char(input_reader);
+                    id.push(c);
+                }
+                Some('.') => {
+                    let dots = Self::peek_inner_dots(input_reader, NTriplesSpecs::is_pn_chars)?;
+
+                    if dots == 0 {
+                        break;
+                    }
+
+                    for _ in 0..dots {
+                        Self::consume_next_char(input_reader);
+                        id.push('.');
+                    }
+                }
+                _ => break,
+            }
+        }
+
+        Ok(Token::BlankNode(id))
     }
 }
 
@@ -151,6 +352,10 @@ impl<R: Read> RdfLexer<R> for NTriplesLexer<R> {
         NTriplesLexer {
             input_reader: InputReader::new(input),
             peeked_token: None,
+            token_span: Span::default(),
+            peeked_span: Span::default(),
+            at_statement_end: false,
+            statement_line: None,
         }
     }
 
@@ -178,25 +383,46 @@ impl<R: Read> RdfLexer<R> for NTriplesLexer<R> {
     /// - Input that does not conform to the NTriples standard.
     ///
     fn get_next_token(&mut self) -> Result<Token> {
-        if let Some(token) = self.peeked_token.clone() {
-            self.peeked_token = None;
+        if let Some(token) = self.peeked_token.take() {
+            self.token_span = self.peeked_span;
             return Ok(token);
         }
 
-        match self.input_reader.peek_next_char_discard_leading_spaces()? {
-            Some('#') => self.get_comment(),
-            Some('"') => self.get_literal(),
-            Some('<') => self.get_uri(),
-            Some('_') => self.get_blank_node(),
-            Some('.') => {
-                self.consume_next_char(); // consume '.'
-                Ok(Token::TripleDelimiter)
+        // the token starts at the first character that is not a whitespace
+        self.input_reader.peek_next_char_discard_leading_spaces()?;
+        let start = *self.input_reader.position();
+
+        self.at_statement_end = false;
+
+        match self.statement_line {
+            Some(line) if start.line() > line => {
+                self.token_span = Span::new(start, start);
+                return Err(Error::new(
+                    ErrorType::InvalidReaderInput,
+                    "Statement does not end at the end of the line.",
+                )
+                .with_location(self.token_span, self.input_reader.snippet()));
+            }
+            Some(_) => {}
+            None => self.statement_line = Some(start.line()),
+        }
+
+        match self.read_token() {
+            Ok(token) => {
+                self.token_span = Span::new(start, *self.input_reader.position());
+                self.at_statement_end = token == Token::TripleDelimiter;
+
+                // comments and triple delimiters end the statement of the line
+                if let Token::TripleDelimiter | Token::Comment(_) | Token::EndOfInput = token {
+                    self.statement_line = None;
+                }
+
+                Ok(token)
+            }
+            Err(err) => {
+                self.token_span = Span::new(start, *self.input_reader.position());
+                Err(err.with_location(self.token_span, self.input_reader.snippet()))
             }
-            None => Ok(Token::EndOfInput),
-            Some(c) => Err(Error::new(
-                ErrorType::InvalidReaderInput,
-                "Invalid NTriples input: ".to_string() + &c.to_string(),
-            )),
         }
     }
 
@@ -225,150 +451,89 @@ impl<R: Read> RdfLexer<R> for NTriplesLexer<R> {
     /// - Invalid input that does not conform with NTriples standard.
     ///
     fn peek_next_token(&mut self) -> Result<Token> {
-        match self.peeked_token.clone() {
-            Some(token) => Ok(token),
-            None => {
-                let next = self.get_next_token()?;
-                self.peeked_token = Some(next.clone());
-                Ok(next)
-            }
+        if let Some(ref token) = self.peeked_token {
+            return Ok(token.clone());
         }
-    }
-}
 
-impl<R: Read> NTriplesLexer<R> {
-    /// Consumes the next character of the input reader.
-    fn consume_next_char(&mut self) {
-        let _ = self.input_reader.get_next_char();
-    }
+        // keep the span of the last returned token
+        let token_span = self.token_span;
+        let next = self.get_next_token()?;
 
-    /// Parses the comment from the input and returns it as token.
-    fn get_comment(&mut self) -> Result<Token> {
-        self.consume_next_char(); // consume '#'
+        self.peeked_span = self.token_span;
+        self.token_span = token_span;
+        self.peeked_token = Some(next.clone());
+        Ok(next)
+    }
 
-        match self
-            .input_reader
-            .get_until_discard_leading_spaces(|c| c == '\n' || c == '\r')
-        {
-            Ok(chars) => {
-                self.consume_next_char(); // consume comment delimiter
-                Ok(Token::Comment(chars.to_string()))
-            }
-            Err(err) => match *err.error_type() {
-                ErrorType::EndOfInput(ref chars) => Ok(Token::Comment(chars.to_string())),
-                _ => Err(Error::new(
-                    ErrorType::InvalidReaderInput,
-                    "Invalid input for Turtle lexer while parsing comment.",
-                )),
-            },
-        }
+    /// Returns the span of the last token that was returned by `get_next_token`.
+    fn last_span(&self) -> &Span {
+        &self.token_span
     }
 
-    /// Parses the language specification from the input and returns it as token.
-    fn get_language_specification(&mut self) -> Result<String> {
-        match self
-            .input_reader
-            .get_until(|c| c == '\n' || c == '\r' || c == ' ' || c == '.')
-        {
-            Ok(chars) => Ok(chars.to_string()),
-            Err(err) => match *err.error_type() {
-                ErrorType::EndOfInput(ref chars) => Ok(chars.to_string()),
-                _ => Err(Error::new(
-                    ErrorType::InvalidReaderInput,
-                    "Invalid input for NTriples lexer while parsing language specification.",
-                )),
-            },
-        }
+    /// Returns the text of the current input line that has been read so far.
+    fn snippet(&self) -> String {
+        self.input_reader.snippet()
     }
+}
 
-    /// Parses a literal from the input and returns it as token.
-    fn get_literal(&mut self) -> Result<Token> {
-        self.consume_next_char(); // consume '"'
-        let literal = self.input_reader.get_until(|c| c == '"')?.to_string();
-        self.consume_next_char(); // consume '"'
+impl<R: Read> NTriplesLexer<R> {
+    /// Skips the input until the end of the line of the current statement.
+    ///
+    /// Nothing is skipped if the token that was read last is the triple delimiter or if the
+    /// input was already read up to a following line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::lexer::rdf_lexer::RdfLexer;
+    /// use rdf::reader::lexer::n_triples_lexer::NTriplesLexer;
+    /// use rdf::reader::lexer::token::Token;
+    ///
+    /// let input = "_:a \"b . c\" ! . _:c\n_:d".as_bytes();
+    ///
+    /// let mut lexer = NTriplesLexer::new(input);
+    /// let _ = lexer.get_next_token();
+    /// lexer.skip_statement().unwrap();
+    ///
+    /// assert_eq!(lexer.get_next_token().unwrap(), Token::BlankNode("d".to_string()));
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - Invalid byte encoding of the input.
+    ///
+    pub fn skip_statement(&mut self) -> Result<()> {
+        self.peeked_token = None;
 
-        match self.input_reader.peek_next_char()? {
-            Some('@') => {
-                self.consume_next_char(); // consume '@'
-                let language = self.get_language_specification()?;
-                Ok(Token::LiteralWithLanguageSpecification(literal, language))
-            }
-            Some('^') => {
-                self.consume_next_char(); // consume '^'
-                self.consume_next_char(); // consume '^'
+        let statement_line = self.statement_line.take();
 
-                match self.input_reader.peek_next_char()? {
-                    Some('<') => {
-                        // data type is an URI (NTriples allows only URI data types)
-                        match self.get_uri()? {
-                            Token::Uri(datatype_uri) => {
-                                Ok(Token::LiteralWithUrlDatatype(literal, datatype_uri))
-                            }
-                            _ => Err(Error::new(
-                                ErrorType::InvalidReaderInput,
-                                "Invalid data type URI for NTriples literal.",
-                            )),
-                        }
-                    }
-                    Some(c) => Err(Error::new(
-                        ErrorType::InvalidReaderInput,
-                        "Invalid data type token for NTriples: ".to_string() + &c.to_string(),
-                    )),
-                    None => Err(Error::new(
-                        ErrorType::InvalidReaderInput,
-                        "Invalid NTriples input.",
-                    )),
-                }
-            }
-            _ => {
-                self.consume_next_char(); // consume '"'
-                Ok(Token::Literal(literal))
-            }
+        if self.at_statement_end {
+            self.at_statement_end = false;
+            return Ok(());
         }
-    }
 
-    /// Parses a URI from the input and returns it as token.
-    fn get_uri(&mut self) -> Result<Token> {
-        self.consume_next_char(); // consume '<'
-        let chars = self.input_reader.get_until(|c| c == '>')?;
-        self.consume_next_char(); // consume '>'
-        Ok(Token::Uri(chars.to_string()))
+        match statement_line {
+            Some(line) if self.input_reader.position().line() > line => Ok(()),
+            _ => NTriplesLexer::skip_to_line_end(&mut self.input_reader),
+        }
     }
 
-    /// Parses a blank node ID from the input and returns it as token.
-    fn get_blank_node(&mut self) -> Result<Token> {
-        self.consume_next_char(); // consume '_'
-
-        // get colon after under score
-        match self.input_reader.get_next_char()? {
-            Some(':') => {}
-            Some(c) => {
-                return Err(Error::new(
-                    ErrorType::InvalidReaderInput,
-                    "Invalid character while parsing NTriples blank node: ".to_string()
-                        + &c.to_string(),
-                ))
-            }
-            None => {
-                return Err(Error::new(
-                    ErrorType::InvalidReaderInput,
-                    "Error while parsing NTriples blank node.",
-                ))
+    /// Determines the next token from the input after leading whitespaces.
+    fn read_token(&mut self) -> Result<Token> {
+        match self.input_reader.peek_next_char_discard_leading_spaces()? {
+            Some('#') => NTriplesLexer::get_comment(&mut self.input_reader),
+            Some('"') => NTriplesLexer::get_literal(&mut self.input_reader),
+            Some('<') => NTriplesLexer::get_uri(&mut self.input_reader),
+            Some('_') => NTriplesLexer::get_blank_node(&mut self.input_reader),
+            Some('.') => {
+                NTriplesLexer::consume_next_char(&mut self.input_reader); // consume '.'
+                Ok(Token::TripleDelimiter)
             }
-        }
-
-        match self
-            .input_reader
-            .get_until(|c| c == '\n' || c == '\r' || c == ' ' || c == '.')
-        {
-            Ok(chars) => Ok(Token::BlankNode(chars.to_string())),
-            Err(err) => match *err.error_type() {
-                ErrorType::EndOfInput(ref chars) => Ok(Token::BlankNode(chars.to_string())),
-                _ => Err(Error::new(
-                    ErrorType::InvalidReaderInput,
-                    "Invalid input for NTriples lexer while parsing blank node.",
-                )),
-            },
+            None => Ok(Token::EndOfInput),
+            Some(c) => Err(Error::new(
+                ErrorType::InvalidReaderInput,
+                "Invalid NTriples input: ".to_string() + &c.to_string(),
+            )),
         }
     }
 }
@@ -468,4 +633,73 @@ mod tests {
         );
         assert_eq!(lexer.get_next_token().unwrap(), Token::TripleDelimiter);
     }
+
+    #[test]
+    fn spans_of_n_triples_tokens() {
+        let input = "_:a <example.org/b> .\n  \"c\"@en .".as_bytes();
+
+        let mut lexer = NTriplesLexer::new(input);
+
+        let spans: Vec<(usize, usize, usize, usize)> = (0..5)
+            .map(|_| {
+                let span = *lexer.get_next_spanned_token().unwrap().span();
+                (
+                    span.start().line(),
+                    span.start().column(),
+                    span.end().line(),
+                    span.end().column(),
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            spans,
+            vec![
+                (1, 1, 1, 4),
+                (1, 5, 1, 20),
+                (1, 21, 1, 22),
+                (2, 3, 2, 9),
+                (2, 10, 2, 11)
+            ]
+        );
+    }
+
+    #[test]
+    fn peeking_keeps_span_of_last_token() {
+        let input = "_:a <example.org/b> .".as_bytes();
+
+        let mut lexer = NTriplesLexer::new(input);
+        let _ = lexer.get_next_token();
+        let _ = lexer.peek_next_token();
+
+        assert_eq!(lexer.last_span().end().column(), 4);
+        let _ = lexer.get_next_token();
+        assert_eq!(lexer.last_span().start().column(), 5);
+    }
+
+    #[test]
+    fn test_n_triples_parse_escaped_literal() {
+        let input = r#""a\tb\n\"c\" \\ é\U0001F600" "\x" ."#.as_bytes();
+
+        let mut lexer = NTriplesLexer::new(input);
+
+        assert_eq!(
+            lexer.get_next_token().unwrap(),
+            Token::Literal("a\tb\n\"c\" \\ é\u{1F600}".to_string())
+        );
+        assert!(lexer.get_next_token().is_err());
+    }
+
+    #[test]
+    fn test_n_triples_parse_escaped_uri() {
+        let input = r#"<http://example.org/a\u0020b> <http://example.org/\n> ."#.as_bytes();
+
+        let mut lexer = NTriplesLexer::new(input);
+
+        assert_eq!(
+            lexer.get_next_token().unwrap(),
+            Token::Uri("http://example.org/a b".to_string())
+        );
+        assert!(lexer.get_next_token().is_err());
+    }
 }
@@ -1,5 +1,6 @@
-use crate::reader::input_reader::InputReader;
-use crate::reader::lexer::token::Token;
+use crate::error::Error;
+use crate::reader::input_reader::{InputReader, Span};
+use crate::reader::lexer::token::{SpannedToken, Token};
 use std::io::Read;
 use crate::Result;
 
@@ -13,6 +14,23 @@ pub trait RdfLexer<R: Read> {
 
     // Determines the next token without consuming it.
     fn peek_next_token(&mut self) -> Result<Token>;
+
+    /// Returns the span of the input of the last token returned by `get_next_token`.
+    fn last_span(&self) -> &Span;
+
+    /// Returns the text of the input line that has been read last.
+    fn snippet(&self) -> String;
+
+    /// Determines the next token from the input together with its span.
+    fn get_next_spanned_token(&mut self) -> Result<SpannedToken> {
+        let token = self.get_next_token()?;
+        Ok(SpannedToken::new(token, *self.last_span()))
+    }
+
+    /// Adds the location of the last token to an error that has no location yet.
+    fn locate_error(&self, err: Error) -> Error {
+        err.with_location(*self.last_span(), self.snippet())
+    }
 }
 
 /// Contains implemented rules for parsing RDF input.
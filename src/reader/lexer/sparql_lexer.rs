@@ -1,5 +1,5 @@
 use crate::error::{Error, ErrorType};
-use crate::reader::input_reader::{InputReader, InputReaderHelper};
+use crate::reader::input_reader::{InputReader, InputReaderHelper, Span};
 use crate::reader::lexer::n_triples_lexer::TokensFromNTriples;
 use crate::reader::lexer::rdf_lexer::{RdfLexer, TokensFromRdf};
 use crate::reader::lexer::token::Token;
@@ -12,6 +12,10 @@ use crate::Result;
 pub struct SparqlLexer<R: Read> {
     input_reader: InputReader<R>,
     peeked_token: Option<Token>,
+
+    /// Spans of the last returned token and of the peeked token.
+    token_span: Span,
+    peeked_span: Span,
 }
 
 impl<R: Read> RdfLexer<R> for SparqlLexer<R> {
@@ -31,6 +35,8 @@ impl<R: Read> RdfLexer<R> for SparqlLexer<R> {
         SparqlLexer {
             input_reader: InputReader::new(input),
             peeked_token: None,
+            token_span: Span::default(),
+            peeked_span: Span::default(),
         }
     }
 
@@ -53,15 +59,75 @@ impl<R: Read> RdfLexer<R> for SparqlLexer<R> {
     /// - Input that does not conform to the SPARQL syntax standard.
     ///
     fn get_next_token(&mut self) -> Result<Token> {
-        // first read peeked characters
-        match self.peeked_token.clone() {
-            Some(token) => {
-                self.peeked_token = None;
-                return Ok(token);
+        if let Some(token) = self.peeked_token.take() {
+            self.token_span = self.peeked_span;
+            return Ok(token);
+        }
+
+        // the token starts at the first character that is not a whitespace
+        self.input_reader.peek_next_char_discard_leading_spaces()?;
+        let start = *self.input_reader.position();
+
+        match self.read_token() {
+            Ok(token) => {
+                self.token_span = Span::new(start, *self.input_reader.position());
+                Ok(token)
+            }
+            Err(err) => {
+                self.token_span = Span::new(start, *self.input_reader.position());
+                Err(err.with_location(self.token_span, self.input_reader.snippet()))
             }
-            None => {}
         }
+    }
+
+    /// Determines the next token without consuming the input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::lexer::rdf_lexer::RdfLexer;
+    /// use rdf::reader::lexer::sparql_lexer::SparqlLexer;
+    /// use rdf::reader::lexer::token::Token;
+    ///
+    /// let input = "SELECT".as_bytes();
+    ///
+    /// let mut lexer = SparqlLexer::new(input);
+    /// ```
+    ///
+    ///  # Failures
+    ///
+    /// - End of input reached.
+    /// - Invalid input that does not conform with NTriples standard.
+    ///
+    fn peek_next_token(&mut self) -> Result<Token> {
+        if let Some(ref token) = self.peeked_token {
+            return Ok(token.clone());
+        }
+
+        // keep the span of the last returned token
+        let token_span = self.token_span;
+        let next = self.get_next_token()?;
+
+        self.peeked_span = self.token_span;
+        self.token_span = token_span;
+        self.peeked_token = Some(next.clone());
+        Ok(next)
+    }
 
+    /// Returns the span of the last token that was returned by `get_next_token`.
+    fn last_span(&self) -> &Span {
+        &self.token_span
+    }
+
+    /// Returns the text of the current input line that has been read so far.
+    fn snippet(&self) -> String {
+        self.input_reader.snippet()
+    }
+}
+
+impl<R: Read> SparqlLexer<R> {
+    /// Determines the next token from the input after leading whitespaces.
+    fn read_token(&mut self) -> Result<Token> {
         // todo
         match self.input_reader.peek_next_char_discard_leading_spaces()? {
             Some('#') => return SparqlLexer::get_comment(&mut self.input_reader),
@@ -80,9 +146,16 @@ impl<R: Read> RdfLexer<R> for SparqlLexer<R> {
             Some('<') => return SparqlLexer::get_uri(&mut self.input_reader),
             Some('_') => return SparqlLexer::get_blank_node(&mut self.input_reader),
             Some('.') => {
-                // try to parse a decimal, if there is an error then it is a triple delimiter
-                return SparqlLexer::get_numeric(&mut self.input_reader)
-                    .or_else(|_| Ok(Token::TripleDelimiter));
+                // a '.' that is followed by a digit is the start of a decimal
+                match self.input_reader.peek_next_k_chars(2)?[1] {
+                    Some(c) if InputReaderHelper::digit(c) => {
+                        return SparqlLexer::get_numeric(&mut self.input_reader)
+                    }
+                    _ => {
+                        SparqlLexer::consume_next_char(&mut self.input_reader); // consume '.'
+                        return Ok(Token::TripleDelimiter);
+                    }
+                }
             }
             Some('[') => {
                 SparqlLexer::consume_next_char(&mut self.input_reader); // consume '['
@@ -126,38 +199,6 @@ impl<R: Read> RdfLexer<R> for SparqlLexer<R> {
 
         SparqlLexer::get_qname_or_keyword(&mut self.input_reader)
     }
-
-    /// Determines the next token without consuming the input.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use rdf::reader::lexer::rdf_lexer::RdfLexer;
-    /// use rdf::reader::lexer::sparql_lexer::SparqlLexer;
-    /// use rdf::reader::lexer::token::Token;
-    ///
-    /// let input = "SELECT".as_bytes();
-    ///
-    /// let mut lexer = SparqlLexer::new(input);
-    /// ```
-    ///
-    ///  # Failures
-    ///
-    /// - End of input reached.
-    /// - Invalid input that does not conform with NTriples standard.
-    ///
-    fn peek_next_token(&mut self) -> Result<Token> {
-        match self.peeked_token.clone() {
-            Some(token) => Ok(token),
-            None => match self.get_next_token() {
-                Ok(next) => {
-                    self.peeked_token = Some(next.clone());
-                    return Ok(next);
-                }
-                Err(err) => return Err(err),
-            },
-        }
-    }
 }
 
 /// Contains all implemented rules for creating tokens from SPARQL syntax.
@@ -1,3 +1,5 @@
+use crate::reader::input_reader::Span;
+
 #[derive(Debug, PartialEq, Clone)]
 /// Tokens are produced by RDF lexers.
 pub enum Token {
@@ -11,6 +13,8 @@ pub enum Token {
     TripleDelimiter,
     PrefixDirective(String, String),
     BaseDirective(String),
+    SparqlPrefixDirective(String, String), // e.g. for Turtle syntax -> PREFIX without trailing '.'
+    SparqlBaseDirective(String),           // e.g. for Turtle syntax -> BASE without trailing '.'
     QName(String, String),
     Prefix(String),
     KeywordA,                // 'a'
@@ -47,3 +51,32 @@ pub enum Token {
     Asterisk,
     SparqlVariable(String), // variable in SPARQL construct with name
 }
+
+/// Token together with the span of the input it was produced from.
+#[derive(Debug, PartialEq, Clone)]
+pub struct SpannedToken {
+    token: Token,
+    span: Span,
+}
+
+impl SpannedToken {
+    /// Constructor of `SpannedToken`.
+    pub fn new(token: Token, span: Span) -> SpannedToken {
+        SpannedToken { token, span }
+    }
+
+    /// Returns the token.
+    pub fn token(&self) -> &Token {
+        &self.token
+    }
+
+    /// Returns the span of the token.
+    pub fn span(&self) -> &Span {
+        &self.span
+    }
+
+    /// Returns the token and discards the span.
+    pub fn into_token(self) -> Token {
+        self.token
+    }
+}
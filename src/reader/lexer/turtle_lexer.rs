@@ -1,9 +1,10 @@
 use crate::error::{Error, ErrorType};
-use crate::reader::input_reader::{InputReader, InputReaderHelper};
+use crate::reader::input_reader::{InputReader, InputReaderHelper, Span};
 use crate::reader::lexer::n_triples_lexer::TokensFromNTriples;
 use crate::reader::lexer::rdf_lexer::RdfLexer;
 use crate::reader::lexer::rdf_lexer::TokensFromRdf;
 use crate::reader::lexer::token::Token;
+use crate::specs::n_triples_specs::NTriplesSpecs;
 use crate::specs::turtle_specs::TurtleSpecs;
 use crate::specs::xml_specs::XmlDataTypes;
 use std::io::Read;
@@ -13,6 +14,13 @@ use crate::Result;
 pub struct TurtleLexer<R: Read> {
     input_reader: InputReader<R>,
     peeked_token: Option<Token>,
+
+    /// Spans of the last returned token and of the peeked token.
+    token_span: Span,
+    peeked_span: Span,
+
+    /// `true` if the token that was read last from the input is a triple delimiter.
+    at_statement_end: bool,
 }
 
 /// Contains all implemented rules for creating tokens from Turtle syntax.
@@ -29,21 +37,40 @@ pub trait TokensFromTurtle<R: Read>: TokensFromNTriples<R> {
         }
     }
 
+    /// Checks if the input continues with the case insensitive keyword of a directive.
+    ///
+    /// The keyword must not be followed by a character that continues a name.
+    fn peek_directive_keyword(input_reader: &mut InputReader<R>, keyword: &str) -> Result<bool> {
+        let chars = input_reader.peek_next_k_chars(keyword.len() + 1)?.to_vec();
+        let word: String = chars[..keyword.len()].iter().flatten().collect();
+
+        let is_terminated = match chars[keyword.len()] {
+            Some(c) => !NTriplesSpecs::is_pn_chars(c) && c != ':',
+            None => true,
+        };
+
+        Ok(is_terminated && word.to_lowercase() == keyword)
+    }
+
     /// Parses the base directive.
     fn get_base_directive(input_reader: &mut InputReader<R>) -> Result<Token> {
-        let base_directive = input_reader.peek_next_k_chars(5)?;
-
-        if base_directive.to_string().to_lowercase() != "base " {
+        if !Self::peek_directive_keyword(input_reader, "base")? {
             return Err(Error::new(
                 ErrorType::InvalidReaderInput,
-                "Invalid URI for base directive.",
+                "Invalid input for base directive.",
             ));
         }
 
-        let _ = input_reader.get_until(|c| c == '<'); // consume 'base'
+        let _ = input_reader.get_next_k_chars(4)?; // consume 'base'
 
-        match Self::get_uri(input_reader)? {
-            Token::Uri(base_uri) => Ok(Token::BaseDirective(base_uri)),
+        match input_reader.peek_next_char_discard_leading_spaces()? {
+            Some('<') => match Self::get_uri(input_reader)? {
+                Token::Uri(base_uri) => Ok(Token::BaseDirective(base_uri)),
+                _ => Err(Error::new(
+                    ErrorType::InvalidReaderInput,
+                    "Invalid URI for base directive.",
+                )),
+            },
             _ => Err(Error::new(
                 ErrorType::InvalidReaderInput,
                 "Invalid URI for base directive.",
@@ -53,27 +80,36 @@ pub trait TokensFromTurtle<R: Read>: TokensFromNTriples<R> {
 
     /// Parses the prefix directive.
     fn get_prefix_directive(input_reader: &mut InputReader<R>) -> Result<Token> {
-        let prefix_directive = input_reader.peek_next_k_chars(7)?;
-
-        if prefix_directive.to_string().to_lowercase() != "prefix " {
+        if !Self::peek_directive_keyword(input_reader, "prefix")? {
             return Err(Error::new(
                 ErrorType::InvalidReaderInput,
-                "Invalid URI for base directive.",
+                "Invalid input for prefix directive.",
             ));
         }
 
-        let _ = input_reader.get_until(|c| c == ' '); // consume 'prefix'
+        let _ = input_reader.get_next_k_chars(6)?; // consume 'prefix'
+        input_reader.peek_next_char_discard_leading_spaces()?;
 
         // get prefix name including ':'
-        let mut name = input_reader
-            .get_until_discard_leading_spaces(|c| c == ':')?
-            .to_string();
-        name.push(':');
+        let mut name = Self::get_prefix_name(input_reader)?;
+
+        if input_reader.get_next_char()? != Some(':') {
+            return Err(Error::new(
+                ErrorType::InvalidReaderInput,
+                "Invalid prefix name for prefix directive.",
+            ));
+        }
 
-        let _ = input_reader.get_until(|c| c == '<'); // consume characters until URI begin
+        name.push(':');
 
-        match Self::get_uri(input_reader)? {
-            Token::Uri(prefix_uri) => Ok(Token::PrefixDirective(name, prefix_uri)),
+        match input_reader.peek_next_char_discard_leading_spaces()? {
+            Some('<') => match Self::get_uri(input_reader)? {
+                Token::Uri(prefix_uri) => Ok(Token::PrefixDirective(name, prefix_uri)),
+                _ => Err(Error::new(
+                    ErrorType::InvalidReaderInput,
+                    "Invalid URI for prefix directive.",
+                )),
+            },
             _ => Err(Error::new(
                 ErrorType::InvalidReaderInput,
                 "Invalid URI for prefix directive.",
@@ -83,137 +119,159 @@ pub trait TokensFromTurtle<R: Read>: TokensFromNTriples<R> {
 
     /// Parses integer, decimals and doubles.
     fn get_numeric(input_reader: &mut InputReader<R>) -> Result<Token> {
-        let numeric =
-            input_reader.get_until_discard_leading_spaces(InputReaderHelper::node_delimiter)?;
-
-        // check if delimiter was '.' and if it is part of a decimal or if it is a delimiter
-        if input_reader.get_next_char()? == Some('.') {
-            let mut complete_numeric = numeric.clone();
-            match input_reader.peek_until(InputReaderHelper::node_delimiter) {
-                Ok(mut input_chars) => {
-                    complete_numeric.push(Some('.'));
-                    complete_numeric.append(&mut input_chars);
-
-                    if TurtleSpecs::is_double_literal(&complete_numeric.to_string()) {
-                        let _ = input_reader
-                            .get_until_discard_leading_spaces(InputReaderHelper::node_delimiter)?; // consume
-                        return Ok(Token::LiteralWithUrlDatatype(
-                            complete_numeric.to_string(),
-                            XmlDataTypes::Double.to_string(),
-                        ));
-                    }
-                }
-                _ => {}
+        let mut numeric = String::new();
+        let mut data_type = XmlDataTypes::Integer;
+
+        match input_reader.peek_next_char()? {
+            Some(c) if c == '+' || c == '-' => {
+                Self::consume_next_char(input_reader);
+                numeric.push(c);
             }
+            _ => {}
         }
 
-        if TurtleSpecs::is_integer_literal(&numeric.to_string()) {
-            return Ok(Token::LiteralWithUrlDatatype(
-                numeric.to_string(),
-                XmlDataTypes::Integer.to_string(),
-            ));
-        } else if TurtleSpecs::is_double_literal(&numeric.to_string()) {
-            return Ok(Token::LiteralWithUrlDatatype(
-                numeric.to_string(),
-                XmlDataTypes::Double.to_string(),
-            ));
-        } else {
+        let has_integer_digits = Self::get_digits(input_reader, &mut numeric)?;
+
+        // '.' is only part of the numeric if it is followed by digits or an exponent
+        let next_chars = input_reader.peek_next_k_chars(2)?;
+
+        if next_chars[0] == Some('.')
+            && (matches!(next_chars[1], Some(c) if InputReaderHelper::digit(c))
+                || (has_integer_digits && Self::peek_exponent(input_reader, 1)?))
+        {
+            Self::consume_next_char(input_reader); // consume '.'
+            numeric.push('.');
+            Self::get_digits(input_reader, &mut numeric)?;
+            data_type = XmlDataTypes::Decimal;
+        } else if !has_integer_digits {
             return Err(Error::new(
                 ErrorType::InvalidReaderInput,
                 "Invalid input for numeric literal.",
             ));
         }
+
+        if Self::peek_exponent(input_reader, 0)? {
+            // consume 'e' or 'E' and the sign
+            while let Some(c) = input_reader.peek_next_char()? {
+                if InputReaderHelper::digit(c) {
+                    break;
+                }
+
+                Self::consume_next_char(input_reader);
+                numeric.push(c);
+            }
+
+            Self::get_digits(input_reader, &mut numeric)?;
+            data_type = XmlDataTypes::Double;
+        }
+
+        Ok(Token::LiteralWithUrlDatatype(numeric, data_type.to_string()))
     }
 
-    /// Parses a boolean value and returns it as token.
-    fn get_boolean_literal(input_reader: &mut InputReader<R>) -> Result<Token> {
-        let boolean =
-            input_reader.peek_until_discard_leading_spaces(InputReaderHelper::node_delimiter)?;
+    /// Reads digits from the input and appends them to the numeric.
+    ///
+    /// Returns `true` if at least one digit was read.
+    fn get_digits(input_reader: &mut InputReader<R>, numeric: &mut String) -> Result<bool> {
+        let length = numeric.len();
 
-        if TurtleSpecs::is_boolean_literal(&boolean.to_string()) {
-            return Ok(Token::LiteralWithUrlDatatype(
-                boolean.to_string(),
-                XmlDataTypes::Boolean.to_string(),
-            ));
-        } else {
-            return Err(Error::new(
-                ErrorType::InvalidReaderInput,
-                "Invalid input for boolean.",
-            ));
+        while let Some(c) = input_reader.peek_next_char()? {
+            if !InputReaderHelper::digit(c) {
+                break;
+            }
+
+            Self::consume_next_char(input_reader);
+            numeric.push(c);
         }
+
+        Ok(numeric.len() > length)
     }
 
-    /// Parses the 'a' keyword.
-    fn get_a_keyword(input_reader: &mut InputReader<R>) -> Result<Token> {
-        let a =
-            input_reader.peek_until_discard_leading_spaces(InputReaderHelper::node_delimiter)?;
+    /// Checks if a valid exponent starts at the `offset` of the next input characters.
+    fn peek_exponent(input_reader: &mut InputReader<R>, offset: usize) -> Result<bool> {
+        let chars = input_reader.peek_next_k_chars(offset + 3)?;
+        let is_digit = |c: Option<char>| matches!(c, Some(c) if InputReaderHelper::digit(c));
+
+        Ok(match (chars[offset], chars[offset + 1]) {
+            (Some('e'), Some('+'))
+            | (Some('e'), Some('-'))
+            | (Some('E'), Some('+'))
+            | (Some('E'), Some('-')) => is_digit(chars[offset + 2]),
+            (Some('e'), c) | (Some('E'), c) => is_digit(c),
+            _ => false,
+        })
+    }
 
-        if a.len() == 1 && a[0] == Some('a') {
-            return Ok(Token::KeywordA);
-        } else {
-            return Err(Error::new(
+    /// Parses a prefixed name or one of the keywords 'a', 'true' and 'false'.
+    fn get_prefixed_name_or_keyword(input_reader: &mut InputReader<R>) -> Result<Token> {
+        let name = Self::get_prefix_name(input_reader)?;
+
+        if input_reader.peek_next_char()? == Some(':') {
+            return Self::get_local_name(input_reader, name);
+        }
+
+        match name.as_str() {
+            "" => Err(Error::new(
                 ErrorType::InvalidReaderInput,
-                "Invalid input for keyword 'a'.",
-            ));
+                match input_reader.peek_next_char()? {
+                    Some(c) => format!("Unexpected character '{}' in Turtle input.", c),
+                    None => "Unexpected end of Turtle input.".to_string(),
+                },
+            )),
+            "a" => Ok(Token::KeywordA),
+            "true" | "false" => Ok(Token::LiteralWithUrlDatatype(
+                name,
+                XmlDataTypes::Boolean.to_string(),
+            )),
+            _ => Err(Error::new(
+                ErrorType::InvalidReaderInput,
+                "Invalid Turtle input: ".to_string() + &name,
+            )),
         }
     }
 
-    /// Parses a literal from the input and returns it as token.
     /// Parses a literal from the input and returns it as token.
     fn get_literal(input_reader: &mut InputReader<R>) -> Result<Token> {
-        let literal_delimiter = input_reader.get_next_char()?;
-        let mut is_multiline = false;
+        let literal_delimiter = match input_reader.get_next_char()? {
+            Some(c) => c,
+            None => {
+                return Err(Error::new(
+                    ErrorType::InvalidReaderInput,
+                    "Invalid input while parsing Turtle literal.",
+                ))
+            }
+        };
 
         let potential_literal_quotes = input_reader.peek_next_k_chars(2)?;
 
         // check if the literal is multiline
-        if potential_literal_quotes[0] == literal_delimiter
-            && potential_literal_quotes[1] == literal_delimiter
+        let literal = if potential_literal_quotes[0] == Some(literal_delimiter)
+            && potential_literal_quotes[1] == Some(literal_delimiter)
         {
-            is_multiline = true;
             let _ = input_reader.get_next_k_chars(2); // consume
-        }
-
-        let mut found_literal_end = false;
-        let mut literal = "".to_string();
-
-        while !found_literal_end {
-            literal.push_str(
-                &input_reader
-                    .get_until(|c| c == literal_delimiter.unwrap())?
-                    .to_string(),
-            );
-
-            if is_multiline {
-                // if not escaped check if the literal is complete
-                let potential_literal_delimiters = input_reader.peek_next_k_chars(2)?.to_vec();
-
-                if potential_literal_delimiters[0] == literal_delimiter
-                    && potential_literal_delimiters[1] == literal_delimiter
-                {
-                    Self::consume_next_char(input_reader);
-                    Self::consume_next_char(input_reader);
-
-                    found_literal_end = true;
-                } else {
-                    literal.push_str(&input_reader.get_next_k_chars(1)?.to_string());
-                }
-            } else {
-                found_literal_end = true;
-            }
-        }
-
-        Self::consume_next_char(input_reader); // consume '"'
+            Self::get_multiline_string(input_reader, literal_delimiter)?
+        } else {
+            Self::get_quoted_string(input_reader, literal_delimiter)?
+        };
 
         match input_reader.peek_next_char()? {
             Some('@') => {
                 Self::consume_next_char(input_reader); // consume '@'
                 let language = Self::get_language_specification(input_reader)?;
+
+                if input_reader.peek_next_char()? == Some('^') {
+                    return Err(Error::new(
+                        ErrorType::InvalidReaderInput,
+                        "Literal with language tag @".to_string()
+                            + &language
+                            + " can not have a data type.",
+                    ));
+                }
+
                 Ok(Token::LiteralWithLanguageSpecification(literal, language))
             }
             Some('^') => {
                 Self::consume_next_char(input_reader); // consume '^'
-                Self::consume_next_char(input_reader); // consume '^'
+                Self::consume_data_type_delimiter(input_reader)?; // consume second '^'
 
                 match input_reader.peek_next_char()? {
                     Some('<') => {
@@ -247,22 +305,161 @@ pub trait TokensFromTurtle<R: Read>: TokensFromNTriples<R> {
         }
     }
 
+    /// Parses a multiline string up to the closing three quotes and decodes its escape sequences.
+    ///
+    /// The opening quotes have to be consumed already, the closing quotes are consumed.
+    fn get_multiline_string(input_reader: &mut InputReader<R>, quote: char) -> Result<String> {
+        let mut string = String::new();
+
+        loop {
+            match input_reader.get_next_char()? {
+                Some('\\') => string.push(Self::get_escaped_char(input_reader, true)?),
+                Some(c) if c == quote => {
+                    let potential_literal_quotes = input_reader.peek_next_k_chars(2)?;
+
+                    if potential_literal_quotes[0] == Some(quote)
+                        && potential_literal_quotes[1] == Some(quote)
+                    {
+                        let _ = input_reader.get_next_k_chars(2); // consume
+                        return Ok(string);
+                    }
+
+                    string.push(c);
+                }
+                Some(c) => string.push(c),
+                None => {
+                    return Err(Error::new(
+                        ErrorType::InvalidReaderInput,
+                        "Unterminated multiline string literal.",
+                    ))
+                }
+            }
+        }
+    }
+
     /// Parses a QName.
     fn get_qname(input_reader: &mut InputReader<R>) -> Result<Token> {
-        let mut prefix = input_reader.get_until(|c| c == ':')?.to_string();
+        let prefix = Self::get_prefix_name(input_reader)?;
+        Self::get_local_name(input_reader, prefix)
+    }
+
+    /// Parses the name of a prefix without the following ':', the name may be empty.
+    fn get_prefix_name(input_reader: &mut InputReader<R>) -> Result<String> {
+        let mut name = String::new();
+
+        loop {
+            match input_reader.peek_next_char()? {
+                Some(c) if name.is_empty() && !NTriplesSpecs::is_pn_chars_base(c) => break,
+                Some(c) if NTriplesSpecs::is_pn_chars(c) => {
+                    Self::consume_next_char(input_reader);
+                    name.push(c);
+                }
+                Some('.') if !name.is_empty() => {
+                    let dots = Self::peek_inner_dots(input_reader, NTriplesSpecs::is_pn_chars)?;
+
+                    if dots == 0 {
+                        break;
+                    }
+
+                    for _ in 0..dots {
+                        Self::consume_next_char(input_reader);
+                        name.push('.');
+                    }
+                }
+                _ => break,
+            }
+        }
+
+        Ok(name)
+    }
+
+    /// Parses the ':' and the local name of a QName whose prefix name has already been read.
+    ///
+    /// Escaped reserved characters are decoded, percent encodings are kept.
+    fn get_local_name(input_reader: &mut InputReader<R>, mut prefix: String) -> Result<Token> {
+        if input_reader.get_next_char()? != Some(':') {
+            return Err(Error::new(
+                ErrorType::InvalidReaderInput,
+                "Invalid QName, expected ':' after prefix: ".to_string() + &prefix,
+            ));
+        }
+
         prefix.push(':'); // ':' is part of prefix name
-        Self::consume_next_char(input_reader); // consume ':'
 
-        match input_reader.get_until(InputReaderHelper::node_delimiter) {
-            Ok(chars) => Ok(Token::QName(prefix, chars.to_string())),
-            Err(err) => match err.error_type() {
-                &ErrorType::EndOfInput(ref chars) => Ok(Token::QName(prefix, chars.to_string())),
-                _ => Err(Error::new(
-                    ErrorType::InvalidReaderInput,
-                    "Invalid input for Turtle lexer while parsing QName.",
-                )),
-            },
+        let continues_local_name =
+            |c: char| NTriplesSpecs::is_pn_chars(c) || c == ':' || c == '%' || c == '\\';
+        let mut local_name = String::new();
+
+        loop {
+            match input_reader.peek_next_char()? {
+                Some('\\') => {
+                    Self::consume_next_char(input_reader); // consume '\'
+
+                    match input_reader.get_next_char()? {
+                        Some(c) if TurtleSpecs::is_reserved_local_name_character(c) => {
+                            local_name.push(c)
+                        }
+                        _ => {
+                            return Err(Error::new(
+                                ErrorType::InvalidReaderInput,
+                                "Invalid escape sequence in Turtle local name.",
+                            ))
+                        }
+                    }
+                }
+                Some('%') => {
+                    Self::consume_next_char(input_reader); // consume '%'
+                    local_name.push('%');
+
+                    for _ in 0..2 {
+                        match input_reader.get_next_char()? {
+                            Some(c) if c.is_ascii_hexdigit() => local_name.push(c),
+                            _ => {
+                                return Err(Error::new(
+                                    ErrorType::InvalidReaderInput,
+                                    "Invalid percent encoding in Turtle local name.",
+                                ))
+                            }
+                        }
+                    }
+                }
+                Some(c)
+                    if local_name.is_empty()
+                        && !NTriplesSpecs::is_pn_chars_u(c)
+                        && !InputReaderHelper::digit(c)
+                        && c != ':' =>
+                {
+                    if NTriplesSpecs::is_pn_chars(c) {
+                        return Err(Error::new(
+                            ErrorType::InvalidReaderInput,
+                            "Invalid first character of Turtle local name: ".to_string()
+                                + &c.to_string(),
+                        ));
+                    }
+
+                    break;
+                }
+                Some(c) if NTriplesSpecs::is_pn_chars(c) || c == ':' => {
+                    Self::consume_next_char(input_reader);
+                    local_name.push(c);
+                }
+                Some('.') if !local_name.is_empty() => {
+                    let dots = Self::peek_inner_dots(input_reader, continues_local_name)?;
+
+                    if dots == 0 {
+                        break;
+                    }
+
+                    for _ in 0..dots {
+                        Self::consume_next_char(input_reader);
+                        local_name.push('.');
+                    }
+                }
+                _ => break,
+            }
         }
+
+        Ok(Token::QName(prefix, local_name))
     }
 }
 
@@ -287,6 +484,9 @@ impl<R: Read> RdfLexer<R> for TurtleLexer<R> {
         TurtleLexer {
             input_reader: InputReader::new(input),
             peeked_token: None,
+            token_span: Span::default(),
+            peeked_span: Span::default(),
+            at_statement_end: false,
         }
     }
 
@@ -314,88 +514,28 @@ impl<R: Read> RdfLexer<R> for TurtleLexer<R> {
     /// - Input that does not conform to the Turtle syntax standard.
     ///
     fn get_next_token(&mut self) -> Result<Token> {
-        // first read peeked characters
-        match self.peeked_token.clone() {
-            Some(token) => {
-                self.peeked_token = None;
-                return Ok(token);
-            }
-            None => {}
+        if let Some(token) = self.peeked_token.take() {
+            self.token_span = self.peeked_span;
+            return Ok(token);
         }
 
-        match self.input_reader.peek_next_char_discard_leading_spaces()? {
-            Some('#') => return TurtleLexer::get_comment(&mut self.input_reader),
-            Some('@') => {
-                TurtleLexer::consume_next_char(&mut self.input_reader); // consume '@'
-                return TurtleLexer::get_base_or_prefix(&mut self.input_reader);
-            }
-            Some('"') | Some('\'') => {
-                return <TurtleLexer<R> as TokensFromTurtle<R>>::get_literal(&mut self.input_reader)
-            }
-            Some('<') => return TurtleLexer::get_uri(&mut self.input_reader),
-            Some('_') => return TurtleLexer::get_blank_node(&mut self.input_reader),
-            Some('.') => {
-                // try to parse a decimal, if there is an error then it is a triple delimiter
-                return TurtleLexer::get_numeric(&mut self.input_reader)
-                    .or_else(|_| Ok(Token::TripleDelimiter));
-            }
-            Some(',') => {
-                TurtleLexer::consume_next_char(&mut self.input_reader); // consume ','
-                return Ok(Token::ObjectListDelimiter);
-            }
-            Some(';') => {
-                TurtleLexer::consume_next_char(&mut self.input_reader); // consume ';'
-                return Ok(Token::PredicateListDelimiter);
-            }
-            Some('(') => {
-                TurtleLexer::consume_next_char(&mut self.input_reader); // consume '('
-                return Ok(Token::CollectionStart);
-            }
-            Some(')') => {
-                TurtleLexer::consume_next_char(&mut self.input_reader); // consume ')'
-                return Ok(Token::CollectionEnd);
-            }
-            Some('[') => {
-                TurtleLexer::consume_next_char(&mut self.input_reader); // consume '['
-                return Ok(Token::UnlabeledBlankNodeStart);
-            }
-            Some(']') => {
-                TurtleLexer::consume_next_char(&mut self.input_reader); // consume ']'
-                return Ok(Token::UnlabeledBlankNodeEnd);
-            }
-            Some('P') | Some('B') => {
-                // try parsing PREFIX or BASE
-                match TurtleLexer::get_base_or_prefix(&mut self.input_reader) {
-                    Ok(token) => return Ok(token),
-                    _ => {} // continue, because it could still be a QName
-                }
-            }
-            Some('t') | Some('f') => {
-                // try parsing 'true' or 'false'
-                match TurtleLexer::get_boolean_literal(&mut self.input_reader) {
-                    Ok(token) => return Ok(token),
-                    _ => {} // continue, because it could still be a QName
-                }
-            }
-            Some('a') => {
-                // try parsing the 'a' keyword
-                match TurtleLexer::get_a_keyword(&mut self.input_reader) {
-                    Ok(token) => {
-                        TurtleLexer::consume_next_char(&mut self.input_reader);
-                        return Ok(token)
-                    },
-                    _ => {} // continue, because it could still be a QName
-                }
+        // the token starts at the first character that is not a whitespace
+        self.input_reader.peek_next_char_discard_leading_spaces()?;
+        let start = *self.input_reader.position();
+
+        self.at_statement_end = false;
+
+        match self.read_token() {
+            Ok(token) => {
+                self.token_span = Span::new(start, *self.input_reader.position());
+                self.at_statement_end = token == Token::TripleDelimiter;
+                Ok(token)
             }
-            Some('+') | Some('-') => return TurtleLexer::get_numeric(&mut self.input_reader),
-            Some(c) if InputReaderHelper::digit(c) => {
-                return TurtleLexer::get_numeric(&mut self.input_reader)
+            Err(err) => {
+                self.token_span = Span::new(start, *self.input_reader.position());
+                Err(err.with_location(self.token_span, self.input_reader.snippet()))
             }
-            Some(_) => {}
-            None => return Ok(Token::EndOfInput),
         }
-
-        TurtleLexer::get_qname(&mut self.input_reader)
     }
 
     /// Determines the next token without consuming the input.
@@ -423,15 +563,149 @@ impl<R: Read> RdfLexer<R> for TurtleLexer<R> {
     /// - Invalid input that does not conform with NTriples standard.
     ///
     fn peek_next_token(&mut self) -> Result<Token> {
-        match self.peeked_token.clone() {
-            Some(token) => Ok(token),
-            None => match self.get_next_token() {
-                Ok(next) => {
-                    self.peeked_token = Some(next.clone());
-                    return Ok(next);
+        if let Some(ref token) = self.peeked_token {
+            return Ok(token.clone());
+        }
+
+        // keep the span of the last returned token
+        let token_span = self.token_span;
+        let next = self.get_next_token()?;
+
+        self.peeked_span = self.token_span;
+        self.token_span = token_span;
+        self.peeked_token = Some(next.clone());
+        Ok(next)
+    }
+
+    /// Returns the span of the last token that was returned by `get_next_token`.
+    fn last_span(&self) -> &Span {
+        &self.token_span
+    }
+
+    /// Returns the text of the current input line that has been read so far.
+    fn snippet(&self) -> String {
+        self.input_reader.snippet()
+    }
+}
+
+impl<R: Read> TurtleLexer<R> {
+    /// Skips the input until the end of the current statement.
+    ///
+    /// Nothing is skipped if the token that was read last is the triple delimiter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::lexer::rdf_lexer::RdfLexer;
+    /// use rdf::reader::lexer::turtle_lexer::TurtleLexer;
+    /// use rdf::reader::lexer::token::Token;
+    ///
+    /// let input = "_:a \"b . c\" ! . _:d".as_bytes();
+    ///
+    /// let mut lexer = TurtleLexer::new(input);
+    /// let _ = lexer.get_next_token();
+    /// lexer.skip_statement().unwrap();
+    ///
+    /// assert_eq!(lexer.get_next_token().unwrap(), Token::BlankNode("d".to_string()));
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - Invalid byte encoding of the input.
+    ///
+    pub fn skip_statement(&mut self) -> Result<()> {
+        self.peeked_token = None;
+
+        if self.at_statement_end {
+            self.at_statement_end = false;
+            return Ok(());
+        }
+
+        TurtleLexer::skip_to_statement_end(&mut self.input_reader)
+    }
+
+    /// Determines the next token from the input after leading whitespaces.
+    fn read_token(&mut self) -> Result<Token> {
+        match self.input_reader.peek_next_char_discard_leading_spaces()? {
+            Some('#') => TurtleLexer::get_comment(&mut self.input_reader),
+            Some('@') => {
+                TurtleLexer::consume_next_char(&mut self.input_reader); // consume '@'
+
+                // directives starting with '@' are case sensitive
+                let directive = self.input_reader.peek_next_k_chars(6)?.to_string();
+
+                if directive.starts_with("base") || directive.starts_with("prefix") {
+                    TurtleLexer::get_base_or_prefix(&mut self.input_reader)
+                } else {
+                    Err(Error::new(
+                        ErrorType::InvalidReaderInput,
+                        "Invalid Turtle directive: @".to_string() + &directive,
+                    ))
                 }
-                Err(err) => return Err(err),
-            },
+            }
+            Some('"') | Some('\'') => {
+                <TurtleLexer<R> as TokensFromTurtle<R>>::get_literal(&mut self.input_reader)
+            }
+            Some('<') => TurtleLexer::get_uri(&mut self.input_reader),
+            Some('_') => TurtleLexer::get_blank_node(&mut self.input_reader),
+            Some('.') => {
+                // a '.' that is followed by a digit is the start of a decimal
+                match self.input_reader.peek_next_k_chars(2)?[1] {
+                    Some(c) if InputReaderHelper::digit(c) => {
+                        TurtleLexer::get_numeric(&mut self.input_reader)
+                    }
+                    _ => {
+                        TurtleLexer::consume_next_char(&mut self.input_reader); // consume '.'
+                        Ok(Token::TripleDelimiter)
+                    }
+                }
+            }
+            Some(',') => {
+                TurtleLexer::consume_next_char(&mut self.input_reader); // consume ','
+                Ok(Token::ObjectListDelimiter)
+            }
+            Some(';') => {
+                TurtleLexer::consume_next_char(&mut self.input_reader); // consume ';'
+                Ok(Token::PredicateListDelimiter)
+            }
+            Some('(') => {
+                TurtleLexer::consume_next_char(&mut self.input_reader); // consume '('
+                Ok(Token::CollectionStart)
+            }
+            Some(')') => {
+                TurtleLexer::consume_next_char(&mut self.input_reader); // consume ')'
+                Ok(Token::CollectionEnd)
+            }
+            Some('[') => {
+                TurtleLexer::consume_next_char(&mut self.input_reader); // consume '['
+                Ok(Token::UnlabeledBlankNodeStart)
+            }
+            Some(']') => {
+                TurtleLexer::consume_next_char(&mut self.input_reader); // consume ']'
+                Ok(Token::UnlabeledBlankNodeEnd)
+            }
+            Some('+') | Some('-') => TurtleLexer::get_numeric(&mut self.input_reader),
+            Some(c) if InputReaderHelper::digit(c) => {
+                TurtleLexer::get_numeric(&mut self.input_reader)
+            }
+            Some(c) if c == 'b' || c == 'B' || c == 'p' || c == 'P' => {
+                // SPARQL style directives are case insensitive and are not terminated by '.'
+                if TurtleLexer::peek_directive_keyword(&mut self.input_reader, "base")?
+                    || TurtleLexer::peek_directive_keyword(&mut self.input_reader, "prefix")?
+                {
+                    match TurtleLexer::get_base_or_prefix(&mut self.input_reader)? {
+                        Token::BaseDirective(uri) => Ok(Token::SparqlBaseDirective(uri)),
+                        Token::PrefixDirective(prefix, uri) => {
+                            Ok(Token::SparqlPrefixDirective(prefix, uri))
+                        }
+                        token => Ok(token),
+                    }
+                } else {
+                    TurtleLexer::get_prefixed_name_or_keyword(&mut self.input_reader)
+                }
+            }
+            Some(_) => TurtleLexer::get_prefixed_name_or_keyword(&mut self.input_reader),
+            None => Ok(Token::EndOfInput),
         }
     }
 }
@@ -458,15 +732,15 @@ mod tests {
 
     #[test]
     fn parse_sparql_base_directive() {
-        let input = "BASE <http://example.org/> .".as_bytes();
+        let input = "BASE <http://example.org/>".as_bytes();
 
         let mut lexer = TurtleLexer::new(input);
 
         assert_eq!(
             lexer.get_next_token().unwrap(),
-            Token::BaseDirective("http://example.org/".to_string())
+            Token::SparqlBaseDirective("http://example.org/".to_string())
         );
-        assert_eq!(lexer.get_next_token().unwrap(), Token::TripleDelimiter);
+        assert_eq!(lexer.get_next_token().unwrap(), Token::EndOfInput);
     }
 
     #[test]
@@ -487,18 +761,18 @@ mod tests {
 
     #[test]
     fn parse_sparql_prefix_directive() {
-        let input = "PREFIX foaf: <http://xmlns.com/foaf/0.1/> .".as_bytes();
+        let input = "PREFIX foaf: <http://xmlns.com/foaf/0.1/>".as_bytes();
 
         let mut lexer = TurtleLexer::new(input);
 
         assert_eq!(
             lexer.get_next_token().unwrap(),
-            Token::PrefixDirective(
+            Token::SparqlPrefixDirective(
                 "foaf:".to_string(),
                 "http://xmlns.com/foaf/0.1/".to_string()
             )
         );
-        assert_eq!(lexer.get_next_token().unwrap(), Token::TripleDelimiter);
+        assert_eq!(lexer.get_next_token().unwrap(), Token::EndOfInput);
     }
 
     #[test]
@@ -651,23 +925,68 @@ mod tests {
         );
         assert_eq!(
             lexer.get_next_token().unwrap(),
-            Token::LiteralWithUrlDatatype("1.2".to_string(), XmlDataTypes::Double.to_string())
+            Token::LiteralWithUrlDatatype("1.2".to_string(), XmlDataTypes::Decimal.to_string())
         );
         assert_eq!(
             lexer.get_next_token().unwrap(),
-            Token::LiteralWithUrlDatatype("-5.123".to_string(), XmlDataTypes::Double.to_string())
+            Token::LiteralWithUrlDatatype("-5.123".to_string(), XmlDataTypes::Decimal.to_string())
         );
         assert_eq!(
             lexer.get_next_token().unwrap(),
-            Token::LiteralWithUrlDatatype("-.123".to_string(), XmlDataTypes::Double.to_string())
+            Token::LiteralWithUrlDatatype("-.123".to_string(), XmlDataTypes::Decimal.to_string())
         );
         assert_eq!(
             lexer.get_next_token().unwrap(),
-            Token::LiteralWithUrlDatatype(".123".to_string(), XmlDataTypes::Double.to_string())
+            Token::LiteralWithUrlDatatype(".123".to_string(), XmlDataTypes::Decimal.to_string())
         );
         assert_eq!(
             lexer.get_next_token().unwrap(),
             Token::LiteralWithUrlDatatype("5e10".to_string(), XmlDataTypes::Double.to_string())
         );
+        assert_eq!(lexer.get_next_token().unwrap(), Token::TripleDelimiter);
+    }
+
+    #[test]
+    fn parse_escaped_literals() {
+        let input = r#"'it\'s' "a\u0009b" """multi "quoted"
+line\n""" '''single ''quoted''' ."#
+            .as_bytes();
+
+        let mut lexer = TurtleLexer::new(input);
+
+        assert_eq!(
+            lexer.get_next_token().unwrap(),
+            Token::Literal("it's".to_string())
+        );
+        assert_eq!(
+            lexer.get_next_token().unwrap(),
+            Token::Literal("a\tb".to_string())
+        );
+        assert_eq!(
+            lexer.get_next_token().unwrap(),
+            Token::Literal("multi \"quoted\"\nline\n".to_string())
+        );
+        assert_eq!(
+            lexer.get_next_token().unwrap(),
+            Token::Literal("single ''quoted".to_string())
+        );
+        assert_eq!(lexer.get_next_token().unwrap(), Token::TripleDelimiter);
+    }
+
+    #[test]
+    fn parse_local_name_escapes() {
+        let input = r#"ex:a\/b\.c ex:d\?e ."#.as_bytes();
+
+        let mut lexer = TurtleLexer::new(input);
+
+        assert_eq!(
+            lexer.get_next_token().unwrap(),
+            Token::QName("ex:".to_string(), "a/b.c".to_string())
+        );
+        assert_eq!(
+            lexer.get_next_token().unwrap(),
+            Token::QName("ex:".to_string(), "d?e".to_string())
+        );
+        assert_eq!(lexer.get_next_token().unwrap(), Token::TripleDelimiter);
     }
 }
@@ -1,10 +1,12 @@
+use crate::dataset::{Dataset, Quad};
 use crate::error::{Error, ErrorType};
 use crate::graph::Graph;
 use crate::node::Node;
 use crate::reader::lexer::n_triples_lexer::NTriplesLexer;
 use crate::reader::lexer::rdf_lexer::RdfLexer;
 use crate::reader::lexer::token::Token;
-use crate::reader::rdf_parser::RdfParser;
+use crate::reader::rdf_parser::{RdfParser, RdfStreamParser};
+use crate::reader::rdf_sink::{CanonicalSink, CheckedSink, RdfSink};
 use std::io::Cursor;
 use std::io::Read;
 use crate::triple::Triple;
@@ -14,6 +16,13 @@ use crate::Result;
 /// RDF parser to generate an RDF graph from N-Triples syntax.
 pub struct NTriplesParser<R: Read> {
     lexer: NTriplesLexer<R>,
+
+    /// Invalid statements are skipped and reported as diagnostics in lenient mode.
+    lenient: bool,
+    diagnostics: Vec<Error>,
+
+    /// Literals are passed to the sink in canonical form.
+    canonical_literals: bool,
 }
 
 impl<R: Read> RdfParser for NTriplesParser<R> {
@@ -46,29 +55,64 @@ impl<R: Read> RdfParser for NTriplesParser<R> {
     fn decode(&mut self) -> Result<Graph> {
         let mut graph = Graph::new(None);
 
-        loop {
-            match self.lexer.peek_next_token()? {
-                Token::Comment(_) => {
-                    // ignore comments
-                    let _ = self.lexer.get_next_token();
-                    continue;
+        self.parse_all(&mut graph)?;
+        Ok(graph)
+    }
+}
+
+impl<R: Read> RdfStreamParser for NTriplesParser<R> {
+    /// Parses the next triple and passes it to the sink.
+    ///
+    /// Returns `false` if the end of the input has been reached.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::n_triples_parser::NTriplesParser;
+    /// use rdf::reader::rdf_parser::RdfStreamParser;
+    /// use rdf::triple::Triple;
+    ///
+    /// let input = "<http://www.w3.org/2001/sw/RDFCore/ntriples/> <http://xmlns.com/foaf/0.1/maker> _:art .
+    ///              _:art <http://xmlns.com/foaf/0.1/name> \"Art Barstow\" .";
+    ///
+    /// let mut count = 0;
+    /// let mut reader = NTriplesParser::from_string(input.to_string());
+    ///
+    /// reader.parse_all(&mut |_: Triple| { count += 1; Ok(()) }).unwrap();
+    ///
+    /// assert_eq!(count, 2);
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - Invalid input that does not conform with NTriples standard.
+    /// - Invalid node type for triple segment.
+    /// - The sink returns an error.
+    ///
+    fn parse_next(&mut self, sink: &mut dyn RdfSink) -> Result<bool> {
+        let mut canonical_sink;
+        let sink: &mut dyn RdfSink = if self.canonical_literals {
+            canonical_sink = CanonicalSink::new(sink);
+            &mut canonical_sink
+        } else {
+            sink
+        };
+
+        let mut checked_sink = CheckedSink::new(sink);
+
+        match self.parse_statement(&mut checked_sink) {
+            Ok(more) => Ok(more),
+            Err(err) => {
+                let err = self.lexer.locate_error(err);
+
+                // errors of the sink are never skipped
+                if !self.lenient || checked_sink.failed() {
+                    return Err(err);
                 }
-                Token::EndOfInput => return Ok(graph),
-                _ => {}
-            }
 
-            match self.read_triple() {
-                Ok(triple) => graph.add_triple(&triple),
-                Err(err) => match *err.error_type() {
-                    ErrorType::EndOfInput(_) => return Ok(graph),
-                    _ => {
-                        println!("Error: {}", err.to_string());
-                        return Err(Error::new(
-                            ErrorType::InvalidReaderInput,
-                            "Error while parsing NTriples syntax.",
-                        ));
-                    }
-                },
+                self.diagnostics.push(err);
+                self.lexer.skip_statement()?;
+                Ok(true)
             }
         }
     }
@@ -113,6 +157,218 @@ impl<R: Read> NTriplesParser<R> {
     pub fn from_reader(input: R) -> NTriplesParser<R> {
         NTriplesParser {
             lexer: NTriplesLexer::new(input),
+            lenient: false,
+            diagnostics: Vec::new(),
+            canonical_literals: false,
+        }
+    }
+
+    /// Enables the lenient mode that skips invalid statements instead of failing.
+    ///
+    /// The errors of skipped statements are collected as diagnostics, errors returned by
+    /// the sink still stop parsing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::n_triples_parser::NTriplesParser;
+    /// use rdf::reader::rdf_parser::RdfParser;
+    ///
+    /// let input = "<http://example.org/a> <http://example.org/b> \"c\" .
+    ///              <http://example.org/a> \"invalid\" <http://example.org/d> .
+    ///              <http://example.org/a> <http://example.org/b> \"e\" .";
+    ///
+    /// let mut reader = NTriplesParser::from_string(input).lenient();
+    /// let graph = reader.decode().unwrap();
+    ///
+    /// assert_eq!(graph.count(), 2);
+    /// assert_eq!(reader.diagnostics().len(), 1);
+    /// assert_eq!(reader.diagnostics()[0].location().unwrap().start().line(), 2);
+    /// ```
+    pub fn lenient(mut self) -> NTriplesParser<R> {
+        self.lenient = true;
+        self
+    }
+
+    /// Passes literals in canonical form to the sink.
+    ///
+    /// Literals with a supported XML schema data type are converted to their canonical
+    /// lexical form and language tags to lower case, see `Node::canonicalize`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::n_triples_parser::NTriplesParser;
+    /// use rdf::reader::rdf_parser::RdfParser;
+    /// use rdf::node::Node;
+    ///
+    /// let input = "<http://example.org/a> <http://example.org/b> \"+1.50\"^^<http://www.w3.org/2001/XMLSchema#decimal> .
+    ///              <http://example.org/a> <http://example.org/b> \"chat\"@fr-CA .";
+    ///
+    /// let graph = NTriplesParser::from_string(input).canonicalize_literals().decode().unwrap();
+    /// let literals: Vec<String> = graph.triples_iter().map(|triple| match *triple.object() {
+    ///   Node::LiteralNode { ref literal, ref language, .. } =>
+    ///     format!("{}{}", literal, language.as_ref().map(|l| "@".to_string() + l).unwrap_or_default()),
+    ///   _ => String::new(),
+    /// }).collect();
+    ///
+    /// assert_eq!(literals, vec!["1.5", "chat@fr-ca"]);
+    /// ```
+    pub fn canonicalize_literals(mut self) -> NTriplesParser<R> {
+        self.canonical_literals = true;
+        self
+    }
+
+    /// Returns the errors of the statements that were skipped in lenient mode.
+    pub fn diagnostics(&self) -> &[Error] {
+        &self.diagnostics
+    }
+
+    /// Generates an RDF graph and returns it together with the collected diagnostics.
+    ///
+    /// Without lenient mode, the diagnostics are always empty.
+    ///
+    /// # Failures
+    ///
+    /// - Invalid input if the lenient mode is not enabled.
+    ///
+    pub fn decode_with_diagnostics(&mut self) -> Result<(Graph, Vec<Error>)> {
+        let graph = self.decode()?;
+        Ok((graph, self.diagnostics.drain(..).collect()))
+    }
+
+    /// Generates an RDF dataset from N-Quads syntax.
+    ///
+    /// Statements without graph label are added to the default graph, N-Triples input is
+    /// therefore also accepted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::n_triples_parser::NTriplesParser;
+    ///
+    /// let input = "<http://example.org/a> <http://example.org/b> \"c\" .
+    ///              _:d <http://example.org/b> \"e\" <http://example.org/g> .
+    ///              _:d <http://example.org/b> \"f\" _:g .";
+    ///
+    /// let dataset = NTriplesParser::from_string(input).decode_dataset().unwrap();
+    ///
+    /// assert_eq!(dataset.count(), 3);
+    /// assert_eq!(dataset.default_graph().count(), 1);
+    /// assert_eq!(dataset.graph_names().len(), 2);
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - Invalid input that does not conform with N-Quads standard.
+    /// - Invalid node type for quad segment.
+    ///
+    pub fn decode_dataset(&mut self) -> Result<Dataset> {
+        let mut dataset = Dataset::new();
+
+        loop {
+            match self.parse_quad() {
+                Ok(Some(quad)) if self.canonical_literals => dataset.add_quad(&Quad::from_triple(
+                    &quad.triple().canonicalize(),
+                    quad.graph_name(),
+                )),
+                Ok(Some(quad)) => dataset.add_quad(&quad),
+                Ok(None) => return Ok(dataset),
+                Err(err) => {
+                    let err = self.lexer.locate_error(err);
+
+                    if !self.lenient {
+                        return Err(err);
+                    }
+
+                    self.diagnostics.push(err);
+                    self.lexer.skip_statement()?;
+                }
+            }
+        }
+    }
+
+    /// Parses the next quad of the input, returns `None` at the end of the input.
+    fn parse_quad(&mut self) -> Result<Option<Quad>> {
+        loop {
+            match self.lexer.peek_next_token()? {
+                Token::Comment(_) => {
+                    // ignore comments
+                    let _ = self.lexer.get_next_token();
+                    continue;
+                }
+                Token::EndOfInput => return Ok(None),
+                _ => {}
+            }
+
+            return match self.read_quad() {
+                Ok(quad) => Ok(Some(quad)),
+                Err(err) => match *err.error_type() {
+                    ErrorType::EndOfInput(_) => Ok(None),
+                    _ => Err(err),
+                },
+            };
+        }
+    }
+
+    /// Creates a quad from the parsed tokens, the graph label is optional.
+    fn read_quad(&mut self) -> Result<Quad> {
+        let subject = self.read_subject()?;
+        let predicate = self.read_predicate()?;
+        let object = self.read_object()?;
+
+        let graph_name = match self.lexer.get_next_token() {
+            Ok(Token::TripleDelimiter) => {
+                return Ok(Quad::new(&subject, &predicate, &object, None));
+            }
+            Ok(Token::BlankNode(id)) => Node::BlankNode { id },
+            Ok(Token::Uri(uri)) => Node::UriNode {
+                uri: Self::create_uri(uri)?,
+            },
+            _ => {
+                return Err(Error::new(
+                    ErrorType::InvalidToken,
+                    "Invalid token for NQuads graph label.",
+                ))
+            }
+        };
+
+        match self.lexer.get_next_token() {
+            Ok(Token::TripleDelimiter) => {}
+            _ => {
+                return Err(Error::new(
+                    ErrorType::InvalidReaderInput,
+                    "Expected quad delimiter.",
+                ))
+            }
+        }
+
+        Ok(Quad::new(&subject, &predicate, &object, Some(&graph_name)))
+    }
+
+    /// Parses the next statement of the input and passes it to the sink.
+    fn parse_statement(&mut self, sink: &mut dyn RdfSink) -> Result<bool> {
+        loop {
+            match self.lexer.peek_next_token()? {
+                Token::Comment(_) => {
+                    // ignore comments
+                    let _ = self.lexer.get_next_token();
+                    continue;
+                }
+                Token::EndOfInput => return Ok(false),
+                _ => {}
+            }
+
+            return match self.read_triple() {
+                Ok(triple) => {
+                    sink.triple(triple)?;
+                    Ok(true)
+                }
+                Err(err) => match *err.error_type() {
+                    ErrorType::EndOfInput(_) => Ok(false),
+                    _ => Err(err),
+                },
+            };
         }
     }
 
@@ -135,11 +391,27 @@ impl<R: Read> NTriplesParser<R> {
         Ok(Triple::new(&subject, &predicate, &object))
     }
 
+    /// Creates a URI, N-Triples only allows valid absolute URIs.
+    fn create_uri(uri: String) -> Result<Uri> {
+        let uri = Uri::parse_iri_ref(&uri)?;
+
+        if !uri.is_absolute() {
+            return Err(Error::new(
+                ErrorType::InvalidReaderInput,
+                "Relative URIs are not allowed in NTriples: ".to_string() + uri.to_string(),
+            ));
+        }
+
+        Ok(uri)
+    }
+
     /// Get the next token and check if it is a valid subject and create a new subject node.
     fn read_subject(&mut self) -> Result<Node> {
         match self.lexer.get_next_token() {
             Ok(Token::BlankNode(id)) => Ok(Node::BlankNode { id }),
-            Ok(Token::Uri(uri)) => Ok(Node::UriNode { uri: Uri::new(uri) }),
+            Ok(Token::Uri(uri)) => Ok(Node::UriNode {
+                uri: Self::create_uri(uri)?,
+            }),
             _ => Err(Error::new(
                 ErrorType::InvalidToken,
                 "Invalid token for NTriples subject.",
@@ -150,7 +422,9 @@ impl<R: Read> NTriplesParser<R> {
     /// Get the next token and check if it is a valid predicate and create a new predicate node.
     fn read_predicate(&mut self) -> Result<Node> {
         match self.lexer.get_next_token() {
-            Ok(Token::Uri(uri)) => Ok(Node::UriNode { uri: Uri::new(uri) }),
+            Ok(Token::Uri(uri)) => Ok(Node::UriNode {
+                uri: Self::create_uri(uri)?,
+            }),
             _ => Err(Error::new(
                 ErrorType::InvalidToken,
                 "Invalid token for NTriples predicate.",
@@ -162,7 +436,9 @@ impl<R: Read> NTriplesParser<R> {
     fn read_object(&mut self) -> Result<Node> {
         match self.lexer.get_next_token()? {
             Token::BlankNode(id) => Ok(Node::BlankNode { id }),
-            Token::Uri(uri) => Ok(Node::UriNode { uri: Uri::new(uri) }),
+            Token::Uri(uri) => Ok(Node::UriNode {
+                uri: Self::create_uri(uri)?,
+            }),
             Token::LiteralWithLanguageSpecification(literal, lang) => Ok(Node::LiteralNode {
                 literal,
                 data_type: None,
@@ -170,7 +446,7 @@ impl<R: Read> NTriplesParser<R> {
             }),
             Token::LiteralWithUrlDatatype(literal, datatype) => Ok(Node::LiteralNode {
                 literal,
-                data_type: Some(Uri::new(datatype)),
+                data_type: Some(Self::create_uri(datatype)?),
                 language: None,
             }),
             Token::Literal(literal) => Ok(Node::LiteralNode {
@@ -189,7 +465,10 @@ impl<R: Read> NTriplesParser<R> {
 #[cfg(test)]
 mod tests {
     use crate::reader::n_triples_parser::NTriplesParser;
-    use crate::reader::rdf_parser::RdfParser;
+    use crate::error::{Error, ErrorType};
+    use crate::graph::Graph;
+    use crate::reader::rdf_parser::{RdfParser, RdfStreamParser};
+    use crate::triple::Triple;
 
     #[test]
     fn test_read_n_triples_from_string() {
@@ -208,4 +487,139 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn iterate_n_triples() {
+        let input = "<http://example.org/a> <http://example.org/b> \"c\" .
+                 # comment
+                 <http://example.org/a> <http://example.org/b> _:d .
+                 <http://example.org/a> \"invalid\" _:d .";
+
+        let mut triples = NTriplesParser::from_string(input).triples();
+
+        assert!(triples.next().unwrap().is_ok());
+        assert!(triples.next().unwrap().is_ok());
+        assert!(triples.next().unwrap().is_err());
+        assert!(triples.next().is_none());
+    }
+
+    #[test]
+    fn stop_parsing_on_sink_error() {
+        let input = "<http://example.org/a> <http://example.org/b> <http://example.org/c> .
+                 <http://example.org/a> <http://example.org/b> <http://example.org/d> .";
+
+        let mut count = 0;
+        let mut sink = |_: Triple| {
+            count += 1;
+            Err(Error::new(ErrorType::InvalidWriterOutput, "Sink is full."))
+        };
+
+        let mut reader = NTriplesParser::from_string(input);
+
+        assert!(reader.parse_all(&mut sink).is_err());
+        assert_eq!(count, 1);
+    }
+
+    #[test]
+    fn locate_n_triples_errors() {
+        let input = "<http://example.org/a> <http://example.org/b> \"c\" .
+<http://example.org/a> \"invalid\" _:d .";
+
+        let mut reader = NTriplesParser::from_string(input);
+        let err = reader.decode().unwrap_err();
+        let location = err.location().unwrap();
+
+        assert_eq!(location.start().line(), 2);
+        assert_eq!(location.start().column(), 24);
+        assert_eq!(location.end().column(), 33);
+        assert_eq!(err.snippet(), Some("<http://example.org/a> \"invalid\""));
+    }
+
+    #[test]
+    fn report_the_cause_of_n_triples_errors() {
+        let input = "<http://example.org/a> <http://example.org/b> \"c .";
+        let expected =
+            "Unterminated string literal. (line 1, column 47: `<http://example.org/a> <http://example.org/b> \"c .`)";
+
+        let err = NTriplesParser::from_string(input).decode().unwrap_err();
+        assert_eq!(err.to_string(), expected);
+
+        let err = NTriplesParser::from_string(input)
+            .decode_dataset()
+            .unwrap_err();
+        assert_eq!(err.to_string(), expected);
+    }
+
+    #[test]
+    fn skip_invalid_n_triples_statements_in_lenient_mode() {
+        let input = "<http://example.org/a> <http://example.org/b> \"c\" .
+                 <http://example.org/a> <http://example.org/b> .
+                 x <http://example.org/b> _:d .
+                 <http://example.org/a> <http://example.org/b> _:d .
+                 <http://example.org/a> \"invalid\" _:d";
+
+        let reader = NTriplesParser::from_string(input).lenient();
+        let triples: Vec<Triple> = reader.triples().filter_map(|triple| triple.ok()).collect();
+        assert_eq!(triples.len(), 2);
+
+        let mut reader = NTriplesParser::from_string(input).lenient();
+        let (graph, diagnostics) = reader.decode_with_diagnostics().unwrap();
+
+        assert_eq!(graph.count(), 2);
+        assert_eq!(diagnostics.len(), 3);
+        assert!(NTriplesParser::from_string(input).decode().is_err());
+    }
+
+    #[test]
+    fn keep_the_following_line_in_lenient_mode() {
+        let valid = "<http://example.org/a> <http://example.org/b> <http://example.org/c> .";
+        let invalid = [
+            "<http://example.org/a> <http://example.org/b> \"unterminated",
+            "<http://example.org/a> <http://example.org/b> <http://example.org/d>",
+            "<http://example.org/a> <http://example.org/b> x . <http://example.org/e>",
+        ];
+
+        for line in invalid.iter() {
+            let input = format!("{}\n{}\n", line, valid);
+            let mut reader = NTriplesParser::from_string(input).lenient();
+            let (graph, diagnostics) = reader.decode_with_diagnostics().unwrap();
+
+            assert_eq!(graph.count(), 1, "{}", line);
+            assert_eq!(diagnostics.len(), 1, "{}", line);
+            assert_eq!(
+                graph.triples_iter().next().unwrap().object(),
+                &graph.create_uri_node_str("http://example.org/c").unwrap()
+            );
+        }
+
+        let input = format!("{}\n{}", invalid[1], valid);
+        let dataset = NTriplesParser::from_string(input)
+            .lenient()
+            .decode_dataset()
+            .unwrap();
+
+        assert_eq!(dataset.count(), 1);
+    }
+
+    #[test]
+    fn reject_invalid_n_triples_uris() {
+        let invalid = [
+            "<http://example.org/a%zz> <http://example.org/b> \"c\" .",
+            "<1http://example.org/a> <http://example.org/b> \"c\" .",
+            "<http://example.org:8a/> <http://example.org/b> \"c\" .",
+            "<http://example.org/a> <http://example.org/b> \"c\"^^<http://example.org/#a#b> .",
+        ];
+
+        for input in invalid.iter() {
+            let mut graph = Graph::new(None);
+
+            match NTriplesParser::from_string(*input).parse_all(&mut graph) {
+                Err(err) => match *err.error_type() {
+                    ErrorType::InvalidUri => {}
+                    _ => panic!("unexpected error type for {}", input),
+                },
+                Ok(_) => panic!("{} was accepted", input),
+            }
+        }
+    }
 }
@@ -1,4 +1,5 @@
 use crate::graph::Graph;
+use crate::reader::rdf_sink::{MergeSink, RdfSink, Triples};
 use crate::Result;
 
 /// Trait implemented by RDF parsers to generate a RDF graph from RDF syntax.
@@ -7,3 +8,56 @@ pub trait RdfParser {
     /// Returns an error if invalid RDF input is provided.
     fn decode(&mut self) -> Result<Graph>;
 }
+
+/// Trait implemented by RDF parsers that process their input statement by statement.
+///
+/// Only the statement that is currently parsed is kept in memory, which allows processing
+/// inputs that do not fit into a graph.
+pub trait RdfStreamParser {
+    /// Parses the next statement of the input and passes it to the sink.
+    ///
+    /// Returns `false` if the end of the input has been reached.
+    fn parse_next(&mut self, sink: &mut dyn RdfSink) -> Result<bool>;
+
+    /// Parses the whole input and passes all statements to the sink.
+    fn parse_all(&mut self, sink: &mut dyn RdfSink) -> Result<()> {
+        while self.parse_next(sink)? {}
+        Ok(())
+    }
+
+    /// Parses the whole input into an existing graph.
+    ///
+    /// Blank nodes of the input are kept apart from the blank nodes the graph already
+    /// contains, blank nodes whose labels are taken are renamed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::graph::Graph;
+    /// use rdf::reader::rdf_parser::RdfStreamParser;
+    /// use rdf::reader::turtle_parser::TurtleParser;
+    ///
+    /// let mut graph = Graph::new(None);
+    ///
+    /// TurtleParser::from_string("_:b1 <http://example.org/name> \"Alice\" .")
+    ///   .parse_into(&mut graph).unwrap();
+    /// TurtleParser::from_string("_:b1 <http://example.org/name> \"Bob\" .")
+    ///   .parse_into(&mut graph).unwrap();
+    ///
+    /// let subjects: Vec<_> = graph.triples_iter().map(|triple| triple.subject()).collect();
+    ///
+    /// assert_eq!(subjects.len(), 2);
+    /// assert_ne!(subjects[0], subjects[1]);
+    /// ```
+    fn parse_into(&mut self, graph: &mut Graph) -> Result<()> {
+        self.parse_all(&mut MergeSink::new(graph))
+    }
+
+    /// Returns an iterator over the triples of the input.
+    fn triples(self) -> Triples<Self>
+    where
+        Self: Sized,
+    {
+        Triples::new(self)
+    }
+}
@@ -0,0 +1,245 @@
+use crate::graph::Graph;
+use crate::namespace::Namespace;
+use crate::node::Node;
+use crate::reader::blank_node_scope::BlankNodeScope;
+use crate::reader::rdf_parser::RdfStreamParser;
+use crate::triple::Triple;
+use crate::uri::Uri;
+use crate::Result;
+use std::collections::VecDeque;
+
+/// Trait implemented by receivers of the statements produced by streaming RDF parsers.
+///
+/// Closures of the form `FnMut(Triple) -> Result<()>` can be used as sinks that only
+/// receive triples. Returning an error from any method stops the parser.
+pub trait RdfSink {
+    /// Receives a base URI that is set by the input.
+    fn base(&mut self, _base_uri: &Uri) -> Result<()> {
+        Ok(())
+    }
+
+    /// Receives a namespace that is declared by the input.
+    fn prefix(&mut self, _namespace: &Namespace) -> Result<()> {
+        Ok(())
+    }
+
+    /// Receives a triple parsed from the input.
+    fn triple(&mut self, triple: Triple) -> Result<()>;
+}
+
+impl<F> RdfSink for F
+where
+    F: FnMut(Triple) -> Result<()>,
+{
+    fn triple(&mut self, triple: Triple) -> Result<()> {
+        self(triple)
+    }
+}
+
+impl RdfSink for Graph {
+    fn base(&mut self, base_uri: &Uri) -> Result<()> {
+        self.set_base_uri(base_uri);
+        Ok(())
+    }
+
+    fn prefix(&mut self, namespace: &Namespace) -> Result<()> {
+        self.add_namespace(namespace);
+        Ok(())
+    }
+
+    fn triple(&mut self, triple: Triple) -> Result<()> {
+        self.add_triple(&triple);
+        Ok(())
+    }
+}
+
+/// Sink that adds triples to a graph and renames blank nodes whose labels the graph already
+/// uses, so that blank nodes of different sources are kept apart.
+pub(crate) struct MergeSink<'a> {
+    graph: &'a mut Graph,
+
+    /// Blank nodes of the merged triples, the labels of the graph are taken.
+    blank_nodes: BlankNodeScope,
+}
+
+impl<'a> MergeSink<'a> {
+    /// Constructor of `MergeSink`.
+    pub(crate) fn new(graph: &'a mut Graph) -> MergeSink<'a> {
+        let ids: Vec<String> = graph
+            .triples_iter()
+            .flat_map(|triple| vec![triple.subject(), triple.predicate(), triple.object()])
+            .filter_map(|node| match *node {
+                Node::BlankNode { ref id } => Some(id.clone()),
+                _ => None,
+            })
+            .collect();
+
+        MergeSink {
+            graph,
+            blank_nodes: BlankNodeScope::with_used_ids(ids),
+        }
+    }
+
+    /// Adds the triple with renamed blank nodes to the graph.
+    pub(crate) fn add_triple(&mut self, triple: &Triple) {
+        let mut rename = |node: &Node| match *node {
+            Node::BlankNode { ref id } => self.blank_nodes.labeled(id),
+            ref node => node.clone(),
+        };
+
+        let subject = rename(triple.subject());
+        let predicate = rename(triple.predicate());
+        let object = rename(triple.object());
+
+        self.graph
+            .add_triple(&Triple::new(&subject, &predicate, &object));
+    }
+}
+
+impl<'a> RdfSink for MergeSink<'a> {
+    fn base(&mut self, base_uri: &Uri) -> Result<()> {
+        self.graph.base(base_uri)
+    }
+
+    fn prefix(&mut self, namespace: &Namespace) -> Result<()> {
+        self.graph.prefix(namespace)
+    }
+
+    fn triple(&mut self, triple: Triple) -> Result<()> {
+        self.add_triple(&triple);
+        Ok(())
+    }
+}
+
+/// Sink that forwards to another sink and remembers if the other sink failed.
+///
+/// Lenient parsers use it to distinguish errors of the sink, that stop parsing, from
+/// errors in the input.
+pub(crate) struct CheckedSink<'a> {
+    sink: &'a mut dyn RdfSink,
+    failed: bool,
+}
+
+impl<'a> CheckedSink<'a> {
+    /// Constructor of `CheckedSink`.
+    pub(crate) fn new(sink: &'a mut dyn RdfSink) -> CheckedSink<'a> {
+        CheckedSink {
+            sink,
+            failed: false,
+        }
+    }
+
+    /// Returns `true` if the forwarded sink returned an error.
+    pub(crate) fn failed(&self) -> bool {
+        self.failed
+    }
+
+    /// Remembers if the result of the forwarded sink is an error.
+    fn check(&mut self, result: Result<()>) -> Result<()> {
+        self.failed = result.is_err();
+        result
+    }
+}
+
+impl<'a> RdfSink for CheckedSink<'a> {
+    fn base(&mut self, base_uri: &Uri) -> Result<()> {
+        let result = self.sink.base(base_uri);
+        self.check(result)
+    }
+
+    fn prefix(&mut self, namespace: &Namespace) -> Result<()> {
+        let result = self.sink.prefix(namespace);
+        self.check(result)
+    }
+
+    fn triple(&mut self, triple: Triple) -> Result<()> {
+        let result = self.sink.triple(triple);
+        self.check(result)
+    }
+}
+
+/// Sink that forwards to another sink with all literals of the triples in canonical form.
+pub(crate) struct CanonicalSink<'a> {
+    sink: &'a mut dyn RdfSink,
+}
+
+impl<'a> CanonicalSink<'a> {
+    /// Constructor of `CanonicalSink`.
+    pub(crate) fn new(sink: &'a mut dyn RdfSink) -> CanonicalSink<'a> {
+        CanonicalSink { sink }
+    }
+}
+
+impl<'a> RdfSink for CanonicalSink<'a> {
+    fn base(&mut self, base_uri: &Uri) -> Result<()> {
+        self.sink.base(base_uri)
+    }
+
+    fn prefix(&mut self, namespace: &Namespace) -> Result<()> {
+        self.sink.prefix(namespace)
+    }
+
+    fn triple(&mut self, triple: Triple) -> Result<()> {
+        self.sink.triple(triple.canonicalize())
+    }
+}
+
+/// Iterator over the triples of a streaming RDF parser.
+///
+/// Only the triples of the statement that is currently processed are kept in memory.
+/// The iteration stops after the first error.
+pub struct Triples<P: RdfStreamParser> {
+    parser: P,
+    buffer: TripleBuffer,
+    finished: bool,
+}
+
+impl<P: RdfStreamParser> Triples<P> {
+    /// Constructor of `Triples` for the provided parser.
+    pub fn new(parser: P) -> Triples<P> {
+        Triples {
+            parser,
+            buffer: TripleBuffer {
+                triples: VecDeque::new(),
+            },
+            finished: false,
+        }
+    }
+}
+
+impl<P: RdfStreamParser> Iterator for Triples<P> {
+    type Item = Result<Triple>;
+
+    fn next(&mut self) -> Option<Result<Triple>> {
+        loop {
+            if let Some(triple) = self.buffer.triples.pop_front() {
+                return Some(Ok(triple));
+            }
+
+            if self.finished {
+                return None;
+            }
+
+            match self.parser.parse_next(&mut self.buffer) {
+                Ok(true) => {}
+                Ok(false) => self.finished = true,
+                Err(err) => {
+                    self.finished = true;
+                    return Some(Err(err));
+                }
+            }
+        }
+    }
+}
+
+/// Sink that buffers the triples of a single statement.
+struct TripleBuffer {
+    triples: VecDeque<Triple>,
+}
+
+impl RdfSink for TripleBuffer {
+    fn triple(&mut self, triple: Triple) -> Result<()> {
+        self.triples.push_back(triple);
+        Ok(())
+    }
+}
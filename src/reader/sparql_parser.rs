@@ -1,4 +1,5 @@
 use crate::error::{Error, ErrorType};
+use crate::namespace::{Namespace, NamespaceStore};
 use crate::node::Node;
 use crate::reader::lexer::rdf_lexer::RdfLexer;
 use crate::reader::lexer::sparql_lexer::SparqlLexer;
@@ -14,6 +15,12 @@ use crate::Result;
 /// SPARQL parser to generate a `SparqlQuery` from SPARQL syntax.
 pub struct SparqlParser<R: Read> {
     lexer: SparqlLexer<R>,
+
+    /// Base URI that relative IRIs are resolved against, changed by `BASE` declarations.
+    base_uri: Option<Uri>,
+
+    /// Namespaces declared by `PREFIX` declarations.
+    namespaces: NamespaceStore,
 }
 
 impl SparqlParser<Cursor<Vec<u8>>> {
@@ -51,9 +58,33 @@ impl<R: Read> SparqlParser<R> {
     pub fn from_reader(input: R) -> SparqlParser<R> {
         SparqlParser {
             lexer: SparqlLexer::new(input),
+            base_uri: None,
+            namespaces: NamespaceStore::new(),
         }
     }
 
+    /// Sets the base URI for resolving relative IRIs of the query.
+    ///
+    /// `BASE` declarations in the query replace the base URI.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::sparql_parser::SparqlParser;
+    /// use rdf::uri::Uri;
+    ///
+    /// let input = "BASE <b/> SELECT ?a WHERE { ?a <p> ?b }";
+    /// let base_uri = Uri::parse("http://example.org/a/").unwrap();
+    ///
+    /// let query = SparqlParser::from_string(input).with_base_uri(base_uri).decode().unwrap();
+    ///
+    /// assert_eq!(query.base_uri(), &Some(Uri::parse("http://example.org/a/b/").unwrap()));
+    /// ```
+    pub fn with_base_uri(mut self, base_uri: Uri) -> SparqlParser<R> {
+        self.base_uri = Some(base_uri);
+        self
+    }
+
     /// Transforms a SPARQL query string into a `SparqlQuery` object.
     ///
     /// Returns an error if invalid SPARQL is provided.
@@ -67,12 +98,29 @@ impl<R: Read> SparqlParser<R> {
     /// - Invalid input that does not conform with SPARQL standard.
     ///
     pub fn decode(&mut self) -> Result<SparqlQuery> {
+        self.read_query()
+            .map_err(|err| self.lexer.locate_error(err))
+    }
+
+    /// Reads the query that starts at the current token.
+    fn read_query(&mut self) -> Result<SparqlQuery> {
         loop {
             match self.lexer.peek_next_token()? {
                 Token::Comment(_) => {
                     let _ = self.lexer.get_next_token();
                     continue;
                 }
+                Token::BaseDirective(uri) => {
+                    let _ = self.lexer.get_next_token();
+                    self.base_uri = Some(self.resolve_uri(&uri)?);
+                    continue;
+                }
+                Token::PrefixDirective(prefix, uri) => {
+                    let _ = self.lexer.get_next_token();
+                    let namespace = Namespace::new(prefix, self.resolve_uri(&uri)?);
+                    self.namespaces.add(&namespace);
+                    continue;
+                }
                 Token::Select => {
                     let _ = self.lexer.get_next_token();
                     return self.read_select_query();
@@ -148,9 +196,17 @@ impl<R: Read> SparqlParser<R> {
             }
         }
 
-        // instantiate the query
+        // instantiate the query with the declarations of the prologue
         let mut query = SparqlQuery::new(query_type);
 
+        if let Some(ref base_uri) = self.base_uri {
+            query.set_base_uri(base_uri);
+        }
+
+        for (prefix, uri) in self.namespaces.namespaces() {
+            query.add_namespace(&Namespace::new(prefix.clone(), uri.clone()));
+        }
+
         // parse WHERE clause
         match self.lexer.peek_next_token()? {
             Token::Where => {
@@ -179,6 +235,16 @@ impl<R: Read> SparqlParser<R> {
         Ok(query)
     }
 
+    /// Parses a URI and resolves it against the base URI, if there is one.
+    fn resolve_uri(&self, uri: &str) -> Result<Uri> {
+        let uri = Uri::parse_iri_ref(uri)?;
+
+        match self.base_uri {
+            Some(ref base_uri) => Ok(base_uri.resolve(&uri)),
+            None => Ok(uri),
+        }
+    }
+
     /// Parse and return the detected patterns.
     fn parse_group(&mut self, query: &mut SparqlQuery) -> Result<GroupPattern> {
         let mut group_pattern = GroupPattern::new();
@@ -236,7 +302,7 @@ impl<R: Read> SparqlParser<R> {
                 uri.append_resource_path(&path.replace(":", "/")); // adjust the QName path to URI path
                 Ok(NodePattern::FixedNode(Node::UriNode { uri: uri }))
             }
-            Token::Uri(uri) => Ok(NodePattern::FixedNode(Node::UriNode { uri: Uri::new(uri) })),
+            Token::Uri(uri) => Ok(NodePattern::FixedNode(Node::UriNode { uri: self.resolve_uri(&uri)? })),
             Token::SparqlVariable(variable_name) => Ok(NodePattern::VariableNode(variable_name)),
             _ => Err(Error::new(
                 ErrorType::InvalidToken,
@@ -292,7 +358,7 @@ impl<R: Read> SparqlParser<R> {
     ) -> Result<(NodePattern, NodePattern)> {
         // read the predicate
         let predicate = match self.lexer.get_next_token()? {
-            Token::Uri(uri) => NodePattern::FixedNode(Node::UriNode { uri: Uri::new(uri) }),
+            Token::Uri(uri) => NodePattern::FixedNode(Node::UriNode { uri: self.resolve_uri(&uri)? }),
             Token::KeywordA => NodePattern::FixedNode(Node::UriNode {
                 uri: RdfSyntaxDataTypes::A.to_uri(),
             }),
@@ -321,7 +387,7 @@ impl<R: Read> SparqlParser<R> {
     fn read_object_pattern(&mut self, query: &mut SparqlQuery) -> Result<NodePattern> {
         match self.lexer.get_next_token()? {
             Token::BlankNode(id) => Ok(NodePattern::FixedNode(Node::BlankNode { id: id })),
-            Token::Uri(uri) => Ok(NodePattern::FixedNode(Node::UriNode { uri: Uri::new(uri) })),
+            Token::Uri(uri) => Ok(NodePattern::FixedNode(Node::UriNode { uri: self.resolve_uri(&uri)? })),
             Token::QName(prefix, path) => {
                 let mut uri = query.get_namespace_uri_by_prefix(prefix)?.to_owned();
                 uri.append_resource_path(&path.replace(":", "/")); // adjust the QName path to URI path
@@ -338,7 +404,7 @@ impl<R: Read> SparqlParser<R> {
             Token::LiteralWithUrlDatatype(literal, datatype) => {
                 Ok(NodePattern::FixedNode(Node::LiteralNode {
                     literal: literal,
-                    data_type: Some(Uri::new(datatype)),
+                    data_type: Some(self.resolve_uri(&datatype)?),
                     language: None,
                 }))
             }
@@ -359,6 +425,7 @@ impl<R: Read> SparqlParser<R> {
 mod tests {
     use crate::reader::sparql_parser::SparqlParser;
     use crate::sparql::query::*;
+    use crate::uri::Uri;
 
     #[test]
     fn sparql_query_type_from_string() {
@@ -400,5 +467,28 @@ mod tests {
         }
     }
 
+    #[test]
+    fn sparql_prologue_from_string() {
+        let input = "BASE <http://example.org/a/>
+                     PREFIX ex: <b/>
+                     BASE <c/>
+                     PREFIX foaf: <http://xmlns.com/foaf/0.1/>
+                     SELECT ?name WHERE { ?a <name> ?name }";
+        let query = SparqlParser::from_string(input).decode().unwrap();
+
+        assert_eq!(
+            query.base_uri(),
+            &Some(Uri::parse("http://example.org/a/c/").unwrap())
+        );
+        assert_eq!(
+            query.get_namespace_uri_by_prefix("ex:".to_string()).unwrap(),
+            &Uri::parse("http://example.org/a/b/").unwrap()
+        );
+        assert_eq!(
+            query.get_namespace_uri_by_prefix("foaf:".to_string()).unwrap(),
+            &Uri::parse("http://xmlns.com/foaf/0.1/").unwrap()
+        );
+    }
+
     // todo: tests
 }
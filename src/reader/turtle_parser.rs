@@ -1,11 +1,13 @@
 use crate::error::{Error, ErrorType};
 use crate::graph::Graph;
-use crate::namespace::Namespace;
+use crate::namespace::{Namespace, NamespaceStore};
 use crate::node::Node;
+use crate::reader::blank_node_scope::BlankNodeScope;
 use crate::reader::lexer::rdf_lexer::RdfLexer;
 use crate::reader::lexer::token::Token;
 use crate::reader::lexer::turtle_lexer::TurtleLexer;
-use crate::reader::rdf_parser::RdfParser;
+use crate::reader::rdf_parser::{RdfParser, RdfStreamParser};
+use crate::reader::rdf_sink::{CanonicalSink, CheckedSink, RdfSink};
 use crate::specs::rdf_syntax_specs::RdfSyntaxDataTypes;
 use std::io::Cursor;
 use std::io::Read;
@@ -16,6 +18,21 @@ use crate::Result;
 /// RDF parser to generate an RDF graph from Turtle syntax.
 pub struct TurtleParser<R: Read> {
     lexer: TurtleLexer<R>,
+
+    /// Namespaces declared by the input so far.
+    namespaces: NamespaceStore,
+
+    /// Base URI that relative IRIs are resolved against, changed by base directives.
+    base_uri: Option<Uri>,
+
+    /// Blank nodes of the document.
+    blank_nodes: BlankNodeScope,
+    /// Invalid statements are skipped and reported as diagnostics in lenient mode.
+    lenient: bool,
+    diagnostics: Vec<Error>,
+
+    /// Literals are passed to the sink in canonical form.
+    canonical_literals: bool,
 }
 
 impl<R: Read> RdfParser for TurtleParser<R> {
@@ -47,45 +64,69 @@ impl<R: Read> RdfParser for TurtleParser<R> {
     ///
     fn decode(&mut self) -> Result<Graph> {
         let mut graph = Graph::new(None);
+        self.parse_all(&mut graph)?;
+        Ok(graph)
+    }
+}
 
-        loop {
-            match self.lexer.peek_next_token() {
-                Ok(Token::Comment(_)) => {
-                    let _ = self.lexer.get_next_token();
-                    continue;
-                }
-                Ok(Token::EndOfInput) => return Ok(graph),
-                Ok(Token::BaseDirective(_)) => {
-                    let base_uri = self.read_base_directive()?;
-                    graph.set_base_uri(&base_uri);
-                }
-                Ok(Token::PrefixDirective(_, _)) => {
-                    let namespace = self.read_prefix_directive()?;
-                    graph.add_namespace(&namespace);
-                }
-                Ok(Token::Uri(_))
-                | Ok(Token::BlankNode(_))
-                | Ok(Token::QName(_, _))
-                | Ok(Token::CollectionStart)
-                | Ok(Token::UnlabeledBlankNodeStart) => {
-                    let triples = self.read_triples(&mut graph)?;
-                    graph.add_triples(&triples);
-                }
-                Err(err) => match err.error_type() {
-                    &ErrorType::EndOfInput(_) => return Ok(graph),
-                    _ => {
-                        return Err(Error::new(
-                            ErrorType::InvalidReaderInput,
-                            "Error while parsing Turtle syntax.",
-                        ))
-                    }
-                },
-                Ok(_) => {
-                    return Err(Error::new(
-                        ErrorType::InvalidToken,
-                        "Invalid token while parsing Turtle syntax.",
-                    ))
+impl<R: Read> RdfStreamParser for TurtleParser<R> {
+    /// Parses the next directive or triples statement and passes it to the sink.
+    ///
+    /// Returns `false` if the end of the input has been reached.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::turtle_parser::TurtleParser;
+    /// use rdf::reader::rdf_parser::RdfStreamParser;
+    /// use rdf::node::Node;
+    /// use rdf::uri::Uri;
+    ///
+    /// let input = "@prefix foaf: <http://xmlns.com/foaf/0.1/> .
+    ///              <http://www.w3.org/2001/sw/RDFCore/ntriples/> foaf:maker _:art .
+    ///              _:art foaf:name \"Art Barstow\" .";
+    ///
+    /// let reader = TurtleParser::from_string(input.to_string());
+    /// let names = reader.triples()
+    ///   .filter_map(|triple| triple.ok())
+    ///   .filter(|triple| triple.predicate() == &Node::UriNode {
+    ///     uri: Uri::parse("http://xmlns.com/foaf/0.1/name").unwrap()
+    ///   })
+    ///   .count();
+    ///
+    /// assert_eq!(names, 1);
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - Invalid input that does not conform with Turtle standard.
+    /// - Invalid node type for triple segment.
+    /// - The sink returns an error.
+    ///
+    fn parse_next(&mut self, sink: &mut dyn RdfSink) -> Result<bool> {
+        let mut canonical_sink;
+        let sink: &mut dyn RdfSink = if self.canonical_literals {
+            canonical_sink = CanonicalSink::new(sink);
+            &mut canonical_sink
+        } else {
+            sink
+        };
+
+        let mut checked_sink = CheckedSink::new(sink);
+
+        match self.parse_statement(&mut checked_sink) {
+            Ok(more) => Ok(more),
+            Err(err) => {
+                let err = self.lexer.locate_error(err);
+
+                // errors of the sink are never skipped
+                if !self.lenient || checked_sink.failed() {
+                    return Err(err);
                 }
+
+                self.diagnostics.push(err);
+                self.lexer.skip_statement()?;
+                Ok(true)
             }
         }
     }
@@ -130,19 +171,242 @@ impl<R: Read> TurtleParser<R> {
     pub fn from_reader(input: R) -> TurtleParser<R> {
         TurtleParser {
             lexer: TurtleLexer::new(input),
+            namespaces: NamespaceStore::new(),
+            base_uri: None,
+            blank_nodes: BlankNodeScope::new(),
+            lenient: false,
+            diagnostics: Vec::new(),
+            canonical_literals: false,
         }
     }
 
-    /// Parses prefix directives and returns the created namespace.
+    /// Enables the lenient mode that skips invalid statements instead of failing.
+    ///
+    /// The errors of skipped statements are collected as diagnostics, errors returned by
+    /// the sink still stop parsing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::turtle_parser::TurtleParser;
+    /// use rdf::reader::rdf_parser::RdfParser;
+    ///
+    /// let input = "<http://example.org/a> <http://example.org/b> \"c\" .
+    ///              <http://example.org/a> \"invalid\" <http://example.org/d> .
+    ///              <http://example.org/a> <http://example.org/b> \"e\" .";
+    ///
+    /// let mut reader = TurtleParser::from_string(input).lenient();
+    /// let graph = reader.decode().unwrap();
+    ///
+    /// assert_eq!(graph.count(), 2);
+    /// assert_eq!(reader.diagnostics().len(), 1);
+    /// assert_eq!(reader.diagnostics()[0].location().unwrap().start().line(), 2);
+    /// ```
+    pub fn lenient(mut self) -> TurtleParser<R> {
+        self.lenient = true;
+        self
+    }
+
+    /// Sets the base URI for resolving relative IRIs, e.g. the location of the document.
+    ///
+    /// Base directives in the input replace the base URI.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::turtle_parser::TurtleParser;
+    /// use rdf::reader::rdf_parser::RdfParser;
+    /// use rdf::uri::Uri;
+    ///
+    /// let input = "<a> <b> <#c> .";
+    /// let base_uri = Uri::parse("http://example.org/doc").unwrap();
+    ///
+    /// let graph = TurtleParser::from_string(input).with_base_uri(base_uri).decode().unwrap();
+    /// let triple = graph.triples_iter().next().unwrap();
+    ///
+    /// assert_eq!(*triple.subject(),
+    ///            graph.create_uri_node(&Uri::parse("http://example.org/a").unwrap()));
+    /// assert_eq!(*triple.object(),
+    ///            graph.create_uri_node(&Uri::parse("http://example.org/doc#c").unwrap()));
+    /// ```
+    pub fn with_base_uri(mut self, base_uri: Uri) -> TurtleParser<R> {
+        self.base_uri = Some(base_uri);
+        self
+    }
+
+    /// Passes literals in canonical form to the sink.
+    ///
+    /// Literals with a supported XML schema data type are converted to their canonical
+    /// lexical form and language tags to lower case, see `Node::canonicalize`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::turtle_parser::TurtleParser;
+    /// use rdf::reader::rdf_parser::RdfParser;
+    /// use rdf::node::Node;
+    ///
+    /// let input = "<http://example.org/a> <http://example.org/b> \"+1.50\"^^<http://www.w3.org/2001/XMLSchema#decimal> .
+    ///              <http://example.org/a> <http://example.org/b> \"chat\"@fr-CA .";
+    ///
+    /// let graph = TurtleParser::from_string(input).canonicalize_literals().decode().unwrap();
+    /// let literals: Vec<String> = graph.triples_iter().map(|triple| match *triple.object() {
+    ///   Node::LiteralNode { ref literal, ref language, .. } =>
+    ///     format!("{}{}", literal, language.as_ref().map(|l| "@".to_string() + l).unwrap_or_default()),
+    ///   _ => String::new(),
+    /// }).collect();
+    ///
+    /// assert_eq!(literals, vec!["1.5", "chat@fr-ca"]);
+    /// ```
+    pub fn canonicalize_literals(mut self) -> TurtleParser<R> {
+        self.canonical_literals = true;
+        self
+    }
+
+    /// Returns the errors of the statements that were skipped in lenient mode.
+    pub fn diagnostics(&self) -> &[Error] {
+        &self.diagnostics
+    }
+
+    /// Generates an RDF graph and returns it together with the collected diagnostics.
+    ///
+    /// Without lenient mode, the diagnostics are always empty.
+    ///
+    /// # Failures
+    ///
+    /// - Invalid input if the lenient mode is not enabled.
+    ///
+    pub fn decode_with_diagnostics(&mut self) -> Result<(Graph, Vec<Error>)> {
+        let graph = self.decode()?;
+        Ok((graph, self.diagnostics.drain(..).collect()))
+    }
+
+    /// Parses the next statement of the input and passes it to the sink.
+    fn parse_statement(&mut self, sink: &mut dyn RdfSink) -> Result<bool> {
+        match self.peek_token() {
+            Ok(Token::EndOfInput) => return Ok(false),
+            Ok(Token::BaseDirective(_)) | Ok(Token::SparqlBaseDirective(_)) => {
+                let base_uri = self.read_base_directive()?;
+                self.base_uri = Some(base_uri.clone());
+                sink.base(&base_uri)?;
+            }
+            Ok(Token::PrefixDirective(_, _)) | Ok(Token::SparqlPrefixDirective(_, _)) => {
+                let namespace = self.read_prefix_directive()?;
+                self.namespaces.add(&namespace);
+                sink.prefix(&namespace)?;
+            }
+            Ok(Token::Uri(_))
+            | Ok(Token::BlankNode(_))
+            | Ok(Token::QName(_, _))
+            | Ok(Token::CollectionStart)
+            | Ok(Token::UnlabeledBlankNodeStart) => {
+                // triples of a statement are buffered until the statement is complete
+                let mut triples = Vec::new();
+                self.read_triples(&mut triples)?;
+
+                for triple in triples {
+                    sink.triple(triple)?;
+                }
+            }
+            Err(err) => match err.error_type() {
+                &ErrorType::EndOfInput(_) => return Ok(false),
+                _ => return Err(err),
+            },
+            Ok(_) => {
+                let _ = self.next_token(); // locate the error at the invalid token
+
+                return Err(Error::new(
+                    ErrorType::InvalidToken,
+                    "Invalid token while parsing Turtle syntax.",
+                ));
+            }
+        }
+
+        Ok(true)
+    }
+
+    /// Returns the next token that is not a comment.
+    fn next_token(&mut self) -> Result<Token> {
+        loop {
+            match self.lexer.get_next_token()? {
+                Token::Comment(_) => continue,
+                token => return Ok(token),
+            }
+        }
+    }
+
+    /// Returns the next token that is not a comment without consuming it.
+    fn peek_token(&mut self) -> Result<Token> {
+        loop {
+            match self.lexer.peek_next_token()? {
+                Token::Comment(_) => {
+                    let _ = self.lexer.get_next_token();
+                }
+                token => return Ok(token),
+            }
+        }
+    }
+
+    /// Consumes the next token and checks if it is the expected token.
+    fn expect_token(&mut self, expected: Token, message: &str) -> Result<()> {
+        if self.next_token()? == expected {
+            Ok(())
+        } else {
+            Err(Error::new(ErrorType::InvalidToken, message))
+        }
+    }
+
+    /// Creates a blank node with an ID that is unique within the input.
+    fn create_blank_node(&mut self) -> Node {
+        self.blank_nodes.generate()
+    }
+
+    /// Parses a URI and resolves it against the base URI, if there is one.
+    fn resolve_uri(&self, uri: &str) -> Result<Uri> {
+        let uri = Uri::parse_iri_ref(uri)?;
+
+        match self.base_uri {
+            Some(ref base_uri) => Ok(base_uri.resolve(&uri)),
+            None => Ok(uri),
+        }
+    }
+
+    /// Creates a URI node, relative URIs are resolved against the base URI.
+    fn create_uri_node(&self, uri: &str) -> Result<Node> {
+        Ok(Node::UriNode {
+            uri: self.resolve_uri(uri)?,
+        })
+    }
+
+    /// Creates a URI node from a QName using the declared namespaces.
+    fn create_qname_node(&self, prefix: &str, path: &str) -> Result<Node> {
+        let mut uri = self.namespaces.get_uri_by_prefix(prefix)?.to_owned();
+        uri.append_resource_path(path);
+
+        // the local name might contain characters that are not allowed in IRIs
+        if !uri.is_valid_iri_ref() {
+            return Err(Error::new(
+                ErrorType::InvalidUri,
+                "Invalid IRI for QName: ".to_string() + uri.to_string(),
+            ));
+        }
+
+        Ok(Node::UriNode { uri })
+    }
+
+    /// Parses base directives and returns the base URI resolved against the current base.
+    ///
+    /// Only base directives starting with '@' end with '.'.
     fn read_base_directive(&mut self) -> Result<Uri> {
-        match self.lexer.get_next_token()? {
-            Token::BaseDirective(uri) => match self.lexer.get_next_token()? {
-                Token::TripleDelimiter => Ok(Uri::new(uri)),
-                _ => Err(Error::new(
-                    ErrorType::InvalidReaderInput,
+        match self.next_token()? {
+            Token::BaseDirective(uri) => {
+                self.expect_token(
+                    Token::TripleDelimiter,
                     "Turtle base directive does not end with '.'",
-                )),
-            },
+                )?;
+                self.resolve_uri(&uri)
+            }
+            Token::SparqlBaseDirective(uri) => self.resolve_uri(&uri),
             _ => Err(Error::new(
                 ErrorType::InvalidReaderInput,
                 "Invalid input for Turtle base directive.",
@@ -151,15 +415,20 @@ impl<R: Read> TurtleParser<R> {
     }
 
     /// Parses prefix directives and returns the created namespace.
+    ///
+    /// Only prefix directives starting with '@' end with '.'.
     fn read_prefix_directive(&mut self) -> Result<Namespace> {
-        match self.lexer.get_next_token()? {
-            Token::PrefixDirective(prefix, uri) => match self.lexer.get_next_token()? {
-                Token::TripleDelimiter => Ok(Namespace::new(prefix, Uri::new(uri))),
-                _ => Err(Error::new(
-                    ErrorType::InvalidReaderInput,
+        match self.next_token()? {
+            Token::PrefixDirective(prefix, uri) => {
+                self.expect_token(
+                    Token::TripleDelimiter,
                     "Turtle prefix directive does not end with '.'",
-                )),
-            },
+                )?;
+                Ok(Namespace::new(prefix, self.resolve_uri(&uri)?))
+            }
+            Token::SparqlPrefixDirective(prefix, uri) => {
+                Ok(Namespace::new(prefix, self.resolve_uri(&uri)?))
+            }
             _ => Err(Error::new(
                 ErrorType::InvalidReaderInput,
                 "Invalid input for Turtle prefix.",
@@ -167,25 +436,47 @@ impl<R: Read> TurtleParser<R> {
         }
     }
 
-    /// Creates a triple from the parsed tokens.
-    fn read_triples(&mut self, graph: &mut Graph) -> Result<Vec<Triple>> {
-        let subject = self.read_subject(graph)?;
+    /// Reads the triples of a statement and adds them to the provided buffer.
+    fn read_triples(&mut self, triples: &mut Vec<Triple>) -> Result<()> {
+        if self.peek_token()? == Token::UnlabeledBlankNodeStart {
+            let _ = self.next_token()?; // consume '['
+
+            let subject = self.create_blank_node();
 
-        self.read_predicate_object_list(&subject, graph)
+            if self.peek_token()? == Token::UnlabeledBlankNodeEnd {
+                // '[]' is a subject that requires a predicate object list
+                let _ = self.next_token()?; // consume ']'
+                self.read_predicate_object_list(&subject, triples)?;
+            } else {
+                // a blank node property list may be a statement on its own
+                self.read_predicate_object_list(&subject, triples)?;
+                self.expect_token(
+                    Token::UnlabeledBlankNodeEnd,
+                    "Turtle blank node property list does not end with ']'",
+                )?;
+
+                if self.peek_token()? != Token::TripleDelimiter {
+                    self.read_predicate_object_list(&subject, triples)?;
+                }
+            }
+        } else {
+            let subject = self.read_subject(triples)?;
+            self.read_predicate_object_list(&subject, triples)?;
+        }
+
+        self.expect_token(
+            Token::TripleDelimiter,
+            "Turtle triples do not end with '.'",
+        )
     }
 
     /// Get the next token and check if it is a valid subject and create a new subject node.
-    fn read_subject(&mut self, graph: &mut Graph) -> Result<Node> {
-        match self.lexer.get_next_token()? {
-            Token::BlankNode(id) => Ok(Node::BlankNode { id }),
-            Token::QName(prefix, path) => {
-                let mut uri = graph.get_namespace_uri_by_prefix(&prefix)?.to_owned();
-                uri.append_resource_path(&path.replace(":", "/")); // adjust the QName path to URI path
-                Ok(Node::UriNode { uri })
-            }
-            Token::Uri(uri) => Ok(graph.create_uri_node_str(&uri)),
-            Token::CollectionStart => self.read_collection(graph),
-            Token::UnlabeledBlankNodeStart => self.read_unlabeled_blank_node(graph),
+    fn read_subject(&mut self, triples: &mut Vec<Triple>) -> Result<Node> {
+        match self.next_token()? {
+            Token::BlankNode(id) => Ok(self.blank_nodes.labeled(&id)),
+            Token::QName(prefix, path) => self.create_qname_node(&prefix, &path),
+            Token::Uri(uri) => self.create_uri_node(&uri),
+            Token::CollectionStart => self.read_collection(triples),
             _ => Err(Error::new(
                 ErrorType::InvalidToken,
                 "Invalid token for Turtle subject.",
@@ -193,79 +484,73 @@ impl<R: Read> TurtleParser<R> {
         }
     }
 
-    /// Reads a list or a single pair of predicate and object nodes.
+    /// Reads the predicates and their object lists that belong to the subject.
+    ///
+    /// Predicates are separated by one or more ';', which may also follow the last object list.
     fn read_predicate_object_list(
         &mut self,
         subject: &Node,
-        graph: &mut Graph,
-    ) -> Result<Vec<Triple>> {
-        let mut triples: Vec<Triple> = Vec::new();
+        triples: &mut Vec<Triple>,
+    ) -> Result<()> {
+        loop {
+            let predicate = self.read_predicate()?;
+            self.read_object_list(subject, &predicate, triples)?;
 
-        let (predicate, object) = self.read_predicate_with_object(graph)?;
-        triples.push(Triple::new(subject, &predicate, &object));
+            if self.peek_token()? != Token::PredicateListDelimiter {
+                return Ok(());
+            }
 
-        loop {
-            match self.lexer.get_next_token()? {
-                Token::TripleDelimiter => break,
-                Token::UnlabeledBlankNodeEnd => break,
-                Token::PredicateListDelimiter => {
-                    let (predicate, object) = self.read_predicate_with_object(graph)?;
-                    triples.push(Triple::new(subject, &predicate, &object));
-                }
-                Token::ObjectListDelimiter => {
-                    let object = self.read_object(graph)?;
-                    triples.push(Triple::new(subject, &predicate, &object));
-                }
-                _ => {
-                    return Err(Error::new(
-                        ErrorType::InvalidToken,
-                        "Invalid token while reading Turtle triples.",
-                    ))
-                }
+            while self.peek_token()? == Token::PredicateListDelimiter {
+                let _ = self.next_token()?; // consume ';'
             }
-        }
 
-        Ok(triples)
+            match self.peek_token()? {
+                Token::Uri(_) | Token::QName(_, _) | Token::KeywordA => {}
+                _ => return Ok(()),
+            }
+        }
     }
 
-    /// Get the next token and check if it is a valid predicate and create a new predicate node.
-    fn read_predicate_with_object(&mut self, graph: &mut Graph) -> Result<(Node, Node)> {
-        // read the predicate
-        let predicate = match self.lexer.get_next_token()? {
-            Token::Uri(uri) => graph.create_uri_node_str(&uri),
-            Token::KeywordA => Node::UriNode {
-                uri: RdfSyntaxDataTypes::A.to_uri(),
-            },
-            Token::QName(prefix, path) => {
-                let mut uri = graph.get_namespace_uri_by_prefix(&prefix)?.to_owned();
-                uri.append_resource_path(&path.replace(":", "/")); // adjust the QName path to URI path
-                Node::UriNode { uri }
-            }
-            Token::BlankNode(id) => Node::BlankNode { id },
-            _ => {
-                return Err(Error::new(
-                    ErrorType::InvalidToken,
-                    "Invalid token for Turtle predicate.",
-                ))
+    /// Reads the objects that are separated by ',' and belong to the subject and predicate.
+    fn read_object_list(
+        &mut self,
+        subject: &Node,
+        predicate: &Node,
+        triples: &mut Vec<Triple>,
+    ) -> Result<()> {
+        loop {
+            let object = self.read_object(triples)?;
+            triples.push(Triple::new(subject, predicate, &object));
+
+            if self.peek_token()? != Token::ObjectListDelimiter {
+                return Ok(());
             }
-        };
 
-        // read the object
-        let object = self.read_object(graph)?;
+            let _ = self.next_token()?; // consume ','
+        }
+    }
 
-        Ok((predicate, object))
+    /// Get the next token and check if it is a valid predicate and create a new predicate node.
+    fn read_predicate(&mut self) -> Result<Node> {
+        match self.next_token()? {
+            Token::Uri(uri) => self.create_uri_node(&uri),
+            Token::KeywordA => Ok(Node::UriNode {
+                uri: RdfSyntaxDataTypes::A.to_uri(),
+            }),
+            Token::QName(prefix, path) => self.create_qname_node(&prefix, &path),
+            _ => Err(Error::new(
+                ErrorType::InvalidToken,
+                "Invalid token for Turtle predicate.",
+            )),
+        }
     }
 
     /// Get the next token and check if it is a valid object and create a new object node.
-    fn read_object(&mut self, graph: &mut Graph) -> Result<Node> {
-        match self.lexer.get_next_token()? {
-            Token::BlankNode(id) => Ok(Node::BlankNode { id }),
-            Token::Uri(uri) => Ok(graph.create_uri_node_str(&uri)),
-            Token::QName(prefix, path) => {
-                let mut uri = graph.get_namespace_uri_by_prefix(&prefix)?.to_owned();
-                uri.append_resource_path(&path.replace(":", "/")); // adjust the QName path to URI path
-                Ok(Node::UriNode { uri })
-            }
+    fn read_object(&mut self, triples: &mut Vec<Triple>) -> Result<Node> {
+        match self.next_token()? {
+            Token::BlankNode(id) => Ok(self.blank_nodes.labeled(&id)),
+            Token::Uri(uri) => self.create_uri_node(&uri),
+            Token::QName(prefix, path) => self.create_qname_node(&prefix, &path),
             Token::LiteralWithLanguageSpecification(literal, lang) => Ok(Node::LiteralNode {
                 literal,
                 data_type: None,
@@ -273,16 +558,29 @@ impl<R: Read> TurtleParser<R> {
             }),
             Token::LiteralWithUrlDatatype(literal, datatype) => Ok(Node::LiteralNode {
                 literal,
-                data_type: Some(Uri::new(datatype)),
+                data_type: Some(self.resolve_uri(&datatype)?),
                 language: None,
             }),
+            Token::LiteralWithQNameDatatype(literal, prefix, path) => {
+                match self.create_qname_node(&prefix, &path)? {
+                    Node::UriNode { uri } => Ok(Node::LiteralNode {
+                        literal,
+                        data_type: Some(uri),
+                        language: None,
+                    }),
+                    _ => Err(Error::new(
+                        ErrorType::InvalidToken,
+                        "Invalid data type for Turtle literal.",
+                    )),
+                }
+            }
             Token::Literal(literal) => Ok(Node::LiteralNode {
                 literal,
                 data_type: None,
                 language: None,
             }),
-            Token::CollectionStart => self.read_collection(graph),
-            Token::UnlabeledBlankNodeStart => self.read_unlabeled_blank_node(graph),
+            Token::CollectionStart => self.read_collection(triples),
+            Token::UnlabeledBlankNodeStart => self.read_unlabeled_blank_node(triples),
             _ => Err(Error::new(
                 ErrorType::InvalidToken,
                 "Invalid token for Turtle object.",
@@ -292,88 +590,76 @@ impl<R: Read> TurtleParser<R> {
 
     /// Reads a unlabeled blank node.
     ///
-    /// Returns the subject node and add all other nested nodes to the graph.
-    fn read_unlabeled_blank_node(&mut self, graph: &mut Graph) -> Result<Node> {
-        let subject = graph.create_blank_node();
+    /// Returns the subject node and adds all other nested triples to the buffer.
+    fn read_unlabeled_blank_node(&mut self, triples: &mut Vec<Triple>) -> Result<Node> {
+        let subject = self.create_blank_node();
 
-        if self.lexer.peek_next_token()? == Token::UnlabeledBlankNodeEnd {
-            let _ = self.lexer.get_next_token()?; // consume the token indicating the node end ']'
-        } else {
-            let triples = self.read_predicate_object_list(&subject, graph)?;
-            graph.add_triples(&triples);
+        if self.peek_token()? != Token::UnlabeledBlankNodeEnd {
+            self.read_predicate_object_list(&subject, triples)?;
         }
 
+        self.expect_token(
+            Token::UnlabeledBlankNodeEnd,
+            "Turtle blank node property list does not end with ']'",
+        )?;
+
         Ok(subject)
     }
 
     /// Reads a collection and returns the collection start as node.
     ///
-    /// The remaining elements are implicitly added to the graph.
-    fn read_collection(&mut self, graph: &mut Graph) -> Result<Node> {
-        // check if the list is empty and return list:nil
-        if self.lexer.peek_next_token()? == Token::CollectionEnd {
-            let _ = self.lexer.get_next_token()?; // consume the token indicating the collection end ')'
+    /// The remaining elements are implicitly added to the buffer.
+    fn read_collection(&mut self, triples: &mut Vec<Triple>) -> Result<Node> {
+        let mut elements = Vec::new();
 
-            return Ok(Node::UriNode {
-                uri: RdfSyntaxDataTypes::ListNil.to_uri(),
-            });
+        while self.peek_token()? != Token::CollectionEnd {
+            elements.push(self.read_object(triples)?);
         }
 
-        // for non-empty list generate blank node
-        let subject = graph.create_blank_node();
+        let _ = self.next_token()?; // consume the token indicating the collection end ')'
 
-        let mut next_subject = subject.to_owned();
+        // the list is built from the end, an empty list is list:nil
+        let mut rest = Node::UriNode {
+            uri: RdfSyntaxDataTypes::ListNil.to_uri(),
+        };
 
-        loop {
-            let rest = graph.create_blank_node();
-            let object = self.read_object(graph)?;
+        let first_nodes: Vec<Node> = elements.iter().map(|_| self.create_blank_node()).collect();
 
-            graph.add_triple(&Triple::new(
-                &next_subject,
+        for (element, node) in elements.iter().zip(first_nodes).rev() {
+            triples.push(Triple::new(
+                &node,
                 &Node::UriNode {
                     uri: RdfSyntaxDataTypes::ListFirst.to_uri(),
                 },
-                &object,
+                element,
+            ));
+            triples.push(Triple::new(
+                &node,
+                &Node::UriNode {
+                    uri: RdfSyntaxDataTypes::ListRest.to_uri(),
+                },
+                &rest,
             ));
 
-            // check if the rest of the list is nil
-            if self.lexer.peek_next_token()? == Token::CollectionEnd {
-                let _ = self.lexer.get_next_token()?; // consume the token indicating the collection end ')'
-
-                // create list:nil node
-                graph.add_triple(&Triple::new(
-                    &next_subject,
-                    &Node::UriNode {
-                        uri: RdfSyntaxDataTypes::ListRest.to_uri(),
-                    },
-                    &Node::UriNode {
-                        uri: RdfSyntaxDataTypes::ListNil.to_uri(),
-                    },
-                ));
-                break; // stop further list evaluation
-            } else {
-                // create node referring to the non-empty rest of the list
-                graph.add_triple(&Triple::new(
-                    &next_subject,
-                    &Node::UriNode {
-                        uri: RdfSyntaxDataTypes::ListRest.to_uri(),
-                    },
-                    &rest,
-                ));
-            }
-
-            next_subject = rest;
+            rest = node;
         }
 
-        Ok(subject)
+        Ok(rest)
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::reader::rdf_parser::RdfParser;
+    use crate::graph::Graph;
+    use crate::error::{Error, ErrorType};
+    use crate::namespace::Namespace;
+    use crate::node::Node;
+    use crate::reader::rdf_parser::{RdfParser, RdfStreamParser};
+    use crate::reader::rdf_sink::RdfSink;
+    use crate::triple::Triple;
     use crate::reader::turtle_parser::TurtleParser;
     use crate::uri::Uri;
+    use std::collections::BTreeSet;
 
     #[test]
     fn test_read_n_triples_as_turtle_from_string() {
@@ -412,7 +698,7 @@ mod tests {
                 assert_eq!(graph.namespaces().len(), 2);
                 assert_eq!(
                     graph.base_uri(),
-                    &Some(Uri::new("http://example.org/".to_string()))
+                    &Some(Uri::parse("http://example.org/").unwrap())
                 )
             }
             Err(e) => {
@@ -444,7 +730,7 @@ mod tests {
                 assert_eq!(graph.namespaces().len(), 2);
                 assert_eq!(
                     graph.base_uri(),
-                    &Some(Uri::new("http://example.org/".to_string()))
+                    &Some(Uri::parse("http://example.org/").unwrap())
                 )
             }
             Err(e) => {
@@ -454,6 +740,34 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_object_list_after_predicate_list_delimiter() {
+        let input = "<http://example.org/s> <http://example.org/p> <http://example.org/a> ;
+                                            <http://example.org/q> <http://example.org/b> , <http://example.org/c> .";
+
+        let graph = TurtleParser::from_string(input.to_string())
+            .decode()
+            .unwrap();
+
+        let subject = graph.create_uri_node(&Uri::parse("http://example.org/s").unwrap());
+        let p = graph.create_uri_node(&Uri::parse("http://example.org/p").unwrap());
+        let q = graph.create_uri_node(&Uri::parse("http://example.org/q").unwrap());
+
+        // the object after ',' belongs to the predicate after ';'
+        assert_eq!(
+            graph
+                .get_triples_with_subject_and_predicate(&subject, &p)
+                .len(),
+            1
+        );
+        assert_eq!(
+            graph
+                .get_triples_with_subject_and_predicate(&subject, &q)
+                .len(),
+            2
+        );
+    }
+
     #[test]
     fn test_parsing_turtle_base_uri() {
         let input = "@base <http://example/> .";
@@ -462,7 +776,7 @@ mod tests {
         match reader.decode() {
             Ok(graph) => assert_eq!(
                 graph.base_uri(),
-                &Some(Uri::new("http://example/".to_string()))
+                &Some(Uri::parse("http://example/").unwrap())
             ),
             Err(e) => {
                 println!("Err {}", e.to_string());
@@ -473,13 +787,13 @@ mod tests {
 
     #[test]
     fn test_parsing_turtle_sparql_base_uri() {
-        let input = "BASE <http://example/> .";
+        let input = "BASE <http://example/>";
         let mut reader = TurtleParser::from_string(input.to_string());
 
         match reader.decode() {
             Ok(graph) => assert_eq!(
                 graph.base_uri(),
-                &Some(Uri::new("http://example/".to_string()))
+                &Some(Uri::parse("http://example/").unwrap())
             ),
             Err(e) => {
                 println!("Err {}", e.to_string());
@@ -504,7 +818,7 @@ mod tests {
 
     #[test]
     fn test_parsing_turtle_sparql_prefix() {
-        let input = "PREFIX p: <http://p.example/> .";
+        let input = "PREFIX p: <http://p.example/>";
         let mut reader = TurtleParser::from_string(input.to_string());
 
         match reader.decode() {
@@ -534,7 +848,7 @@ mod tests {
 
     #[test]
     fn read_collection_from_string() {
-        let input = "_:a _:b ( _:c _:g ) . ";
+        let input = "_:a <http://example/b> ( _:c _:g ) . ";
 
         let mut reader = TurtleParser::from_string(input.to_string());
 
@@ -549,7 +863,7 @@ mod tests {
 
     #[test]
     fn read_empty_collection_from_string() {
-        let input = "() _:b (  ) .";
+        let input = "() <http://example/b> (  ) .";
 
         let mut reader = TurtleParser::from_string(input.to_string());
 
@@ -564,7 +878,7 @@ mod tests {
 
     #[test]
     fn read_nested_collections_from_string() {
-        let input = "( _:a (_:b _:c ) ) _:b ( _:b ( ( ( ) ) ) ) .";
+        let input = "( _:a (_:b _:c ) ) <http://example/b> ( _:b ( ( ( ) ) ) ) .";
 
         let mut reader = TurtleParser::from_string(input.to_string());
 
@@ -579,7 +893,7 @@ mod tests {
 
     #[test]
     fn read_empty_unlabeled_node_from_string() {
-        let input = "[ ] _:b [ ] .";
+        let input = "[ ] <http://example/b> [ ] .";
 
         let mut reader = TurtleParser::from_string(input.to_string());
 
@@ -594,10 +908,11 @@ mod tests {
 
     #[test]
     fn read_unlabeled_nodes_from_string() {
-        let input = "[ _:a _:g ] _:b [ _:c [
-      _:s _:d ,
-          [ _:asd _:asdf ] ;
-      _:g _:h
+        let input = "@prefix ex: <http://example/> .
+    [ ex:a _:g ] ex:b [ ex:c [
+      ex:s _:d ,
+          [ ex:asd _:asdf ] ;
+      ex:g _:h
     ] ] .";
 
         let mut reader = TurtleParser::from_string(input.to_string());
@@ -621,17 +936,261 @@ mod tests {
                 assert_eq!(graph.count(), 1);
                 assert_eq!(
                     graph.base_uri(),
-                    &Some(Uri::new("https://example.com/alice/card".to_string()))
+                    &Some(Uri::parse("https://example.com/alice/card").unwrap())
                 );
                 match graph.triples_iter().next(){
                     Some(t) => assert_eq!(
-                        &graph.create_uri_node(&Uri::new("https://example.com/alice/card#this".to_string()))
-                        ,t.subject()),
-                    None => assert!(false)
+                        &graph.create_uri_node(
+                            &Uri::parse("https://example.com/alice/card#this").unwrap()
+                        ),
+                        t.subject()
+                    ),
+                    None => assert!(false),
                 }
 
                 }    ,
             Err(e) => panic!("Err {}", e.to_string())
         }
     }
+
+    #[test]
+    fn stream_turtle_statements_to_sink() {
+        struct Recorder {
+            events: Vec<String>,
+        }
+
+        impl RdfSink for Recorder {
+            fn base(&mut self, base_uri: &Uri) -> crate::Result<()> {
+                self.events.push("base ".to_string() + base_uri.to_string());
+                Ok(())
+            }
+
+            fn prefix(&mut self, namespace: &Namespace) -> crate::Result<()> {
+                self.events.push("prefix ".to_string() + namespace.prefix());
+                Ok(())
+            }
+
+            fn triple(&mut self, _: Triple) -> crate::Result<()> {
+                self.events.push("triple".to_string());
+                Ok(())
+            }
+        }
+
+        let input = "@base <http://example.org/> .
+                 @prefix foaf: <http://xmlns.com/foaf/0.1/> .
+                 <#alice> foaf:knows [ foaf:name \"Bob\" ] .";
+
+        let mut recorder = Recorder { events: Vec::new() };
+        let mut reader = TurtleParser::from_string(input);
+
+        assert!(reader.parse_next(&mut recorder).unwrap());
+        assert_eq!(recorder.events, vec!["base http://example.org/"]);
+
+        reader.parse_all(&mut recorder).unwrap();
+        assert_eq!(
+            recorder.events,
+            vec!["base http://example.org/", "prefix foaf:", "triple", "triple"]
+        );
+    }
+
+    #[test]
+    fn iterate_turtle_triples() {
+        let input = "@prefix : <http://example/> .
+                 :a :b ( :c :d ) .
+                 :a :b :e .";
+
+        let triples: Vec<Triple> = TurtleParser::from_string(input)
+            .triples()
+            .collect::<crate::Result<Vec<Triple>>>()
+            .unwrap();
+
+        assert_eq!(triples.len(), 6);
+    }
+
+    #[test]
+    fn decoded_blank_nodes_do_not_clash_with_new_blank_nodes() {
+        let input = "_:a <http://example/b> [ <http://example/c> _:d ] .";
+
+        let mut graph = TurtleParser::from_string(input).decode().unwrap();
+        let blank_node = graph.create_blank_node();
+
+        assert_eq!(graph.get_triples_with_object(&blank_node).len(), 0);
+        assert_eq!(graph.get_triples_with_subject(&blank_node).len(), 0);
+    }
+
+    #[test]
+    fn labeled_blank_nodes_do_not_clash_with_generated_blank_nodes() {
+        let input = "[ <http://example/b> \"c\" ] .
+                     _:auto0 <http://example/b> \"d\" .";
+
+        let graph = TurtleParser::from_string(input).decode().unwrap();
+        let subjects: Vec<&Node> = graph.triples_iter().map(|t| t.subject()).collect();
+
+        assert_eq!(subjects.len(), 2);
+        assert_ne!(subjects[0], subjects[1]);
+    }
+
+    #[test]
+    fn parse_documents_into_graph() {
+        let mut graph = Graph::new(None);
+
+        for name in &["\"Alice\"", "\"Bob\""] {
+            let input = format!(
+                "_:b1 <http://example/name> {} ; <http://example/knows> [] .",
+                name
+            );
+            TurtleParser::from_string(input)
+                .parse_into(&mut graph)
+                .unwrap();
+        }
+
+        let subjects: BTreeSet<&Node> = graph.triples_iter().map(|t| t.subject()).collect();
+        let objects: BTreeSet<&Node> = graph.triples_iter().map(|t| t.object()).collect();
+
+        assert_eq!(subjects.len(), 2);
+        assert_eq!(objects.len(), 4);
+    }
+
+    #[test]
+    fn locate_turtle_errors() {
+        let input = "@prefix foaf: <http://xmlns.com/foaf/0.1/> .
+
+                     _:a foaf:name \"Alice\" ;
+                         foaf:knows ] .";
+
+        let err = TurtleParser::from_string(input).decode().unwrap_err();
+        let location = err.location().unwrap();
+
+        assert_eq!(location.start().line(), 4);
+        assert_eq!(location.start().column(), 37);
+        assert_eq!(err.snippet(), Some("foaf:knows ]"));
+        assert!(err
+            .to_string()
+            .ends_with("(line 4, column 37: `foaf:knows ]`)"));
+    }
+
+    #[test]
+    fn report_literals_with_language_and_data_type() {
+        let input = "@prefix : <http://example.org/> .
+                     :s :p \"a\"@en^^:dt .";
+
+        let err = TurtleParser::from_string(input).decode().unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Literal with language tag @en can not have a data type. \
+             (line 2, column 28: `:s :p \"a\"@en`)"
+        );
+
+        let err = TurtleParser::from_string(input.replace("@en^^", "@en ^^"))
+            .decode()
+            .unwrap_err();
+
+        assert!(err
+            .to_string()
+            .starts_with("Unexpected character '^' in Turtle input."));
+    }
+
+    #[test]
+    fn skip_invalid_turtle_statements_in_lenient_mode() {
+        let input = "@prefix ex: <http://example.org/> .
+                     ex:a ex:b \"valid. still valid\" .
+                     ex:a ex:b .
+                     ex:a unknown:b ex:c .
+                     ex:a ex:b ex:d ; 42 ex:f .
+                     ex:a ex:b [ ex:c ex:d ] .
+                     ex:a ex:b ex:e";
+
+        let mut reader = TurtleParser::from_string(input).lenient();
+        let (graph, diagnostics) = reader.decode_with_diagnostics().unwrap();
+
+        assert_eq!(graph.count(), 3);
+        assert_eq!(diagnostics.len(), 4);
+
+        let lines: Vec<usize> = diagnostics
+            .iter()
+            .map(|err| err.location().unwrap().start().line())
+            .collect();
+        assert_eq!(lines, vec![3, 4, 5, 7]);
+
+        match *diagnostics[1].error_type() {
+            ErrorType::InvalidNamespace => {}
+            _ => panic!("unexpected error type"),
+        }
+    }
+
+    #[test]
+    fn lenient_mode_stops_on_sink_error() {
+        let input = "_:a <http://example/b> _:c .
+                     _:a <http://example/b> _:d .";
+
+        let mut sink = |_: Triple| Err(Error::new(ErrorType::InvalidWriterOutput, "Sink is full."));
+        let mut reader = TurtleParser::from_string(input).lenient();
+
+        assert!(reader.parse_all(&mut sink).is_err());
+        assert!(reader.diagnostics().is_empty());
+    }
+
+    #[test]
+    fn reject_invalid_turtle_uris() {
+        let invalid = [
+            "<http://example.org/a%zz> <http://example.org/b> \"c\" .",
+            "@prefix ex: <http://example.org/#> . ex:b\\#c <http://example.org/b> \"c\" .",
+            "@base <1http://example.org/> .",
+            "@prefix ex: <1http://example.org/> .",
+        ];
+
+        for input in invalid.iter() {
+            let mut graph = Graph::new(None);
+
+            match TurtleParser::from_string(*input).parse_all(&mut graph) {
+                Err(err) => match *err.error_type() {
+                    ErrorType::InvalidUri => {}
+                    _ => panic!("unexpected error type for {}", input),
+                },
+                Ok(_) => panic!("{} was accepted", input),
+            }
+        }
+    }
+
+    #[test]
+    fn resolve_relative_uris_against_base_directives() {
+        let input = "<a> <b> <c> .
+                     @base <http://example.org/x/y> .
+                     <a> <b> <#c> .
+                     @base <../z/> .
+                     <a> <b> <?c> .
+                     BASE <//example.com/>
+                     @prefix ex: <d/> .
+                     ex:a <b> \"c\"^^<t> .";
+
+        let mut graph = Graph::new(None);
+        TurtleParser::from_string(input)
+            .with_base_uri(Uri::parse("http://example.org/doc").unwrap())
+            .parse_all(&mut graph)
+            .unwrap();
+
+        let triples: Vec<String> = graph
+            .triples_iter()
+            .map(|triple| match (triple.subject(), triple.object()) {
+                (Node::UriNode { uri: s }, Node::UriNode { uri: o }) => {
+                    s.to_string().clone() + " " + o.to_string()
+                }
+                (Node::UriNode { uri: s }, Node::LiteralNode { data_type: Some(dt), .. }) => {
+                    s.to_string().clone() + " " + dt.to_string()
+                }
+                _ => panic!("unexpected triple"),
+            })
+            .collect();
+
+        assert_eq!(
+            triples,
+            vec![
+                "http://example.org/a http://example.org/c",
+                "http://example.org/x/a http://example.org/x/y#c",
+                "http://example.org/z/a http://example.org/z/?c",
+                "http://example.com/d/a http://example.com/t",
+            ]
+        );
+    }
 }
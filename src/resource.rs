@@ -0,0 +1,395 @@
+use crate::error::{Error, ErrorType};
+use crate::graph::Graph;
+use crate::node::Node;
+use crate::specs::rdf_syntax_specs::RdfSyntaxDataTypes;
+use crate::uri::Uri;
+use crate::Result;
+use std::collections::BTreeSet;
+
+/// Values that identify a node, used for the predicates of resources.
+///
+/// Strings are IRIs, e.g. `"http://xmlns.com/foaf/0.1/name"`.
+pub trait ToNode {
+    /// Returns the node.
+    fn to_node(&self) -> Node;
+}
+
+impl ToNode for Node {
+    fn to_node(&self) -> Node {
+        self.clone()
+    }
+}
+
+impl ToNode for Uri {
+    fn to_node(&self) -> Node {
+        Node::UriNode { uri: self.clone() }
+    }
+}
+
+impl ToNode for str {
+    fn to_node(&self) -> Node {
+        Node::UriNode {
+            uri: Uri::new_unchecked(self.to_string()),
+        }
+    }
+}
+
+impl ToNode for String {
+    fn to_node(&self) -> Node {
+        self.as_str().to_node()
+    }
+}
+
+impl<T> ToNode for &T
+where
+    T: ToNode + ?Sized,
+{
+    fn to_node(&self) -> Node {
+        (**self).to_node()
+    }
+}
+
+/// View of a node of a graph that gives access to the statements about the node.
+///
+/// Objects of the statements are resources as well, so that the graph can be traversed.
+///
+/// # Examples
+///
+/// ```
+/// use rdf::graph::Graph;
+/// use rdf::reader::rdf_parser::RdfParser;
+/// use rdf::reader::turtle_parser::TurtleParser;
+///
+/// let input = "@prefix foaf: <http://xmlns.com/foaf/0.1/> .
+///              <http://example.org/alice> foaf:name \"Alice\" ; foaf:knows [ foaf:name \"Bob\" ] .";
+/// let graph = TurtleParser::from_string(input).decode().unwrap();
+///
+/// let alice = graph.resource(&graph.create_uri_node_str("http://example.org/alice").unwrap());
+/// let bob = alice.object("http://xmlns.com/foaf/0.1/knows").unwrap();
+///
+/// assert_eq!(alice.literal_str("http://xmlns.com/foaf/0.1/name").unwrap(), "Alice");
+/// assert_eq!(bob.literal_str("http://xmlns.com/foaf/0.1/name").unwrap(), "Bob");
+/// ```
+#[derive(Clone, Debug)]
+pub struct Resource<'a> {
+    graph: &'a Graph,
+    node: Node,
+}
+
+impl<'a> Resource<'a> {
+    /// Constructor of `Resource`, see `Graph::resource`.
+    pub fn new(graph: &'a Graph, node: &Node) -> Resource<'a> {
+        Resource {
+            graph,
+            node: node.clone(),
+        }
+    }
+
+    /// Returns the node of the resource.
+    pub fn node(&self) -> &Node {
+        &self.node
+    }
+
+    /// Returns the graph of the resource.
+    pub fn graph(&self) -> &'a Graph {
+        self.graph
+    }
+
+    /// Returns the objects of the statements with the resource as subject and the predicate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::graph::Graph;
+    /// use rdf::reader::rdf_parser::RdfParser;
+    /// use rdf::reader::turtle_parser::TurtleParser;
+    ///
+    /// let graph = TurtleParser::from_string("<http://example.org/a> <http://example.org/b> 1, 2 .")
+    ///   .decode().unwrap();
+    ///
+    /// let a = graph.resource(&graph.create_uri_node_str("http://example.org/a").unwrap());
+    ///
+    /// assert_eq!(a.objects("http://example.org/b").len(), 2);
+    /// assert!(a.objects("http://example.org/c").is_empty());
+    /// ```
+    pub fn objects<P>(&self, predicate: &P) -> Vec<Resource<'a>>
+    where
+        P: ToNode + ?Sized,
+    {
+        let mut visited = BTreeSet::new();
+
+        // the same statement may be stored more than once
+        self.graph
+            .get_triples_with_subject_and_predicate(&self.node, &predicate.to_node())
+            .into_iter()
+            .filter(|triple| visited.insert(triple.object()))
+            .map(|triple| Resource::new(self.graph, triple.object()))
+            .collect()
+    }
+
+    /// Returns the only object of the statements with the resource as subject and the predicate.
+    ///
+    /// # Failures
+    ///
+    /// - The resource has no or several objects for the predicate.
+    ///
+    pub fn object<P>(&self, predicate: &P) -> Result<Resource<'a>>
+    where
+        P: ToNode + ?Sized,
+    {
+        self.object_node(&predicate.to_node())
+            .map(|node| Resource::new(self.graph, node))
+    }
+
+    /// Returns the lexical form of the only object of the predicate, which must be a literal.
+    ///
+    /// # Failures
+    ///
+    /// - The resource has no or several objects for the predicate.
+    /// - The object is not a literal.
+    ///
+    pub fn literal_str<P>(&self, predicate: &P) -> Result<&'a str>
+    where
+        P: ToNode + ?Sized,
+    {
+        match *self.object_node(&predicate.to_node())? {
+            Node::LiteralNode { ref literal, .. } => Ok(literal),
+            ref node => Err(Error::new(
+                ErrorType::InvalidResource,
+                format!("Expected a literal, found {:?}.", node),
+            )),
+        }
+    }
+
+    /// Returns the types of the resource, the objects of `rdf:type`.
+    pub fn types(&self) -> Vec<Resource<'a>> {
+        self.objects(&RdfSyntaxDataTypes::A.to_uri())
+    }
+
+    /// Returns `true` if the resource has the type.
+    pub fn has_type<T>(&self, rdf_type: &T) -> bool
+    where
+        T: ToNode + ?Sized,
+    {
+        let rdf_type = rdf_type.to_node();
+
+        self.types()
+            .iter()
+            .any(|resource| resource.node == rdf_type)
+    }
+
+    /// Returns the subjects of the statements with the predicate and the resource as object.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::graph::Graph;
+    /// use rdf::reader::rdf_parser::RdfParser;
+    /// use rdf::reader::turtle_parser::TurtleParser;
+    ///
+    /// let graph = TurtleParser::from_string("<http://example.org/a> <http://example.org/b> <http://example.org/c> .")
+    ///   .decode().unwrap();
+    ///
+    /// let c = graph.resource(&graph.create_uri_node_str("http://example.org/c").unwrap());
+    /// let subjects = c.subjects_of("http://example.org/b");
+    ///
+    /// assert_eq!(*subjects[0].node(), graph.create_uri_node_str("http://example.org/a").unwrap());
+    /// ```
+    pub fn subjects_of<P>(&self, predicate: &P) -> Vec<Resource<'a>>
+    where
+        P: ToNode + ?Sized,
+    {
+        self.graph
+            .get_triples_with_predicate_and_object(&predicate.to_node(), &self.node)
+            .into_iter()
+            .map(|triple| Resource::new(self.graph, triple.subject()))
+            .collect()
+    }
+
+    /// Returns the resources that are reached by following the predicates in order, each
+    /// resource is returned once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::graph::Graph;
+    /// use rdf::reader::rdf_parser::RdfParser;
+    /// use rdf::reader::turtle_parser::TurtleParser;
+    ///
+    /// let input = "@prefix foaf: <http://xmlns.com/foaf/0.1/> .
+    ///              _:alice foaf:knows _:bob, _:carol .
+    ///              _:bob foaf:name \"Bob\" .
+    ///              _:carol foaf:name \"Carol\" .";
+    /// let graph = TurtleParser::from_string(input).decode().unwrap();
+    ///
+    /// let alice = graph.resource(&graph.create_blank_node_with_id("alice".to_string()));
+    /// let names = alice.path(&["http://xmlns.com/foaf/0.1/knows", "http://xmlns.com/foaf/0.1/name"]);
+    ///
+    /// assert_eq!(names.len(), 2);
+    /// ```
+    pub fn path<P>(&self, predicates: &[P]) -> Vec<Resource<'a>>
+    where
+        P: ToNode,
+    {
+        let mut resources = vec![self.clone()];
+
+        for predicate in predicates {
+            let predicate = predicate.to_node();
+            let mut visited = BTreeSet::new();
+
+            resources = resources
+                .iter()
+                .flat_map(|resource| resource.objects(&predicate))
+                .filter(|resource| visited.insert(resource.node.clone()))
+                .collect();
+        }
+
+        resources
+    }
+
+    /// Returns the only object of the predicate.
+    fn object_node(&self, predicate: &Node) -> Result<&'a Node> {
+        let objects: BTreeSet<&'a Node> = self
+            .graph
+            .get_triples_with_subject_and_predicate(&self.node, predicate)
+            .into_iter()
+            .map(|triple| triple.object())
+            .collect();
+
+        match objects.iter().collect::<Vec<_>>()[..] {
+            [object] => Ok(object),
+            _ => Err(Error::new(
+                ErrorType::InvalidResource,
+                format!(
+                    "Expected one object of {:?} for {:?}, found {}.",
+                    predicate,
+                    self.node,
+                    objects.len()
+                ),
+            )),
+        }
+    }
+}
+
+impl<'a> PartialEq for Resource<'a> {
+    fn eq(&self, other: &Resource) -> bool {
+        std::ptr::eq(self.graph, other.graph) && self.node == other.node
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::ErrorType;
+    use crate::graph::Graph;
+    use crate::reader::rdf_parser::RdfParser;
+    use crate::reader::turtle_parser::TurtleParser;
+
+    const FOAF: &str = "http://xmlns.com/foaf/0.1/";
+
+    fn foaf(name: &str) -> String {
+        FOAF.to_string() + name
+    }
+
+    fn people() -> Graph {
+        let input = "@prefix foaf: <http://xmlns.com/foaf/0.1/> .
+                     @prefix ex: <http://example.org/> .
+                     ex:alice a foaf:Person ; foaf:name \"Alice\" ; foaf:knows ex:bob, ex:carol .
+                     ex:bob a foaf:Person, foaf:Agent ; foaf:name \"Bob\", \"Robert\" ;
+                         foaf:knows ex:carol .
+                     ex:carol foaf:name \"Carol\" ; foaf:homepage ex:carol_home .";
+
+        TurtleParser::from_string(input).decode().unwrap()
+    }
+
+    #[test]
+    fn navigate_resources() {
+        let graph = people();
+        let alice = graph.resource(
+            &graph
+                .create_uri_node_str("http://example.org/alice")
+                .unwrap(),
+        );
+        let carol = graph.resource(
+            &graph
+                .create_uri_node_str("http://example.org/carol")
+                .unwrap(),
+        );
+
+        assert_eq!(alice.objects(&foaf("knows")).len(), 2);
+        assert_eq!(alice.literal_str(&foaf("name")).unwrap(), "Alice");
+        assert!(alice.has_type(&foaf("Person")));
+        assert_eq!(carol.types(), vec![]);
+
+        let known_by: Vec<_> = carol.subjects_of(&foaf("knows"));
+        assert_eq!(known_by.len(), 2);
+        assert!(known_by.contains(&alice));
+
+        let homepages = alice.path(&[foaf("knows"), foaf("knows"), foaf("homepage")]);
+        assert_eq!(
+            *homepages[0].node(),
+            graph
+                .create_uri_node_str("http://example.org/carol_home")
+                .unwrap()
+        );
+        assert_eq!(homepages.len(), 1);
+    }
+
+    #[test]
+    fn object_expects_exactly_one_value() {
+        let graph = people();
+        let alice = graph.resource(
+            &graph
+                .create_uri_node_str("http://example.org/alice")
+                .unwrap(),
+        );
+        let bob = graph.resource(&graph.create_uri_node_str("http://example.org/bob").unwrap());
+
+        for result in [
+            bob.object(&foaf("name")),
+            alice.object(&foaf("knows")),
+            alice.object(&foaf("homepage")),
+        ] {
+            match result {
+                Err(err) => match err.error_type() {
+                    ErrorType::InvalidResource => {}
+                    _ => panic!("Unexpected error type"),
+                },
+                Ok(_) => panic!("Only one object is expected"),
+            }
+        }
+
+        assert!(alice
+            .object(&foaf("name"))
+            .unwrap()
+            .object(&foaf("name"))
+            .is_err());
+        assert!(graph
+            .resource(
+                &graph
+                    .create_uri_node_str("http://example.org/carol")
+                    .unwrap()
+            )
+            .literal_str(&foaf("homepage"))
+            .is_err());
+    }
+
+    #[test]
+    fn duplicate_statements() {
+        let mut graph = people();
+        let carol = graph
+            .create_uri_node_str("http://example.org/carol")
+            .unwrap();
+        let name = graph.get_triples_with_subject_and_predicate(
+            &carol,
+            &graph.create_uri_node_str(&foaf("name")).unwrap(),
+        )[0]
+        .clone();
+
+        graph.add_triple(&name);
+
+        let carol = graph.resource(&carol);
+
+        assert_eq!(carol.literal_str(&foaf("name")).unwrap(), "Carol");
+        assert_eq!(carol.objects(&foaf("name")).len(), 1);
+    }
+}
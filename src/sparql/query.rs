@@ -83,6 +83,21 @@ impl SparqlQuery {
     //    &self.patterns
     //  }
 
+    /// Returns the base URI of the query.
+    pub fn base_uri(&self) -> &Option<Uri> {
+        &self.base_uri
+    }
+
+    /// Sets the base URI of the query.
+    pub fn set_base_uri(&mut self, uri: &Uri) {
+        self.base_uri = Some(uri.clone());
+    }
+
+    /// Adds a namespace that is declared by the query.
+    pub fn add_namespace(&mut self, ns: &Namespace) {
+        self.namespaces.add(ns);
+    }
+
     /// Returns the URI of a namespace with the provided prefix.
     ///
     /// # Examples
@@ -0,0 +1,82 @@
+/// Contains specifications of the JSON-LD 1.1 syntax.
+pub struct JsonLdSpecs {}
+
+impl JsonLdSpecs {
+    /// All keywords defined by JSON-LD 1.1.
+    pub const KEYWORDS: [&'static str; 24] = [
+        "@base",
+        "@container",
+        "@context",
+        "@direction",
+        "@graph",
+        "@id",
+        "@import",
+        "@included",
+        "@index",
+        "@json",
+        "@language",
+        "@list",
+        "@nest",
+        "@none",
+        "@prefix",
+        "@propagate",
+        "@protected",
+        "@reverse",
+        "@set",
+        "@type",
+        "@value",
+        "@version",
+        "@vocab",
+        "@default",
+    ];
+
+    /// Checks if the provided string is a JSON-LD keyword.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::specs::json_ld_specs::JsonLdSpecs;
+    ///
+    /// assert!(JsonLdSpecs::is_keyword("@id"));
+    /// assert_eq!(JsonLdSpecs::is_keyword("@ignored"), false);
+    /// assert_eq!(JsonLdSpecs::is_keyword("name"), false);
+    /// ```
+    pub fn is_keyword(s: &str) -> bool {
+        JsonLdSpecs::KEYWORDS.contains(&s)
+    }
+
+    /// Checks if the provided string has the form of a keyword (`@` followed by letters).
+    ///
+    /// Such strings are reserved for future use and ignored by JSON-LD processors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::specs::json_ld_specs::JsonLdSpecs;
+    ///
+    /// assert!(JsonLdSpecs::has_keyword_form("@ignored"));
+    /// assert_eq!(JsonLdSpecs::has_keyword_form("@"), false);
+    /// assert_eq!(JsonLdSpecs::has_keyword_form("@a1"), false);
+    /// ```
+    pub fn has_keyword_form(s: &str) -> bool {
+        s.len() > 1 && s.starts_with('@') && s[1..].chars().all(|c| c.is_ascii_alphabetic())
+    }
+
+    /// Checks if the provided string is a valid container mapping value.
+    pub fn is_container(s: &str) -> bool {
+        matches!(
+            s,
+            "@list" | "@set" | "@index" | "@language" | "@graph" | "@id" | "@type"
+        )
+    }
+
+    /// Checks if an IRI ends with a generic delimiter as defined in RFC 3986.
+    ///
+    /// Only terms that map to such IRIs are used as prefixes of compact IRIs.
+    pub fn ends_with_gen_delim(iri: &str) -> bool {
+        match iri.chars().last() {
+            Some(c) => ":/?#[]@".contains(c),
+            None => false,
+        }
+    }
+}
@@ -0,0 +1,173 @@
+/// Contains specifications for escaping literals and URIs in N-Triples syntax.
+///
+/// Turtle uses the same escape sequences for literals and URIs.
+pub struct NTriplesSpecs {}
+
+impl NTriplesSpecs {
+    /// Returns the character of a string escape sequence (e.g. `\n`) without the backslash.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::specs::n_triples_specs::NTriplesSpecs;
+    ///
+    /// assert_eq!(NTriplesSpecs::unescape_character('t'), Some('\t'));
+    /// assert_eq!(NTriplesSpecs::unescape_character('"'), Some('"'));
+    /// assert_eq!(NTriplesSpecs::unescape_character('a'), None);
+    /// ```
+    pub fn unescape_character(c: char) -> Option<char> {
+        match c {
+            't' => Some('\t'),
+            'b' => Some('\u{8}'),
+            'n' => Some('\n'),
+            'r' => Some('\r'),
+            'f' => Some('\u{c}'),
+            '"' => Some('"'),
+            '\'' => Some('\''),
+            '\\' => Some('\\'),
+            _ => None,
+        }
+    }
+
+    /// Escapes a literal in canonical N-Triples form.
+    ///
+    /// Quotes, backslashes and line breaks are escaped with string escape sequences,
+    /// other control characters with unicode escape sequences.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::specs::n_triples_specs::NTriplesSpecs;
+    ///
+    /// assert_eq!(NTriplesSpecs::escape_literal("a \"b\"\n\u{1}é'"), "a \\\"b\\\"\\n\\u0001é'");
+    /// ```
+    pub fn escape_literal(literal: &str) -> String {
+        let mut output_string = String::with_capacity(literal.len());
+
+        for c in literal.chars() {
+            match c {
+                '"' => output_string.push_str("\\\""),
+                '\\' => output_string.push_str("\\\\"),
+                '\n' => output_string.push_str("\\n"),
+                '\r' => output_string.push_str("\\r"),
+                '\t' => output_string.push_str("\\t"),
+                '\u{8}' => output_string.push_str("\\b"),
+                '\u{c}' => output_string.push_str("\\f"),
+                c if c.is_control() && (c as u32) < 0x80 => {
+                    output_string.push_str(&format!("\\u{:04X}", c as u32))
+                }
+                c => output_string.push(c),
+            }
+        }
+
+        output_string
+    }
+
+    /// Escapes the characters of a URI that are not allowed in N-Triples and Turtle IRIs.
+    ///
+    /// The escaped characters are not allowed in IRIs either, parsers reject them after
+    /// decoding the escapes. The writers therefore return an error for such IRIs, the
+    /// escapes are only used by formatters, which do not validate their input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::specs::n_triples_specs::NTriplesSpecs;
+    ///
+    /// assert_eq!(NTriplesSpecs::escape_uri("http://example.org/a b>"),
+    ///            "http://example.org/a\\u0020b\\u003E");
+    /// ```
+    pub fn escape_uri(uri: &str) -> String {
+        let mut output_string = String::with_capacity(uri.len());
+
+        for c in uri.chars() {
+            if NTriplesSpecs::is_allowed_in_uri(c) {
+                output_string.push(c);
+            } else {
+                output_string.push_str(&format!("\\u{:04X}", c as u32));
+            }
+        }
+
+        output_string
+    }
+
+    /// Checks if the character may appear unescaped in an IRI.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::specs::n_triples_specs::NTriplesSpecs;
+    ///
+    /// assert!(NTriplesSpecs::is_allowed_in_uri('é'));
+    /// assert_eq!(NTriplesSpecs::is_allowed_in_uri('{'), false);
+    /// ```
+    pub fn is_allowed_in_uri(c: char) -> bool {
+        match c {
+            '<' | '>' | '"' | '{' | '}' | '|' | '^' | '`' | '\\' => false,
+            c => c > ' ',
+        }
+    }
+
+    /// Checks if the character is a `PN_CHARS_BASE` character that may start names.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::specs::n_triples_specs::NTriplesSpecs;
+    ///
+    /// assert!(NTriplesSpecs::is_pn_chars_base('a'));
+    /// assert!(NTriplesSpecs::is_pn_chars_base('\u{10000}'));
+    /// assert_eq!(NTriplesSpecs::is_pn_chars_base('_'), false);
+    /// ```
+    pub fn is_pn_chars_base(c: char) -> bool {
+        matches!(
+            c,
+            'A'..='Z'
+                | 'a'..='z'
+                | '\u{C0}'..='\u{D6}'
+                | '\u{D8}'..='\u{F6}'
+                | '\u{F8}'..='\u{2FF}'
+                | '\u{370}'..='\u{37D}'
+                | '\u{37F}'..='\u{1FFF}'
+                | '\u{200C}'..='\u{200D}'
+                | '\u{2070}'..='\u{218F}'
+                | '\u{2C00}'..='\u{2FEF}'
+                | '\u{3001}'..='\u{D7FF}'
+                | '\u{F900}'..='\u{FDCF}'
+                | '\u{FDF0}'..='\u{FFFD}'
+                | '\u{10000}'..='\u{EFFFF}'
+        )
+    }
+
+    /// Checks if the character is a `PN_CHARS_U` character (`PN_CHARS_BASE` or '_').
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::specs::n_triples_specs::NTriplesSpecs;
+    ///
+    /// assert!(NTriplesSpecs::is_pn_chars_u('_'));
+    /// assert_eq!(NTriplesSpecs::is_pn_chars_u('-'), false);
+    /// ```
+    pub fn is_pn_chars_u(c: char) -> bool {
+        c == '_' || NTriplesSpecs::is_pn_chars_base(c)
+    }
+
+    /// Checks if the character is a `PN_CHARS` character that may appear inside names.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::specs::n_triples_specs::NTriplesSpecs;
+    ///
+    /// assert!(NTriplesSpecs::is_pn_chars('-'));
+    /// assert!(NTriplesSpecs::is_pn_chars('\u{B7}'));
+    /// assert_eq!(NTriplesSpecs::is_pn_chars('.'), false);
+    /// ```
+    pub fn is_pn_chars(c: char) -> bool {
+        match c {
+            '-' | '0'..='9' | '\u{B7}' | '\u{300}'..='\u{36F}' | '\u{203F}'..='\u{2040}' => true,
+            c => NTriplesSpecs::is_pn_chars_u(c),
+        }
+    }
+}
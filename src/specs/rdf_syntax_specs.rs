@@ -1,4 +1,5 @@
 use crate::uri::Uri;
+use crate::vocab::rdf;
 
 /// RDF Schema data types and vocabulary.
 pub enum RdfSyntaxDataTypes {
@@ -6,23 +7,46 @@ pub enum RdfSyntaxDataTypes {
     ListFirst,
     ListRest,
     ListNil,
+    Bag,
+    Seq,
+    Alt,
 }
 
 impl RdfSyntaxDataTypes {
     /// Returns a specific data type as URI.
     pub fn to_uri(&self) -> Uri {
-        Uri::new(self.to_string())
+        Uri::new_unchecked(self.to_string())
     }
 
     /// Returns a specific data type as string.
     pub fn to_string(&self) -> String {
-        let schema_name = "http://www.w3.org/1999/02/22-rdf-syntax-ns#".to_string();
+        self.as_str().to_string()
+    }
 
+    /// Returns the URI of a specific data type as string slice.
+    pub fn as_str(&self) -> &'static str {
         match *self {
-            RdfSyntaxDataTypes::A => schema_name + "type",
-            RdfSyntaxDataTypes::ListFirst => schema_name + "first",
-            RdfSyntaxDataTypes::ListRest => schema_name + "rest",
-            RdfSyntaxDataTypes::ListNil => schema_name + "nil",
+            RdfSyntaxDataTypes::A => rdf::TYPE,
+            RdfSyntaxDataTypes::ListFirst => rdf::FIRST,
+            RdfSyntaxDataTypes::ListRest => rdf::REST,
+            RdfSyntaxDataTypes::ListNil => rdf::NIL,
+            RdfSyntaxDataTypes::Bag => rdf::BAG,
+            RdfSyntaxDataTypes::Seq => rdf::SEQ,
+            RdfSyntaxDataTypes::Alt => rdf::ALT,
         }
     }
+
+    /// Returns the container membership property `rdf:_n` of the n-th member, starting at 1.
+    pub fn member_uri(n: usize) -> Uri {
+        Uri::new_unchecked(format!("{}_{}", rdf::NAMESPACE_URI, n))
+    }
+
+    /// Returns n if the URI is the container membership property `rdf:_n`.
+    pub fn member_index(uri: &Uri) -> Option<usize> {
+        uri.to_string()
+            .strip_prefix(rdf::NAMESPACE_URI)
+            .and_then(|name| name.strip_prefix('_'))
+            .filter(|n| !n.starts_with('0') && n.chars().all(|c| c.is_ascii_digit()))
+            .and_then(|n| n.parse().ok())
+    }
 }
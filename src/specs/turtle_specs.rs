@@ -1,3 +1,4 @@
+use crate::specs::n_triples_specs::NTriplesSpecs;
 use crate::specs::xml_specs::XmlDataTypes;
 use crate::uri::Uri;
 
@@ -94,4 +95,103 @@ impl TurtleSpecs {
             Err(_) => false,
         }
     }
+
+    /// Checks if the character may be escaped with '\' in the local part of a prefixed name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::specs::turtle_specs::TurtleSpecs;
+    ///
+    /// assert!(TurtleSpecs::is_reserved_local_name_character('/'));
+    /// assert!(TurtleSpecs::is_reserved_local_name_character('.'));
+    /// assert_eq!(TurtleSpecs::is_reserved_local_name_character('a'), false);
+    /// ```
+    pub fn is_reserved_local_name_character(c: char) -> bool {
+        "_~.-!$&'()*+,;=/?#@%".contains(c)
+    }
+
+    /// Checks if the prefix is a valid `PN_PREFIX`, the empty prefix is allowed as well.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::specs::turtle_specs::TurtleSpecs;
+    ///
+    /// assert!(TurtleSpecs::is_prefix_name("foaf"));
+    /// assert!(TurtleSpecs::is_prefix_name("a.b-c"));
+    /// assert!(TurtleSpecs::is_prefix_name(""));
+    /// assert_eq!(TurtleSpecs::is_prefix_name("_a"), false);
+    /// assert_eq!(TurtleSpecs::is_prefix_name("a."), false);
+    /// assert_eq!(TurtleSpecs::is_prefix_name("a b"), false);
+    /// ```
+    pub fn is_prefix_name(prefix: &str) -> bool {
+        let mut chars = prefix.chars();
+
+        match chars.next() {
+            Some(c) if NTriplesSpecs::is_pn_chars_base(c) => {
+                !prefix.ends_with('.') && chars.all(|c| c == '.' || NTriplesSpecs::is_pn_chars(c))
+            }
+            Some(_) => false,
+            None => true,
+        }
+    }
+
+    /// Escapes the local part of a prefixed name so that it is a valid `PN_LOCAL`.
+    ///
+    /// Reserved characters are escaped with a backslash, percent-encodings are kept as they are.
+    /// Returns `None` if the local part contains characters that cannot appear in a
+    /// prefixed name, e.g. spaces or a '%' that is not part of a percent-encoding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::specs::turtle_specs::TurtleSpecs;
+    ///
+    /// assert_eq!(TurtleSpecs::escape_local_name("show/localName"), Some("show\\/localName".to_string()));
+    /// assert_eq!(TurtleSpecs::escape_local_name("-a.b."), Some("\\-a.b\\.".to_string()));
+    /// assert_eq!(TurtleSpecs::escape_local_name("a%20b"), Some("a%20b".to_string()));
+    /// assert_eq!(TurtleSpecs::escape_local_name("a b"), None);
+    /// ```
+    pub fn escape_local_name(local_name: &str) -> Option<String> {
+        let chars: Vec<char> = local_name.chars().collect();
+        let mut output_string = String::with_capacity(local_name.len());
+        let mut index = 0;
+
+        while index < chars.len() {
+            let c = chars[index];
+            let is_first = index == 0;
+            let is_last = index == chars.len() - 1;
+
+            if c == '%'
+                && chars.len() > index + 2
+                && chars[index + 1].is_ascii_hexdigit()
+                && chars[index + 2].is_ascii_hexdigit()
+            {
+                output_string.extend(&chars[index..index + 3]);
+                index += 3;
+                continue;
+            }
+
+            let is_allowed = match c {
+                ':' | '0'..='9' => true,
+                '.' => !is_first && !is_last,
+                '-' | '\u{B7}' | '\u{300}'..='\u{36F}' | '\u{203F}'..='\u{2040}' => !is_first,
+                c => NTriplesSpecs::is_pn_chars_u(c),
+            };
+
+            if is_allowed {
+                output_string.push(c);
+            } else if TurtleSpecs::is_reserved_local_name_character(c) && c != '%' {
+                output_string.push('\\');
+                output_string.push(c);
+            } else {
+                return None;
+            }
+
+            index += 1;
+        }
+
+        Some(output_string)
+    }
 }
@@ -1,6 +1,8 @@
 use crate::uri::Uri;
+use crate::vocab::xsd;
 
 /// XML schema data types.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
 pub enum XmlDataTypes {
     String,
     Decimal,
@@ -11,28 +13,142 @@ pub enum XmlDataTypes {
     UnsignedLong,
     Int,
     Integer,
+    Float,
+    NonPositiveInteger,
+    NegativeInteger,
+    Short,
+    Byte,
+    NonNegativeInteger,
+    UnsignedInt,
+    UnsignedShort,
+    UnsignedByte,
+    PositiveInteger,
+    DateTime,
+    Time,
+    Duration,
+    GYear,
+    HexBinary,
+    Base64Binary,
 }
 
+/// All supported XML schema data types.
+const DATA_TYPES: [XmlDataTypes; 25] = [
+    XmlDataTypes::String,
+    XmlDataTypes::Decimal,
+    XmlDataTypes::Double,
+    XmlDataTypes::Boolean,
+    XmlDataTypes::Date,
+    XmlDataTypes::Long,
+    XmlDataTypes::UnsignedLong,
+    XmlDataTypes::Int,
+    XmlDataTypes::Integer,
+    XmlDataTypes::Float,
+    XmlDataTypes::NonPositiveInteger,
+    XmlDataTypes::NegativeInteger,
+    XmlDataTypes::Short,
+    XmlDataTypes::Byte,
+    XmlDataTypes::NonNegativeInteger,
+    XmlDataTypes::UnsignedInt,
+    XmlDataTypes::UnsignedShort,
+    XmlDataTypes::UnsignedByte,
+    XmlDataTypes::PositiveInteger,
+    XmlDataTypes::DateTime,
+    XmlDataTypes::Time,
+    XmlDataTypes::Duration,
+    XmlDataTypes::GYear,
+    XmlDataTypes::HexBinary,
+    XmlDataTypes::Base64Binary,
+];
+
 impl XmlDataTypes {
+    /// Returns the data type that is identified by the URI, if it is supported.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::specs::xml_specs::XmlDataTypes;
+    /// use rdf::uri::Uri;
+    ///
+    /// let uri = Uri::parse("http://www.w3.org/2001/XMLSchema#gYear").unwrap();
+    ///
+    /// assert_eq!(XmlDataTypes::from_uri(&uri), Some(XmlDataTypes::GYear));
+    /// ```
+    pub fn from_uri(uri: &Uri) -> Option<XmlDataTypes> {
+        DATA_TYPES
+            .iter()
+            .find(|data_type| data_type.as_str() == uri.to_string())
+            .cloned()
+    }
+
     /// Returns a specific data type as URI.
     pub fn to_uri(&self) -> Uri {
-        Uri::new(self.to_string())
+        Uri::new_unchecked(self.to_string())
     }
 
     /// Returns a specific data type as string.
     pub fn to_string(&self) -> String {
-        let schema_name = "http://www.w3.org/2001/XMLSchema#".to_string();
+        self.as_str().to_string()
+    }
+
+    /// Returns the URI of a specific data type as string slice.
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            XmlDataTypes::Boolean => xsd::BOOLEAN,
+            XmlDataTypes::Integer => xsd::INTEGER,
+            XmlDataTypes::Decimal => xsd::DECIMAL,
+            XmlDataTypes::Double => xsd::DOUBLE,
+            XmlDataTypes::Date => xsd::DATE,
+            XmlDataTypes::Long => xsd::LONG,
+            XmlDataTypes::UnsignedLong => xsd::UNSIGNED_LONG,
+            XmlDataTypes::Int => xsd::INT,
+            XmlDataTypes::String => xsd::STRING,
+            XmlDataTypes::Float => xsd::FLOAT,
+            XmlDataTypes::NonPositiveInteger => xsd::NON_POSITIVE_INTEGER,
+            XmlDataTypes::NegativeInteger => xsd::NEGATIVE_INTEGER,
+            XmlDataTypes::Short => xsd::SHORT,
+            XmlDataTypes::Byte => xsd::BYTE,
+            XmlDataTypes::NonNegativeInteger => xsd::NON_NEGATIVE_INTEGER,
+            XmlDataTypes::UnsignedInt => xsd::UNSIGNED_INT,
+            XmlDataTypes::UnsignedShort => xsd::UNSIGNED_SHORT,
+            XmlDataTypes::UnsignedByte => xsd::UNSIGNED_BYTE,
+            XmlDataTypes::PositiveInteger => xsd::POSITIVE_INTEGER,
+            XmlDataTypes::DateTime => xsd::DATE_TIME,
+            XmlDataTypes::Time => xsd::TIME,
+            XmlDataTypes::Duration => xsd::DURATION,
+            XmlDataTypes::GYear => xsd::G_YEAR,
+            XmlDataTypes::HexBinary => xsd::HEX_BINARY,
+            XmlDataTypes::Base64Binary => xsd::BASE64_BINARY,
+        }
+    }
 
+    /// Returns the range of values of `xsd:integer` and the data types derived from it.
+    ///
+    /// Returns `None` for data types that are not integers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::specs::xml_specs::XmlDataTypes;
+    ///
+    /// assert_eq!(XmlDataTypes::UnsignedByte.integer_range(), Some((0, 255)));
+    /// assert_eq!(XmlDataTypes::Date.integer_range(), None);
+    /// ```
+    pub fn integer_range(&self) -> Option<(i128, i128)> {
         match *self {
-            XmlDataTypes::Boolean => schema_name + "boolean",
-            XmlDataTypes::Integer => schema_name + "integer",
-            XmlDataTypes::Decimal => schema_name + "decimal",
-            XmlDataTypes::Double => schema_name + "double",
-            XmlDataTypes::Date => schema_name + "date",
-            XmlDataTypes::Long => schema_name + "long",
-            XmlDataTypes::UnsignedLong => schema_name + "unsignedLong",
-            XmlDataTypes::Int => schema_name + "int",
-            XmlDataTypes::String => schema_name + "string",
+            XmlDataTypes::Integer => Some((i128::MIN, i128::MAX)),
+            XmlDataTypes::NonPositiveInteger => Some((i128::MIN, 0)),
+            XmlDataTypes::NegativeInteger => Some((i128::MIN, -1)),
+            XmlDataTypes::Long => Some((i64::MIN.into(), i64::MAX.into())),
+            XmlDataTypes::Int => Some((i32::MIN.into(), i32::MAX.into())),
+            XmlDataTypes::Short => Some((i16::MIN.into(), i16::MAX.into())),
+            XmlDataTypes::Byte => Some((i8::MIN.into(), i8::MAX.into())),
+            XmlDataTypes::NonNegativeInteger => Some((0, i128::MAX)),
+            XmlDataTypes::UnsignedLong => Some((0, u64::MAX.into())),
+            XmlDataTypes::UnsignedInt => Some((0, u32::MAX.into())),
+            XmlDataTypes::UnsignedShort => Some((0, u16::MAX.into())),
+            XmlDataTypes::UnsignedByte => Some((0, u8::MAX.into())),
+            XmlDataTypes::PositiveInteger => Some((1, i128::MAX)),
+            _ => None,
         }
     }
 }
@@ -1,5 +1,7 @@
 use crate::node::Node;
 use std::cmp::PartialEq;
+use std::collections::HashSet;
+use std::hash::{Hash, Hasher};
 use std::slice::Iter;
 use std::vec::IntoIter;
 
@@ -32,7 +34,7 @@ impl Triple {
     /// use rdf::uri::Uri;
     ///
     /// let subject = Node::BlankNode { id: "a".to_string() };
-    /// let predicate = Node::UriNode { uri: Uri::new("http://example.org/show/localName".to_string()) } ;
+    /// let predicate = Node::UriNode { uri: Uri::parse("http://example.org/show/localName").unwrap() } ;
     /// let object = Node::BlankNode { id: "b".to_string() };
     ///
     /// Triple::new(&subject, &predicate, &object);
@@ -59,6 +61,15 @@ impl Triple {
     pub fn object(&self) -> &Node {
         &self.object
     }
+
+    /// Returns the triple with all literals in canonical form, see `Node::canonicalize`.
+    pub fn canonicalize(&self) -> Triple {
+        Triple {
+            subject: self.subject.canonicalize(),
+            predicate: self.predicate.canonicalize(),
+            object: self.object.canonicalize(),
+        }
+    }
 }
 
 impl PartialEq for Triple {
@@ -71,10 +82,21 @@ impl PartialEq for Triple {
 
 impl Eq for Triple {}
 
+impl Hash for Triple {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.subject().hash(state);
+        self.predicate().hash(state);
+        self.object().hash(state);
+    }
+}
+
 /// Storage for triples.
 #[derive(Debug, Default)]
 pub struct TripleStore {
     triples: Vec<Triple>,
+
+    /// Stored triples, for looking up triples without scanning all of them.
+    index: HashSet<Triple>,
 }
 
 impl TripleStore {
@@ -82,6 +104,7 @@ impl TripleStore {
     pub fn new() -> TripleStore {
         TripleStore {
             triples: Vec::new(),
+            index: HashSet::new(),
         }
     }
 
@@ -97,12 +120,26 @@ impl TripleStore {
 
     /// Adds a new triple to the store.
     pub fn add_triple(&mut self, triple: &Triple) {
+        if !self.index.contains(triple) {
+            self.index.insert(triple.clone());
+        }
+
         self.triples.push(triple.clone());
     }
 
     /// Deletes the triple from the store.
+    ///
+    /// Triples that are not stored are looked up in the index, the stored triples are only
+    /// scanned if the triple has to be removed.
     pub fn remove_triple(&mut self, triple: &Triple) {
-        self.triples.retain(|t| t != triple);
+        if self.index.remove(triple) {
+            self.triples.retain(|t| t != triple);
+        }
+    }
+
+    /// Checks if the triple is stored, without scanning all triples.
+    pub fn contains(&self, triple: &Triple) -> bool {
+        self.index.contains(triple)
     }
 
     /// Returns all triples where the subject node matches the provided node.
@@ -254,4 +291,64 @@ mod tests {
 
         assert_eq!(store.count(), 1);
     }
+
+    #[test]
+    fn look_up_triples_in_triple_store() {
+        let mut store = TripleStore::new();
+
+        let node = Node::BlankNode {
+            id: "a".to_string(),
+        };
+        let trip = Triple::new(&node, &node, &node);
+
+        store.add_triple(&trip);
+        store.add_triple(&trip);
+        assert!(store.contains(&trip));
+
+        store.remove_triple(&trip);
+        assert!(!store.contains(&trip));
+        assert!(store.is_empty());
+    }
+
+    #[test]
+    fn look_up_triples_after_removal() {
+        let mut store = TripleStore::new();
+        let node = |id: usize| Node::BlankNode { id: id.to_string() };
+        let triples: Vec<Triple> = (0..20)
+            .map(|id| Triple::new(&node(id * 7 % 20), &node(0), &node(id)))
+            .collect();
+
+        for triple in triples.iter() {
+            store.add_triple(triple);
+        }
+
+        store.remove_triple(&triples[5]);
+
+        for (index, triple) in triples.iter().enumerate() {
+            assert_eq!(store.contains(triple), index != 5);
+        }
+
+        assert_eq!(store.count(), 19);
+    }
+
+    #[test]
+    fn look_up_triples_in_large_triple_store() {
+        let mut store = TripleStore::new();
+        let node = |id: usize| Node::BlankNode { id: id.to_string() };
+        let triple = |id: usize| Triple::new(&node(id % 1000), &node(0), &node(id));
+
+        for id in 0..200_000 {
+            store.add_triple(&triple(id));
+        }
+
+        for id in (0..200_000).step_by(4000) {
+            store.remove_triple(&triple(id));
+            store.remove_triple(&triple(id + 200_000));
+        }
+
+        assert_eq!(store.count(), 199_950);
+        assert!(
+            (0..400_000).all(|id| store.contains(&triple(id)) == (id < 200_000 && id % 4000 != 0))
+        );
+    }
 }
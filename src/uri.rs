@@ -1,14 +1,267 @@
-// todo: implement
+use crate::error::{Error, ErrorType};
+use crate::Result;
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
 
-#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+/// IRI reference as defined in RFC 3987.
+///
+/// An IRI reference is either an absolute IRI that starts with a scheme or a relative
+/// reference that is resolved against a base IRI.
+#[derive(Clone, Debug)]
 pub struct Uri {
     uri: String,
+    offsets: Offsets,
+}
+
+/// Byte offsets of the components of an IRI reference as split by RFC 3986, appendix B.
+///
+/// The start of each component follows from the end of the previous one and its delimiter.
+#[derive(Clone, Copy, Debug)]
+struct Offsets {
+    /// End of the scheme, which is followed by ':'.
+    scheme_end: Option<usize>,
+    /// End of the authority, which starts after "//".
+    authority_end: Option<usize>,
+    path_end: usize,
+    /// End of the query, which starts after '?'. A fragment follows after '#' if the IRI
+    /// does not end here.
+    query_end: Option<usize>,
+}
+
+/// Components of an IRI reference as split by RFC 3986, appendix B.
+struct Components<'a> {
+    scheme: Option<&'a str>,
+    authority: Option<&'a str>,
+    path: &'a str,
+    query: Option<&'a str>,
+    fragment: Option<&'a str>,
+}
+
+// the offsets are derived from the IRI, so only the IRI is compared and hashed
+impl PartialEq for Uri {
+    fn eq(&self, other: &Uri) -> bool {
+        self.uri == other.uri
+    }
+}
+
+impl Eq for Uri {}
+
+impl PartialOrd for Uri {
+    fn partial_cmp(&self, other: &Uri) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Uri {
+    fn cmp(&self, other: &Uri) -> Ordering {
+        self.uri.cmp(&other.uri)
+    }
+}
+
+impl Hash for Uri {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.uri.hash(state);
+    }
+}
+
+impl Offsets {
+    fn split(uri: &str) -> Offsets {
+        let end = uri.find('#').unwrap_or(uri.len());
+
+        let (path_end, query_end) = match uri[..end].find('?') {
+            Some(index) => (index, Some(end)),
+            None => (end, None),
+        };
+
+        let scheme_end = match uri[..path_end].find([':', '/']) {
+            Some(index) if index > 0 && uri[index..].starts_with(':') => Some(index),
+            _ => None,
+        };
+
+        let start = scheme_end.map_or(0, |scheme_end| scheme_end + 1);
+        let authority_end = if uri[start..path_end].starts_with("//") {
+            match uri[start + 2..path_end].find('/') {
+                Some(index) => Some(start + 2 + index),
+                None => Some(path_end),
+            }
+        } else {
+            None
+        };
+
+        Offsets {
+            scheme_end,
+            authority_end,
+            path_end,
+            query_end,
+        }
+    }
+
+    /// Returns the components of the IRI that has been split into these offsets.
+    fn components(self, uri: &str) -> Components<'_> {
+        let mut path_start = self.scheme_end.map_or(0, |scheme_end| scheme_end + 1);
+        let authority = match self.authority_end {
+            Some(authority_end) => {
+                let authority = &uri[path_start + 2..authority_end];
+                path_start = authority_end;
+                Some(authority)
+            }
+            None => None,
+        };
+
+        let end = self.query_end.unwrap_or(self.path_end);
+
+        Components {
+            scheme: self.scheme_end.map(|scheme_end| &uri[..scheme_end]),
+            authority,
+            path: &uri[path_start..self.path_end],
+            query: self
+                .query_end
+                .map(|query_end| &uri[self.path_end + 1..query_end]),
+            fragment: if end < uri.len() {
+                Some(&uri[end + 1..])
+            } else {
+                None
+            },
+        }
+    }
 }
 
-// todo: look into using servo URI
 impl Uri {
-    pub fn new(uri: String) -> Uri {
-        Uri { uri }
+    /// Constructor of `Uri` that does not validate the IRI.
+    ///
+    /// The IRI is only split into its components, the caller is responsible for passing a
+    /// valid IRI reference. Use `Uri::parse` for IRIs that are not known to be valid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::uri::Uri;
+    ///
+    /// let uri = Uri::new_unchecked("http://example.org/a b".to_string());
+    ///
+    /// assert_eq!(uri.path(), "/a b");
+    /// assert_eq!(uri.is_valid(), false);
+    /// ```
+    pub fn new_unchecked(uri: String) -> Uri {
+        Uri {
+            offsets: Offsets::split(&uri),
+            uri,
+        }
+    }
+
+    /// Parses and validates an IRI reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::uri::Uri;
+    ///
+    /// let uri = Uri::parse("http://example.org/a?b#c").unwrap();
+    ///
+    /// assert_eq!(uri.to_string(), "http://example.org/a?b#c");
+    /// assert!(Uri::parse("../a#b").is_ok());
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - The IRI contains characters that are not allowed, e.g. spaces or '<'.
+    /// - The scheme, authority, path, query or fragment are malformed.
+    /// - A percent-encoding is not followed by two hexadecimal digits.
+    ///
+    pub fn parse(uri: &str) -> Result<Uri> {
+        let uri = Uri::new_unchecked(uri.to_string());
+        uri.validate(Uri::is_ucschar)?;
+        Ok(uri)
+    }
+
+    /// Parses and validates an IRI reference of the Turtle, N-Triples and SPARQL grammars.
+    ///
+    /// The grammars allow all non-ASCII characters in `IRIREF` and `PN_CHARS_BASE` includes
+    /// characters up to U+FFFD and above U+10000, which are not all `ucschar` of RFC 3987.
+    /// The IRI is validated like in `Uri::parse`, except that all non-ASCII characters are
+    /// accepted.
+    ///
+    /// # Failures
+    ///
+    /// - The IRI contains ASCII characters that are not allowed, e.g. spaces or '<'.
+    /// - The scheme, authority, path, query or fragment are malformed.
+    /// - A percent-encoding is not followed by two hexadecimal digits.
+    ///
+    pub(crate) fn parse_iri_ref(uri: &str) -> Result<Uri> {
+        let uri = Uri::new_unchecked(uri.to_string());
+        uri.validate(|c| !c.is_ascii())?;
+        Ok(uri)
+    }
+
+    /// Validates an IRI reference, `is_ucschar` decides which non-ASCII characters are allowed
+    /// outside of the query.
+    fn validate(&self, is_ucschar: fn(char) -> bool) -> Result<()> {
+        let uri = &self.uri;
+        let components = self.components();
+
+        if let Some(scheme) = components.scheme {
+            if !Uri::is_valid_scheme(scheme) {
+                return Err(Error::new(
+                    ErrorType::InvalidUri,
+                    "Invalid IRI scheme: ".to_string() + uri,
+                ));
+            }
+        } else if components.authority.is_none()
+            && components
+                .path
+                .split('/')
+                .next()
+                .unwrap_or("")
+                .contains(':')
+        {
+            return Err(Error::new(
+                ErrorType::InvalidUri,
+                "First path segment of relative IRI contains ':': ".to_string() + uri,
+            ));
+        }
+
+        if let Some(authority) = components.authority {
+            if !Uri::is_valid_authority(authority, is_ucschar) {
+                return Err(Error::new(
+                    ErrorType::InvalidUri,
+                    "Invalid IRI authority: ".to_string() + uri,
+                ));
+            }
+        }
+
+        if !Uri::is_valid_component(components.path, |c| {
+            c == '/' || Uri::is_ipchar(c, is_ucschar)
+        }) {
+            return Err(Error::new(
+                ErrorType::InvalidUri,
+                "Invalid IRI path: ".to_string() + uri,
+            ));
+        }
+
+        if let Some(query) = components.query {
+            let is_query_char =
+                |c| c == '/' || c == '?' || Uri::is_ipchar(c, is_ucschar) || Uri::is_iprivate(c);
+
+            if !Uri::is_valid_component(query, is_query_char) {
+                return Err(Error::new(
+                    ErrorType::InvalidUri,
+                    "Invalid IRI query: ".to_string() + uri,
+                ));
+            }
+        }
+
+        if let Some(fragment) = components.fragment {
+            let is_fragment_char = |c| c == '/' || c == '?' || Uri::is_ipchar(c, is_ucschar);
+
+            if !Uri::is_valid_component(fragment, is_fragment_char) {
+                return Err(Error::new(
+                    ErrorType::InvalidUri,
+                    "Invalid IRI fragment: ".to_string() + uri,
+                ));
+            }
+        }
+
+        Ok(())
     }
 
     /// Returns the string representation of the URI.
@@ -16,9 +269,684 @@ impl Uri {
         &self.uri
     }
 
-    /// todo
+    /// Checks if the IRI is valid according to RFC 3987.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::uri::Uri;
+    ///
+    /// assert!(Uri::parse("http://example.org/").unwrap().is_valid());
+    /// assert_eq!(Uri::new_unchecked("http://example.org/a b".to_string()).is_valid(), false);
+    /// ```
+    pub fn is_valid(&self) -> bool {
+        self.validate(Uri::is_ucschar).is_ok()
+    }
+
+    /// Checks if the IRI is valid in Turtle and N-Triples syntax, see `Uri::parse_iri_ref`.
+    pub(crate) fn is_valid_iri_ref(&self) -> bool {
+        self.validate(|c| !c.is_ascii()).is_ok()
+    }
+
+    /// Returns the components of the IRI.
+    fn components(&self) -> Components<'_> {
+        self.offsets.components(&self.uri)
+    }
+
+    /// Checks if the IRI is absolute, which means that it has a scheme.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::uri::Uri;
+    ///
+    /// assert!(Uri::parse("urn:isbn:123").unwrap().is_absolute());
+    /// assert_eq!(Uri::parse("example.org/a").unwrap().is_absolute(), false);
+    /// ```
+    pub fn is_absolute(&self) -> bool {
+        self.scheme().is_some()
+    }
+
+    /// Returns the scheme of the IRI, if there is one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::uri::Uri;
+    ///
+    /// let uri = Uri::parse("http://example.org/a").unwrap();
+    ///
+    /// assert_eq!(uri.scheme(), Some("http"));
+    /// ```
+    pub fn scheme(&self) -> Option<&str> {
+        self.components().scheme
+    }
+
+    /// Returns the authority of the IRI without the leading "//", if there is one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::uri::Uri;
+    ///
+    /// let uri = Uri::parse("http://user@example.org:8080/a").unwrap();
+    ///
+    /// assert_eq!(uri.authority(), Some("user@example.org:8080"));
+    /// ```
+    pub fn authority(&self) -> Option<&str> {
+        self.components().authority
+    }
+
+    /// Returns the path of the IRI, which might be empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::uri::Uri;
+    ///
+    /// let uri = Uri::parse("http://example.org/a/b?c").unwrap();
+    ///
+    /// assert_eq!(uri.path(), "/a/b");
+    /// ```
+    pub fn path(&self) -> &str {
+        self.components().path
+    }
+
+    /// Returns the query of the IRI without the leading '?', if there is one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::uri::Uri;
+    ///
+    /// let uri = Uri::parse("http://example.org/a?b=c#d").unwrap();
+    ///
+    /// assert_eq!(uri.query(), Some("b=c"));
+    /// ```
+    pub fn query(&self) -> Option<&str> {
+        self.components().query
+    }
+
+    /// Returns the fragment of the IRI without the leading '#', if there is one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::uri::Uri;
+    ///
+    /// let uri = Uri::parse("http://example.org/a#b").unwrap();
+    ///
+    /// assert_eq!(uri.fragment(), Some("b"));
+    /// ```
+    pub fn fragment(&self) -> Option<&str> {
+        self.components().fragment
+    }
+
+    /// Appends a path to the URI, e.g. the local name of a QName to the namespace URI.
+    ///
+    /// The path is appended as is, no separator is inserted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::uri::Uri;
+    ///
+    /// let mut uri = Uri::parse("http://xmlns.com/foaf/0.1/").unwrap();
+    /// uri.append_resource_path("name");
+    ///
+    /// assert_eq!(uri.to_string(), "http://xmlns.com/foaf/0.1/name");
+    /// ```
     pub fn append_resource_path(&mut self, path: &str) {
-        // todo: check if URI ends with '/', if not add '/'
-        self.uri.push_str(&path.to_string());
+        self.uri.push_str(path);
+        self.offsets = Offsets::split(&self.uri);
+    }
+
+    /// Resolves a relative IRI reference against this URI as base (RFC 3986, section 5.2).
+    ///
+    /// Absolute references are returned with dot segments removed. If this URI is not
+    /// absolute, it cannot be used as base and the reference is returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::uri::Uri;
+    ///
+    /// let base = Uri::parse("http://example.org/a/b?c#d").unwrap();
+    ///
+    /// assert_eq!(base.resolve(&Uri::parse("e").unwrap()).to_string(), "http://example.org/a/e");
+    /// assert_eq!(base.resolve(&Uri::parse("#f").unwrap()).to_string(), "http://example.org/a/b?c#f");
+    /// assert_eq!(base.resolve(&Uri::parse("../g").unwrap()).to_string(), "http://example.org/g");
+    /// ```
+    pub fn resolve(&self, reference: &Uri) -> Uri {
+        let base = self.components();
+        let relative = reference.components();
+
+        let base_scheme = match base.scheme {
+            Some(scheme) => scheme,
+            None => return reference.clone(),
+        };
+
+        let (scheme, authority, path, query) = if let Some(scheme) = relative.scheme {
+            (
+                scheme,
+                relative.authority,
+                Uri::remove_dot_segments(relative.path),
+                relative.query,
+            )
+        } else if relative.authority.is_some() {
+            (
+                base_scheme,
+                relative.authority,
+                Uri::remove_dot_segments(relative.path),
+                relative.query,
+            )
+        } else if relative.path.is_empty() {
+            (
+                base_scheme,
+                base.authority,
+                base.path.to_string(),
+                relative.query.or(base.query),
+            )
+        } else if relative.path.starts_with('/') {
+            (
+                base_scheme,
+                base.authority,
+                Uri::remove_dot_segments(relative.path),
+                relative.query,
+            )
+        } else {
+            // merge the relative path with the base path
+            let merged = if base.authority.is_some() && base.path.is_empty() {
+                "/".to_string() + relative.path
+            } else {
+                match base.path.rfind('/') {
+                    Some(index) => base.path[..=index].to_string() + relative.path,
+                    None => relative.path.to_string(),
+                }
+            };
+
+            (
+                base_scheme,
+                base.authority,
+                Uri::remove_dot_segments(&merged),
+                relative.query,
+            )
+        };
+
+        let mut uri = scheme.to_string() + ":";
+
+        if let Some(authority) = authority {
+            uri.push_str("//");
+            uri.push_str(authority);
+        }
+
+        uri.push_str(&path);
+
+        if let Some(query) = query {
+            uri.push('?');
+            uri.push_str(query);
+        }
+
+        if let Some(fragment) = relative.fragment {
+            uri.push('#');
+            uri.push_str(fragment);
+        }
+
+        Uri::new_unchecked(uri)
+    }
+
+    /// Creates a relative reference to the provided IRI with this URI as base.
+    ///
+    /// This is the inverse of `resolve`: a reference is only returned if resolving it
+    /// against this URI yields the provided IRI again. Only references within the
+    /// same scheme and authority are created.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::uri::Uri;
+    ///
+    /// let base = Uri::parse("http://example.org/a/b").unwrap();
+    ///
+    /// let relativize = |uri: &str| base.relativize(&Uri::parse(uri).unwrap());
+    ///
+    /// assert_eq!(relativize("http://example.org/a/c"), Some(Uri::parse("c").unwrap()));
+    /// assert_eq!(relativize("http://example.org/a/b#d"), Some(Uri::parse("#d").unwrap()));
+    /// assert_eq!(relativize("http://example.org/e"), Some(Uri::parse("/e").unwrap()));
+    /// assert_eq!(relativize("http://example.com/a/c"), None);
+    /// ```
+    pub fn relativize(&self, uri: &Uri) -> Option<Uri> {
+        let base = self.components();
+        let target = uri.components();
+
+        if base.scheme.is_none()
+            || base.scheme != target.scheme
+            || base.authority != target.authority
+        {
+            return None;
+        }
+
+        let mut reference = if base.path == target.path && base.query == target.query {
+            String::new()
+        } else if base.path == target.path && target.query.is_some() {
+            "?".to_string() + target.query.unwrap_or_default()
+        } else {
+            let directory = match base.path.rfind('/') {
+                Some(index) => &base.path[..=index],
+                None => "",
+            };
+
+            let mut path = match target.path.strip_prefix(directory) {
+                Some(path) if !directory.is_empty() => {
+                    let first_segment = path.split('/').next().unwrap_or_default();
+
+                    // the first segment must not be mistaken for a scheme or an authority
+                    if path.is_empty() || path.starts_with('/') || first_segment.contains(':') {
+                        "./".to_string() + path
+                    } else {
+                        path.to_string()
+                    }
+                }
+                _ if base.authority.is_some() && target.path.starts_with('/') => {
+                    target.path.to_string()
+                }
+                _ => return None,
+            };
+
+            if let Some(query) = target.query {
+                path.push('?');
+                path.push_str(query);
+            }
+
+            path
+        };
+
+        if let Some(fragment) = target.fragment {
+            reference.push('#');
+            reference.push_str(fragment);
+        }
+
+        let reference = Uri::new_unchecked(reference);
+
+        if self.resolve(&reference) == *uri {
+            Some(reference)
+        } else {
+            None
+        }
+    }
+
+    /// Removes `.` and `..` segments from a path (RFC 3986, section 5.2.4).
+    fn remove_dot_segments(path: &str) -> String {
+        let mut input = path;
+        let mut output = String::new();
+
+        while !input.is_empty() {
+            if let Some(rest) = input.strip_prefix("../") {
+                input = rest;
+            } else if input.starts_with("./") || input.starts_with("/./") {
+                input = &input[2..];
+            } else if input == "/." {
+                input = "/";
+            } else if input.starts_with("/../") || input == "/.." {
+                input = if input == "/.." { "/" } else { &input[3..] };
+
+                match output.rfind('/') {
+                    Some(index) => output.truncate(index),
+                    None => output.clear(),
+                }
+            } else if input == "." || input == ".." {
+                input = "";
+            } else {
+                let start = if input.starts_with('/') { 1 } else { 0 };
+                let end = input[start..].find('/').map_or(input.len(), |i| i + start);
+
+                output.push_str(&input[..end]);
+                input = &input[end..];
+            }
+        }
+
+        output
+    }
+
+    fn is_valid_scheme(scheme: &str) -> bool {
+        scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+            && scheme
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '+' || c == '-' || c == '.')
+    }
+
+    /// Checks `[ iuserinfo "@" ] ihost [ ":" port ]`.
+    fn is_valid_authority(authority: &str, is_ucschar: fn(char) -> bool) -> bool {
+        let (user_info, host_port) = match authority.find('@') {
+            Some(index) => (Some(&authority[..index]), &authority[index + 1..]),
+            None => (None, authority),
+        };
+
+        if let Some(user_info) = user_info {
+            let is_user_info_char =
+                |c| c == ':' || Uri::is_iunreserved(c, is_ucschar) || Uri::is_sub_delim(c);
+
+            if !Uri::is_valid_component(user_info, is_user_info_char) {
+                return false;
+            }
+        }
+
+        let (host, port) = if host_port.starts_with('[') {
+            // IP literal
+            match host_port.find(']') {
+                Some(index) => (&host_port[..index + 1], &host_port[index + 1..]),
+                None => return false,
+            }
+        } else {
+            match host_port.find(':') {
+                Some(index) => (&host_port[..index], &host_port[index..]),
+                None => (host_port, ""),
+            }
+        };
+
+        let is_valid_host = if host.starts_with('[') {
+            let ip_literal = &host[1..host.len() - 1];
+
+            !ip_literal.is_empty()
+                && ip_literal.chars().all(|c| {
+                    c == ':'
+                        || (c.is_ascii()
+                            && (Uri::is_iunreserved(c, is_ucschar) || Uri::is_sub_delim(c)))
+                })
+        } else {
+            Uri::is_valid_component(host, |c| {
+                Uri::is_iunreserved(c, is_ucschar) || Uri::is_sub_delim(c)
+            })
+        };
+
+        let is_valid_port = port.is_empty()
+            || (port.starts_with(':') && port[1..].chars().all(|c| c.is_ascii_digit()));
+
+        is_valid_host && is_valid_port
+    }
+
+    /// Checks if all characters are allowed in the component or part of a percent-encoding.
+    fn is_valid_component<F>(component: &str, is_allowed: F) -> bool
+    where
+        F: Fn(char) -> bool,
+    {
+        let mut chars = component.chars();
+
+        while let Some(c) = chars.next() {
+            if c == '%' {
+                let is_hex_digit = |c: Option<char>| c.is_some_and(|c| c.is_ascii_hexdigit());
+
+                if !is_hex_digit(chars.next()) || !is_hex_digit(chars.next()) {
+                    return false;
+                }
+            } else if !is_allowed(c) {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    fn is_ipchar(c: char, is_ucschar: fn(char) -> bool) -> bool {
+        c == ':' || c == '@' || Uri::is_iunreserved(c, is_ucschar) || Uri::is_sub_delim(c)
+    }
+
+    fn is_iunreserved(c: char, is_ucschar: fn(char) -> bool) -> bool {
+        match c {
+            'a'..='z' | 'A'..='Z' | '0'..='9' | '-' | '.' | '_' | '~' => true,
+            c => is_ucschar(c),
+        }
+    }
+
+    fn is_sub_delim(c: char) -> bool {
+        matches!(
+            c,
+            '!' | '$' | '&' | '\'' | '(' | ')' | '*' | '+' | ',' | ';' | '='
+        )
+    }
+
+    fn is_ucschar(c: char) -> bool {
+        match c as u32 {
+            0xA0..=0xD7FF | 0xF900..=0xFDCF | 0xFDF0..=0xFFEF => true,
+            0xE1000..=0xEFFFD => true,
+            // the last two code points of the planes 1 - 13 are not allowed
+            c => (0x10000..=0xDFFFF).contains(&c) && c & 0xFFFE != 0xFFFE,
+        }
+    }
+
+    fn is_iprivate(c: char) -> bool {
+        matches!(
+            c as u32,
+            0xE000..=0xF8FF | 0xF0000..=0xFFFFD | 0x100000..=0x10FFFD
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::uri::Uri;
+
+    #[test]
+    fn parse_uri_components() {
+        let uri = Uri::parse("http://user@example.org:8080/a/b?c=d#e").unwrap();
+
+        assert_eq!(uri.scheme(), Some("http"));
+        assert_eq!(uri.authority(), Some("user@example.org:8080"));
+        assert_eq!(uri.path(), "/a/b");
+        assert_eq!(uri.query(), Some("c=d"));
+        assert_eq!(uri.fragment(), Some("e"));
+    }
+
+    #[test]
+    fn parse_uri_without_authority() {
+        let uri = Uri::parse("urn:isbn:0451450523").unwrap();
+
+        assert_eq!(uri.scheme(), Some("urn"));
+        assert_eq!(uri.authority(), None);
+        assert_eq!(uri.path(), "isbn:0451450523");
+        assert_eq!(uri.query(), None);
+        assert_eq!(uri.fragment(), None);
+    }
+
+    #[test]
+    fn split_uris_without_validation() {
+        let mut uri = Uri::new_unchecked("http://example.org/a b?c".to_string());
+
+        assert_eq!(uri.path(), "/a b");
+        assert_eq!(uri.query(), Some("c"));
+        assert!(!uri.is_valid());
+
+        uri.append_resource_path("#d");
+
+        assert_eq!(uri.query(), Some("c"));
+        assert_eq!(uri.fragment(), Some("d"));
+    }
+
+    #[test]
+    fn parse_relative_references() {
+        for reference in &["", "#a", "a/b", "../a", "/a?b", "//example.org/a", "a/b:c"] {
+            let uri = Uri::parse(reference).unwrap();
+
+            assert!(!uri.is_absolute());
+        }
+    }
+
+    #[test]
+    fn parse_unicode_uris() {
+        assert!(Uri::parse("http://例え.テスト/é?\u{E000}#ü").is_ok());
+        assert!(Uri::parse("http://example.org/a#\u{E000}").is_err());
+        assert!(Uri::parse("http://example.org/\u{FFFF}").is_err());
+    }
+
+    #[test]
+    fn parse_ip_literal_uris() {
+        let uri = Uri::parse("http://[2001:db8::7]:80/c").unwrap();
+
+        assert_eq!(uri.authority(), Some("[2001:db8::7]:80"));
+        assert!(Uri::parse("http://[2001:db8::7/c").is_err());
+    }
+
+    #[test]
+    fn resolve_normal_examples() {
+        // examples of RFC 3986, section 5.4.1
+        let base = Uri::parse("http://a/b/c/d;p?q").unwrap();
+        let examples = [
+            ("g:h", "g:h"),
+            ("g", "http://a/b/c/g"),
+            ("./g", "http://a/b/c/g"),
+            ("g/", "http://a/b/c/g/"),
+            ("/g", "http://a/g"),
+            ("//g", "http://g"),
+            ("?y", "http://a/b/c/d;p?y"),
+            ("g?y", "http://a/b/c/g?y"),
+            ("#s", "http://a/b/c/d;p?q#s"),
+            ("g#s", "http://a/b/c/g#s"),
+            ("g?y#s", "http://a/b/c/g?y#s"),
+            (";x", "http://a/b/c/;x"),
+            ("g;x", "http://a/b/c/g;x"),
+            ("g;x?y#s", "http://a/b/c/g;x?y#s"),
+            ("", "http://a/b/c/d;p?q"),
+            (".", "http://a/b/c/"),
+            ("./", "http://a/b/c/"),
+            ("..", "http://a/b/"),
+            ("../", "http://a/b/"),
+            ("../g", "http://a/b/g"),
+            ("../..", "http://a/"),
+            ("../../", "http://a/"),
+            ("../../g", "http://a/g"),
+        ];
+
+        for (reference, expected) in examples.iter() {
+            let resolved = base.resolve(&Uri::parse(reference).unwrap());
+            assert_eq!(resolved.to_string(), expected, "resolving {}", reference);
+        }
+    }
+
+    #[test]
+    fn resolve_abnormal_examples() {
+        // examples of RFC 3986, section 5.4.2
+        let base = Uri::parse("http://a/b/c/d;p?q").unwrap();
+        let examples = [
+            ("../../../g", "http://a/g"),
+            ("../../../../g", "http://a/g"),
+            ("/./g", "http://a/g"),
+            ("/../g", "http://a/g"),
+            ("g.", "http://a/b/c/g."),
+            (".g", "http://a/b/c/.g"),
+            ("g..", "http://a/b/c/g.."),
+            ("..g", "http://a/b/c/..g"),
+            ("./../g", "http://a/b/g"),
+            ("./g/.", "http://a/b/c/g/"),
+            ("g/./h", "http://a/b/c/g/h"),
+            ("g/../h", "http://a/b/c/h"),
+            ("g;x=1/./y", "http://a/b/c/g;x=1/y"),
+            ("g;x=1/../y", "http://a/b/c/y"),
+            ("g?y/./x", "http://a/b/c/g?y/./x"),
+            ("g#s/../x", "http://a/b/c/g#s/../x"),
+            ("http:g", "http:g"),
+        ];
+
+        for (reference, expected) in examples.iter() {
+            let resolved = base.resolve(&Uri::parse(reference).unwrap());
+            assert_eq!(resolved.to_string(), expected, "resolving {}", reference);
+        }
+    }
+
+    #[test]
+    fn resolve_against_relative_base() {
+        let base = Uri::parse("a/b").unwrap();
+
+        assert_eq!(base.resolve(&Uri::parse("c").unwrap()).to_string(), "c");
+    }
+
+    #[test]
+    fn reject_invalid_uris() {
+        let invalid = [
+            "http://example.org/a b",
+            "http://example.org/<a>",
+            "http://example.org/a\"b",
+            "http://example.org/{a}",
+            "http://example.org/a|b",
+            "http://example.org/a\\b",
+            "http://example.org/a^b",
+            "http://example.org/a`b",
+            "http://example.org/%2",
+            "http://example.org/%zz",
+            "http://example.org/a#b#c",
+            "http://example.org/a[b]",
+            "http://example.org:80a/",
+            "1http://example.org/",
+            "ht tp://example.org/",
+            ":a",
+        ];
+
+        for uri in invalid.iter() {
+            assert!(Uri::parse(uri).is_err(), "{} is valid", uri);
+        }
+    }
+
+    #[test]
+    fn parse_iri_refs_of_the_grammar() {
+        // U+FFFD and U+EFFFF are allowed by PN_CHARS_BASE, but are not ucschar
+        for uri in [
+            "http://example.org/\u{fffd}",
+            "http://example.org/\u{effff}#\u{fff0}",
+        ]
+        .iter()
+        {
+            assert!(Uri::parse(uri).is_err(), "{} is valid", uri);
+            assert!(Uri::parse_iri_ref(uri).is_ok(), "{} is invalid", uri);
+        }
+
+        for uri in [
+            "http://example.org/a b\u{fffd}",
+            "http://example.org/%zz",
+            ":a",
+        ]
+        .iter()
+        {
+            assert!(Uri::parse_iri_ref(uri).is_err(), "{} is valid", uri);
+        }
+    }
+
+    #[test]
+    fn relativize_uris() {
+        let base = Uri::parse("http://a/b/c/d;p?q").unwrap();
+
+        let examples = [
+            ("http://a/b/c/g", "g"),
+            ("http://a/b/c/g/", "g/"),
+            ("http://a/b/c/", "./"),
+            ("http://a/b/c/d;p?q", ""),
+            ("http://a/b/c/d;p?y", "?y"),
+            ("http://a/b/c/d;p", "d;p"),
+            ("http://a/b/c/d;p?q#s", "#s"),
+            ("http://a/b/c/g:h", "./g:h"),
+            ("http://a/b/c//g", ".//g"),
+            ("http://a/b/g?y#s", "/b/g?y#s"),
+            ("http://a", "http://a"),
+            ("http://a/b/c/./g", "http://a/b/c/./g"),
+            ("https://a/b/c/g", "https://a/b/c/g"),
+            ("http://x/b/c/g", "http://x/b/c/g"),
+        ];
+
+        for &(uri, reference) in examples.iter() {
+            let uri = Uri::parse(uri).unwrap();
+            let relative = base.relativize(&uri);
+
+            assert_eq!(
+                relative.as_ref().unwrap_or(&uri).to_string(),
+                reference,
+                "relativizing {}",
+                uri.to_string()
+            );
+
+            if let Some(relative) = relative {
+                assert_eq!(base.resolve(&relative), uri);
+            }
+        }
+
+        let urn = Uri::parse("urn:isbn:123").unwrap();
+        assert_eq!(urn.relativize(&Uri::parse("urn:isbn:456").unwrap()), None);
     }
 }
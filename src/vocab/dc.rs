@@ -0,0 +1,83 @@
+//! Dublin Core Metadata Element Set, version 1.1, `http://purl.org/dc/elements/1.1/`.
+
+/// Prefix that is bound to the namespace by default.
+pub const DEFAULT_PREFIX: &str = "dc";
+
+/// Namespace URI of the vocabulary.
+pub const NAMESPACE_URI: &str = "http://purl.org/dc/elements/1.1/";
+
+/// `dc:contributor`
+///
+/// An entity responsible for making contributions to the resource.
+pub const CONTRIBUTOR: &str = "http://purl.org/dc/elements/1.1/contributor";
+
+/// `dc:coverage`
+///
+/// The spatial or temporal topic of the resource, spatial applicability of the resource, or
+/// jurisdiction under which the resource is relevant.
+pub const COVERAGE: &str = "http://purl.org/dc/elements/1.1/coverage";
+
+/// `dc:creator`
+///
+/// An entity primarily responsible for making the resource.
+pub const CREATOR: &str = "http://purl.org/dc/elements/1.1/creator";
+
+/// `dc:date`
+///
+/// A point or period of time associated with an event in the lifecycle of the resource.
+pub const DATE: &str = "http://purl.org/dc/elements/1.1/date";
+
+/// `dc:description`
+///
+/// An account of the resource.
+pub const DESCRIPTION: &str = "http://purl.org/dc/elements/1.1/description";
+
+/// `dc:format`
+///
+/// The file format, physical medium, or dimensions of the resource.
+pub const FORMAT: &str = "http://purl.org/dc/elements/1.1/format";
+
+/// `dc:identifier`
+///
+/// An unambiguous reference to the resource within a given context.
+pub const IDENTIFIER: &str = "http://purl.org/dc/elements/1.1/identifier";
+
+/// `dc:language`
+///
+/// A language of the resource.
+pub const LANGUAGE: &str = "http://purl.org/dc/elements/1.1/language";
+
+/// `dc:publisher`
+///
+/// An entity responsible for making the resource available.
+pub const PUBLISHER: &str = "http://purl.org/dc/elements/1.1/publisher";
+
+/// `dc:relation`
+///
+/// A related resource.
+pub const RELATION: &str = "http://purl.org/dc/elements/1.1/relation";
+
+/// `dc:rights`
+///
+/// Information about rights held in and over the resource.
+pub const RIGHTS: &str = "http://purl.org/dc/elements/1.1/rights";
+
+/// `dc:source`
+///
+/// A related resource from which the described resource is derived.
+pub const SOURCE: &str = "http://purl.org/dc/elements/1.1/source";
+
+/// `dc:subject`
+///
+/// The topic of the resource.
+pub const SUBJECT: &str = "http://purl.org/dc/elements/1.1/subject";
+
+/// `dc:title`
+///
+/// A name given to the resource.
+pub const TITLE: &str = "http://purl.org/dc/elements/1.1/title";
+
+/// `dc:type`
+///
+/// The nature or genre of the resource.
+pub const TYPE: &str = "http://purl.org/dc/elements/1.1/type";
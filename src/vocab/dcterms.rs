@@ -0,0 +1,405 @@
+//! DCMI Metadata Terms, `http://purl.org/dc/terms/`.
+
+/// Prefix that is bound to the namespace by default.
+pub const DEFAULT_PREFIX: &str = "dcterms";
+
+/// Namespace URI of the vocabulary.
+pub const NAMESPACE_URI: &str = "http://purl.org/dc/terms/";
+
+/// `dcterms:Agent`
+///
+/// A resource that acts or has the power to act.
+pub const AGENT: &str = "http://purl.org/dc/terms/Agent";
+
+/// `dcterms:AgentClass`
+///
+/// A group of agents.
+pub const AGENT_CLASS: &str = "http://purl.org/dc/terms/AgentClass";
+
+/// `dcterms:BibliographicResource`
+///
+/// A book, article, or other documentary resource.
+pub const BIBLIOGRAPHIC_RESOURCE: &str = "http://purl.org/dc/terms/BibliographicResource";
+
+/// `dcterms:FileFormat`
+///
+/// A digital resource format.
+pub const FILE_FORMAT: &str = "http://purl.org/dc/terms/FileFormat";
+
+/// `dcterms:Frequency`
+///
+/// A rate at which something recurs.
+pub const FREQUENCY: &str = "http://purl.org/dc/terms/Frequency";
+
+/// `dcterms:Jurisdiction`
+///
+/// The extent or range of judicial, law enforcement, or other authority.
+pub const JURISDICTION: &str = "http://purl.org/dc/terms/Jurisdiction";
+
+/// `dcterms:LicenseDocument`
+///
+/// A legal document giving official permission to do something with a resource.
+pub const LICENSE_DOCUMENT: &str = "http://purl.org/dc/terms/LicenseDocument";
+
+/// `dcterms:LinguisticSystem`
+///
+/// A system of signs, symbols, sounds, gestures, or rules used in communication.
+pub const LINGUISTIC_SYSTEM: &str = "http://purl.org/dc/terms/LinguisticSystem";
+
+/// `dcterms:Location`
+///
+/// A spatial region or named place.
+pub const LOCATION: &str = "http://purl.org/dc/terms/Location";
+
+/// `dcterms:LocationPeriodOrJurisdiction`
+///
+/// A location, period of time, or jurisdiction.
+pub const LOCATION_PERIOD_OR_JURISDICTION: &str =
+    "http://purl.org/dc/terms/LocationPeriodOrJurisdiction";
+
+/// `dcterms:MediaType`
+///
+/// A file format or physical medium.
+pub const MEDIA_TYPE: &str = "http://purl.org/dc/terms/MediaType";
+
+/// `dcterms:MediaTypeOrExtent`
+///
+/// A media type or extent.
+pub const MEDIA_TYPE_OR_EXTENT: &str = "http://purl.org/dc/terms/MediaTypeOrExtent";
+
+/// `dcterms:MethodOfAccrual`
+///
+/// A method by which resources are added to a collection.
+pub const METHOD_OF_ACCRUAL: &str = "http://purl.org/dc/terms/MethodOfAccrual";
+
+/// `dcterms:MethodOfInstruction`
+///
+/// A process that is used to engender knowledge, attitudes, and skills.
+pub const METHOD_OF_INSTRUCTION: &str = "http://purl.org/dc/terms/MethodOfInstruction";
+
+/// `dcterms:PeriodOfTime`
+///
+/// An interval of time that is named or defined by its start and end dates.
+pub const PERIOD_OF_TIME: &str = "http://purl.org/dc/terms/PeriodOfTime";
+
+/// `dcterms:PhysicalMedium`
+///
+/// A physical material or carrier.
+pub const PHYSICAL_MEDIUM: &str = "http://purl.org/dc/terms/PhysicalMedium";
+
+/// `dcterms:PhysicalResource`
+///
+/// A material thing.
+pub const PHYSICAL_RESOURCE: &str = "http://purl.org/dc/terms/PhysicalResource";
+
+/// `dcterms:Policy`
+///
+/// A plan or course of action by an authority, intended to influence and determine decisions,
+/// actions, and other matters.
+pub const POLICY: &str = "http://purl.org/dc/terms/Policy";
+
+/// `dcterms:ProvenanceStatement`
+///
+/// Any changes in ownership and custody of a resource since its creation that are significant for
+/// its authenticity, integrity, and interpretation.
+pub const PROVENANCE_STATEMENT: &str = "http://purl.org/dc/terms/ProvenanceStatement";
+
+/// `dcterms:RightsStatement`
+///
+/// A statement about the intellectual property rights (IPR) held in or over a resource, a legal
+/// document giving official permission to do something with a resource, or a statement about access
+/// rights.
+pub const RIGHTS_STATEMENT: &str = "http://purl.org/dc/terms/RightsStatement";
+
+/// `dcterms:SizeOrDuration`
+///
+/// A dimension or extent, or a time taken to play or execute.
+pub const SIZE_OR_DURATION: &str = "http://purl.org/dc/terms/SizeOrDuration";
+
+/// `dcterms:Standard`
+///
+/// A reference point against which other things can be evaluated or compared.
+pub const STANDARD: &str = "http://purl.org/dc/terms/Standard";
+
+/// `dcterms:abstract`
+///
+/// A summary of the resource.
+pub const ABSTRACT: &str = "http://purl.org/dc/terms/abstract";
+
+/// `dcterms:accessRights`
+///
+/// Information about who access the resource or an indication of its security status.
+pub const ACCESS_RIGHTS: &str = "http://purl.org/dc/terms/accessRights";
+
+/// `dcterms:accrualMethod`
+///
+/// The method by which items are added to a collection.
+pub const ACCRUAL_METHOD: &str = "http://purl.org/dc/terms/accrualMethod";
+
+/// `dcterms:accrualPeriodicity`
+///
+/// The frequency with which items are added to a collection.
+pub const ACCRUAL_PERIODICITY: &str = "http://purl.org/dc/terms/accrualPeriodicity";
+
+/// `dcterms:accrualPolicy`
+///
+/// The policy governing the addition of items to a collection.
+pub const ACCRUAL_POLICY: &str = "http://purl.org/dc/terms/accrualPolicy";
+
+/// `dcterms:alternative`
+///
+/// An alternative name for the resource.
+pub const ALTERNATIVE: &str = "http://purl.org/dc/terms/alternative";
+
+/// `dcterms:audience`
+///
+/// A class of agents for whom the resource is intended or useful.
+pub const AUDIENCE: &str = "http://purl.org/dc/terms/audience";
+
+/// `dcterms:available`
+///
+/// Date that the resource became or will become available.
+pub const AVAILABLE: &str = "http://purl.org/dc/terms/available";
+
+/// `dcterms:bibliographicCitation`
+///
+/// A bibliographic reference for the resource.
+pub const BIBLIOGRAPHIC_CITATION: &str = "http://purl.org/dc/terms/bibliographicCitation";
+
+/// `dcterms:conformsTo`
+///
+/// An established standard to which the described resource conforms.
+pub const CONFORMS_TO: &str = "http://purl.org/dc/terms/conformsTo";
+
+/// `dcterms:contributor`
+///
+/// An entity responsible for making contributions to the resource.
+pub const CONTRIBUTOR: &str = "http://purl.org/dc/terms/contributor";
+
+/// `dcterms:coverage`
+///
+/// The spatial or temporal topic of the resource, spatial applicability of the resource, or
+/// jurisdiction under which the resource is relevant.
+pub const COVERAGE: &str = "http://purl.org/dc/terms/coverage";
+
+/// `dcterms:created`
+///
+/// Date of creation of the resource.
+pub const CREATED: &str = "http://purl.org/dc/terms/created";
+
+/// `dcterms:creator`
+///
+/// An entity responsible for making the resource.
+pub const CREATOR: &str = "http://purl.org/dc/terms/creator";
+
+/// `dcterms:date`
+///
+/// A point or period of time associated with an event in the lifecycle of the resource.
+pub const DATE: &str = "http://purl.org/dc/terms/date";
+
+/// `dcterms:dateAccepted`
+///
+/// Date of acceptance of the resource.
+pub const DATE_ACCEPTED: &str = "http://purl.org/dc/terms/dateAccepted";
+
+/// `dcterms:dateCopyrighted`
+///
+/// Date of copyright of the resource.
+pub const DATE_COPYRIGHTED: &str = "http://purl.org/dc/terms/dateCopyrighted";
+
+/// `dcterms:dateSubmitted`
+///
+/// Date of submission of the resource.
+pub const DATE_SUBMITTED: &str = "http://purl.org/dc/terms/dateSubmitted";
+
+/// `dcterms:description`
+///
+/// An account of the resource.
+pub const DESCRIPTION: &str = "http://purl.org/dc/terms/description";
+
+/// `dcterms:educationLevel`
+///
+/// A class of agents, defined in terms of progression through an educational or training context,
+/// for which the described resource is intended.
+pub const EDUCATION_LEVEL: &str = "http://purl.org/dc/terms/educationLevel";
+
+/// `dcterms:extent`
+///
+/// The size or duration of the resource.
+pub const EXTENT: &str = "http://purl.org/dc/terms/extent";
+
+/// `dcterms:format`
+///
+/// The file format, physical medium, or dimensions of the resource.
+pub const FORMAT: &str = "http://purl.org/dc/terms/format";
+
+/// `dcterms:hasFormat`
+///
+/// A related resource that is substantially the same as the pre-existing described resource, but in
+/// another format.
+pub const HAS_FORMAT: &str = "http://purl.org/dc/terms/hasFormat";
+
+/// `dcterms:hasPart`
+///
+/// A related resource that is included either physically or logically in the described resource.
+pub const HAS_PART: &str = "http://purl.org/dc/terms/hasPart";
+
+/// `dcterms:hasVersion`
+///
+/// A related resource that is a version, edition, or adaptation of the described resource.
+pub const HAS_VERSION: &str = "http://purl.org/dc/terms/hasVersion";
+
+/// `dcterms:identifier`
+///
+/// An unambiguous reference to the resource within a given context.
+pub const IDENTIFIER: &str = "http://purl.org/dc/terms/identifier";
+
+/// `dcterms:instructionalMethod`
+///
+/// A process, used to engender knowledge, attitudes and skills, that the described resource is
+/// designed to support.
+pub const INSTRUCTIONAL_METHOD: &str = "http://purl.org/dc/terms/instructionalMethod";
+
+/// `dcterms:isFormatOf`
+///
+/// A pre-existing related resource that is substantially the same as the described resource, but in
+/// another format.
+pub const IS_FORMAT_OF: &str = "http://purl.org/dc/terms/isFormatOf";
+
+/// `dcterms:isPartOf`
+///
+/// A related resource in which the described resource is physically or logically included.
+pub const IS_PART_OF: &str = "http://purl.org/dc/terms/isPartOf";
+
+/// `dcterms:isReferencedBy`
+///
+/// A related resource that references, cites, or otherwise points to the described resource.
+pub const IS_REFERENCED_BY: &str = "http://purl.org/dc/terms/isReferencedBy";
+
+/// `dcterms:isReplacedBy`
+///
+/// A related resource that supplants, displaces, or supersedes the described resource.
+pub const IS_REPLACED_BY: &str = "http://purl.org/dc/terms/isReplacedBy";
+
+/// `dcterms:isRequiredBy`
+///
+/// A related resource that requires the described resource to support its function, delivery, or
+/// coherence.
+pub const IS_REQUIRED_BY: &str = "http://purl.org/dc/terms/isRequiredBy";
+
+/// `dcterms:isVersionOf`
+///
+/// A related resource of which the described resource is a version, edition, or adaptation.
+pub const IS_VERSION_OF: &str = "http://purl.org/dc/terms/isVersionOf";
+
+/// `dcterms:issued`
+///
+/// Date of formal issuance of the resource.
+pub const ISSUED: &str = "http://purl.org/dc/terms/issued";
+
+/// `dcterms:language`
+///
+/// A language of the resource.
+pub const LANGUAGE: &str = "http://purl.org/dc/terms/language";
+
+/// `dcterms:license`
+///
+/// A legal document giving official permission to do something with the resource.
+pub const LICENSE: &str = "http://purl.org/dc/terms/license";
+
+/// `dcterms:mediator`
+///
+/// An entity that mediates access to the resource.
+pub const MEDIATOR: &str = "http://purl.org/dc/terms/mediator";
+
+/// `dcterms:medium`
+///
+/// The material or physical carrier of the resource.
+pub const MEDIUM: &str = "http://purl.org/dc/terms/medium";
+
+/// `dcterms:modified`
+///
+/// Date on which the resource was changed.
+pub const MODIFIED: &str = "http://purl.org/dc/terms/modified";
+
+/// `dcterms:provenance`
+///
+/// A statement of any changes in ownership and custody of the resource since its creation that are
+/// significant for its authenticity, integrity, and interpretation.
+pub const PROVENANCE: &str = "http://purl.org/dc/terms/provenance";
+
+/// `dcterms:publisher`
+///
+/// An entity responsible for making the resource available.
+pub const PUBLISHER: &str = "http://purl.org/dc/terms/publisher";
+
+/// `dcterms:references`
+///
+/// A related resource that is referenced, cited, or otherwise pointed to by the described resource.
+pub const REFERENCES: &str = "http://purl.org/dc/terms/references";
+
+/// `dcterms:relation`
+///
+/// A related resource.
+pub const RELATION: &str = "http://purl.org/dc/terms/relation";
+
+/// `dcterms:replaces`
+///
+/// A related resource that is supplanted, displaced, or superseded by the described resource.
+pub const REPLACES: &str = "http://purl.org/dc/terms/replaces";
+
+/// `dcterms:requires`
+///
+/// A related resource that is required by the described resource to support its function, delivery,
+/// or coherence.
+pub const REQUIRES: &str = "http://purl.org/dc/terms/requires";
+
+/// `dcterms:rights`
+///
+/// Information about rights held in and over the resource.
+pub const RIGHTS: &str = "http://purl.org/dc/terms/rights";
+
+/// `dcterms:rightsHolder`
+///
+/// A person or organization owning or managing rights over the resource.
+pub const RIGHTS_HOLDER: &str = "http://purl.org/dc/terms/rightsHolder";
+
+/// `dcterms:source`
+///
+/// A related resource from which the described resource is derived.
+pub const SOURCE: &str = "http://purl.org/dc/terms/source";
+
+/// `dcterms:spatial`
+///
+/// Spatial characteristics of the resource.
+pub const SPATIAL: &str = "http://purl.org/dc/terms/spatial";
+
+/// `dcterms:subject`
+///
+/// A topic of the resource.
+pub const SUBJECT: &str = "http://purl.org/dc/terms/subject";
+
+/// `dcterms:tableOfContents`
+///
+/// A list of subunits of the resource.
+pub const TABLE_OF_CONTENTS: &str = "http://purl.org/dc/terms/tableOfContents";
+
+/// `dcterms:temporal`
+///
+/// Temporal characteristics of the resource.
+pub const TEMPORAL: &str = "http://purl.org/dc/terms/temporal";
+
+/// `dcterms:title`
+///
+/// A name given to the resource.
+pub const TITLE: &str = "http://purl.org/dc/terms/title";
+
+/// `dcterms:type`
+///
+/// The nature or genre of the resource.
+pub const TYPE: &str = "http://purl.org/dc/terms/type";
+
+/// `dcterms:valid`
+///
+/// Date (often a range) of validity of a resource.
+pub const VALID: &str = "http://purl.org/dc/terms/valid";
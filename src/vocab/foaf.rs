@@ -0,0 +1,378 @@
+//! Friend of a Friend (FOAF) vocabulary, `http://xmlns.com/foaf/0.1/`.
+
+/// Prefix that is bound to the namespace by default.
+pub const DEFAULT_PREFIX: &str = "foaf";
+
+/// Namespace URI of the vocabulary.
+pub const NAMESPACE_URI: &str = "http://xmlns.com/foaf/0.1/";
+
+/// `foaf:Agent`
+///
+/// An agent (e.g. person, group, software or physical artifact).
+pub const AGENT: &str = "http://xmlns.com/foaf/0.1/Agent";
+
+/// `foaf:Document`
+///
+/// A document.
+pub const DOCUMENT: &str = "http://xmlns.com/foaf/0.1/Document";
+
+/// `foaf:Group`
+///
+/// A class of agents.
+pub const GROUP: &str = "http://xmlns.com/foaf/0.1/Group";
+
+/// `foaf:Image`
+///
+/// An image.
+pub const IMAGE: &str = "http://xmlns.com/foaf/0.1/Image";
+
+/// `foaf:LabelProperty`
+///
+/// A foaf:LabelProperty is any RDF property with textual values that serve as labels.
+pub const LABEL_PROPERTY: &str = "http://xmlns.com/foaf/0.1/LabelProperty";
+
+/// `foaf:OnlineAccount`
+///
+/// An online account.
+pub const ONLINE_ACCOUNT: &str = "http://xmlns.com/foaf/0.1/OnlineAccount";
+
+/// `foaf:OnlineChatAccount`
+///
+/// An online chat account.
+pub const ONLINE_CHAT_ACCOUNT: &str = "http://xmlns.com/foaf/0.1/OnlineChatAccount";
+
+/// `foaf:OnlineEcommerceAccount`
+///
+/// An online e-commerce account.
+pub const ONLINE_ECOMMERCE_ACCOUNT: &str = "http://xmlns.com/foaf/0.1/OnlineEcommerceAccount";
+
+/// `foaf:OnlineGamingAccount`
+///
+/// An online gaming account.
+pub const ONLINE_GAMING_ACCOUNT: &str = "http://xmlns.com/foaf/0.1/OnlineGamingAccount";
+
+/// `foaf:Organization`
+///
+/// An organization.
+pub const ORGANIZATION: &str = "http://xmlns.com/foaf/0.1/Organization";
+
+/// `foaf:Person`
+///
+/// A person.
+pub const PERSON: &str = "http://xmlns.com/foaf/0.1/Person";
+
+/// `foaf:PersonalProfileDocument`
+///
+/// A personal profile RDF document.
+pub const PERSONAL_PROFILE_DOCUMENT: &str = "http://xmlns.com/foaf/0.1/PersonalProfileDocument";
+
+/// `foaf:Project`
+///
+/// A project (a collective endeavour of some kind).
+pub const PROJECT: &str = "http://xmlns.com/foaf/0.1/Project";
+
+/// `foaf:account`
+///
+/// Indicates an account held by this agent.
+pub const ACCOUNT: &str = "http://xmlns.com/foaf/0.1/account";
+
+/// `foaf:accountName`
+///
+/// Indicates the name (identifier) associated with this online account.
+pub const ACCOUNT_NAME: &str = "http://xmlns.com/foaf/0.1/accountName";
+
+/// `foaf:accountServiceHomepage`
+///
+/// Indicates a homepage of the service provide for this online account.
+pub const ACCOUNT_SERVICE_HOMEPAGE: &str = "http://xmlns.com/foaf/0.1/accountServiceHomepage";
+
+/// `foaf:age`
+///
+/// The age in years of some agent.
+pub const AGE: &str = "http://xmlns.com/foaf/0.1/age";
+
+/// `foaf:aimChatID`
+///
+/// An AIM chat ID.
+pub const AIM_CHAT_ID: &str = "http://xmlns.com/foaf/0.1/aimChatID";
+
+/// `foaf:based_near`
+///
+/// A location that something is based near, for some broadly human notion of near.
+pub const BASED_NEAR: &str = "http://xmlns.com/foaf/0.1/based_near";
+
+/// `foaf:birthday`
+///
+/// The birthday of this agent, represented in mm-dd string form.
+pub const BIRTHDAY: &str = "http://xmlns.com/foaf/0.1/birthday";
+
+/// `foaf:currentProject`
+///
+/// A current project this person works on.
+pub const CURRENT_PROJECT: &str = "http://xmlns.com/foaf/0.1/currentProject";
+
+/// `foaf:depiction`
+///
+/// A depiction of some thing.
+pub const DEPICTION: &str = "http://xmlns.com/foaf/0.1/depiction";
+
+/// `foaf:depicts`
+///
+/// A thing depicted in this representation.
+pub const DEPICTS: &str = "http://xmlns.com/foaf/0.1/depicts";
+
+/// `foaf:dnaChecksum`
+///
+/// A checksum for the DNA of some thing. Joke.
+pub const DNA_CHECKSUM: &str = "http://xmlns.com/foaf/0.1/dnaChecksum";
+
+/// `foaf:familyName`
+///
+/// The family name of some person.
+pub const FAMILY_NAME: &str = "http://xmlns.com/foaf/0.1/familyName";
+
+/// `foaf:firstName`
+///
+/// The first name of a person.
+pub const FIRST_NAME: &str = "http://xmlns.com/foaf/0.1/firstName";
+
+/// `foaf:focus`
+///
+/// The underlying or 'focal' entity associated with some SKOS-described concept.
+pub const FOCUS: &str = "http://xmlns.com/foaf/0.1/focus";
+
+/// `foaf:fundedBy`
+///
+/// An organization funding a project or person.
+pub const FUNDED_BY: &str = "http://xmlns.com/foaf/0.1/fundedBy";
+
+/// `foaf:geekcode`
+///
+/// A textual geekcode for this person, see <http://www.geekcode.com/geek.html>.
+pub const GEEKCODE: &str = "http://xmlns.com/foaf/0.1/geekcode";
+
+/// `foaf:gender`
+///
+/// The gender of this agent (typically but not necessarily 'male' or 'female').
+pub const GENDER: &str = "http://xmlns.com/foaf/0.1/gender";
+
+/// `foaf:givenName`
+///
+/// The given name of some person.
+pub const GIVEN_NAME: &str = "http://xmlns.com/foaf/0.1/givenName";
+
+/// `foaf:holdsAccount`
+///
+/// Indicates an account held by this agent.
+pub const HOLDS_ACCOUNT: &str = "http://xmlns.com/foaf/0.1/holdsAccount";
+
+/// `foaf:homepage`
+///
+/// A homepage for some thing.
+pub const HOMEPAGE: &str = "http://xmlns.com/foaf/0.1/homepage";
+
+/// `foaf:icqChatID`
+///
+/// An ICQ chat ID.
+pub const ICQ_CHAT_ID: &str = "http://xmlns.com/foaf/0.1/icqChatID";
+
+/// `foaf:img`
+///
+/// An image that can be used to represent some thing (i.e. those depictions which are particularly
+/// representative of something, e.g. one's photo on a homepage).
+pub const IMG: &str = "http://xmlns.com/foaf/0.1/img";
+
+/// `foaf:interest`
+///
+/// A page about a topic of interest to this person.
+pub const INTEREST: &str = "http://xmlns.com/foaf/0.1/interest";
+
+/// `foaf:isPrimaryTopicOf`
+///
+/// A document that this thing is the primary topic of.
+pub const IS_PRIMARY_TOPIC_OF: &str = "http://xmlns.com/foaf/0.1/isPrimaryTopicOf";
+
+/// `foaf:jabberID`
+///
+/// A jabber ID for something.
+pub const JABBER_ID: &str = "http://xmlns.com/foaf/0.1/jabberID";
+
+/// `foaf:knows`
+///
+/// A person known by this person (indicating some level of reciprocated interaction between the
+/// parties).
+pub const KNOWS: &str = "http://xmlns.com/foaf/0.1/knows";
+
+/// `foaf:lastName`
+///
+/// The last name of a person.
+pub const LAST_NAME: &str = "http://xmlns.com/foaf/0.1/lastName";
+
+/// `foaf:logo`
+///
+/// A logo representing some thing.
+pub const LOGO: &str = "http://xmlns.com/foaf/0.1/logo";
+
+/// `foaf:made`
+///
+/// Something that was made by this agent.
+pub const MADE: &str = "http://xmlns.com/foaf/0.1/made";
+
+/// `foaf:maker`
+///
+/// An agent that made this thing.
+pub const MAKER: &str = "http://xmlns.com/foaf/0.1/maker";
+
+/// `foaf:mbox`
+///
+/// A personal mailbox, i.e. an Internet mailbox associated with exactly one owner, the first owner
+/// of this mailbox.
+pub const MBOX: &str = "http://xmlns.com/foaf/0.1/mbox";
+
+/// `foaf:mbox_sha1sum`
+///
+/// The sha1sum of the URI of an Internet mailbox associated with exactly one owner, the first owner
+/// of the mailbox.
+pub const MBOX_SHA1SUM: &str = "http://xmlns.com/foaf/0.1/mbox_sha1sum";
+
+/// `foaf:member`
+///
+/// Indicates a member of a group.
+pub const MEMBER: &str = "http://xmlns.com/foaf/0.1/member";
+
+/// `foaf:membershipClass`
+///
+/// Indicates the class of individuals that are a member of a group.
+pub const MEMBERSHIP_CLASS: &str = "http://xmlns.com/foaf/0.1/membershipClass";
+
+/// `foaf:msnChatID`
+///
+/// An MSN chat ID.
+pub const MSN_CHAT_ID: &str = "http://xmlns.com/foaf/0.1/msnChatID";
+
+/// `foaf:myersBriggs`
+///
+/// A Myers Briggs (MBTI) personality classification.
+pub const MYERS_BRIGGS: &str = "http://xmlns.com/foaf/0.1/myersBriggs";
+
+/// `foaf:name`
+///
+/// A name for some thing.
+pub const NAME: &str = "http://xmlns.com/foaf/0.1/name";
+
+/// `foaf:nick`
+///
+/// A short informal nickname characterising an agent (includes login identifiers, IRC and other
+/// chat nicknames).
+pub const NICK: &str = "http://xmlns.com/foaf/0.1/nick";
+
+/// `foaf:openid`
+///
+/// An OpenID for an agent.
+pub const OPENID: &str = "http://xmlns.com/foaf/0.1/openid";
+
+/// `foaf:page`
+///
+/// A page or document about this thing.
+pub const PAGE: &str = "http://xmlns.com/foaf/0.1/page";
+
+/// `foaf:pastProject`
+///
+/// A project this person has previously worked on.
+pub const PAST_PROJECT: &str = "http://xmlns.com/foaf/0.1/pastProject";
+
+/// `foaf:phone`
+///
+/// A phone, specified using fully qualified tel: URI scheme.
+pub const PHONE: &str = "http://xmlns.com/foaf/0.1/phone";
+
+/// `foaf:plan`
+///
+/// A .plan comment, in the tradition of finger and '.plan' files.
+pub const PLAN: &str = "http://xmlns.com/foaf/0.1/plan";
+
+/// `foaf:primaryTopic`
+///
+/// The primary topic of some page or document.
+pub const PRIMARY_TOPIC: &str = "http://xmlns.com/foaf/0.1/primaryTopic";
+
+/// `foaf:publications`
+///
+/// A link to the publications of this person.
+pub const PUBLICATIONS: &str = "http://xmlns.com/foaf/0.1/publications";
+
+/// `foaf:schoolHomepage`
+///
+/// A homepage of a school attended by the person.
+pub const SCHOOL_HOMEPAGE: &str = "http://xmlns.com/foaf/0.1/schoolHomepage";
+
+/// `foaf:sha1`
+///
+/// A sha1sum hash, in hex.
+pub const SHA1: &str = "http://xmlns.com/foaf/0.1/sha1";
+
+/// `foaf:skypeID`
+///
+/// A Skype ID.
+pub const SKYPE_ID: &str = "http://xmlns.com/foaf/0.1/skypeID";
+
+/// `foaf:status`
+///
+/// A string expressing what the user is happy for the general public (normally) to know about their
+/// current activity.
+pub const STATUS: &str = "http://xmlns.com/foaf/0.1/status";
+
+/// `foaf:surname`
+///
+/// The surname of some person.
+pub const SURNAME: &str = "http://xmlns.com/foaf/0.1/surname";
+
+/// `foaf:theme`
+///
+/// A theme.
+pub const THEME: &str = "http://xmlns.com/foaf/0.1/theme";
+
+/// `foaf:thumbnail`
+///
+/// A derived thumbnail image.
+pub const THUMBNAIL: &str = "http://xmlns.com/foaf/0.1/thumbnail";
+
+/// `foaf:tipjar`
+///
+/// A tipjar document for this agent, describing means for payment and reward.
+pub const TIPJAR: &str = "http://xmlns.com/foaf/0.1/tipjar";
+
+/// `foaf:title`
+///
+/// Title (Mr, Mrs, Ms, Dr. etc).
+pub const TITLE: &str = "http://xmlns.com/foaf/0.1/title";
+
+/// `foaf:topic`
+///
+/// A topic of some page or document.
+pub const TOPIC: &str = "http://xmlns.com/foaf/0.1/topic";
+
+/// `foaf:topic_interest`
+///
+/// A thing of interest to this person.
+pub const TOPIC_INTEREST: &str = "http://xmlns.com/foaf/0.1/topic_interest";
+
+/// `foaf:weblog`
+///
+/// A weblog of some thing (whether person, group, company etc.).
+pub const WEBLOG: &str = "http://xmlns.com/foaf/0.1/weblog";
+
+/// `foaf:workInfoHomepage`
+///
+/// A work info homepage of some person; a page about their work for some organization.
+pub const WORK_INFO_HOMEPAGE: &str = "http://xmlns.com/foaf/0.1/workInfoHomepage";
+
+/// `foaf:workplaceHomepage`
+///
+/// A workplace homepage of some person; the homepage of an organization they work for.
+pub const WORKPLACE_HOMEPAGE: &str = "http://xmlns.com/foaf/0.1/workplaceHomepage";
+
+/// `foaf:yahooChatID`
+///
+/// A Yahoo chat ID.
+pub const YAHOO_CHAT_ID: &str = "http://xmlns.com/foaf/0.1/yahooChatID";
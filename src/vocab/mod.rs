@@ -0,0 +1,104 @@
+pub mod dc;
+pub mod dcterms;
+pub mod foaf;
+pub mod owl;
+pub mod rdf;
+pub mod rdfs;
+pub mod sh;
+pub mod skos;
+pub mod xsd;
+
+#[cfg(test)]
+mod tests {
+    use crate::codegen::upper_snake_case;
+    use crate::vocab::*;
+    use std::collections::BTreeSet;
+
+    /// Returns the name and value of each constant that is declared in the source of a module.
+    fn constants(source: &str) -> Vec<(&str, &str)> {
+        source
+            .split("pub const ")
+            .skip(1)
+            .map(|declaration| {
+                let name = &declaration[..declaration.find(':').unwrap()];
+                let value = declaration.split('"').nth(1).unwrap();
+
+                (name, value)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn constants_in_namespace() {
+        let vocabularies = vec![
+            (dc::DEFAULT_PREFIX, dc::NAMESPACE_URI, include_str!("dc.rs")),
+            (
+                dcterms::DEFAULT_PREFIX,
+                dcterms::NAMESPACE_URI,
+                include_str!("dcterms.rs"),
+            ),
+            (
+                foaf::DEFAULT_PREFIX,
+                foaf::NAMESPACE_URI,
+                include_str!("foaf.rs"),
+            ),
+            (
+                owl::DEFAULT_PREFIX,
+                owl::NAMESPACE_URI,
+                include_str!("owl.rs"),
+            ),
+            (
+                rdf::DEFAULT_PREFIX,
+                rdf::NAMESPACE_URI,
+                include_str!("rdf.rs"),
+            ),
+            (
+                rdfs::DEFAULT_PREFIX,
+                rdfs::NAMESPACE_URI,
+                include_str!("rdfs.rs"),
+            ),
+            (sh::DEFAULT_PREFIX, sh::NAMESPACE_URI, include_str!("sh.rs")),
+            (
+                skos::DEFAULT_PREFIX,
+                skos::NAMESPACE_URI,
+                include_str!("skos.rs"),
+            ),
+            (
+                xsd::DEFAULT_PREFIX,
+                xsd::NAMESPACE_URI,
+                include_str!("xsd.rs"),
+            ),
+        ];
+
+        for (prefix, namespace, source) in vocabularies {
+            let constants = constants(source);
+
+            assert_eq!(constants[0], ("DEFAULT_PREFIX", prefix));
+            assert_eq!(constants[1], ("NAMESPACE_URI", namespace));
+
+            let mut iris = BTreeSet::new();
+
+            for &(name, iri) in constants[2..].iter() {
+                let local_name = match iri.strip_prefix(namespace) {
+                    Some(local_name) => local_name,
+                    None => panic!("{} is not in the namespace {}", iri, namespace),
+                };
+                let expected = upper_snake_case(local_name);
+
+                assert!(
+                    name == expected || name == expected.clone() + "_PROPERTY",
+                    "{} is not the constant name of {}",
+                    name,
+                    iri
+                );
+                assert!(iris.insert(iri), "{} is declared twice", iri);
+            }
+        }
+
+        assert_eq!(rdf::TYPE, "http://www.w3.org/1999/02/22-rdf-syntax-ns#type");
+        assert_eq!(
+            skos::PREF_LABEL,
+            "http://www.w3.org/2004/02/skos/core#prefLabel"
+        );
+    }
+}
@@ -0,0 +1,420 @@
+//! OWL 2 vocabulary, `http://www.w3.org/2002/07/owl#`.
+
+/// Prefix that is bound to the namespace by default.
+pub const DEFAULT_PREFIX: &str = "owl";
+
+/// Namespace URI of the vocabulary.
+pub const NAMESPACE_URI: &str = "http://www.w3.org/2002/07/owl#";
+
+/// `owl:AllDifferent`
+///
+/// The class of collections of pairwise different individuals.
+pub const ALL_DIFFERENT: &str = "http://www.w3.org/2002/07/owl#AllDifferent";
+
+/// `owl:AllDisjointClasses`
+///
+/// The class of collections of pairwise disjoint classes.
+pub const ALL_DISJOINT_CLASSES: &str = "http://www.w3.org/2002/07/owl#AllDisjointClasses";
+
+/// `owl:AllDisjointProperties`
+///
+/// The class of collections of pairwise disjoint properties.
+pub const ALL_DISJOINT_PROPERTIES: &str = "http://www.w3.org/2002/07/owl#AllDisjointProperties";
+
+/// `owl:Annotation`
+///
+/// The class of annotated annotations for which the RDF serialization consists of an annotated
+/// subject, predicate and object.
+pub const ANNOTATION: &str = "http://www.w3.org/2002/07/owl#Annotation";
+
+/// `owl:AnnotationProperty`
+///
+/// The class of annotation properties.
+pub const ANNOTATION_PROPERTY: &str = "http://www.w3.org/2002/07/owl#AnnotationProperty";
+
+/// `owl:AsymmetricProperty`
+///
+/// The class of asymmetric properties.
+pub const ASYMMETRIC_PROPERTY: &str = "http://www.w3.org/2002/07/owl#AsymmetricProperty";
+
+/// `owl:Axiom`
+///
+/// The class of annotated axioms for which the RDF serialization consists of an annotated subject,
+/// predicate and object.
+pub const AXIOM: &str = "http://www.w3.org/2002/07/owl#Axiom";
+
+/// `owl:Class`
+///
+/// The class of OWL classes.
+pub const CLASS: &str = "http://www.w3.org/2002/07/owl#Class";
+
+/// `owl:DataRange`
+///
+/// The class of OWL data ranges, which are special kinds of datatypes.
+pub const DATA_RANGE: &str = "http://www.w3.org/2002/07/owl#DataRange";
+
+/// `owl:DatatypeProperty`
+///
+/// The class of data properties.
+pub const DATATYPE_PROPERTY: &str = "http://www.w3.org/2002/07/owl#DatatypeProperty";
+
+/// `owl:DeprecatedClass`
+///
+/// The class of deprecated classes.
+pub const DEPRECATED_CLASS: &str = "http://www.w3.org/2002/07/owl#DeprecatedClass";
+
+/// `owl:DeprecatedProperty`
+///
+/// The class of deprecated properties.
+pub const DEPRECATED_PROPERTY: &str = "http://www.w3.org/2002/07/owl#DeprecatedProperty";
+
+/// `owl:FunctionalProperty`
+///
+/// The class of functional properties.
+pub const FUNCTIONAL_PROPERTY: &str = "http://www.w3.org/2002/07/owl#FunctionalProperty";
+
+/// `owl:InverseFunctionalProperty`
+///
+/// The class of inverse-functional properties.
+pub const INVERSE_FUNCTIONAL_PROPERTY: &str =
+    "http://www.w3.org/2002/07/owl#InverseFunctionalProperty";
+
+/// `owl:IrreflexiveProperty`
+///
+/// The class of irreflexive properties.
+pub const IRREFLEXIVE_PROPERTY: &str = "http://www.w3.org/2002/07/owl#IrreflexiveProperty";
+
+/// `owl:NamedIndividual`
+///
+/// The class of named individuals.
+pub const NAMED_INDIVIDUAL: &str = "http://www.w3.org/2002/07/owl#NamedIndividual";
+
+/// `owl:NegativePropertyAssertion`
+///
+/// The class of negative property assertions.
+pub const NEGATIVE_PROPERTY_ASSERTION: &str =
+    "http://www.w3.org/2002/07/owl#NegativePropertyAssertion";
+
+/// `owl:Nothing`
+///
+/// This is the empty class.
+pub const NOTHING: &str = "http://www.w3.org/2002/07/owl#Nothing";
+
+/// `owl:ObjectProperty`
+///
+/// The class of object properties.
+pub const OBJECT_PROPERTY: &str = "http://www.w3.org/2002/07/owl#ObjectProperty";
+
+/// `owl:Ontology`
+///
+/// The class of ontologies.
+pub const ONTOLOGY: &str = "http://www.w3.org/2002/07/owl#Ontology";
+
+/// `owl:OntologyProperty`
+///
+/// The class of ontology properties.
+pub const ONTOLOGY_PROPERTY: &str = "http://www.w3.org/2002/07/owl#OntologyProperty";
+
+/// `owl:ReflexiveProperty`
+///
+/// The class of reflexive properties.
+pub const REFLEXIVE_PROPERTY: &str = "http://www.w3.org/2002/07/owl#ReflexiveProperty";
+
+/// `owl:Restriction`
+///
+/// The class of property restrictions.
+pub const RESTRICTION: &str = "http://www.w3.org/2002/07/owl#Restriction";
+
+/// `owl:SymmetricProperty`
+///
+/// The class of symmetric properties.
+pub const SYMMETRIC_PROPERTY: &str = "http://www.w3.org/2002/07/owl#SymmetricProperty";
+
+/// `owl:Thing`
+///
+/// The class of OWL individuals.
+pub const THING: &str = "http://www.w3.org/2002/07/owl#Thing";
+
+/// `owl:TransitiveProperty`
+///
+/// The class of transitive properties.
+pub const TRANSITIVE_PROPERTY: &str = "http://www.w3.org/2002/07/owl#TransitiveProperty";
+
+/// `owl:allValuesFrom`
+///
+/// The property that determines the class that a universal property restriction refers to.
+pub const ALL_VALUES_FROM: &str = "http://www.w3.org/2002/07/owl#allValuesFrom";
+
+/// `owl:annotatedProperty`
+///
+/// The property that determines the predicate of an annotated axiom or annotated annotation.
+pub const ANNOTATED_PROPERTY: &str = "http://www.w3.org/2002/07/owl#annotatedProperty";
+
+/// `owl:annotatedSource`
+///
+/// The property that determines the subject of an annotated axiom or annotated annotation.
+pub const ANNOTATED_SOURCE: &str = "http://www.w3.org/2002/07/owl#annotatedSource";
+
+/// `owl:annotatedTarget`
+///
+/// The property that determines the object of an annotated axiom or annotated annotation.
+pub const ANNOTATED_TARGET: &str = "http://www.w3.org/2002/07/owl#annotatedTarget";
+
+/// `owl:assertionProperty`
+///
+/// The property that determines the predicate of a negative property assertion.
+pub const ASSERTION_PROPERTY: &str = "http://www.w3.org/2002/07/owl#assertionProperty";
+
+/// `owl:backwardCompatibleWith`
+///
+/// The annotation property that indicates that a given ontology is backward compatible with another
+/// ontology.
+pub const BACKWARD_COMPATIBLE_WITH: &str = "http://www.w3.org/2002/07/owl#backwardCompatibleWith";
+
+/// `owl:bottomDataProperty`
+///
+/// The data property that does not relate any individual to any data value.
+pub const BOTTOM_DATA_PROPERTY: &str = "http://www.w3.org/2002/07/owl#bottomDataProperty";
+
+/// `owl:bottomObjectProperty`
+///
+/// The object property that does not relate any two individuals.
+pub const BOTTOM_OBJECT_PROPERTY: &str = "http://www.w3.org/2002/07/owl#bottomObjectProperty";
+
+/// `owl:cardinality`
+///
+/// The property that determines the cardinality of an exact cardinality restriction.
+pub const CARDINALITY: &str = "http://www.w3.org/2002/07/owl#cardinality";
+
+/// `owl:complementOf`
+///
+/// The property that determines that a given class is the complement of another class.
+pub const COMPLEMENT_OF: &str = "http://www.w3.org/2002/07/owl#complementOf";
+
+/// `owl:datatypeComplementOf`
+///
+/// The property that determines that a given data range is the complement of another data range
+/// with respect to the data domain.
+pub const DATATYPE_COMPLEMENT_OF: &str = "http://www.w3.org/2002/07/owl#datatypeComplementOf";
+
+/// `owl:deprecated`
+///
+/// The annotation property that indicates that a given entity has been deprecated.
+pub const DEPRECATED: &str = "http://www.w3.org/2002/07/owl#deprecated";
+
+/// `owl:differentFrom`
+///
+/// The property that determines that two given individuals are different.
+pub const DIFFERENT_FROM: &str = "http://www.w3.org/2002/07/owl#differentFrom";
+
+/// `owl:disjointUnionOf`
+///
+/// The property that determines that a given class is equivalent to the disjoint union of a
+/// collection of other classes.
+pub const DISJOINT_UNION_OF: &str = "http://www.w3.org/2002/07/owl#disjointUnionOf";
+
+/// `owl:disjointWith`
+///
+/// The property that determines that two given classes are disjoint.
+pub const DISJOINT_WITH: &str = "http://www.w3.org/2002/07/owl#disjointWith";
+
+/// `owl:distinctMembers`
+///
+/// The property that determines the collection of pairwise different individuals in an
+/// `owl:AllDifferent` axiom.
+pub const DISTINCT_MEMBERS: &str = "http://www.w3.org/2002/07/owl#distinctMembers";
+
+/// `owl:equivalentClass`
+///
+/// The property that determines that two given classes are equivalent.
+pub const EQUIVALENT_CLASS: &str = "http://www.w3.org/2002/07/owl#equivalentClass";
+
+/// `owl:equivalentProperty`
+///
+/// The property that determines that two given properties are equivalent.
+pub const EQUIVALENT_PROPERTY: &str = "http://www.w3.org/2002/07/owl#equivalentProperty";
+
+/// `owl:hasKey`
+///
+/// The property that determines the collection of properties that jointly build a key.
+pub const HAS_KEY: &str = "http://www.w3.org/2002/07/owl#hasKey";
+
+/// `owl:hasSelf`
+///
+/// The property that determines the property that a self restriction refers to.
+pub const HAS_SELF: &str = "http://www.w3.org/2002/07/owl#hasSelf";
+
+/// `owl:hasValue`
+///
+/// The property that determines the individual that a has-value restriction refers to.
+pub const HAS_VALUE: &str = "http://www.w3.org/2002/07/owl#hasValue";
+
+/// `owl:imports`
+///
+/// The property that is used for importing other ontologies into a given ontology.
+pub const IMPORTS: &str = "http://www.w3.org/2002/07/owl#imports";
+
+/// `owl:incompatibleWith`
+///
+/// The annotation property that indicates that a given ontology is incompatible with another
+/// ontology.
+pub const INCOMPATIBLE_WITH: &str = "http://www.w3.org/2002/07/owl#incompatibleWith";
+
+/// `owl:intersectionOf`
+///
+/// The property that determines the collection of classes or data ranges that build an
+/// intersection.
+pub const INTERSECTION_OF: &str = "http://www.w3.org/2002/07/owl#intersectionOf";
+
+/// `owl:inverseOf`
+///
+/// The property that determines that two given properties are inverse.
+pub const INVERSE_OF: &str = "http://www.w3.org/2002/07/owl#inverseOf";
+
+/// `owl:maxCardinality`
+///
+/// The property that determines the cardinality of a maximum cardinality restriction.
+pub const MAX_CARDINALITY: &str = "http://www.w3.org/2002/07/owl#maxCardinality";
+
+/// `owl:maxQualifiedCardinality`
+///
+/// The property that determines the cardinality of a maximum qualified cardinality restriction.
+pub const MAX_QUALIFIED_CARDINALITY: &str = "http://www.w3.org/2002/07/owl#maxQualifiedCardinality";
+
+/// `owl:members`
+///
+/// The property that determines the collection of members in either an `owl:AllDifferent`,
+/// `owl:AllDisjointClasses` or `owl:AllDisjointProperties` axiom.
+pub const MEMBERS: &str = "http://www.w3.org/2002/07/owl#members";
+
+/// `owl:minCardinality`
+///
+/// The property that determines the cardinality of a minimum cardinality restriction.
+pub const MIN_CARDINALITY: &str = "http://www.w3.org/2002/07/owl#minCardinality";
+
+/// `owl:minQualifiedCardinality`
+///
+/// The property that determines the cardinality of a minimum qualified cardinality restriction.
+pub const MIN_QUALIFIED_CARDINALITY: &str = "http://www.w3.org/2002/07/owl#minQualifiedCardinality";
+
+/// `owl:onClass`
+///
+/// The property that determines the class that a qualified object cardinality restriction refers
+/// to.
+pub const ON_CLASS: &str = "http://www.w3.org/2002/07/owl#onClass";
+
+/// `owl:onDataRange`
+///
+/// The property that determines the data range that a qualified data cardinality restriction refers
+/// to.
+pub const ON_DATA_RANGE: &str = "http://www.w3.org/2002/07/owl#onDataRange";
+
+/// `owl:onDatatype`
+///
+/// The property that determines the datatype that a datatype restriction refers to.
+pub const ON_DATATYPE: &str = "http://www.w3.org/2002/07/owl#onDatatype";
+
+/// `owl:onProperties`
+///
+/// The property that determines the n-tuple of properties that a property restriction on an n-ary
+/// data range refers to.
+pub const ON_PROPERTIES: &str = "http://www.w3.org/2002/07/owl#onProperties";
+
+/// `owl:onProperty`
+///
+/// The property that determines the property that a property restriction refers to.
+pub const ON_PROPERTY: &str = "http://www.w3.org/2002/07/owl#onProperty";
+
+/// `owl:oneOf`
+///
+/// The property that determines the collection of individuals or data values that build an
+/// enumeration.
+pub const ONE_OF: &str = "http://www.w3.org/2002/07/owl#oneOf";
+
+/// `owl:priorVersion`
+///
+/// The annotation property that indicates the predecessor ontology of a given ontology.
+pub const PRIOR_VERSION: &str = "http://www.w3.org/2002/07/owl#priorVersion";
+
+/// `owl:propertyChainAxiom`
+///
+/// The property that determines the n-tuple of properties that build a sub property chain of a
+/// given property.
+pub const PROPERTY_CHAIN_AXIOM: &str = "http://www.w3.org/2002/07/owl#propertyChainAxiom";
+
+/// `owl:propertyDisjointWith`
+///
+/// The property that determines that two given properties are disjoint.
+pub const PROPERTY_DISJOINT_WITH: &str = "http://www.w3.org/2002/07/owl#propertyDisjointWith";
+
+/// `owl:qualifiedCardinality`
+///
+/// The property that determines the cardinality of an exact qualified cardinality restriction.
+pub const QUALIFIED_CARDINALITY: &str = "http://www.w3.org/2002/07/owl#qualifiedCardinality";
+
+/// `owl:rational`
+///
+/// The datatype of rational numbers.
+pub const RATIONAL: &str = "http://www.w3.org/2002/07/owl#rational";
+
+/// `owl:real`
+///
+/// The datatype of real numbers.
+pub const REAL: &str = "http://www.w3.org/2002/07/owl#real";
+
+/// `owl:sameAs`
+///
+/// The property that determines that two given individuals are equal.
+pub const SAME_AS: &str = "http://www.w3.org/2002/07/owl#sameAs";
+
+/// `owl:someValuesFrom`
+///
+/// The property that determines the class that an existential property restriction refers to.
+pub const SOME_VALUES_FROM: &str = "http://www.w3.org/2002/07/owl#someValuesFrom";
+
+/// `owl:sourceIndividual`
+///
+/// The property that determines the subject of a negative property assertion.
+pub const SOURCE_INDIVIDUAL: &str = "http://www.w3.org/2002/07/owl#sourceIndividual";
+
+/// `owl:targetIndividual`
+///
+/// The property that determines the object of a negative object property assertion.
+pub const TARGET_INDIVIDUAL: &str = "http://www.w3.org/2002/07/owl#targetIndividual";
+
+/// `owl:targetValue`
+///
+/// The property that determines the value of a negative data property assertion.
+pub const TARGET_VALUE: &str = "http://www.w3.org/2002/07/owl#targetValue";
+
+/// `owl:topDataProperty`
+///
+/// The data property that relates every individual to every data value.
+pub const TOP_DATA_PROPERTY: &str = "http://www.w3.org/2002/07/owl#topDataProperty";
+
+/// `owl:topObjectProperty`
+///
+/// The object property that relates every two individuals.
+pub const TOP_OBJECT_PROPERTY: &str = "http://www.w3.org/2002/07/owl#topObjectProperty";
+
+/// `owl:unionOf`
+///
+/// The property that determines the collection of classes or data ranges that build a union.
+pub const UNION_OF: &str = "http://www.w3.org/2002/07/owl#unionOf";
+
+/// `owl:versionIRI`
+///
+/// The property that identifies the version IRI of an ontology.
+pub const VERSION_IRI: &str = "http://www.w3.org/2002/07/owl#versionIRI";
+
+/// `owl:versionInfo`
+///
+/// The annotation property that provides version information for an ontology or another OWL
+/// construct.
+pub const VERSION_INFO: &str = "http://www.w3.org/2002/07/owl#versionInfo";
+
+/// `owl:withRestrictions`
+///
+/// The property that determines the collection of facet-value pairs that define a datatype
+/// restriction.
+pub const WITH_RESTRICTIONS: &str = "http://www.w3.org/2002/07/owl#withRestrictions";
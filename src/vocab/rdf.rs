@@ -0,0 +1,117 @@
+//! RDF vocabulary, `http://www.w3.org/1999/02/22-rdf-syntax-ns#`.
+
+/// Prefix that is bound to the namespace by default.
+pub const DEFAULT_PREFIX: &str = "rdf";
+
+/// Namespace URI of the vocabulary.
+pub const NAMESPACE_URI: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#";
+
+/// `rdf:Alt`
+///
+/// The class of containers of alternatives.
+pub const ALT: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#Alt";
+
+/// `rdf:Bag`
+///
+/// The class of unordered containers.
+pub const BAG: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#Bag";
+
+/// `rdf:CompoundLiteral`
+///
+/// The class of literals with a language and a base direction.
+pub const COMPOUND_LITERAL: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#CompoundLiteral";
+
+/// `rdf:HTML`
+///
+/// The datatype of RDF literals storing fragments of HTML content.
+pub const HTML: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#HTML";
+
+/// `rdf:JSON`
+///
+/// The datatype of RDF literals storing JSON content.
+pub const JSON: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#JSON";
+
+/// `rdf:List`
+///
+/// The class of RDF lists.
+pub const LIST: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#List";
+
+/// `rdf:PlainLiteral`
+///
+/// The class of plain (i.e. untyped) literal values, as used in RIF and OWL 2.
+pub const PLAIN_LITERAL: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#PlainLiteral";
+
+/// `rdf:Property`
+///
+/// The class of RDF properties.
+pub const PROPERTY: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#Property";
+
+/// `rdf:Seq`
+///
+/// The class of ordered containers.
+pub const SEQ: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#Seq";
+
+/// `rdf:Statement`
+///
+/// The class of RDF statements.
+pub const STATEMENT: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#Statement";
+
+/// `rdf:XMLLiteral`
+///
+/// The datatype of XML literal values.
+pub const XML_LITERAL: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#XMLLiteral";
+
+/// `rdf:direction`
+///
+/// The base direction component of a compound literal.
+pub const DIRECTION: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#direction";
+
+/// `rdf:first`
+///
+/// The first item in the subject RDF list.
+pub const FIRST: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#first";
+
+/// `rdf:langString`
+///
+/// The datatype of language-tagged string values.
+pub const LANG_STRING: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#langString";
+
+/// `rdf:language`
+///
+/// The language component of a compound literal.
+pub const LANGUAGE: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#language";
+
+/// `rdf:nil`
+///
+/// The empty list, with no items in it.
+pub const NIL: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#nil";
+
+/// `rdf:object`
+///
+/// The object of the subject RDF statement.
+pub const OBJECT: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#object";
+
+/// `rdf:predicate`
+///
+/// The predicate of the subject RDF statement.
+pub const PREDICATE: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#predicate";
+
+/// `rdf:rest`
+///
+/// The rest of the subject RDF list after the first item.
+pub const REST: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#rest";
+
+/// `rdf:subject`
+///
+/// The subject of the subject RDF statement.
+pub const SUBJECT: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#subject";
+
+/// `rdf:type`
+///
+/// The subject is an instance of a class.
+pub const TYPE: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#type";
+
+/// `rdf:value`
+///
+/// Idiomatic property used for structured values.
+pub const VALUE: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#value";
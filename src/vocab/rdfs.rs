@@ -0,0 +1,84 @@
+//! RDF Schema vocabulary, `http://www.w3.org/2000/01/rdf-schema#`.
+
+/// Prefix that is bound to the namespace by default.
+pub const DEFAULT_PREFIX: &str = "rdfs";
+
+/// Namespace URI of the vocabulary.
+pub const NAMESPACE_URI: &str = "http://www.w3.org/2000/01/rdf-schema#";
+
+/// `rdfs:Class`
+///
+/// The class of classes.
+pub const CLASS: &str = "http://www.w3.org/2000/01/rdf-schema#Class";
+
+/// `rdfs:Container`
+///
+/// The class of RDF containers.
+pub const CONTAINER: &str = "http://www.w3.org/2000/01/rdf-schema#Container";
+
+/// `rdfs:ContainerMembershipProperty`
+///
+/// The class of container membership properties, `rdf:_1`, `rdf:_2`, ..., all of which are
+/// sub-properties of `rdfs:member`.
+pub const CONTAINER_MEMBERSHIP_PROPERTY: &str =
+    "http://www.w3.org/2000/01/rdf-schema#ContainerMembershipProperty";
+
+/// `rdfs:Datatype`
+///
+/// The class of RDF datatypes.
+pub const DATATYPE: &str = "http://www.w3.org/2000/01/rdf-schema#Datatype";
+
+/// `rdfs:Literal`
+///
+/// The class of literal values, e.g. textual strings and integers.
+pub const LITERAL: &str = "http://www.w3.org/2000/01/rdf-schema#Literal";
+
+/// `rdfs:Resource`
+///
+/// The class resource, everything.
+pub const RESOURCE: &str = "http://www.w3.org/2000/01/rdf-schema#Resource";
+
+/// `rdfs:comment`
+///
+/// A description of the subject resource.
+pub const COMMENT: &str = "http://www.w3.org/2000/01/rdf-schema#comment";
+
+/// `rdfs:domain`
+///
+/// A domain of the subject property.
+pub const DOMAIN: &str = "http://www.w3.org/2000/01/rdf-schema#domain";
+
+/// `rdfs:isDefinedBy`
+///
+/// The definition of the subject resource.
+pub const IS_DEFINED_BY: &str = "http://www.w3.org/2000/01/rdf-schema#isDefinedBy";
+
+/// `rdfs:label`
+///
+/// A human-readable name for the subject.
+pub const LABEL: &str = "http://www.w3.org/2000/01/rdf-schema#label";
+
+/// `rdfs:member`
+///
+/// A member of the subject resource.
+pub const MEMBER: &str = "http://www.w3.org/2000/01/rdf-schema#member";
+
+/// `rdfs:range`
+///
+/// A range of the subject property.
+pub const RANGE: &str = "http://www.w3.org/2000/01/rdf-schema#range";
+
+/// `rdfs:seeAlso`
+///
+/// Further information about the subject resource.
+pub const SEE_ALSO: &str = "http://www.w3.org/2000/01/rdf-schema#seeAlso";
+
+/// `rdfs:subClassOf`
+///
+/// The subject is a subclass of a class.
+pub const SUB_CLASS_OF: &str = "http://www.w3.org/2000/01/rdf-schema#subClassOf";
+
+/// `rdfs:subPropertyOf`
+///
+/// The subject is a subproperty of a property.
+pub const SUB_PROPERTY_OF: &str = "http://www.w3.org/2000/01/rdf-schema#subPropertyOf";
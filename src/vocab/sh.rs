@@ -0,0 +1,908 @@
+//! Shapes Constraint Language (SHACL) vocabulary, `http://www.w3.org/ns/shacl#`.
+//!
+//! Properties whose names only differ in case from a class end with `_PROPERTY`, e.g.
+//! `VALIDATOR_PROPERTY`.
+
+/// Prefix that is bound to the namespace by default.
+pub const DEFAULT_PREFIX: &str = "sh";
+
+/// Namespace URI of the vocabulary.
+pub const NAMESPACE_URI: &str = "http://www.w3.org/ns/shacl#";
+
+/// `sh:AbstractResult`
+///
+/// The base class of validation results, typically not instantiated directly.
+pub const ABSTRACT_RESULT: &str = "http://www.w3.org/ns/shacl#AbstractResult";
+
+/// `sh:BlankNode`
+///
+/// The node kind of all blank nodes.
+pub const BLANK_NODE: &str = "http://www.w3.org/ns/shacl#BlankNode";
+
+/// `sh:BlankNodeOrIRI`
+///
+/// The node kind of all blank nodes or IRIs.
+pub const BLANK_NODE_OR_IRI: &str = "http://www.w3.org/ns/shacl#BlankNodeOrIRI";
+
+/// `sh:BlankNodeOrLiteral`
+///
+/// The node kind of all blank nodes or literals.
+pub const BLANK_NODE_OR_LITERAL: &str = "http://www.w3.org/ns/shacl#BlankNodeOrLiteral";
+
+/// `sh:ConstraintComponent`
+///
+/// The class of constraint components.
+pub const CONSTRAINT_COMPONENT: &str = "http://www.w3.org/ns/shacl#ConstraintComponent";
+
+/// `sh:Function`
+///
+/// The class of SHACL functions.
+pub const FUNCTION: &str = "http://www.w3.org/ns/shacl#Function";
+
+/// `sh:IRI`
+///
+/// The node kind of all IRIs.
+pub const IRI: &str = "http://www.w3.org/ns/shacl#IRI";
+
+/// `sh:IRIOrLiteral`
+///
+/// The node kind of all IRIs or literals.
+pub const IRI_OR_LITERAL: &str = "http://www.w3.org/ns/shacl#IRIOrLiteral";
+
+/// `sh:Info`
+///
+/// The severity for an informational validation result.
+pub const INFO: &str = "http://www.w3.org/ns/shacl#Info";
+
+/// `sh:Literal`
+///
+/// The node kind of all literals.
+pub const LITERAL: &str = "http://www.w3.org/ns/shacl#Literal";
+
+/// `sh:NodeKind`
+///
+/// The class of all node kinds, including `sh:BlankNode`, `sh:IRI`, `sh:Literal` or the
+/// combinations of these.
+pub const NODE_KIND: &str = "http://www.w3.org/ns/shacl#NodeKind";
+
+/// `sh:NodeShape`
+///
+/// A node shape is a shape that specifies constraint that need to be met with respect to focus
+/// nodes.
+pub const NODE_SHAPE: &str = "http://www.w3.org/ns/shacl#NodeShape";
+
+/// `sh:Parameter`
+///
+/// The class of parameter declarations, consisting of a path predicate and (possibly) information
+/// about allowed value type, cardinality and other characteristics.
+pub const PARAMETER: &str = "http://www.w3.org/ns/shacl#Parameter";
+
+/// `sh:Parameterizable`
+///
+/// Superclass of components that can take parameters, especially functions and constraint
+/// components.
+pub const PARAMETERIZABLE: &str = "http://www.w3.org/ns/shacl#Parameterizable";
+
+/// `sh:PrefixDeclaration`
+///
+/// The class of prefix declarations, consisting of pairs of a prefix with a namespace.
+pub const PREFIX_DECLARATION: &str = "http://www.w3.org/ns/shacl#PrefixDeclaration";
+
+/// `sh:PropertyGroup`
+///
+/// Instances of this class represent groups of property shapes that belong together.
+pub const PROPERTY_GROUP: &str = "http://www.w3.org/ns/shacl#PropertyGroup";
+
+/// `sh:PropertyShape`
+///
+/// A property shape is a shape that specifies constraints on the values of a focus node for a given
+/// property or path.
+pub const PROPERTY_SHAPE: &str = "http://www.w3.org/ns/shacl#PropertyShape";
+
+/// `sh:ResultAnnotation`
+///
+/// A class of result annotations, which define the rules to derive the values of a given annotation
+/// property as extra values for a validation result.
+pub const RESULT_ANNOTATION: &str = "http://www.w3.org/ns/shacl#ResultAnnotation";
+
+/// `sh:Rule`
+///
+/// The class of SHACL rules. Never instantiated directly.
+pub const RULE: &str = "http://www.w3.org/ns/shacl#Rule";
+
+/// `sh:SPARQLAskExecutable`
+///
+/// The class of SPARQL executables that are based on an ASK query.
+pub const SPARQL_ASK_EXECUTABLE: &str = "http://www.w3.org/ns/shacl#SPARQLAskExecutable";
+
+/// `sh:SPARQLAskValidator`
+///
+/// The class of validators based on SPARQL ASK queries.
+pub const SPARQL_ASK_VALIDATOR: &str = "http://www.w3.org/ns/shacl#SPARQLAskValidator";
+
+/// `sh:SPARQLConstraint`
+///
+/// The class of constraints based on SPARQL SELECT queries.
+pub const SPARQL_CONSTRAINT: &str = "http://www.w3.org/ns/shacl#SPARQLConstraint";
+
+/// `sh:SPARQLConstructExecutable`
+///
+/// The class of SPARQL executables that are based on a CONSTRUCT query.
+pub const SPARQL_CONSTRUCT_EXECUTABLE: &str =
+    "http://www.w3.org/ns/shacl#SPARQLConstructExecutable";
+
+/// `sh:SPARQLExecutable`
+///
+/// The class of resources that encapsulate a SPARQL query.
+pub const SPARQL_EXECUTABLE: &str = "http://www.w3.org/ns/shacl#SPARQLExecutable";
+
+/// `sh:SPARQLFunction`
+///
+/// A function backed by a SPARQL query - either ASK or SELECT.
+pub const SPARQL_FUNCTION: &str = "http://www.w3.org/ns/shacl#SPARQLFunction";
+
+/// `sh:SPARQLRule`
+///
+/// The class of SHACL rules based on SPARQL CONSTRUCT queries.
+pub const SPARQL_RULE: &str = "http://www.w3.org/ns/shacl#SPARQLRule";
+
+/// `sh:SPARQLSelectExecutable`
+///
+/// The class of SPARQL executables based on a SELECT query.
+pub const SPARQL_SELECT_EXECUTABLE: &str = "http://www.w3.org/ns/shacl#SPARQLSelectExecutable";
+
+/// `sh:SPARQLSelectValidator`
+///
+/// The class of validators based on SPARQL SELECT queries.
+pub const SPARQL_SELECT_VALIDATOR: &str = "http://www.w3.org/ns/shacl#SPARQLSelectValidator";
+
+/// `sh:SPARQLTarget`
+///
+/// The class of targets that are based on SPARQL queries.
+pub const SPARQL_TARGET: &str = "http://www.w3.org/ns/shacl#SPARQLTarget";
+
+/// `sh:SPARQLTargetType`
+///
+/// The (meta) class for parameterizable targets that are based on SPARQL queries.
+pub const SPARQL_TARGET_TYPE: &str = "http://www.w3.org/ns/shacl#SPARQLTargetType";
+
+/// `sh:SPARQLUpdateExecutable`
+///
+/// The class of SPARQL executables based on a SPARQL UPDATE.
+pub const SPARQL_UPDATE_EXECUTABLE: &str = "http://www.w3.org/ns/shacl#SPARQLUpdateExecutable";
+
+/// `sh:Severity`
+///
+/// The class of validation result severity levels, including violation and warning levels.
+pub const SEVERITY: &str = "http://www.w3.org/ns/shacl#Severity";
+
+/// `sh:Shape`
+///
+/// A shape is a collection of constraints that may be targeted for certain nodes.
+pub const SHAPE: &str = "http://www.w3.org/ns/shacl#Shape";
+
+/// `sh:Target`
+///
+/// The base class of targets such as those based on SPARQL queries.
+pub const TARGET: &str = "http://www.w3.org/ns/shacl#Target";
+
+/// `sh:TargetType`
+///
+/// The (meta) class for parameterizable targets.
+pub const TARGET_TYPE: &str = "http://www.w3.org/ns/shacl#TargetType";
+
+/// `sh:TripleRule`
+///
+/// A rule based on triple (subject, predicate, object) pattern.
+pub const TRIPLE_RULE: &str = "http://www.w3.org/ns/shacl#TripleRule";
+
+/// `sh:ValidationReport`
+///
+/// The class of SHACL validation reports.
+pub const VALIDATION_REPORT: &str = "http://www.w3.org/ns/shacl#ValidationReport";
+
+/// `sh:ValidationResult`
+///
+/// The class of validation results.
+pub const VALIDATION_RESULT: &str = "http://www.w3.org/ns/shacl#ValidationResult";
+
+/// `sh:Validator`
+///
+/// The class of validators, which provide instructions on how to process a constraint definition.
+pub const VALIDATOR: &str = "http://www.w3.org/ns/shacl#Validator";
+
+/// `sh:Violation`
+///
+/// The severity for a violation validation result.
+pub const VIOLATION: &str = "http://www.w3.org/ns/shacl#Violation";
+
+/// `sh:Warning`
+///
+/// The severity for a warning validation result.
+pub const WARNING: &str = "http://www.w3.org/ns/shacl#Warning";
+
+/// `sh:AndConstraintComponent`
+///
+/// The constraint component of `sh:and`.
+pub const AND_CONSTRAINT_COMPONENT: &str = "http://www.w3.org/ns/shacl#AndConstraintComponent";
+
+/// `sh:ClassConstraintComponent`
+///
+/// The constraint component of `sh:class`.
+pub const CLASS_CONSTRAINT_COMPONENT: &str = "http://www.w3.org/ns/shacl#ClassConstraintComponent";
+
+/// `sh:ClosedConstraintComponent`
+///
+/// The constraint component of `sh:closed`.
+pub const CLOSED_CONSTRAINT_COMPONENT: &str =
+    "http://www.w3.org/ns/shacl#ClosedConstraintComponent";
+
+/// `sh:DatatypeConstraintComponent`
+///
+/// The constraint component of `sh:datatype`.
+pub const DATATYPE_CONSTRAINT_COMPONENT: &str =
+    "http://www.w3.org/ns/shacl#DatatypeConstraintComponent";
+
+/// `sh:DisjointConstraintComponent`
+///
+/// The constraint component of `sh:disjoint`.
+pub const DISJOINT_CONSTRAINT_COMPONENT: &str =
+    "http://www.w3.org/ns/shacl#DisjointConstraintComponent";
+
+/// `sh:EqualsConstraintComponent`
+///
+/// The constraint component of `sh:equals`.
+pub const EQUALS_CONSTRAINT_COMPONENT: &str =
+    "http://www.w3.org/ns/shacl#EqualsConstraintComponent";
+
+/// `sh:ExpressionConstraintComponent`
+///
+/// The constraint component of `sh:expression`.
+pub const EXPRESSION_CONSTRAINT_COMPONENT: &str =
+    "http://www.w3.org/ns/shacl#ExpressionConstraintComponent";
+
+/// `sh:HasValueConstraintComponent`
+///
+/// The constraint component of `sh:hasValue`.
+pub const HAS_VALUE_CONSTRAINT_COMPONENT: &str =
+    "http://www.w3.org/ns/shacl#HasValueConstraintComponent";
+
+/// `sh:InConstraintComponent`
+///
+/// The constraint component of `sh:in`.
+pub const IN_CONSTRAINT_COMPONENT: &str = "http://www.w3.org/ns/shacl#InConstraintComponent";
+
+/// `sh:LanguageInConstraintComponent`
+///
+/// The constraint component of `sh:languageIn`.
+pub const LANGUAGE_IN_CONSTRAINT_COMPONENT: &str =
+    "http://www.w3.org/ns/shacl#LanguageInConstraintComponent";
+
+/// `sh:LessThanConstraintComponent`
+///
+/// The constraint component of `sh:lessThan`.
+pub const LESS_THAN_CONSTRAINT_COMPONENT: &str =
+    "http://www.w3.org/ns/shacl#LessThanConstraintComponent";
+
+/// `sh:LessThanOrEqualsConstraintComponent`
+///
+/// The constraint component of `sh:lessThanOrEquals`.
+pub const LESS_THAN_OR_EQUALS_CONSTRAINT_COMPONENT: &str =
+    "http://www.w3.org/ns/shacl#LessThanOrEqualsConstraintComponent";
+
+/// `sh:MaxCountConstraintComponent`
+///
+/// The constraint component of `sh:maxCount`.
+pub const MAX_COUNT_CONSTRAINT_COMPONENT: &str =
+    "http://www.w3.org/ns/shacl#MaxCountConstraintComponent";
+
+/// `sh:MaxExclusiveConstraintComponent`
+///
+/// The constraint component of `sh:maxExclusive`.
+pub const MAX_EXCLUSIVE_CONSTRAINT_COMPONENT: &str =
+    "http://www.w3.org/ns/shacl#MaxExclusiveConstraintComponent";
+
+/// `sh:MaxInclusiveConstraintComponent`
+///
+/// The constraint component of `sh:maxInclusive`.
+pub const MAX_INCLUSIVE_CONSTRAINT_COMPONENT: &str =
+    "http://www.w3.org/ns/shacl#MaxInclusiveConstraintComponent";
+
+/// `sh:MaxLengthConstraintComponent`
+///
+/// The constraint component of `sh:maxLength`.
+pub const MAX_LENGTH_CONSTRAINT_COMPONENT: &str =
+    "http://www.w3.org/ns/shacl#MaxLengthConstraintComponent";
+
+/// `sh:MinCountConstraintComponent`
+///
+/// The constraint component of `sh:minCount`.
+pub const MIN_COUNT_CONSTRAINT_COMPONENT: &str =
+    "http://www.w3.org/ns/shacl#MinCountConstraintComponent";
+
+/// `sh:MinExclusiveConstraintComponent`
+///
+/// The constraint component of `sh:minExclusive`.
+pub const MIN_EXCLUSIVE_CONSTRAINT_COMPONENT: &str =
+    "http://www.w3.org/ns/shacl#MinExclusiveConstraintComponent";
+
+/// `sh:MinInclusiveConstraintComponent`
+///
+/// The constraint component of `sh:minInclusive`.
+pub const MIN_INCLUSIVE_CONSTRAINT_COMPONENT: &str =
+    "http://www.w3.org/ns/shacl#MinInclusiveConstraintComponent";
+
+/// `sh:MinLengthConstraintComponent`
+///
+/// The constraint component of `sh:minLength`.
+pub const MIN_LENGTH_CONSTRAINT_COMPONENT: &str =
+    "http://www.w3.org/ns/shacl#MinLengthConstraintComponent";
+
+/// `sh:NodeConstraintComponent`
+///
+/// The constraint component of `sh:node`.
+pub const NODE_CONSTRAINT_COMPONENT: &str = "http://www.w3.org/ns/shacl#NodeConstraintComponent";
+
+/// `sh:NodeKindConstraintComponent`
+///
+/// The constraint component of `sh:nodeKind`.
+pub const NODE_KIND_CONSTRAINT_COMPONENT: &str =
+    "http://www.w3.org/ns/shacl#NodeKindConstraintComponent";
+
+/// `sh:NotConstraintComponent`
+///
+/// The constraint component of `sh:not`.
+pub const NOT_CONSTRAINT_COMPONENT: &str = "http://www.w3.org/ns/shacl#NotConstraintComponent";
+
+/// `sh:OrConstraintComponent`
+///
+/// The constraint component of `sh:or`.
+pub const OR_CONSTRAINT_COMPONENT: &str = "http://www.w3.org/ns/shacl#OrConstraintComponent";
+
+/// `sh:PatternConstraintComponent`
+///
+/// The constraint component of `sh:pattern`.
+pub const PATTERN_CONSTRAINT_COMPONENT: &str =
+    "http://www.w3.org/ns/shacl#PatternConstraintComponent";
+
+/// `sh:PropertyConstraintComponent`
+///
+/// The constraint component of `sh:property`.
+pub const PROPERTY_CONSTRAINT_COMPONENT: &str =
+    "http://www.w3.org/ns/shacl#PropertyConstraintComponent";
+
+/// `sh:QualifiedMaxCountConstraintComponent`
+///
+/// The constraint component of `sh:qualifiedMaxCount`.
+pub const QUALIFIED_MAX_COUNT_CONSTRAINT_COMPONENT: &str =
+    "http://www.w3.org/ns/shacl#QualifiedMaxCountConstraintComponent";
+
+/// `sh:QualifiedMinCountConstraintComponent`
+///
+/// The constraint component of `sh:qualifiedMinCount`.
+pub const QUALIFIED_MIN_COUNT_CONSTRAINT_COMPONENT: &str =
+    "http://www.w3.org/ns/shacl#QualifiedMinCountConstraintComponent";
+
+/// `sh:SPARQLConstraintComponent`
+///
+/// The constraint component of `sh:sparql`.
+pub const SPARQL_CONSTRAINT_COMPONENT: &str =
+    "http://www.w3.org/ns/shacl#SPARQLConstraintComponent";
+
+/// `sh:UniqueLangConstraintComponent`
+///
+/// The constraint component of `sh:uniqueLang`.
+pub const UNIQUE_LANG_CONSTRAINT_COMPONENT: &str =
+    "http://www.w3.org/ns/shacl#UniqueLangConstraintComponent";
+
+/// `sh:XoneConstraintComponent`
+///
+/// The constraint component of `sh:xone`.
+pub const XONE_CONSTRAINT_COMPONENT: &str = "http://www.w3.org/ns/shacl#XoneConstraintComponent";
+
+/// `sh:alternativePath`
+///
+/// The (single) value of this property must be a list of path elements, representing the elements
+/// of alternative paths.
+pub const ALTERNATIVE_PATH: &str = "http://www.w3.org/ns/shacl#alternativePath";
+
+/// `sh:and`
+///
+/// RDF list of shapes to validate the value nodes against.
+pub const AND: &str = "http://www.w3.org/ns/shacl#and";
+
+/// `sh:annotationProperty`
+///
+/// The annotation property that shall be set.
+pub const ANNOTATION_PROPERTY: &str = "http://www.w3.org/ns/shacl#annotationProperty";
+
+/// `sh:annotationValue`
+///
+/// The (default) values of the annotation property.
+pub const ANNOTATION_VALUE: &str = "http://www.w3.org/ns/shacl#annotationValue";
+
+/// `sh:annotationVarName`
+///
+/// The name of the SPARQL variable from the SELECT clause that shall be used for the values.
+pub const ANNOTATION_VAR_NAME: &str = "http://www.w3.org/ns/shacl#annotationVarName";
+
+/// `sh:ask`
+///
+/// The SPARQL ASK query to execute.
+pub const ASK: &str = "http://www.w3.org/ns/shacl#ask";
+
+/// `sh:class`
+///
+/// The type that all value nodes must have.
+pub const CLASS: &str = "http://www.w3.org/ns/shacl#class";
+
+/// `sh:closed`
+///
+/// If set to true then the shape is closed.
+pub const CLOSED: &str = "http://www.w3.org/ns/shacl#closed";
+
+/// `sh:condition`
+///
+/// The shapes that the focus nodes need to conform to before a rule is executed on them.
+pub const CONDITION: &str = "http://www.w3.org/ns/shacl#condition";
+
+/// `sh:conforms`
+///
+/// True if the validation did not produce any validation results, and false otherwise.
+pub const CONFORMS: &str = "http://www.w3.org/ns/shacl#conforms";
+
+/// `sh:construct`
+///
+/// The SPARQL CONSTRUCT query to execute.
+pub const CONSTRUCT: &str = "http://www.w3.org/ns/shacl#construct";
+
+/// `sh:datatype`
+///
+/// Specifies an RDF datatype that all value nodes must have.
+pub const DATATYPE: &str = "http://www.w3.org/ns/shacl#datatype";
+
+/// `sh:deactivated`
+///
+/// If set to true then all nodes conform to this.
+pub const DEACTIVATED: &str = "http://www.w3.org/ns/shacl#deactivated";
+
+/// `sh:declare`
+///
+/// Links a resource with its namespace prefix declarations.
+pub const DECLARE: &str = "http://www.w3.org/ns/shacl#declare";
+
+/// `sh:defaultValue`
+///
+/// A default value for a property, for example for user interface tools to pre-populate input
+/// fields.
+pub const DEFAULT_VALUE: &str = "http://www.w3.org/ns/shacl#defaultValue";
+
+/// `sh:description`
+///
+/// Human-readable descriptions for the property in the context of the surrounding shape.
+pub const DESCRIPTION: &str = "http://www.w3.org/ns/shacl#description";
+
+/// `sh:detail`
+///
+/// Links a result with other results that provide more details, for example to describe violations
+/// against nested shapes.
+pub const DETAIL: &str = "http://www.w3.org/ns/shacl#detail";
+
+/// `sh:disjoint`
+///
+/// Specifies a property where the set of values must be disjoint with the value nodes.
+pub const DISJOINT: &str = "http://www.w3.org/ns/shacl#disjoint";
+
+/// `sh:entailment`
+///
+/// An entailment regime that indicates what kind of inferencing is required by a shapes graph.
+pub const ENTAILMENT: &str = "http://www.w3.org/ns/shacl#entailment";
+
+/// `sh:equals`
+///
+/// Specifies a property that must have the same values as the value nodes.
+pub const EQUALS: &str = "http://www.w3.org/ns/shacl#equals";
+
+/// `sh:expression`
+///
+/// The node expression that must return true for the value nodes.
+pub const EXPRESSION: &str = "http://www.w3.org/ns/shacl#expression";
+
+/// `sh:filterShape`
+///
+/// The shape that all input nodes of the expression need to conform to.
+pub const FILTER_SHAPE: &str = "http://www.w3.org/ns/shacl#filterShape";
+
+/// `sh:flags`
+///
+/// An optional flag to be used with regular expression pattern matching.
+pub const FLAGS: &str = "http://www.w3.org/ns/shacl#flags";
+
+/// `sh:focusNode`
+///
+/// The focus node that was validated when the result was produced.
+pub const FOCUS_NODE: &str = "http://www.w3.org/ns/shacl#focusNode";
+
+/// `sh:group`
+///
+/// Can be used to link to a property group to indicate that a property shape belongs to a group of
+/// related property shapes.
+pub const GROUP: &str = "http://www.w3.org/ns/shacl#group";
+
+/// `sh:hasValue`
+///
+/// Specifies a value that must be among the value nodes.
+pub const HAS_VALUE: &str = "http://www.w3.org/ns/shacl#hasValue";
+
+/// `sh:ignoredProperties`
+///
+/// An optional RDF list of properties that are also permitted in addition to those explicitly
+/// enumerated via `sh:property`/`sh:path`.
+pub const IGNORED_PROPERTIES: &str = "http://www.w3.org/ns/shacl#ignoredProperties";
+
+/// `sh:in`
+///
+/// Specifies a list of allowed values so that each value node must be among the members of the
+/// given list.
+pub const IN: &str = "http://www.w3.org/ns/shacl#in";
+
+/// `sh:intersection`
+///
+/// A list of node expressions that shall be intersected.
+pub const INTERSECTION: &str = "http://www.w3.org/ns/shacl#intersection";
+
+/// `sh:inversePath`
+///
+/// The (single) value of this property represents an inverse path (object to subject).
+pub const INVERSE_PATH: &str = "http://www.w3.org/ns/shacl#inversePath";
+
+/// `sh:labelTemplate`
+///
+/// Outlines how human-readable labels of instances of the associated parameterizable shall be
+/// produced.
+pub const LABEL_TEMPLATE: &str = "http://www.w3.org/ns/shacl#labelTemplate";
+
+/// `sh:languageIn`
+///
+/// Specifies a list of language tags that all value nodes must have.
+pub const LANGUAGE_IN: &str = "http://www.w3.org/ns/shacl#languageIn";
+
+/// `sh:lessThan`
+///
+/// Specifies a property that must have smaller values than the value nodes.
+pub const LESS_THAN: &str = "http://www.w3.org/ns/shacl#lessThan";
+
+/// `sh:lessThanOrEquals`
+///
+/// Specifies a property that must have smaller or equal values than the value nodes.
+pub const LESS_THAN_OR_EQUALS: &str = "http://www.w3.org/ns/shacl#lessThanOrEquals";
+
+/// `sh:maxCount`
+///
+/// Specifies the maximum number of values in the set of value nodes.
+pub const MAX_COUNT: &str = "http://www.w3.org/ns/shacl#maxCount";
+
+/// `sh:maxExclusive`
+///
+/// Specifies the maximum exclusive value of each value node.
+pub const MAX_EXCLUSIVE: &str = "http://www.w3.org/ns/shacl#maxExclusive";
+
+/// `sh:maxInclusive`
+///
+/// Specifies the maximum inclusive value of each value node.
+pub const MAX_INCLUSIVE: &str = "http://www.w3.org/ns/shacl#maxInclusive";
+
+/// `sh:maxLength`
+///
+/// Specifies the maximum string length of each value node.
+pub const MAX_LENGTH: &str = "http://www.w3.org/ns/shacl#maxLength";
+
+/// `sh:message`
+///
+/// A human-readable message (possibly with placeholders for variables) explaining the cause of the
+/// result.
+pub const MESSAGE: &str = "http://www.w3.org/ns/shacl#message";
+
+/// `sh:minCount`
+///
+/// Specifies the minimum number of values in the set of value nodes.
+pub const MIN_COUNT: &str = "http://www.w3.org/ns/shacl#minCount";
+
+/// `sh:minExclusive`
+///
+/// Specifies the minimum exclusive value of each value node.
+pub const MIN_EXCLUSIVE: &str = "http://www.w3.org/ns/shacl#minExclusive";
+
+/// `sh:minInclusive`
+///
+/// Specifies the minimum inclusive value of each value node.
+pub const MIN_INCLUSIVE: &str = "http://www.w3.org/ns/shacl#minInclusive";
+
+/// `sh:minLength`
+///
+/// Specifies the minimum string length of each value node.
+pub const MIN_LENGTH: &str = "http://www.w3.org/ns/shacl#minLength";
+
+/// `sh:name`
+///
+/// Human-readable labels for the property in the context of the surrounding shape.
+pub const NAME: &str = "http://www.w3.org/ns/shacl#name";
+
+/// `sh:namespace`
+///
+/// The namespace associated with a prefix in a prefix declaration.
+pub const NAMESPACE: &str = "http://www.w3.org/ns/shacl#namespace";
+
+/// `sh:node`
+///
+/// Specifies the node shape that all value nodes must conform to.
+pub const NODE: &str = "http://www.w3.org/ns/shacl#node";
+
+/// `sh:nodeKind`
+///
+/// Specifies the node kind (e.g. IRI or literal) each value node.
+pub const NODE_KIND_PROPERTY: &str = "http://www.w3.org/ns/shacl#nodeKind";
+
+/// `sh:nodeValidator`
+///
+/// The validator(s) used to evaluate a constraint in the context of a node shape.
+pub const NODE_VALIDATOR: &str = "http://www.w3.org/ns/shacl#nodeValidator";
+
+/// `sh:nodes`
+///
+/// The node expression producing the input nodes of a filter shape expression.
+pub const NODES: &str = "http://www.w3.org/ns/shacl#nodes";
+
+/// `sh:not`
+///
+/// Specifies a shape that the value nodes must not conform to.
+pub const NOT: &str = "http://www.w3.org/ns/shacl#not";
+
+/// `sh:object`
+///
+/// An expression producing the nodes that shall be inferred as objects.
+pub const OBJECT: &str = "http://www.w3.org/ns/shacl#object";
+
+/// `sh:oneOrMorePath`
+///
+/// The (single) value of this property represents a path that is matched one or more times.
+pub const ONE_OR_MORE_PATH: &str = "http://www.w3.org/ns/shacl#oneOrMorePath";
+
+/// `sh:optional`
+///
+/// Indicates whether a parameter is optional.
+pub const OPTIONAL: &str = "http://www.w3.org/ns/shacl#optional";
+
+/// `sh:or`
+///
+/// Specifies a list of shapes so that the value nodes must conform to at least one of the shapes.
+pub const OR: &str = "http://www.w3.org/ns/shacl#or";
+
+/// `sh:order`
+///
+/// Specifies the relative order of this compared to its siblings.
+pub const ORDER: &str = "http://www.w3.org/ns/shacl#order";
+
+/// `sh:parameter`
+///
+/// The parameters of a function or constraint component.
+pub const PARAMETER_PROPERTY: &str = "http://www.w3.org/ns/shacl#parameter";
+
+/// `sh:path`
+///
+/// Specifies the property path of a property shape.
+pub const PATH: &str = "http://www.w3.org/ns/shacl#path";
+
+/// `sh:pattern`
+///
+/// Specifies a regular expression pattern that the string representations of the value nodes must
+/// match.
+pub const PATTERN: &str = "http://www.w3.org/ns/shacl#pattern";
+
+/// `sh:predicate`
+///
+/// An expression producing the properties that shall be inferred as predicates.
+pub const PREDICATE: &str = "http://www.w3.org/ns/shacl#predicate";
+
+/// `sh:prefix`
+///
+/// The prefix of a prefix declaration.
+pub const PREFIX: &str = "http://www.w3.org/ns/shacl#prefix";
+
+/// `sh:prefixes`
+///
+/// The prefixes that shall be applied before parsing the associated SPARQL query.
+pub const PREFIXES: &str = "http://www.w3.org/ns/shacl#prefixes";
+
+/// `sh:property`
+///
+/// Links a shape to its property shapes.
+pub const PROPERTY: &str = "http://www.w3.org/ns/shacl#property";
+
+/// `sh:propertyValidator`
+///
+/// The validator(s) used to evaluate a constraint in the context of a property shape.
+pub const PROPERTY_VALIDATOR: &str = "http://www.w3.org/ns/shacl#propertyValidator";
+
+/// `sh:qualifiedMaxCount`
+///
+/// The maximum number of value nodes that can conform to the shape.
+pub const QUALIFIED_MAX_COUNT: &str = "http://www.w3.org/ns/shacl#qualifiedMaxCount";
+
+/// `sh:qualifiedMinCount`
+///
+/// The minimum number of value nodes that must conform to the shape.
+pub const QUALIFIED_MIN_COUNT: &str = "http://www.w3.org/ns/shacl#qualifiedMinCount";
+
+/// `sh:qualifiedValueShape`
+///
+/// The shape that a specified number of values must conform to.
+pub const QUALIFIED_VALUE_SHAPE: &str = "http://www.w3.org/ns/shacl#qualifiedValueShape";
+
+/// `sh:qualifiedValueShapesDisjoint`
+///
+/// Can be used to mark the qualified value shape to be disjoint with its sibling shapes.
+pub const QUALIFIED_VALUE_SHAPES_DISJOINT: &str =
+    "http://www.w3.org/ns/shacl#qualifiedValueShapesDisjoint";
+
+/// `sh:result`
+///
+/// The validation results contained in a validation report.
+pub const RESULT: &str = "http://www.w3.org/ns/shacl#result";
+
+/// `sh:resultAnnotation`
+///
+/// Links a SPARQL validator with zero or more `sh:ResultAnnotation` instances, defining how to
+/// derive additional result properties based on the variables of the SELECT query.
+pub const RESULT_ANNOTATION_PROPERTY: &str = "http://www.w3.org/ns/shacl#resultAnnotation";
+
+/// `sh:resultMessage`
+///
+/// Human-readable messages explaining the cause of the result.
+pub const RESULT_MESSAGE: &str = "http://www.w3.org/ns/shacl#resultMessage";
+
+/// `sh:resultPath`
+///
+/// The path of a validation result, based on the path of the validated property shape.
+pub const RESULT_PATH: &str = "http://www.w3.org/ns/shacl#resultPath";
+
+/// `sh:resultSeverity`
+///
+/// The severity of the result, e.g. warning.
+pub const RESULT_SEVERITY: &str = "http://www.w3.org/ns/shacl#resultSeverity";
+
+/// `sh:returnType`
+///
+/// The expected type of values returned by the associated function.
+pub const RETURN_TYPE: &str = "http://www.w3.org/ns/shacl#returnType";
+
+/// `sh:rule`
+///
+/// The rules linked to a shape.
+pub const RULE_PROPERTY: &str = "http://www.w3.org/ns/shacl#rule";
+
+/// `sh:select`
+///
+/// The SPARQL SELECT query to execute.
+pub const SELECT: &str = "http://www.w3.org/ns/shacl#select";
+
+/// `sh:severity`
+///
+/// Defines the severity that validation results produced by a shape must have.
+pub const SEVERITY_PROPERTY: &str = "http://www.w3.org/ns/shacl#severity";
+
+/// `sh:shapesGraph`
+///
+/// Shapes graphs that should be used when validating this data graph.
+pub const SHAPES_GRAPH: &str = "http://www.w3.org/ns/shacl#shapesGraph";
+
+/// `sh:shapesGraphWellFormed`
+///
+/// If true then the validation engine was certain that the shapes graph has passed all SHACL syntax
+/// requirements during the validation process.
+pub const SHAPES_GRAPH_WELL_FORMED: &str = "http://www.w3.org/ns/shacl#shapesGraphWellFormed";
+
+/// `sh:sourceConstraint`
+///
+/// The constraint that was validated when the result was produced.
+pub const SOURCE_CONSTRAINT: &str = "http://www.w3.org/ns/shacl#sourceConstraint";
+
+/// `sh:sourceConstraintComponent`
+///
+/// The constraint component that is the source of the result.
+pub const SOURCE_CONSTRAINT_COMPONENT: &str =
+    "http://www.w3.org/ns/shacl#sourceConstraintComponent";
+
+/// `sh:sourceShape`
+///
+/// The shape that is was validated when the result was produced.
+pub const SOURCE_SHAPE: &str = "http://www.w3.org/ns/shacl#sourceShape";
+
+/// `sh:sparql`
+///
+/// Links a shape with SPARQL constraints.
+pub const SPARQL: &str = "http://www.w3.org/ns/shacl#sparql";
+
+/// `sh:subject`
+///
+/// An expression producing the resources that shall be inferred as subjects.
+pub const SUBJECT: &str = "http://www.w3.org/ns/shacl#subject";
+
+/// `sh:suggestedShapesGraph`
+///
+/// Suggested shapes graphs for this ontology. The values of this property may be used in the
+/// absence of specific sh:shapesGraph statements.
+pub const SUGGESTED_SHAPES_GRAPH: &str = "http://www.w3.org/ns/shacl#suggestedShapesGraph";
+
+/// `sh:target`
+///
+/// Links a shape to a target specified by an extension language, for example instances of
+/// `sh:SPARQLTarget`.
+pub const TARGET_PROPERTY: &str = "http://www.w3.org/ns/shacl#target";
+
+/// `sh:targetClass`
+///
+/// Links a shape to a class, indicating that all instances of the class must conform to the shape.
+pub const TARGET_CLASS: &str = "http://www.w3.org/ns/shacl#targetClass";
+
+/// `sh:targetNode`
+///
+/// Links a shape to individual nodes, indicating that these nodes must conform to the shape.
+pub const TARGET_NODE: &str = "http://www.w3.org/ns/shacl#targetNode";
+
+/// `sh:targetObjectsOf`
+///
+/// Links a shape to a property, indicating that all all objects of triples that have the given
+/// property as their predicate must conform to the shape.
+pub const TARGET_OBJECTS_OF: &str = "http://www.w3.org/ns/shacl#targetObjectsOf";
+
+/// `sh:targetSubjectsOf`
+///
+/// Links a shape to a property, indicating that all subjects of triples that have the given
+/// property as their predicate must conform to the shape.
+pub const TARGET_SUBJECTS_OF: &str = "http://www.w3.org/ns/shacl#targetSubjectsOf";
+
+/// `sh:this`
+///
+/// A node expression that represents the current focus node.
+pub const THIS: &str = "http://www.w3.org/ns/shacl#this";
+
+/// `sh:union`
+///
+/// A list of node expressions that shall be used together.
+pub const UNION: &str = "http://www.w3.org/ns/shacl#union";
+
+/// `sh:uniqueLang`
+///
+/// Specifies whether all node values must have a unique (or no) language tag.
+pub const UNIQUE_LANG: &str = "http://www.w3.org/ns/shacl#uniqueLang";
+
+/// `sh:update`
+///
+/// The SPARQL UPDATE to execute.
+pub const UPDATE: &str = "http://www.w3.org/ns/shacl#update";
+
+/// `sh:validator`
+///
+/// The validator(s) used to evaluate constraints of either node or property shapes.
+pub const VALIDATOR_PROPERTY: &str = "http://www.w3.org/ns/shacl#validator";
+
+/// `sh:value`
+///
+/// An RDF node that has caused the result.
+pub const VALUE: &str = "http://www.w3.org/ns/shacl#value";
+
+/// `sh:xone`
+///
+/// Specifies a list of shapes so that the value nodes must conform to exactly one of the shapes.
+pub const XONE: &str = "http://www.w3.org/ns/shacl#xone";
+
+/// `sh:zeroOrMorePath`
+///
+/// The (single) value of this property represents a path that is matched zero or more times.
+pub const ZERO_OR_MORE_PATH: &str = "http://www.w3.org/ns/shacl#zeroOrMorePath";
+
+/// `sh:zeroOrOnePath`
+///
+/// The (single) value of this property represents a path that is matched zero or one times.
+pub const ZERO_OR_ONE_PATH: &str = "http://www.w3.org/ns/shacl#zeroOrOnePath";
@@ -0,0 +1,177 @@
+//! SKOS vocabulary, `http://www.w3.org/2004/02/skos/core#`.
+
+/// Prefix that is bound to the namespace by default.
+pub const DEFAULT_PREFIX: &str = "skos";
+
+/// Namespace URI of the vocabulary.
+pub const NAMESPACE_URI: &str = "http://www.w3.org/2004/02/skos/core#";
+
+/// `skos:Collection`
+///
+/// A meaningful collection of concepts.
+pub const COLLECTION: &str = "http://www.w3.org/2004/02/skos/core#Collection";
+
+/// `skos:Concept`
+///
+/// An idea or notion; a unit of thought.
+pub const CONCEPT: &str = "http://www.w3.org/2004/02/skos/core#Concept";
+
+/// `skos:ConceptScheme`
+///
+/// A set of concepts, optionally including statements about semantic relationships between those
+/// concepts.
+pub const CONCEPT_SCHEME: &str = "http://www.w3.org/2004/02/skos/core#ConceptScheme";
+
+/// `skos:OrderedCollection`
+///
+/// An ordered collection of concepts, where both the grouping and the ordering are meaningful.
+pub const ORDERED_COLLECTION: &str = "http://www.w3.org/2004/02/skos/core#OrderedCollection";
+
+/// `skos:altLabel`
+///
+/// An alternative lexical label for a resource.
+pub const ALT_LABEL: &str = "http://www.w3.org/2004/02/skos/core#altLabel";
+
+/// `skos:broadMatch`
+///
+/// Used to state a hierarchical mapping link between two conceptual resources in different concept
+/// schemes.
+pub const BROAD_MATCH: &str = "http://www.w3.org/2004/02/skos/core#broadMatch";
+
+/// `skos:broader`
+///
+/// Relates a concept to a concept that is more general in meaning.
+pub const BROADER: &str = "http://www.w3.org/2004/02/skos/core#broader";
+
+/// `skos:broaderTransitive`
+///
+/// The transitive super-property of `skos:broader`.
+pub const BROADER_TRANSITIVE: &str = "http://www.w3.org/2004/02/skos/core#broaderTransitive";
+
+/// `skos:changeNote`
+///
+/// A note about a modification to a concept.
+pub const CHANGE_NOTE: &str = "http://www.w3.org/2004/02/skos/core#changeNote";
+
+/// `skos:closeMatch`
+///
+/// Used to link two concepts that are sufficiently similar that they can be used interchangeably in
+/// some information retrieval applications.
+pub const CLOSE_MATCH: &str = "http://www.w3.org/2004/02/skos/core#closeMatch";
+
+/// `skos:definition`
+///
+/// A statement or formal explanation of the meaning of a concept.
+pub const DEFINITION: &str = "http://www.w3.org/2004/02/skos/core#definition";
+
+/// `skos:editorialNote`
+///
+/// A note for an editor, translator or maintainer of the vocabulary.
+pub const EDITORIAL_NOTE: &str = "http://www.w3.org/2004/02/skos/core#editorialNote";
+
+/// `skos:exactMatch`
+///
+/// Used to link two concepts, indicating a high degree of confidence that the concepts can be used
+/// interchangeably across a wide range of information retrieval applications.
+pub const EXACT_MATCH: &str = "http://www.w3.org/2004/02/skos/core#exactMatch";
+
+/// `skos:example`
+///
+/// An example of the use of a concept.
+pub const EXAMPLE: &str = "http://www.w3.org/2004/02/skos/core#example";
+
+/// `skos:hasTopConcept`
+///
+/// Relates, by convention, a concept scheme to a concept which is topmost in the broader/narrower
+/// concept hierarchies for that scheme.
+pub const HAS_TOP_CONCEPT: &str = "http://www.w3.org/2004/02/skos/core#hasTopConcept";
+
+/// `skos:hiddenLabel`
+///
+/// A lexical label for a resource that should be hidden when generating visual displays of the
+/// resource, but should still be accessible to free text search operations.
+pub const HIDDEN_LABEL: &str = "http://www.w3.org/2004/02/skos/core#hiddenLabel";
+
+/// `skos:historyNote`
+///
+/// A note about the past state/use/meaning of a concept.
+pub const HISTORY_NOTE: &str = "http://www.w3.org/2004/02/skos/core#historyNote";
+
+/// `skos:inScheme`
+///
+/// Relates a resource to a concept scheme in which it is included.
+pub const IN_SCHEME: &str = "http://www.w3.org/2004/02/skos/core#inScheme";
+
+/// `skos:mappingRelation`
+///
+/// Relates two concepts coming, by convention, from different schemes, and that have comparable
+/// meanings.
+pub const MAPPING_RELATION: &str = "http://www.w3.org/2004/02/skos/core#mappingRelation";
+
+/// `skos:member`
+///
+/// Relates a collection to one of its members.
+pub const MEMBER: &str = "http://www.w3.org/2004/02/skos/core#member";
+
+/// `skos:memberList`
+///
+/// Relates an ordered collection to the RDF list containing its members.
+pub const MEMBER_LIST: &str = "http://www.w3.org/2004/02/skos/core#memberList";
+
+/// `skos:narrowMatch`
+///
+/// Used to state a hierarchical mapping link between two conceptual resources in different concept
+/// schemes.
+pub const NARROW_MATCH: &str = "http://www.w3.org/2004/02/skos/core#narrowMatch";
+
+/// `skos:narrower`
+///
+/// Relates a concept to a concept that is more specific in meaning.
+pub const NARROWER: &str = "http://www.w3.org/2004/02/skos/core#narrower";
+
+/// `skos:narrowerTransitive`
+///
+/// The transitive super-property of `skos:narrower`.
+pub const NARROWER_TRANSITIVE: &str = "http://www.w3.org/2004/02/skos/core#narrowerTransitive";
+
+/// `skos:notation`
+///
+/// A notation, also known as classification code, is a string of characters used to uniquely
+/// identify a concept within the scope of a given concept scheme.
+pub const NOTATION: &str = "http://www.w3.org/2004/02/skos/core#notation";
+
+/// `skos:note`
+///
+/// A general note, for any purpose.
+pub const NOTE: &str = "http://www.w3.org/2004/02/skos/core#note";
+
+/// `skos:prefLabel`
+///
+/// The preferred and emphasized lexical label for a resource, in a given language.
+pub const PREF_LABEL: &str = "http://www.w3.org/2004/02/skos/core#prefLabel";
+
+/// `skos:related`
+///
+/// Relates a concept to a concept with which there is an associative semantic relationship.
+pub const RELATED: &str = "http://www.w3.org/2004/02/skos/core#related";
+
+/// `skos:relatedMatch`
+///
+/// Used to state an associative mapping link between two conceptual resources in different concept
+/// schemes.
+pub const RELATED_MATCH: &str = "http://www.w3.org/2004/02/skos/core#relatedMatch";
+
+/// `skos:scopeNote`
+///
+/// A note that helps to clarify the meaning and/or the use of a concept.
+pub const SCOPE_NOTE: &str = "http://www.w3.org/2004/02/skos/core#scopeNote";
+
+/// `skos:semanticRelation`
+///
+/// Links a concept to a concept related by meaning.
+pub const SEMANTIC_RELATION: &str = "http://www.w3.org/2004/02/skos/core#semanticRelation";
+
+/// `skos:topConceptOf`
+///
+/// Relates a concept to the concept scheme that it is a top level concept of.
+pub const TOP_CONCEPT_OF: &str = "http://www.w3.org/2004/02/skos/core#topConceptOf";
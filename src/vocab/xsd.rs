@@ -0,0 +1,202 @@
+//! XML Schema datatypes that can be used in RDF, `http://www.w3.org/2001/XMLSchema#`.
+
+/// Prefix that is bound to the namespace by default.
+pub const DEFAULT_PREFIX: &str = "xsd";
+
+/// Namespace URI of the vocabulary.
+pub const NAMESPACE_URI: &str = "http://www.w3.org/2001/XMLSchema#";
+
+/// `xsd:NCName`
+///
+/// XML names without colons.
+pub const NC_NAME: &str = "http://www.w3.org/2001/XMLSchema#NCName";
+
+/// `xsd:NMTOKEN`
+///
+/// XML name tokens.
+pub const NMTOKEN: &str = "http://www.w3.org/2001/XMLSchema#NMTOKEN";
+
+/// `xsd:Name`
+///
+/// XML names.
+pub const NAME: &str = "http://www.w3.org/2001/XMLSchema#Name";
+
+/// `xsd:anyURI`
+///
+/// Absolute or relative URIs and IRIs.
+pub const ANY_URI: &str = "http://www.w3.org/2001/XMLSchema#anyURI";
+
+/// `xsd:base64Binary`
+///
+/// Base64-encoded binary data.
+pub const BASE64_BINARY: &str = "http://www.w3.org/2001/XMLSchema#base64Binary";
+
+/// `xsd:boolean`
+///
+/// Truth values, `true` and `false`.
+pub const BOOLEAN: &str = "http://www.w3.org/2001/XMLSchema#boolean";
+
+/// `xsd:byte`
+///
+/// Integers between -128 and 127.
+pub const BYTE: &str = "http://www.w3.org/2001/XMLSchema#byte";
+
+/// `xsd:date`
+///
+/// Dates (yyyy-mm-dd) with or without timezone.
+pub const DATE: &str = "http://www.w3.org/2001/XMLSchema#date";
+
+/// `xsd:dateTime`
+///
+/// Date and time with or without timezone.
+pub const DATE_TIME: &str = "http://www.w3.org/2001/XMLSchema#dateTime";
+
+/// `xsd:dateTimeStamp`
+///
+/// Date and time with required timezone.
+pub const DATE_TIME_STAMP: &str = "http://www.w3.org/2001/XMLSchema#dateTimeStamp";
+
+/// `xsd:dayTimeDuration`
+///
+/// Durations of days, hours, minutes and seconds.
+pub const DAY_TIME_DURATION: &str = "http://www.w3.org/2001/XMLSchema#dayTimeDuration";
+
+/// `xsd:decimal`
+///
+/// Arbitrary-precision decimal numbers.
+pub const DECIMAL: &str = "http://www.w3.org/2001/XMLSchema#decimal";
+
+/// `xsd:double`
+///
+/// 64-bit floating point numbers incl. ±Inf, ±0, NaN.
+pub const DOUBLE: &str = "http://www.w3.org/2001/XMLSchema#double";
+
+/// `xsd:duration`
+///
+/// Durations of time.
+pub const DURATION: &str = "http://www.w3.org/2001/XMLSchema#duration";
+
+/// `xsd:float`
+///
+/// 32-bit floating point numbers incl. ±Inf, ±0, NaN.
+pub const FLOAT: &str = "http://www.w3.org/2001/XMLSchema#float";
+
+/// `xsd:gDay`
+///
+/// Gregorian calendar days of the month.
+pub const G_DAY: &str = "http://www.w3.org/2001/XMLSchema#gDay";
+
+/// `xsd:gMonth`
+///
+/// Gregorian calendar months.
+pub const G_MONTH: &str = "http://www.w3.org/2001/XMLSchema#gMonth";
+
+/// `xsd:gMonthDay`
+///
+/// Gregorian calendar days of the year.
+pub const G_MONTH_DAY: &str = "http://www.w3.org/2001/XMLSchema#gMonthDay";
+
+/// `xsd:gYear`
+///
+/// Gregorian calendar years.
+pub const G_YEAR: &str = "http://www.w3.org/2001/XMLSchema#gYear";
+
+/// `xsd:gYearMonth`
+///
+/// Gregorian calendar months of a year.
+pub const G_YEAR_MONTH: &str = "http://www.w3.org/2001/XMLSchema#gYearMonth";
+
+/// `xsd:hexBinary`
+///
+/// Hex-encoded binary data.
+pub const HEX_BINARY: &str = "http://www.w3.org/2001/XMLSchema#hexBinary";
+
+/// `xsd:int`
+///
+/// Integers between -2147483648 and 2147483647.
+pub const INT: &str = "http://www.w3.org/2001/XMLSchema#int";
+
+/// `xsd:integer`
+///
+/// Arbitrary-size integer numbers.
+pub const INTEGER: &str = "http://www.w3.org/2001/XMLSchema#integer";
+
+/// `xsd:language`
+///
+/// Language tags per BCP 47.
+pub const LANGUAGE: &str = "http://www.w3.org/2001/XMLSchema#language";
+
+/// `xsd:long`
+///
+/// Integers between -9223372036854775808 and 9223372036854775807.
+pub const LONG: &str = "http://www.w3.org/2001/XMLSchema#long";
+
+/// `xsd:negativeInteger`
+///
+/// Integer numbers less than 0.
+pub const NEGATIVE_INTEGER: &str = "http://www.w3.org/2001/XMLSchema#negativeInteger";
+
+/// `xsd:nonNegativeInteger`
+///
+/// Integer numbers greater than or equal to 0.
+pub const NON_NEGATIVE_INTEGER: &str = "http://www.w3.org/2001/XMLSchema#nonNegativeInteger";
+
+/// `xsd:nonPositiveInteger`
+///
+/// Integer numbers less than or equal to 0.
+pub const NON_POSITIVE_INTEGER: &str = "http://www.w3.org/2001/XMLSchema#nonPositiveInteger";
+
+/// `xsd:normalizedString`
+///
+/// Whitespace-replaced strings.
+pub const NORMALIZED_STRING: &str = "http://www.w3.org/2001/XMLSchema#normalizedString";
+
+/// `xsd:positiveInteger`
+///
+/// Integer numbers greater than 0.
+pub const POSITIVE_INTEGER: &str = "http://www.w3.org/2001/XMLSchema#positiveInteger";
+
+/// `xsd:short`
+///
+/// Integers between -32768 and 32767.
+pub const SHORT: &str = "http://www.w3.org/2001/XMLSchema#short";
+
+/// `xsd:string`
+///
+/// Character strings (but not all Unicode character strings).
+pub const STRING: &str = "http://www.w3.org/2001/XMLSchema#string";
+
+/// `xsd:time`
+///
+/// Times (hh:mm:ss.sss...) with or without timezone.
+pub const TIME: &str = "http://www.w3.org/2001/XMLSchema#time";
+
+/// `xsd:token`
+///
+/// Tokenized strings.
+pub const TOKEN: &str = "http://www.w3.org/2001/XMLSchema#token";
+
+/// `xsd:unsignedByte`
+///
+/// Integers between 0 and 255.
+pub const UNSIGNED_BYTE: &str = "http://www.w3.org/2001/XMLSchema#unsignedByte";
+
+/// `xsd:unsignedInt`
+///
+/// Integers between 0 and 4294967295.
+pub const UNSIGNED_INT: &str = "http://www.w3.org/2001/XMLSchema#unsignedInt";
+
+/// `xsd:unsignedLong`
+///
+/// Integers between 0 and 18446744073709551615.
+pub const UNSIGNED_LONG: &str = "http://www.w3.org/2001/XMLSchema#unsignedLong";
+
+/// `xsd:unsignedShort`
+///
+/// Integers between 0 and 65535.
+pub const UNSIGNED_SHORT: &str = "http://www.w3.org/2001/XMLSchema#unsignedShort";
+
+/// `xsd:yearMonthDuration`
+///
+/// Durations of years and months.
+pub const YEAR_MONTH_DURATION: &str = "http://www.w3.org/2001/XMLSchema#yearMonthDuration";
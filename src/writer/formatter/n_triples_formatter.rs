@@ -1,4 +1,6 @@
+use crate::dataset::Quad;
 use crate::node::Node;
+use crate::specs::n_triples_specs::NTriplesSpecs;
 use crate::uri::Uri;
 use crate::writer::formatter::rdf_formatter::RdfFormatter;
 
@@ -12,6 +14,41 @@ impl NTriplesFormatter {
     pub fn new() -> NTriplesFormatter {
         NTriplesFormatter {}
     }
+
+    /// Formats a quad to an N-Quads statement, the graph label is omitted for the default graph.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::dataset::Quad;
+    /// use rdf::node::Node;
+    /// use rdf::uri::Uri;
+    /// use rdf::writer::formatter::n_triples_formatter::NTriplesFormatter;
+    ///
+    /// let blank = Node::BlankNode { id: "a".to_string() };
+    /// let uri = Node::UriNode { uri: Uri::parse("http://example.org/p").unwrap() };
+    ///
+    /// let formatter = NTriplesFormatter::new();
+    ///
+    /// assert_eq!(formatter.format_quad(&Quad::new(&blank, &uri, &blank, Some(&uri))),
+    ///            "_:a <http://example.org/p> _:a <http://example.org/p> .");
+    /// assert_eq!(formatter.format_quad(&Quad::new(&blank, &uri, &blank, None)),
+    ///            "_:a <http://example.org/p> _:a .");
+    /// ```
+    pub fn format_quad(&self, quad: &Quad) -> String {
+        let mut output_string = self.format_node(quad.subject());
+
+        for node in [quad.predicate(), quad.object()]
+            .iter()
+            .chain(quad.graph_name().iter())
+        {
+            output_string.push(' ');
+            output_string.push_str(&self.format_node(node));
+        }
+
+        output_string.push_str(" .");
+        output_string
+    }
 }
 
 impl RdfFormatter for NTriplesFormatter {
@@ -42,7 +79,7 @@ impl RdfFormatter for NTriplesFormatter {
         language: &Option<String>,
     ) -> String {
         let mut output_string = "\"".to_string();
-        output_string.push_str(format!("{}", literal.escape_debug()).as_ref());
+        output_string.push_str(&NTriplesSpecs::escape_literal(literal));
         output_string.push_str("\"");
 
         if let Some(ref lang) = *language {
@@ -66,7 +103,7 @@ impl RdfFormatter for NTriplesFormatter {
     /// Formats a URI to N-Triples syntax.
     fn format_uri(&self, uri: &Uri) -> String {
         let mut output_string = "<".to_string();
-        output_string.push_str(uri.to_string());
+        output_string.push_str(&NTriplesSpecs::escape_uri(uri.to_string()));
         output_string.push_str(">");
 
         output_string
@@ -94,7 +131,7 @@ mod tests {
     fn test_n_triples_uri_node_formatting() {
         let formatter = NTriplesFormatter::new();
         let node = Node::UriNode {
-            uri: Uri::new("http://example.org/show/localName".to_string()),
+            uri: Uri::parse("http://example.org/show/localName").unwrap(),
         };
 
         assert_eq!(
@@ -120,7 +157,7 @@ mod tests {
         let formatter = NTriplesFormatter::new();
         let node = Node::LiteralNode {
             literal: "literal".to_string(),
-            data_type: Some(Uri::new("http://example.org/show/localName".to_string())),
+            data_type: Some(Uri::parse("http://example.org/show/localName").unwrap()),
             language: None,
         };
 
@@ -141,7 +178,7 @@ mod tests {
 
         assert_eq!(
             formatter.format_node(&node),
-            "\"literal \\' \\\\ \\\" \"".to_string()
+            "\"literal ' \\\\ \\\" \"".to_string()
         );
     }
 
@@ -1,4 +1,5 @@
 use crate::node::Node;
+use crate::specs::n_triples_specs::NTriplesSpecs;
 use crate::specs::turtle_specs::TurtleSpecs;
 use std::collections::HashMap;
 use crate::uri::Uri;
@@ -8,12 +9,39 @@ use crate::writer::formatter::rdf_formatter::RdfFormatter;
 /// This formatter is used by `TurtleWriter`.
 pub struct TurtleFormatter<'a> {
     namespaces: &'a HashMap<String, Uri>,
+
+    /// Base URI that IRIs are relativized against.
+    base_uri: Option<&'a Uri>,
 }
 
 impl<'a> TurtleFormatter<'a> {
     /// Constructor of `TurtleFormatter`.
     pub fn new(namespaces: &'a HashMap<String, Uri>) -> TurtleFormatter<'a> {
-        TurtleFormatter { namespaces }
+        TurtleFormatter {
+            namespaces,
+            base_uri: None,
+        }
+    }
+
+    /// Sets the base URI that IRIs without matching namespace are relativized against.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::uri::Uri;
+    /// use rdf::writer::formatter::rdf_formatter::RdfFormatter;
+    /// use rdf::writer::formatter::turtle_formatter::TurtleFormatter;
+    /// use std::collections::HashMap;
+    ///
+    /// let namespaces = HashMap::new();
+    /// let base_uri = Uri::parse("http://example.org/a/").unwrap();
+    /// let formatter = TurtleFormatter::new(&namespaces).with_base_uri(&base_uri);
+    ///
+    /// assert_eq!(formatter.format_uri(&Uri::parse("http://example.org/a/b").unwrap()), "<b>");
+    /// ```
+    pub fn with_base_uri(mut self, base_uri: &'a Uri) -> TurtleFormatter<'a> {
+        self.base_uri = Some(base_uri);
+        self
     }
 }
 
@@ -43,30 +71,19 @@ impl<'a> RdfFormatter for TurtleFormatter<'a> {
         data_type: &Option<Uri>,
         language: &Option<String>,
     ) -> String {
-        let mut output_string = "".to_string();
-
-        if TurtleSpecs::is_boolean_literal(literal) && *language == None && *data_type == None {
-            // some boolean
-            output_string.push_str(literal);
-        } else if TurtleSpecs::is_integer_literal(literal) && *language == None {
-            // some number
-            output_string.push_str(literal);
-        } else {
-            output_string.push_str("\"");
-            output_string.push_str(format!("{}", literal.escape_debug()).as_ref());
-            output_string.push_str("\"");
-            if let Some(ref lang) = *language {
-                output_string.push_str("@");
-                output_string.push_str(lang);
-            }
-    
-            if let Some(ref dt) = *data_type {
-                output_string.push_str("^^");
-                output_string.push_str(&self.format_uri(dt));
-            }
+        let mut output_string = "\"".to_string();
+        output_string.push_str(&NTriplesSpecs::escape_literal(literal));
+        output_string.push('"');
+
+        if let Some(ref lang) = *language {
+            output_string.push('@');
+            output_string.push_str(lang);
         }
 
-        
+        if let Some(ref dt) = *data_type {
+            output_string.push_str("^^");
+            output_string.push_str(&self.format_uri(dt));
+        }
 
         output_string
     }
@@ -77,28 +94,37 @@ impl<'a> RdfFormatter for TurtleFormatter<'a> {
     }
 
     /// Formats a URI to Turtle syntax.
+    ///
+    /// A QName is written if the URI starts with a namespace and the rest of the URI is
+    /// a valid local name. The longest matching namespace is used, ties are broken by the
+    /// prefix. Other URIs are written relative to the base URI, if possible.
     fn format_uri(&self, uri: &Uri) -> String {
-        let mut output_string = "".to_string();
+        let qname = self
+            .namespaces
+            .iter()
+            .filter_map(|(prefix, namespace_uri)| {
+                let local_name = uri
+                    .to_string()
+                    .strip_prefix(namespace_uri.to_string().as_str())?;
+                let local_name = TurtleSpecs::escape_local_name(local_name)?;
 
-        // write QName if namespace for URI exists
-        for (prefix, namespace_uri) in self.namespaces.iter() {
-            if uri.to_string().starts_with(namespace_uri.to_string()) {
-                output_string.push_str(prefix);
-                output_string.push_str(":");
+                // prefixes declared by the Turtle parser include the trailing ':'
+                let prefix = prefix.trim_end_matches(':');
 
-                let path = uri
-                    .to_string()
-                    .to_owned()
-                    .replace(namespace_uri.to_string(), "")
-                    .replace("/", ":");
-                output_string.push_str(&path);
+                Some((namespace_uri.to_string().len(), prefix, local_name))
+            })
+            .max_by(|a, b| a.0.cmp(&b.0).then_with(|| b.1.cmp(a.1)));
 
-                return output_string;
-            }
+        if let Some((_, prefix, local_name)) = qname {
+            return prefix.to_string() + ":" + &local_name;
         }
 
-        output_string.push_str("<");
-        output_string.push_str(uri.to_string());
+        let relative_uri = self.base_uri.and_then(|base_uri| base_uri.relativize(uri));
+
+        let mut output_string = "<".to_string();
+        output_string.push_str(&NTriplesSpecs::escape_uri(
+            relative_uri.as_ref().unwrap_or(uri).to_string(),
+        ));
         output_string.push_str(">");
 
         output_string
@@ -130,7 +156,7 @@ mod tests {
         let hashmap = HashMap::new();
         let formatter = TurtleFormatter::new(&hashmap);
         let node = Node::UriNode {
-            uri: Uri::new("http://example.org/show/localName".to_string()),
+            uri: Uri::parse("http://example.org/show/localName").unwrap(),
         };
 
         assert_eq!(
@@ -144,17 +170,83 @@ mod tests {
         let mut hashmap = HashMap::new();
         hashmap.insert(
             "example".to_string(),
-            Uri::new("http://example.org/".to_string()),
+            Uri::parse("http://example.org/").unwrap(),
         );
 
         let formatter = TurtleFormatter::new(&hashmap);
         let node = Node::UriNode {
-            uri: Uri::new("http://example.org/show/localName".to_string()),
+            uri: Uri::parse("http://example.org/show/localName").unwrap(),
         };
 
         assert_eq!(
             formatter.format_node(&node),
-            "example:show:localName".to_string()
+            "example:show\\/localName".to_string()
+        );
+    }
+
+    #[test]
+    fn test_turtle_qname_longest_namespace_formatting() {
+        let mut hashmap = HashMap::new();
+        hashmap.insert(
+            "example".to_string(),
+            Uri::parse("http://example.org/").unwrap(),
+        );
+        hashmap.insert(
+            "show:".to_string(),
+            Uri::parse("http://example.org/show/").unwrap(),
+        );
+        hashmap.insert(
+            "b".to_string(),
+            Uri::parse("http://example.org/show/").unwrap(),
+        );
+
+        let formatter = TurtleFormatter::new(&hashmap);
+        let node = Node::UriNode {
+            uri: Uri::parse("http://example.org/show/localName").unwrap(),
+        };
+
+        assert_eq!(formatter.format_node(&node), "b:localName".to_string());
+    }
+
+    #[test]
+    fn test_turtle_qname_invalid_local_name_formatting() {
+        let mut hashmap = HashMap::new();
+        hashmap.insert(
+            "example".to_string(),
+            Uri::parse("http://example.org/").unwrap(),
+        );
+
+        let formatter = TurtleFormatter::new(&hashmap);
+        let node = Node::UriNode {
+            uri: Uri::new_unchecked("http://example.org/a%b".to_string()),
+        };
+
+        assert_eq!(
+            formatter.format_node(&node),
+            "<http://example.org/a%b>".to_string()
+        );
+    }
+
+    #[test]
+    fn test_turtle_relative_uri_node_formatting() {
+        let hashmap = HashMap::new();
+        let base_uri = Uri::parse("http://example.org/show/").unwrap();
+        let formatter = TurtleFormatter::new(&hashmap).with_base_uri(&base_uri);
+
+        let relative = Node::UriNode {
+            uri: Uri::parse("http://example.org/show/localName#a").unwrap(),
+        };
+        let absolute = Node::UriNode {
+            uri: Uri::parse("https://example.org/show/localName").unwrap(),
+        };
+
+        assert_eq!(
+            formatter.format_node(&relative),
+            "<localName#a>".to_string()
+        );
+        assert_eq!(
+            formatter.format_node(&absolute),
+            "<https://example.org/show/localName>".to_string()
         );
     }
 
@@ -183,7 +275,7 @@ mod tests {
 
         assert_eq!(
             formatter.format_node(&node),
-            "\"literal \\' \\\\ \\\" \"".to_string()
+            "\"literal ' \\\\ \\\" \"".to_string()
         );
     }
 
@@ -193,7 +285,7 @@ mod tests {
         let formatter = TurtleFormatter::new(&hashmap);
         let node = Node::LiteralNode {
             literal: "literal".to_string(),
-            data_type: Some(Uri::new("http://example.org/show/localName".to_string())),
+            data_type: Some(Uri::parse("http://example.org/show/localName").unwrap()),
             language: None,
         };
 
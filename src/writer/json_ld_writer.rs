@@ -0,0 +1,688 @@
+use crate::error::{Error, ErrorType};
+use crate::graph::Graph;
+use crate::json::{JsonObject, JsonValue};
+use crate::json_ld::compaction::compact;
+use crate::json_ld::context::Context;
+use crate::json_ld::loader::{ContextLoader, NoContextLoader};
+use crate::node::Node;
+use crate::specs::rdf_syntax_specs::RdfSyntaxDataTypes;
+use crate::triple::Triple;
+use crate::uri::Uri;
+use crate::writer::rdf_writer::RdfWriter;
+use crate::Result;
+use std::collections::{BTreeMap, HashMap};
+
+/// Node objects of a graph, by subject identifier.
+type NodeMap = BTreeMap<String, JsonObject>;
+
+/// Subject, property and value index referencing a list, with the list items and list nodes.
+type ListUsage = (String, String, usize, Vec<JsonValue>, Vec<String>);
+
+/// RDF writer to generate JSON-LD.
+///
+/// Without a context, the graph is written as expanded JSON-LD. If a context or namespaces
+/// are provided, the output is compacted using their terms and prefixes.
+pub struct JsonLdWriter<'a> {
+    namespaces: Option<&'a HashMap<String, Uri>>,
+    context: Option<JsonValue>,
+    context_loader: Box<dyn ContextLoader>,
+    frame: Option<Node>,
+    indentation: usize,
+}
+
+impl<'a> RdfWriter for JsonLdWriter<'a> {
+    /// Generates JSON-LD for the triples stored in the provided graph.
+    ///
+    /// Returns an error if invalid JSON-LD would be generated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::writer::json_ld_writer::JsonLdWriter;
+    /// use rdf::writer::rdf_writer::RdfWriter;
+    /// use rdf::graph::Graph;
+    /// use rdf::uri::Uri;
+    /// use rdf::triple::Triple;
+    ///
+    /// let mut graph = Graph::new(None);
+    ///
+    /// let subject = graph.create_uri_node(&Uri::parse("http://example.org/alice").unwrap());
+    /// let predicate = graph.create_uri_node(&Uri::parse("http://xmlns.com/foaf/0.1/name").unwrap());
+    /// let object = graph.create_literal_node("Alice".to_string());
+    ///
+    /// graph.add_triple(&Triple::new(&subject, &predicate, &object));
+    ///
+    /// let writer = JsonLdWriter::new();
+    ///
+    /// assert_eq!(writer.write_to_string(&graph).unwrap(),
+    ///            "[{\"@id\":\"http://example.org/alice\",\"http://xmlns.com/foaf/0.1/name\":[{\"@value\":\"Alice\"}]}]");
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - Literals are used as subjects or predicates, or blank nodes as predicates.
+    /// - The provided context is invalid.
+    ///
+    fn write_to_string(&self, graph: &Graph) -> Result<String> {
+        let mut node_map = JsonLdWriter::node_map(graph)?;
+        convert_lists(&mut node_map);
+
+        let nodes: Vec<JsonValue> = match self.frame {
+            Some(ref root) => {
+                let root = node_id(root);
+                vec![embed_node(&node_map, &root, &mut Vec::new())]
+            }
+            None => node_map.into_values().map(JsonValue::Object).collect(),
+        };
+
+        let output = match self.output_context() {
+            Some(local) => {
+                let base_uri = graph.base_uri().as_ref().map(|b| b.to_string().as_str());
+                let context =
+                    Context::new(base_uri).process(&local, self.context_loader.as_ref())?;
+
+                let mut compacted = match compact(&JsonValue::Array(nodes), &context) {
+                    JsonValue::Array(mut nodes) if nodes.len() == 1 => match nodes.remove(0) {
+                        JsonValue::Object(node) => node,
+                        _ => JsonObject::new(),
+                    },
+                    nodes => {
+                        let mut document = JsonObject::new();
+                        document.insert("@graph".to_string(), nodes);
+                        document
+                    }
+                };
+
+                compacted.insert("@context".to_string(), local);
+                JsonValue::Object(compacted)
+            }
+            None => JsonValue::Array(nodes),
+        };
+
+        if self.indentation > 0 {
+            Ok(output.to_pretty_string(self.indentation))
+        } else {
+            Ok(output.to_string())
+        }
+    }
+}
+
+impl<'a> Default for JsonLdWriter<'a> {
+    fn default() -> JsonLdWriter<'a> {
+        JsonLdWriter::new()
+    }
+}
+
+impl<'a> JsonLdWriter<'a> {
+    /// Constructor of `JsonLdWriter` that writes expanded JSON-LD.
+    pub fn new() -> JsonLdWriter<'a> {
+        JsonLdWriter {
+            namespaces: None,
+            context: None,
+            context_loader: Box::new(NoContextLoader::new()),
+            frame: None,
+            indentation: 0,
+        }
+    }
+
+    /// Compacts the output using the provided context (the value of `@context`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::json::JsonValue;
+    /// use rdf::writer::json_ld_writer::JsonLdWriter;
+    /// use rdf::writer::rdf_writer::RdfWriter;
+    /// use rdf::graph::Graph;
+    /// use rdf::uri::Uri;
+    /// use rdf::triple::Triple;
+    ///
+    /// let mut graph = Graph::new(None);
+    ///
+    /// let subject = graph.create_uri_node(&Uri::parse("http://example.org/alice").unwrap());
+    /// let predicate = graph.create_uri_node(&Uri::parse("http://xmlns.com/foaf/0.1/name").unwrap());
+    /// let object = graph.create_literal_node("Alice".to_string());
+    ///
+    /// graph.add_triple(&Triple::new(&subject, &predicate, &object));
+    ///
+    /// let context = JsonValue::parse("{\"name\": \"http://xmlns.com/foaf/0.1/name\"}").unwrap();
+    /// let writer = JsonLdWriter::new().with_context(context);
+    ///
+    /// assert_eq!(writer.write_to_string(&graph).unwrap(),
+    ///            "{\"@context\":{\"name\":\"http://xmlns.com/foaf/0.1/name\"},\"@id\":\"http://example.org/alice\",\"name\":\"Alice\"}");
+    /// ```
+    pub fn with_context(mut self, context: JsonValue) -> JsonLdWriter<'a> {
+        self.context = Some(context);
+        self
+    }
+
+    /// Adds the provided namespaces as prefixes to the context of the output.
+    ///
+    /// Term definitions of a context provided via `with_context` take precedence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::writer::json_ld_writer::JsonLdWriter;
+    /// use rdf::writer::rdf_writer::RdfWriter;
+    /// use rdf::graph::Graph;
+    /// use rdf::namespace::Namespace;
+    /// use rdf::uri::Uri;
+    /// use rdf::triple::Triple;
+    ///
+    /// let mut graph = Graph::new(None);
+    /// graph.add_namespace(&Namespace::new("foaf".to_string(), Uri::parse("http://xmlns.com/foaf/0.1/").unwrap()));
+    ///
+    /// let subject = graph.create_uri_node(&Uri::parse("http://example.org/alice").unwrap());
+    /// let predicate = graph.create_uri_node(&Uri::parse("http://xmlns.com/foaf/0.1/name").unwrap());
+    /// let object = graph.create_literal_node("Alice".to_string());
+    ///
+    /// graph.add_triple(&Triple::new(&subject, &predicate, &object));
+    ///
+    /// let writer = JsonLdWriter::new().with_namespaces(graph.namespaces());
+    ///
+    /// assert_eq!(writer.write_to_string(&graph).unwrap(),
+    ///            "{\"@context\":{\"foaf\":\"http://xmlns.com/foaf/0.1/\"},\"@id\":\"http://example.org/alice\",\"foaf:name\":\"Alice\"}");
+    /// ```
+    pub fn with_namespaces(mut self, namespaces: &'a HashMap<String, Uri>) -> JsonLdWriter<'a> {
+        self.namespaces = Some(namespaces);
+        self
+    }
+
+    /// Sets the loader that is used to retrieve remote contexts referenced by the context.
+    pub fn with_context_loader<L>(mut self, loader: L) -> JsonLdWriter<'a>
+    where
+        L: ContextLoader + 'static,
+    {
+        self.context_loader = Box::new(loader);
+        self
+    }
+
+    /// Frames the output as a tree of node objects starting at the provided root subject.
+    ///
+    /// Node objects of referenced subjects are embedded, except if they are already
+    /// embedded on the path from the root (to break cycles).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::writer::json_ld_writer::JsonLdWriter;
+    /// use rdf::writer::rdf_writer::RdfWriter;
+    /// use rdf::graph::Graph;
+    /// use rdf::uri::Uri;
+    /// use rdf::triple::Triple;
+    ///
+    /// let mut graph = Graph::new(None);
+    ///
+    /// let alice = graph.create_uri_node(&Uri::parse("http://example.org/alice").unwrap());
+    /// let knows = graph.create_uri_node(&Uri::parse("http://xmlns.com/foaf/0.1/knows").unwrap());
+    /// let name = graph.create_uri_node(&Uri::parse("http://xmlns.com/foaf/0.1/name").unwrap());
+    /// let bob = graph.create_blank_node();
+    ///
+    /// graph.add_triple(&Triple::new(&alice, &knows, &bob));
+    /// graph.add_triple(&Triple::new(&bob, &name, &graph.create_literal_node("Bob".to_string())));
+    ///
+    /// let writer = JsonLdWriter::new().with_frame(&alice);
+    ///
+    /// assert_eq!(writer.write_to_string(&graph).unwrap(),
+    ///            "[{\"@id\":\"http://example.org/alice\",\"http://xmlns.com/foaf/0.1/knows\":[{\"@id\":\"_:auto0\",\
+    ///             \"http://xmlns.com/foaf/0.1/name\":[{\"@value\":\"Bob\"}]}]}]");
+    /// ```
+    pub fn with_frame(mut self, root: &Node) -> JsonLdWriter<'a> {
+        self.frame = Some(root.clone());
+        self
+    }
+
+    /// Sets the number of spaces used for indentation; `0` writes the output on a single line.
+    pub fn with_indentation(mut self, indentation: usize) -> JsonLdWriter<'a> {
+        self.indentation = indentation;
+        self
+    }
+
+    /// Returns the context that is written to the output, if the output is compacted.
+    fn output_context(&self) -> Option<JsonValue> {
+        let mut prefixes = JsonObject::new();
+
+        if let Some(namespaces) = self.namespaces {
+            for (prefix, uri) in namespaces {
+                // prefixes declared by the Turtle parser include the trailing ':'
+                prefixes.insert(
+                    prefix.trim_end_matches(':').to_string(),
+                    JsonValue::String(uri.to_string().clone()),
+                );
+            }
+        }
+
+        match self.context.clone() {
+            None if self.namespaces.is_none() => None,
+            None => Some(JsonValue::Object(prefixes)),
+            Some(context) if prefixes.is_empty() => Some(context),
+            Some(JsonValue::Object(terms)) => {
+                prefixes.extend(terms);
+                Some(JsonValue::Object(prefixes))
+            }
+            Some(context) => {
+                let mut contexts = vec![JsonValue::Object(prefixes)];
+                contexts.extend(context.to_vec());
+                Some(JsonValue::Array(contexts))
+            }
+        }
+    }
+
+    /// Creates the expanded node objects of all subjects of the graph.
+    ///
+    /// # Failures
+    ///
+    /// - The node type is invalid for the triple segment.
+    ///
+    fn node_map(graph: &Graph) -> Result<NodeMap> {
+        let mut node_map = NodeMap::new();
+
+        let mut triples: Vec<&Triple> = graph.triples_iter().collect();
+        triples.sort();
+        triples.dedup_by(|a, b| a == b);
+
+        let rdf_type = RdfSyntaxDataTypes::A.to_string();
+
+        for triple in triples {
+            let subject = match *triple.subject() {
+                Node::LiteralNode { .. } => {
+                    return Err(Error::new(
+                        ErrorType::InvalidWriterOutput,
+                        "Literals are not allowed as subjects in JSON-LD.",
+                    ))
+                }
+                ref subject => node_id(subject),
+            };
+
+            let predicate = match *triple.predicate() {
+                Node::UriNode { ref uri } => uri.to_string().clone(),
+                _ => {
+                    return Err(Error::new(
+                        ErrorType::InvalidWriterOutput,
+                        "Only URIs are allowed as predicates in JSON-LD.",
+                    ))
+                }
+            };
+
+            let node = node_map.entry(subject.clone()).or_insert_with(|| {
+                let mut node = JsonObject::new();
+                node.insert("@id".to_string(), JsonValue::String(subject));
+                node
+            });
+
+            match *triple.object() {
+                Node::LiteralNode {
+                    ref literal,
+                    ref data_type,
+                    ref language,
+                } => {
+                    let mut value = JsonObject::new();
+                    value.insert("@value".to_string(), JsonValue::String(literal.clone()));
+
+                    if let Some(ref data_type) = *data_type {
+                        value.insert(
+                            "@type".to_string(),
+                            JsonValue::String(data_type.to_string().clone()),
+                        );
+                    }
+
+                    if let Some(ref language) = *language {
+                        value.insert("@language".to_string(), JsonValue::String(language.clone()));
+                    }
+
+                    add_value(node, &predicate, JsonValue::Object(value));
+                }
+                ref object if predicate == rdf_type => {
+                    add_value(node, "@type", JsonValue::String(node_id(object)));
+                }
+                ref object => add_value(node, &predicate, node_reference(&node_id(object))),
+            }
+        }
+
+        Ok(node_map)
+    }
+}
+
+/// Returns the identifier of a URI or blank node as used by JSON-LD.
+fn node_id(node: &Node) -> String {
+    match *node {
+        Node::UriNode { ref uri } => uri.to_string().clone(),
+        Node::BlankNode { ref id } => "_:".to_string() + id,
+        Node::LiteralNode { ref literal, .. } => literal.clone(),
+    }
+}
+
+/// Creates a node reference from an identifier.
+fn node_reference(id: &str) -> JsonValue {
+    let mut reference = JsonObject::new();
+    reference.insert("@id".to_string(), JsonValue::String(id.to_string()));
+    JsonValue::Object(reference)
+}
+
+/// Appends a value to the array stored at the provided key.
+fn add_value(object: &mut JsonObject, key: &str, value: JsonValue) {
+    let entry = object
+        .entry(key.to_string())
+        .or_insert_with(|| JsonValue::Array(Vec::new()));
+
+    if let JsonValue::Array(ref mut values) = *entry {
+        values.push(value);
+    }
+}
+
+/// Replaces well-formed RDF collections by list objects.
+///
+/// A collection is well-formed if all of its nodes are blank nodes that are referenced once
+/// and have exactly one `rdf:first` and one `rdf:rest` value.
+fn convert_lists(node_map: &mut NodeMap) {
+    let first = RdfSyntaxDataTypes::ListFirst.to_string();
+    let rest = RdfSyntaxDataTypes::ListRest.to_string();
+    let nil = RdfSyntaxDataTypes::ListNil.to_string();
+
+    // count references to each blank node
+    let mut references: HashMap<String, usize> = HashMap::new();
+
+    for node in node_map.values() {
+        for (_, values) in node.iter().filter(|(p, _)| !p.starts_with('@')) {
+            for value in values.to_vec() {
+                if let Some(id) = value.get("@id").and_then(|id| id.as_str()) {
+                    if id.starts_with("_:") {
+                        *references.entry(id.to_string()).or_insert(0) += 1;
+                    }
+                }
+            }
+        }
+    }
+
+    let is_list_node = |node: &JsonObject| -> bool {
+        let id = node.get("@id").and_then(|id| id.as_str()).unwrap_or("");
+
+        node.len() == 3
+            && id.starts_with("_:")
+            && references.get(id) == Some(&1)
+            && node.get(&first).map(|f| f.to_vec().len()) == Some(1)
+            && node.get(&rest).map(|r| r.to_vec().len()) == Some(1)
+    };
+
+    // collect the items and nodes of all lists referenced from outside of lists
+    let mut lists: Vec<ListUsage> = Vec::new();
+
+    for (subject, node) in node_map.iter() {
+        // lists are only started from outside of other lists
+        if is_list_node(node) {
+            continue;
+        }
+
+        for (property, values) in node.iter().filter(|(p, _)| !p.starts_with('@')) {
+            for (index, value) in values.to_vec().iter().enumerate() {
+                let mut items = Vec::new();
+                let mut list_nodes = Vec::new();
+                let mut current = value
+                    .get("@id")
+                    .and_then(|id| id.as_str())
+                    .map(|id| id.to_string());
+
+                while let Some(id) = current.take() {
+                    let list_node = match node_map.get(&id) {
+                        Some(list_node) if is_list_node(list_node) && !list_nodes.contains(&id) => {
+                            list_node
+                        }
+                        _ => break,
+                    };
+
+                    items.push(list_node[&first].to_vec().remove(0));
+                    list_nodes.push(id);
+
+                    let next = list_node[&rest].to_vec().remove(0);
+
+                    match next.get("@id").and_then(|id| id.as_str()) {
+                        Some(id) if id == nil => {
+                            lists.push((
+                                subject.clone(),
+                                property.clone(),
+                                index,
+                                items,
+                                list_nodes,
+                            ));
+                            break;
+                        }
+                        id => current = id.map(|id| id.to_string()),
+                    }
+
+                    if current.is_none() {
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    for (subject, property, index, items, list_nodes) in lists {
+        if let Some(JsonValue::Array(values)) = node_map
+            .get_mut(&subject)
+            .and_then(|node| node.get_mut(&property))
+        {
+            let mut list = JsonObject::new();
+            list.insert("@list".to_string(), JsonValue::Array(items));
+            values[index] = JsonValue::Object(list);
+        }
+
+        for id in list_nodes {
+            node_map.remove(&id);
+        }
+    }
+}
+
+/// Returns the node object of a subject with the node objects of referenced subjects embedded.
+fn embed_node(node_map: &NodeMap, id: &str, path: &mut Vec<String>) -> JsonValue {
+    let node = match node_map.get(id) {
+        Some(node) => node,
+        None => return node_reference(id),
+    };
+
+    path.push(id.to_string());
+
+    let mut result = JsonObject::new();
+
+    for (property, values) in node {
+        let value = if property.starts_with('@') {
+            values.clone()
+        } else {
+            JsonValue::Array(
+                values
+                    .to_vec()
+                    .iter()
+                    .map(|value| embed_value(node_map, value, path))
+                    .collect(),
+            )
+        };
+
+        result.insert(property.clone(), value);
+    }
+
+    path.pop();
+
+    JsonValue::Object(result)
+}
+
+/// Embeds the node object of a node reference, including node references within lists.
+fn embed_value(node_map: &NodeMap, value: &JsonValue, path: &mut Vec<String>) -> JsonValue {
+    if let Some(items) = value.get("@list") {
+        let items = items
+            .to_vec()
+            .iter()
+            .map(|item| embed_value(node_map, item, path))
+            .collect();
+
+        let mut list = JsonObject::new();
+        list.insert("@list".to_string(), JsonValue::Array(items));
+        return JsonValue::Object(list);
+    }
+
+    match value.get("@id").and_then(|id| id.as_str()) {
+        Some(id) if !path.iter().any(|p| p == id) => embed_node(node_map, id, path),
+        _ => value.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::graph::Graph;
+    use crate::json::JsonValue;
+    use crate::namespace::Namespace;
+    use crate::reader::json_ld_parser::JsonLdParser;
+    use crate::reader::rdf_parser::RdfParser;
+    use crate::reader::turtle_parser::TurtleParser;
+    use crate::triple::Triple;
+    use crate::uri::Uri;
+    use crate::writer::json_ld_writer::JsonLdWriter;
+    use crate::writer::rdf_writer::RdfWriter;
+
+    fn people_graph() -> Graph {
+        let mut graph = Graph::new(None);
+
+        graph.add_namespace(&Namespace::new(
+            "foaf".to_string(),
+            Uri::parse("http://xmlns.com/foaf/0.1/").unwrap(),
+        ));
+
+        let alice = graph.create_uri_node(&Uri::parse("http://example.org/alice").unwrap());
+        let bob = graph.create_uri_node(&Uri::parse("http://example.org/bob").unwrap());
+        let knows = graph.create_uri_node(&Uri::parse("http://xmlns.com/foaf/0.1/knows").unwrap());
+        let name = graph.create_uri_node(&Uri::parse("http://xmlns.com/foaf/0.1/name").unwrap());
+        let age = graph.create_uri_node(&Uri::parse("http://xmlns.com/foaf/0.1/age").unwrap());
+        let rdf_type = graph.create_uri_node(
+            &Uri::parse("http://www.w3.org/1999/02/22-rdf-syntax-ns#type").unwrap(),
+        );
+        let person =
+            graph.create_uri_node(&Uri::parse("http://xmlns.com/foaf/0.1/Person").unwrap());
+
+        let alice_name =
+            graph.create_literal_node_with_language("Alice".to_string(), "en".to_string());
+        let bob_name = graph.create_literal_node("Bob".to_string());
+        let bob_age = graph.create_integer_node(42);
+
+        graph.add_triple(&Triple::new(&alice, &rdf_type, &person));
+        graph.add_triple(&Triple::new(&alice, &name, &alice_name));
+        graph.add_triple(&Triple::new(&alice, &knows, &bob));
+        graph.add_triple(&Triple::new(&bob, &name, &bob_name));
+        graph.add_triple(&Triple::new(&bob, &age, &bob_age));
+        graph.add_triple(&Triple::new(&bob, &knows, &alice));
+
+        graph
+    }
+
+    #[test]
+    fn write_expanded_json_ld() {
+        let graph = people_graph();
+        let writer = JsonLdWriter::new();
+
+        assert_eq!(
+            writer.write_to_string(&graph).unwrap(),
+            "[{\"@id\":\"http://example.org/alice\",\"@type\":[\"http://xmlns.com/foaf/0.1/Person\"],\
+             \"http://xmlns.com/foaf/0.1/knows\":[{\"@id\":\"http://example.org/bob\"}],\
+             \"http://xmlns.com/foaf/0.1/name\":[{\"@language\":\"en\",\"@value\":\"Alice\"}]},\
+             {\"@id\":\"http://example.org/bob\",\
+             \"http://xmlns.com/foaf/0.1/age\":[{\"@type\":\"http://www.w3.org/2001/XMLSchema#integer\",\"@value\":\"42\"}],\
+             \"http://xmlns.com/foaf/0.1/knows\":[{\"@id\":\"http://example.org/alice\"}],\
+             \"http://xmlns.com/foaf/0.1/name\":[{\"@value\":\"Bob\"}]}]"
+        );
+    }
+
+    #[test]
+    fn write_compacted_json_ld() {
+        let graph = people_graph();
+        let context = JsonValue::parse(
+            "{
+              \"xsd\": \"http://www.w3.org/2001/XMLSchema#\",
+              \"knows\": {\"@id\": \"foaf:knows\", \"@type\": \"@id\"},
+              \"age\": {\"@id\": \"foaf:age\", \"@type\": \"xsd:integer\"}
+            }",
+        )
+        .unwrap();
+
+        let writer = JsonLdWriter::new()
+            .with_namespaces(graph.namespaces())
+            .with_context(context);
+
+        assert_eq!(
+            writer.write_to_string(&graph).unwrap(),
+            "{\"@context\":{\"age\":{\"@id\":\"foaf:age\",\"@type\":\"xsd:integer\"},\
+             \"foaf\":\"http://xmlns.com/foaf/0.1/\",\
+             \"knows\":{\"@id\":\"foaf:knows\",\"@type\":\"@id\"},\
+             \"xsd\":\"http://www.w3.org/2001/XMLSchema#\"},\
+             \"@graph\":[{\"@id\":\"http://example.org/alice\",\"@type\":\"foaf:Person\",\
+             \"foaf:name\":{\"@language\":\"en\",\"@value\":\"Alice\"},\"knows\":\"http://example.org/bob\"},\
+             {\"@id\":\"http://example.org/bob\",\"age\":\"42\",\"foaf:name\":\"Bob\",\"knows\":\"http://example.org/alice\"}]}"
+        );
+    }
+
+    #[test]
+    fn write_framed_json_ld() {
+        let graph = people_graph();
+        let alice = graph.create_uri_node(&Uri::parse("http://example.org/alice").unwrap());
+
+        let writer = JsonLdWriter::new()
+            .with_namespaces(graph.namespaces())
+            .with_frame(&alice);
+
+        // bob is embedded, the reference back to alice is not to break the cycle
+        assert_eq!(
+            writer.write_to_string(&graph).unwrap(),
+            "{\"@context\":{\"foaf\":\"http://xmlns.com/foaf/0.1/\"},\
+             \"@id\":\"http://example.org/alice\",\"@type\":\"foaf:Person\",\
+             \"foaf:knows\":{\"@id\":\"http://example.org/bob\",\
+             \"foaf:age\":{\"@type\":\"http://www.w3.org/2001/XMLSchema#integer\",\"@value\":\"42\"},\
+             \"foaf:knows\":{\"@id\":\"http://example.org/alice\"},\"foaf:name\":\"Bob\"},\
+             \"foaf:name\":{\"@language\":\"en\",\"@value\":\"Alice\"}}"
+        );
+    }
+
+    #[test]
+    fn write_lists_and_round_trip() {
+        let input = "{
+          \"@context\": {\"ex\": \"http://example.org/\", \"items\": {\"@id\": \"ex:items\", \"@container\": \"@list\"}},
+          \"@id\": \"ex:a\",
+          \"items\": [\"x\", {\"@id\": \"ex:b\"}, \"z\"]
+        }";
+
+        let graph = JsonLdParser::from_string(input).decode().unwrap();
+        let output = JsonLdWriter::new().write_to_string(&graph).unwrap();
+
+        assert_eq!(
+            output,
+            "[{\"@id\":\"http://example.org/a\",\"http://example.org/items\":[{\"@list\":\
+             [{\"@value\":\"x\"},{\"@id\":\"http://example.org/b\"},{\"@value\":\"z\"}]}]}]"
+        );
+
+        let round_trip = JsonLdParser::from_string(output).decode().unwrap();
+        assert_eq!(round_trip.count(), graph.count());
+    }
+
+    #[test]
+    fn write_prefixes_of_turtle_input() {
+        let input = "@prefix foaf: <http://xmlns.com/foaf/0.1/> .
+                     <http://example.org/alice> foaf:name \"Alice\" .";
+        let graph = TurtleParser::from_string(input).decode().unwrap();
+
+        let writer = JsonLdWriter::new().with_namespaces(graph.namespaces());
+
+        assert_eq!(
+            writer.write_to_string(&graph).unwrap(),
+            "{\"@context\":{\"foaf\":\"http://xmlns.com/foaf/0.1/\"},\
+             \"@id\":\"http://example.org/alice\",\"foaf:name\":\"Alice\"}"
+        );
+    }
+
+    #[test]
+    fn write_invalid_json_ld() {
+        let mut graph = Graph::new(None);
+
+        let subject = graph.create_uri_node(&Uri::parse("http://example.org/a").unwrap());
+        let predicate = graph.create_blank_node();
+        graph.add_triple(&Triple::new(&subject, &predicate, &subject));
+
+        assert!(JsonLdWriter::new().write_to_string(&graph).is_err());
+    }
+}
@@ -1,11 +1,14 @@
 use crate::error::*;
 use crate::graph::Graph;
 use crate::node::Node;
+use crate::reader::rdf_sink::RdfSink;
 use crate::triple::*;
+use crate::uri::Uri;
 use crate::writer::formatter::n_triples_formatter::NTriplesFormatter;
 use crate::writer::formatter::rdf_formatter::*;
 use crate::writer::rdf_writer::RdfWriter;
 use crate::Result;
+use std::io::Write;
 
 /// RDF writer to generate N-Triples syntax.
 #[derive(Default)]
@@ -51,6 +54,48 @@ impl RdfWriter for NTriplesWriter {
 
         Ok(output_string)
     }
+
+    /// Writes the N-Triples syntax of each triple stored in the provided graph to the output.
+    ///
+    /// Triples are written one by one without buffering the output.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::writer::n_triples_writer::NTriplesWriter;
+    /// use rdf::writer::rdf_writer::RdfWriter;
+    /// use rdf::graph::Graph;
+    /// use rdf::uri::Uri;
+    /// use rdf::triple::Triple;
+    ///
+    /// let mut graph = Graph::new(None);
+    ///
+    /// let subject = graph.create_blank_node();
+    /// let predicate = graph.create_uri_node(&Uri::parse("http://example.org/show/localName").unwrap());
+    /// graph.add_triple(&Triple::new(&subject, &predicate, &subject));
+    ///
+    /// let mut output: Vec<u8> = Vec::new();
+    /// NTriplesWriter::new().write_to(&graph, &mut output).unwrap();
+    ///
+    /// assert_eq!(String::from_utf8(output).unwrap(),
+    ///            "_:auto0 <http://example.org/show/localName> _:auto0 .\n".to_string());
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - Invalid triples are to be written to the output that do not conform the NTriples syntax standard.
+    /// - Writing to the output fails.
+    ///
+    fn write_to(&self, graph: &Graph, output: &mut dyn Write) -> Result<()> {
+        let mut stream_writer = NTriplesStreamWriter::new(output);
+
+        for triple in graph.triples_iter() {
+            stream_writer.write_triple(triple)?;
+        }
+
+        stream_writer.finish()?;
+        Ok(())
+    }
 }
 
 impl NTriplesWriter {
@@ -85,7 +130,7 @@ impl NTriplesWriter {
     ///
     /// let subject = Node::BlankNode { id: "blank".to_string() };
     /// let object = Node::LiteralNode { literal: "literal".to_string(), data_type: None, language: Some("en".to_string()) };
-    /// let predicate = Node::UriNode { uri: Uri::new("http://example.org/show/localName".to_string()) };
+    /// let predicate = Node::UriNode { uri: Uri::parse("http://example.org/show/localName").unwrap() };
     /// let triple = Triple::new(&subject, &predicate, &object);
     ///
     /// assert_eq!(writer.triple_to_n_triples(&triple).unwrap(),
@@ -137,6 +182,7 @@ impl NTriplesWriter {
     /// use rdf::writer::rdf_writer::RdfWriter;
     /// use rdf::node::Node;
     /// use rdf::triple::TripleSegment;
+    /// use rdf::uri::Uri;
     ///
     /// let writer = NTriplesWriter::new();
     ///
@@ -144,14 +190,21 @@ impl NTriplesWriter {
     ///
     /// assert_eq!(writer.node_to_n_triples(&node, &TripleSegment::Subject).unwrap(),
     ///            "_:blank".to_string());
+    ///
+    /// // IRIs with characters that would have to be escaped are rejected
+    /// let node = Node::UriNode { uri: Uri::new_unchecked("http://example.org/a b".to_string()) };
+    ///
+    /// assert!(writer.node_to_n_triples(&node, &TripleSegment::Object).is_err());
     /// ```
     ///
     /// # Failures
     ///
     /// - Node type for triple segment does not conform with NTriples syntax standard.
+    /// - The node contains an IRI that is invalid or relative.
     ///
     pub fn node_to_n_triples(&self, node: &Node, segment: &TripleSegment) -> Result<String> {
         match *node {
+            Node::UriNode { ref uri } => check_uri(uri)?,
             Node::BlankNode { .. } =>
             // blank nodes are not allowed as predicates
             {
@@ -182,11 +235,105 @@ impl NTriplesWriter {
                         "Language and data type defined for a literal.",
                     ));
                 }
+
+                if let Some(ref dt) = *dt {
+                    check_uri(dt)?;
+                }
             }
-            _ => {}
         }
 
         // use the formatter to get the corresponding N-Triple syntax
         Ok(self.formatter.format_node(node))
     }
 }
+
+/// Checks that a URI is a valid absolute IRI, N-Triples does not allow relative IRIs.
+fn check_uri(uri: &Uri) -> Result<()> {
+    if uri.is_valid_iri_ref() && uri.is_absolute() {
+        Ok(())
+    } else {
+        Err(Error::new(
+            ErrorType::InvalidWriterOutput,
+            "Invalid IRI for N-Triples: ".to_string() + uri.to_string(),
+        ))
+    }
+}
+
+/// RDF writer that writes N-Triples syntax triple by triple to an output.
+///
+/// The stream writer can be used as `RdfSink` of streaming parsers to convert inputs
+/// without keeping them in memory.
+pub struct NTriplesStreamWriter<W: Write> {
+    output: W,
+    writer: NTriplesWriter,
+}
+
+impl<W: Write> NTriplesStreamWriter<W> {
+    /// Constructor of `NTriplesStreamWriter`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::writer::n_triples_writer::NTriplesStreamWriter;
+    ///
+    /// let writer = NTriplesStreamWriter::new(Vec::new());
+    /// ```
+    pub fn new(output: W) -> NTriplesStreamWriter<W> {
+        NTriplesStreamWriter {
+            output,
+            writer: NTriplesWriter::new(),
+        }
+    }
+
+    /// Writes a single triple to the output.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::writer::n_triples_writer::NTriplesStreamWriter;
+    /// use rdf::node::Node;
+    /// use rdf::triple::Triple;
+    /// use rdf::uri::Uri;
+    ///
+    /// let mut writer = NTriplesStreamWriter::new(Vec::new());
+    ///
+    /// let subject = Node::BlankNode { id: "blank".to_string() };
+    /// let predicate = Node::UriNode { uri: Uri::parse("http://example.org/show/localName").unwrap() };
+    /// let object = Node::LiteralNode { literal: "literal".to_string(), data_type: None, language: None };
+    ///
+    /// writer.write_triple(&Triple::new(&subject, &predicate, &object)).unwrap();
+    ///
+    /// assert_eq!(writer.finish().unwrap(),
+    ///            b"_:blank <http://example.org/show/localName> \"literal\" .\n".to_vec());
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - The triple does not conform the NTriples syntax standard.
+    /// - Writing to the output fails.
+    ///
+    pub fn write_triple(&mut self, triple: &Triple) -> Result<()> {
+        let line = self.writer.triple_to_n_triples(triple)?;
+
+        self.output.write_all(line.as_bytes())?;
+        self.output.write_all(b"\n")?;
+        Ok(())
+    }
+
+    /// Flushes the output and returns it.
+    ///
+    /// # Failures
+    ///
+    /// - Flushing the output fails.
+    ///
+    pub fn finish(mut self) -> Result<W> {
+        self.output.flush()?;
+        Ok(self.output)
+    }
+}
+
+impl<W: Write> RdfSink for NTriplesStreamWriter<W> {
+    fn triple(&mut self, triple: Triple) -> Result<()> {
+        self.write_triple(&triple)
+    }
+}
@@ -1,9 +1,20 @@
 use crate::graph::Graph;
 use crate::Result;
+use std::io::Write;
 
 /// Trait implemented by RDF writers to generate a specific syntax.
 pub trait RdfWriter {
     /// Generates RDF syntax from a provided RDF graph and writes it to a string.
     /// Returns an error if invalid RDF would be generated.
     fn write_to_string(&self, graph: &Graph) -> Result<String>;
+
+    /// Generates RDF syntax from a provided RDF graph and writes it to the output.
+    /// Returns an error if invalid RDF would be generated or writing to the output fails.
+    ///
+    /// Writers that support streaming override this method so that the syntax is not
+    /// buffered in memory.
+    fn write_to(&self, graph: &Graph, output: &mut dyn Write) -> Result<()> {
+        output.write_all(self.write_to_string(graph)?.as_bytes())?;
+        Ok(())
+    }
 }
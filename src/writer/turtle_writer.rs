@@ -1,8 +1,11 @@
 use crate::error::{Error, ErrorType};
 use crate::graph::Graph;
+use crate::namespace::Namespace;
 use crate::node::Node;
-use std::collections::HashMap;
-use std::iter::repeat;
+use crate::reader::rdf_sink::RdfSink;
+use crate::specs::n_triples_specs::NTriplesSpecs;
+use crate::specs::rdf_syntax_specs::RdfSyntaxDataTypes;
+use crate::specs::turtle_specs::TurtleSpecs;
 use crate::triple::Triple;
 use crate::triple::TripleSegment;
 use crate::uri::Uri;
@@ -10,10 +13,30 @@ use crate::writer::formatter::rdf_formatter::*;
 use crate::writer::formatter::turtle_formatter::TurtleFormatter;
 use crate::writer::rdf_writer::RdfWriter;
 use crate::Result;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::io::Write;
 
 /// RDF writer to generate Turtle syntax.
+///
+/// By default, triples are grouped by subject and predicate with predicates aligned to the
+/// subject. `pretty` enables a more compact and human-friendly layout.
 pub struct TurtleWriter<'a> {
-    formatter: TurtleFormatter<'a>,
+    namespaces: &'a HashMap<String, Uri>,
+
+    /// Number of spaces per nesting level, predicates are aligned to the subject if `None`.
+    indentation: Option<usize>,
+
+    /// Whether `a` is written instead of `rdf:type`.
+    abbreviate_rdf_type: bool,
+
+    /// Whether `rdf:type` is written before the other predicates of a subject.
+    rdf_type_first: bool,
+
+    /// Whether blank nodes that are referenced once are written as `[ ... ]`.
+    nest_blank_nodes: bool,
+
+    /// Whether well-formed RDF lists are written as `( ... )`.
+    collections: bool,
 }
 
 // todo: decide if grouping should be done or ignored based on number of distinct subjects
@@ -37,7 +60,7 @@ impl<'a> RdfWriter for TurtleWriter<'a> {
     ///
     /// let subject = graph.create_blank_node();
     /// let object = graph.create_blank_node();
-    /// let predicate = graph.create_uri_node(&Uri::new("http://example.org/show/localName".to_string()));
+    /// let predicate = graph.create_uri_node(&Uri::parse("http://example.org/show/localName").unwrap());
     ///
     /// let trip = Triple::new(&subject, &predicate, &object);
     /// graph.add_triple(&trip);
@@ -52,80 +75,99 @@ impl<'a> RdfWriter for TurtleWriter<'a> {
     /// - The node type is invalid for the triple segment.
     ///
     fn write_to_string(&self, graph: &Graph) -> Result<String> {
-        let mut output_string = "".to_string();
-
-        output_string.push_str(&self.write_base_uri(graph));
-        output_string.push_str(&self.write_prefixes(graph));
-
-        let mut triples_vec: Vec<Triple> = graph.triples_iter().cloned().collect();
-        triples_vec.sort();
-
-        // store subjects and predicates for grouping
-        let mut previous_subject: Option<&Node> = None;
-        let mut previous_predicate: Option<&Node> = None;
-
-        // number of spaces required to indent the predicate and object
-        let mut predicate_indentation = 0;
-        let mut object_indentation = 0;
-
-        for triple in &triples_vec {
-            if previous_subject == Some(triple.subject()) {
-                // continue group
-                if previous_predicate == Some(triple.predicate()) {
-                    // indent object
-                    output_string.push_str(" ,\n");
-                    output_string
-                        .push_str(&repeat(" ").take(object_indentation).collect::<String>());
-                } else {
-                    output_string.push_str(" ;\n");
+        let mut output: Vec<u8> = Vec::new();
+        self.write_to(graph, &mut output)?;
 
-                    // write predicate
-                    let turtle_predicate =
-                        self.node_to_turtle(triple.predicate(), &TripleSegment::Predicate)?;
-                    // indent predicate
-                    output_string
-                        .push_str(&repeat(" ").take(predicate_indentation).collect::<String>());
-                    output_string.push_str(&turtle_predicate);
+        match String::from_utf8(output) {
+            Ok(output_string) => Ok(output_string),
+            Err(_) => Err(Error::new(
+                ErrorType::InvalidWriterOutput,
+                "Turtle output is not valid UTF-8.",
+            )),
+        }
+    }
 
-                    previous_predicate = Some(triple.predicate());
+    /// Writes the Turtle syntax for each triple stored in the provided graph to the output.
+    ///
+    /// Triples are sorted so that triples with the same subject and predicate are grouped,
+    /// which means that all triples of the graph are collected in memory before anything is
+    /// written. Use `TurtleStreamWriter` to write triples as they are produced.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::writer::turtle_writer::TurtleWriter;
+    /// use rdf::writer::rdf_writer::RdfWriter;
+    /// use rdf::graph::Graph;
+    /// use rdf::uri::Uri;
+    /// use rdf::triple::Triple;
+    ///
+    /// let mut graph = Graph::new(None);
+    ///
+    /// let subject = graph.create_blank_node();
+    /// let predicate = graph.create_uri_node(&Uri::parse("http://example.org/show/localName").unwrap());
+    /// graph.add_triple(&Triple::new(&subject, &predicate, &subject));
+    ///
+    /// let mut output: Vec<u8> = Vec::new();
+    /// TurtleWriter::new(graph.namespaces()).write_to(&graph, &mut output).unwrap();
+    ///
+    /// assert_eq!(String::from_utf8(output).unwrap(),
+    ///            "_:auto0 <http://example.org/show/localName> _:auto0 .".to_string());
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - The node type is invalid for the triple segment.
+    /// - Writing to the output fails.
+    ///
+    fn write_to(&self, graph: &Graph, output: &mut dyn Write) -> Result<()> {
+        for triple in graph.triples_iter() {
+            check_node(triple.subject(), &TripleSegment::Subject)?;
+            check_node(triple.predicate(), &TripleSegment::Predicate)?;
+            check_node(triple.object(), &TripleSegment::Object)?;
+        }
 
-                    output_string.push_str(" ");
+        let mut stream_writer = TurtleStreamWriter::new(&mut *output);
 
-                    // recalculate object indentation
-                    object_indentation = predicate_indentation + turtle_predicate.len() + 1;
-                }
-            } else {
-                if previous_subject != None {
-                    output_string.push_str(" .\n");
-                }
+        if let Some(ref base) = *graph.base_uri() {
+            stream_writer.write_base(base)?;
+        }
 
-                // start new group
-                let turtle_subject =
-                    self.node_to_turtle(triple.subject(), &TripleSegment::Subject)?;
-                output_string.push_str(&turtle_subject);
-                previous_subject = Some(triple.subject());
+        let mut prefixes: Vec<(&String, &Uri)> = graph.namespaces().iter().collect();
+        prefixes.sort();
 
-                output_string.push_str(" ");
-                let turtle_predicate =
-                    self.node_to_turtle(triple.predicate(), &TripleSegment::Predicate)?;
-                output_string.push_str(&turtle_predicate);
-                previous_predicate = Some(triple.predicate());
-                output_string.push_str(" ");
+        for (prefix, namespace_uri) in prefixes {
+            stream_writer.write_prefix(&Namespace::new(prefix.clone(), namespace_uri.clone()))?;
+        }
 
-                predicate_indentation = turtle_subject.len() + 1;
-                object_indentation = predicate_indentation + turtle_predicate.len() + 1;
-            }
+        stream_writer.finish()?;
 
-            // write object
-            let turtle_object = self.node_to_turtle(triple.object(), &TripleSegment::Object)?;
-            output_string.push_str(&turtle_object);
-        }
+        let has_directives = graph.base_uri().is_some() || !graph.namespaces().is_empty();
+
+        let mut namespaces = self.namespaces.clone();
+        namespaces.extend(
+            graph
+                .namespaces()
+                .iter()
+                .map(|(prefix, uri)| (prefix.clone(), uri.clone())),
+        );
+
+        let formatter = match *graph.base_uri() {
+            Some(ref base_uri) => TurtleFormatter::new(&namespaces).with_base_uri(base_uri),
+            None => TurtleFormatter::new(&namespaces),
+        };
 
-        if !graph.is_empty() {
-            output_string.push_str(" .");
+        let layout = TurtleLayout::new(self, formatter, graph);
+        let triples = layout.write();
+
+        // separate the directives from the triples in the indented layout
+        if has_directives && !triples.is_empty() && self.indentation.is_some() {
+            output.write_all(b"\n")?;
         }
 
-        Ok(output_string)
+        output.write_all(triples.as_bytes())?;
+        output.flush()?;
+        Ok(())
     }
 }
 
@@ -133,37 +175,638 @@ impl<'a> TurtleWriter<'a> {
     /// Constructor of `TurtleWriter`.
     pub fn new(namespaces: &'a HashMap<String, Uri>) -> TurtleWriter<'a> {
         TurtleWriter {
-            formatter: TurtleFormatter::new(namespaces),
+            namespaces,
+            indentation: None,
+            abbreviate_rdf_type: false,
+            rdf_type_first: false,
+            nest_blank_nodes: false,
+            collections: false,
         }
     }
 
-    /// Returns the formatted base URI as string.
-    fn write_base_uri(&self, graph: &Graph) -> String {
-        let mut output_string = "".to_string();
+    /// Enables the human-friendly layout.
+    ///
+    /// Nesting levels are indented by four spaces, subjects are separated by empty lines,
+    /// `rdf:type` is written first as `a`, blank nodes that are referenced once are nested
+    /// and well-formed lists are written as collections.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::writer::turtle_writer::TurtleWriter;
+    /// use rdf::writer::rdf_writer::RdfWriter;
+    /// use rdf::reader::rdf_parser::RdfParser;
+    /// use rdf::reader::turtle_parser::TurtleParser;
+    ///
+    /// let input = "@prefix ex: <http://example.org/> .
+    ///              ex:a ex:knows _:b ; a ex:Person .
+    ///              _:b ex:name \"B\" ; ex:list ( ex:c ex:d ) .";
+    ///
+    /// let graph = TurtleParser::from_string(input.to_string()).decode().unwrap();
+    /// let writer = TurtleWriter::new(graph.namespaces()).pretty();
+    ///
+    /// assert_eq!(writer.write_to_string(&graph).unwrap(),
+    ///            "@prefix ex: <http://example.org/> .\n\n\
+    ///             ex:a a ex:Person ;\n\
+    ///             \x20   ex:knows [\n\
+    ///             \x20       ex:list ( ex:c ex:d ) ;\n\
+    ///             \x20       ex:name \"B\"\n\
+    ///             \x20   ] .");
+    /// ```
+    pub fn pretty(self) -> TurtleWriter<'a> {
+        self.with_indentation(4)
+            .with_rdf_type_abbreviation(true)
+            .with_rdf_type_first(true)
+            .with_blank_node_nesting(true)
+            .with_collections(true)
+    }
 
-        if let Some(ref base) = *graph.base_uri() {
-            output_string.push_str("@base ");
-            output_string.push_str(&self.formatter.format_uri(base));
-            output_string.push_str(" .\n");
+    /// Indents predicates by the number of spaces per nesting level instead of aligning them
+    /// to the subject.
+    ///
+    /// Subjects are separated by empty lines.
+    pub fn with_indentation(mut self, indentation: usize) -> TurtleWriter<'a> {
+        self.indentation = Some(indentation);
+        self
+    }
+
+    /// Sets whether `a` is written instead of `rdf:type`.
+    pub fn with_rdf_type_abbreviation(mut self, abbreviate_rdf_type: bool) -> TurtleWriter<'a> {
+        self.abbreviate_rdf_type = abbreviate_rdf_type;
+        self
+    }
+
+    /// Sets whether `rdf:type` is written before the other predicates of a subject.
+    ///
+    /// Other predicates are sorted.
+    pub fn with_rdf_type_first(mut self, rdf_type_first: bool) -> TurtleWriter<'a> {
+        self.rdf_type_first = rdf_type_first;
+        self
+    }
+
+    /// Sets whether blank nodes that are referenced once are written as nested `[ ... ]`.
+    ///
+    /// Blank nodes that are part of a cycle of such references keep their label.
+    pub fn with_blank_node_nesting(mut self, nest_blank_nodes: bool) -> TurtleWriter<'a> {
+        self.nest_blank_nodes = nest_blank_nodes;
+        self
+    }
+
+    /// Sets whether well-formed RDF lists are written as `( ... )` and `rdf:nil` as `()`.
+    ///
+    /// Lists are only written as collections if their nodes are blank nodes that are
+    /// referenced once and have no other properties than `rdf:first` and `rdf:rest`.
+    /// Requires blank node nesting.
+    pub fn with_collections(mut self, collections: bool) -> TurtleWriter<'a> {
+        self.collections = collections;
+        self
+    }
+}
+
+/// Triples of a graph grouped by subject and predicate, with the blank nodes and lists
+/// that are written inline.
+struct TurtleLayout<'g> {
+    writer: &'g TurtleWriter<'g>,
+    formatter: TurtleFormatter<'g>,
+
+    /// Predicates and objects of each subject, sorted.
+    subjects: BTreeMap<&'g Node, BTreeMap<&'g Node, BTreeSet<&'g Node>>>,
+
+    /// Blank nodes that are written inline where they are referenced.
+    nested: BTreeSet<&'g Node>,
+
+    /// Elements of the lists that are written as collections, by list head.
+    lists: BTreeMap<&'g Node, Vec<&'g Node>>,
+
+    rdf_type: Node,
+    rdf_nil: Node,
+}
+
+impl<'g> TurtleLayout<'g> {
+    fn new(
+        writer: &'g TurtleWriter<'g>,
+        formatter: TurtleFormatter<'g>,
+        graph: &'g Graph,
+    ) -> TurtleLayout<'g> {
+        let mut subjects = BTreeMap::new();
+
+        for triple in graph.triples_iter() {
+            subjects
+                .entry(triple.subject())
+                .or_insert_with(BTreeMap::new)
+                .entry(triple.predicate())
+                .or_insert_with(BTreeSet::new)
+                .insert(triple.object());
+        }
+
+        let mut layout = TurtleLayout {
+            writer,
+            formatter,
+            subjects,
+            nested: BTreeSet::new(),
+            lists: BTreeMap::new(),
+            rdf_type: Node::UriNode {
+                uri: RdfSyntaxDataTypes::A.to_uri(),
+            },
+            rdf_nil: Node::UriNode {
+                uri: RdfSyntaxDataTypes::ListNil.to_uri(),
+            },
+        };
+
+        if writer.nest_blank_nodes {
+            layout.nested = layout.nested_blank_nodes();
+        }
+
+        if writer.nest_blank_nodes && writer.collections {
+            layout.lists = layout.collections();
         }
 
-        output_string
+        layout
     }
 
-    /// Returns all prefixes as formatted string.
-    fn write_prefixes(&self, graph: &Graph) -> String {
-        let mut output_string = "".to_string();
+    /// Returns the blank nodes that are referenced once and can be nested in their referencing
+    /// subject without creating a cycle.
+    fn nested_blank_nodes(&self) -> BTreeSet<&'g Node> {
+        let mut references: BTreeMap<&'g Node, usize> = BTreeMap::new();
 
-        // write prefixes
-        for (prefix, namespace_uri) in graph.namespaces() {
-            output_string.push_str("@prefix ");
-            output_string.push_str(prefix);
-            output_string.push_str(": <");
-            output_string.push_str(namespace_uri.to_string());
-            output_string.push_str("> .\n");
+        for objects in self
+            .subjects
+            .values()
+            .flat_map(|predicates| predicates.values())
+        {
+            for object in objects {
+                *references.entry(object).or_insert(0) += 1;
+            }
         }
 
-        output_string
+        let candidates: BTreeSet<&'g Node> = references
+            .into_iter()
+            .filter(|&(node, count)| count == 1 && matches!(*node, Node::BlankNode { .. }))
+            .map(|(node, _)| node)
+            .collect();
+
+        // nest candidates that are reachable from subjects written at the top level
+        let mut nested = BTreeSet::new();
+        let mut top_level = BTreeSet::new();
+        let mut roots: Vec<&'g Node> = self
+            .subjects
+            .keys()
+            .filter(|subject| !candidates.contains(*subject))
+            .cloned()
+            .collect();
+
+        loop {
+            while let Some(node) = roots.pop() {
+                let objects = self
+                    .subjects
+                    .get(node)
+                    .into_iter()
+                    .flat_map(|predicates| predicates.values())
+                    .flatten();
+
+                for object in objects {
+                    if candidates.contains(object)
+                        && !top_level.contains(object)
+                        && nested.insert(*object)
+                    {
+                        roots.push(object);
+                    }
+                }
+            }
+
+            // candidates in a cycle are unreachable, one of them is written at the top level
+            let unreachable = candidates
+                .iter()
+                .find(|node| !nested.contains(*node) && !top_level.contains(*node));
+
+            match unreachable {
+                Some(node) => {
+                    top_level.insert(*node);
+                    roots.push(node);
+                }
+                None => return nested,
+            }
+        }
+    }
+
+    /// Returns the elements of well-formed lists, by list head.
+    fn collections(&self) -> BTreeMap<&'g Node, Vec<&'g Node>> {
+        let mut lists = BTreeMap::new();
+        let mut tails = BTreeSet::new();
+
+        for node in self.nested.iter() {
+            if let Some(elements) = self.list_elements(node, &mut tails) {
+                lists.insert(*node, elements);
+            }
+        }
+
+        // only the heads of lists are written as collections
+        lists.retain(|node, _| !tails.contains(node));
+        lists
+    }
+
+    /// Returns the elements of the list starting at the node, if the list is well-formed.
+    fn list_elements(
+        &self,
+        head: &'g Node,
+        tails: &mut BTreeSet<&'g Node>,
+    ) -> Option<Vec<&'g Node>> {
+        let first = Node::UriNode {
+            uri: RdfSyntaxDataTypes::ListFirst.to_uri(),
+        };
+        let rest = Node::UriNode {
+            uri: RdfSyntaxDataTypes::ListRest.to_uri(),
+        };
+
+        let mut elements = Vec::new();
+        let mut nodes = Vec::new();
+        let mut node = head;
+
+        while *node != self.rdf_nil {
+            let predicates = self.subjects.get(node)?;
+
+            if !self.nested.contains(node) || predicates.len() != 2 {
+                return None;
+            }
+
+            let element = single(predicates.get(&first)?)?;
+            let next = single(predicates.get(&rest)?)?;
+
+            elements.push(element);
+            nodes.push(node);
+            node = next;
+        }
+
+        tails.extend(nodes.into_iter().skip(1));
+        Some(elements)
+    }
+
+    /// Returns the Turtle syntax for the triples of the graph.
+    fn write(&self) -> String {
+        let mut output = String::new();
+
+        for (subject, predicates) in self.subjects.iter() {
+            if self.nested.contains(subject) {
+                continue;
+            }
+
+            if !output.is_empty() {
+                output.push_str(if self.writer.indentation.is_some() {
+                    " .\n\n"
+                } else {
+                    " .\n"
+                });
+            }
+
+            let turtle_subject = self.formatter.format_node(subject);
+            output.push_str(&turtle_subject);
+            output.push(' ');
+            output.push_str(&self.predicate_object_list(
+                predicates,
+                1,
+                Some(turtle_subject.len() + 1),
+            ));
+        }
+
+        if !output.is_empty() {
+            output.push_str(" .");
+        }
+
+        output
+    }
+
+    /// Returns the predicates and objects of a subject.
+    ///
+    /// Without indentation, predicates and objects are aligned to the column if there is one,
+    /// otherwise they are written on a single line.
+    fn predicate_object_list(
+        &self,
+        predicates: &BTreeMap<&'g Node, BTreeSet<&'g Node>>,
+        depth: usize,
+        column: Option<usize>,
+    ) -> String {
+        let mut predicates: Vec<(&&'g Node, &BTreeSet<&'g Node>)> = predicates.iter().collect();
+
+        if self.writer.rdf_type_first {
+            predicates.sort_by_key(|&(predicate, _)| **predicate != self.rdf_type);
+        }
+
+        let mut output = String::new();
+
+        for (index, (predicate, objects)) in predicates.into_iter().enumerate() {
+            if index > 0 {
+                output.push_str(" ;");
+                output.push_str(&self.line_break(depth, column));
+            }
+
+            let turtle_predicate =
+                if self.writer.abbreviate_rdf_type && **predicate == self.rdf_type {
+                    "a".to_string()
+                } else {
+                    self.formatter.format_node(predicate)
+                };
+
+            output.push_str(&turtle_predicate);
+            output.push(' ');
+
+            let object_column = column.map(|column| column + turtle_predicate.len() + 1);
+
+            for (index, object) in objects.iter().enumerate() {
+                if index > 0 {
+                    output.push_str(" ,");
+                    output.push_str(&self.line_break(0, object_column));
+                }
+
+                output.push_str(&self.object(object, depth));
+            }
+        }
+
+        output
+    }
+
+    /// Returns an object, nested blank nodes and collections are written inline.
+    fn object(&self, node: &'g Node, depth: usize) -> String {
+        if let Some(elements) = self.lists.get(node) {
+            let elements: Vec<String> = elements
+                .iter()
+                .map(|element| self.object(element, depth))
+                .collect();
+
+            return "( ".to_string() + &elements.join(" ") + " )";
+        }
+
+        if self.writer.collections && *node == self.rdf_nil {
+            return "()".to_string();
+        }
+
+        if !self.nested.contains(node) {
+            return self.formatter.format_node(node);
+        }
+
+        match (self.subjects.get(node), self.writer.indentation) {
+            (None, _) => "[]".to_string(),
+            (Some(predicates), Some(indentation)) => {
+                "[".to_string()
+                    + &self.line_break(depth + 1, None)
+                    + &self.predicate_object_list(predicates, depth + 1, None)
+                    + "\n"
+                    + &" ".repeat(indentation * depth)
+                    + "]"
+            }
+            (Some(predicates), None) => {
+                "[ ".to_string() + &self.predicate_object_list(predicates, depth + 1, None) + " ]"
+            }
+        }
+    }
+
+    /// Returns the separator between predicates or objects.
+    fn line_break(&self, depth: usize, column: Option<usize>) -> String {
+        match (self.writer.indentation, column) {
+            (Some(_), _) if depth == 0 => " ".to_string(),
+            (Some(indentation), _) => "\n".to_string() + &" ".repeat(indentation * depth),
+            (None, Some(column)) => "\n".to_string() + &" ".repeat(column),
+            (None, None) => " ".to_string(),
+        }
+    }
+}
+
+/// Returns the node of a set with exactly one node.
+fn single<'g>(nodes: &BTreeSet<&'g Node>) -> Option<&'g Node> {
+    if nodes.len() == 1 {
+        nodes.iter().next().cloned()
+    } else {
+        None
+    }
+}
+
+/// RDF writer that writes Turtle syntax triple by triple to an output.
+///
+/// Consecutive triples with the same subject (and predicate) are grouped, other triples are
+/// not buffered. The stream writer can be used as `RdfSink` of streaming parsers.
+pub struct TurtleStreamWriter<W: Write> {
+    output: W,
+
+    /// Namespaces used for writing QNames.
+    namespaces: HashMap<String, Uri>,
+
+    /// Base URI of the last base directive that IRIs are written relative to.
+    base_uri: Option<Uri>,
+
+    /// Subject and predicate of the previous triple for grouping.
+    previous_subject: Option<Node>,
+    previous_predicate: Option<Node>,
+
+    /// Number of spaces required to indent the predicate and object.
+    predicate_indentation: usize,
+    object_indentation: usize,
+}
+
+impl<W: Write> TurtleStreamWriter<W> {
+    /// Constructor of `TurtleStreamWriter`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::writer::turtle_writer::TurtleStreamWriter;
+    ///
+    /// let writer = TurtleStreamWriter::new(Vec::new());
+    /// ```
+    pub fn new(output: W) -> TurtleStreamWriter<W> {
+        TurtleStreamWriter::with_namespaces(output, &HashMap::new())
+    }
+
+    /// Constructor of `TurtleStreamWriter` that uses the provided namespaces for QNames.
+    ///
+    /// No prefix directives are written for these namespaces.
+    pub fn with_namespaces(output: W, namespaces: &HashMap<String, Uri>) -> TurtleStreamWriter<W> {
+        TurtleStreamWriter {
+            output,
+            namespaces: namespaces.clone(),
+            base_uri: None,
+            previous_subject: None,
+            previous_predicate: None,
+            predicate_indentation: 0,
+            object_indentation: 0,
+        }
+    }
+
+    /// Writes a base directive, IRIs of the following triples are written relative to the base URI.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::writer::turtle_writer::TurtleStreamWriter;
+    /// use rdf::node::Node;
+    /// use rdf::triple::Triple;
+    /// use rdf::uri::Uri;
+    ///
+    /// let mut writer = TurtleStreamWriter::new(Vec::new());
+    ///
+    /// let subject = Node::UriNode { uri: Uri::parse("http://example.org/a/s").unwrap() };
+    /// let predicate = Node::UriNode { uri: Uri::parse("http://example.org/p").unwrap() };
+    /// let object = Node::UriNode { uri: Uri::parse("http://example.org/a/#o").unwrap() };
+    ///
+    /// writer.write_base(&Uri::parse("http://example.org/a/").unwrap()).unwrap();
+    /// writer.write_triple(&Triple::new(&subject, &predicate, &object)).unwrap();
+    ///
+    /// assert_eq!(String::from_utf8(writer.finish().unwrap()).unwrap(),
+    ///            "@base <http://example.org/a/> .\n<s> </p> <#o> .");
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - The base URI is not a valid IRI.
+    /// - Writing to the output fails.
+    ///
+    pub fn write_base(&mut self, base_uri: &Uri) -> Result<()> {
+        check_uri(base_uri)?;
+        self.end_group()?;
+
+        writeln!(
+            self.output,
+            "@base <{}> .",
+            NTriplesSpecs::escape_uri(base_uri.to_string())
+        )?;
+
+        self.base_uri = Some(base_uri.clone());
+        Ok(())
+    }
+
+    /// Writes a prefix directive, the namespace is used for QNames of the following triples.
+    ///
+    /// # Failures
+    ///
+    /// - The namespace URI is not a valid IRI.
+    /// - The prefix is not a valid `PN_PREFIX`.
+    /// - Writing to the output fails.
+    ///
+    pub fn write_prefix(&mut self, namespace: &Namespace) -> Result<()> {
+        check_uri(namespace.uri())?;
+
+        // prefixes declared by the Turtle parser include the trailing ':'
+        let prefix = namespace.prefix().trim_end_matches(':');
+
+        if !TurtleSpecs::is_prefix_name(prefix) {
+            return Err(Error::new(
+                ErrorType::InvalidWriterOutput,
+                "Invalid Turtle prefix: ".to_string() + prefix,
+            ));
+        }
+
+        self.end_group()?;
+
+        writeln!(
+            self.output,
+            "@prefix {}: <{}> .",
+            prefix,
+            NTriplesSpecs::escape_uri(namespace.uri().to_string())
+        )?;
+
+        self.namespaces
+            .insert(prefix.to_string(), namespace.uri().clone());
+        Ok(())
+    }
+
+    /// Writes a single triple, grouped with the previous triple if the subject is the same.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::writer::turtle_writer::TurtleStreamWriter;
+    /// use rdf::node::Node;
+    /// use rdf::triple::Triple;
+    /// use rdf::uri::Uri;
+    ///
+    /// let mut writer = TurtleStreamWriter::new(Vec::new());
+    ///
+    /// let subject = Node::BlankNode { id: "a".to_string() };
+    /// let predicate = Node::UriNode { uri: Uri::parse("http://example.org/p").unwrap() };
+    /// let object1 = Node::BlankNode { id: "b".to_string() };
+    /// let object2 = Node::BlankNode { id: "c".to_string() };
+    ///
+    /// writer.write_triple(&Triple::new(&subject, &predicate, &object1)).unwrap();
+    /// writer.write_triple(&Triple::new(&subject, &predicate, &object2)).unwrap();
+    ///
+    /// assert_eq!(String::from_utf8(writer.finish().unwrap()).unwrap(),
+    ///            "_:a <http://example.org/p> _:b ,\n                           _:c .");
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - The node type is invalid for the triple segment.
+    /// - Writing to the output fails.
+    ///
+    pub fn write_triple(&mut self, triple: &Triple) -> Result<()> {
+        // the object is converted first, so that nothing is written for invalid triples
+        let turtle_object = self.node_to_turtle(triple.object(), &TripleSegment::Object)?;
+
+        if self.previous_subject.as_ref() == Some(triple.subject()) {
+            // continue group
+            if self.previous_predicate.as_ref() == Some(triple.predicate()) {
+                // indent object
+                write!(self.output, " ,\n{}", " ".repeat(self.object_indentation))?;
+            } else {
+                // write predicate
+                let turtle_predicate =
+                    self.node_to_turtle(triple.predicate(), &TripleSegment::Predicate)?;
+
+                write!(
+                    self.output,
+                    " ;\n{}{} ",
+                    " ".repeat(self.predicate_indentation),
+                    turtle_predicate
+                )?;
+
+                self.previous_predicate = Some(triple.predicate().clone());
+
+                // recalculate object indentation
+                self.object_indentation = self.predicate_indentation + turtle_predicate.len() + 1;
+            }
+        } else {
+            self.end_group()?;
+
+            // start new group
+            let turtle_subject = self.node_to_turtle(triple.subject(), &TripleSegment::Subject)?;
+            let turtle_predicate =
+                self.node_to_turtle(triple.predicate(), &TripleSegment::Predicate)?;
+
+            write!(self.output, "{} {} ", turtle_subject, turtle_predicate)?;
+
+            self.previous_subject = Some(triple.subject().clone());
+            self.previous_predicate = Some(triple.predicate().clone());
+
+            self.predicate_indentation = turtle_subject.len() + 1;
+            self.object_indentation = self.predicate_indentation + turtle_predicate.len() + 1;
+        }
+
+        // write object
+        self.output.write_all(turtle_object.as_bytes())?;
+        Ok(())
+    }
+
+    /// Terminates the last group of triples, flushes the output and returns it.
+    ///
+    /// # Failures
+    ///
+    /// - Writing to the output fails.
+    ///
+    pub fn finish(mut self) -> Result<W> {
+        if self.previous_subject.is_some() {
+            self.output.write_all(b" .")?;
+        }
+
+        self.output.flush()?;
+        Ok(self.output)
+    }
+
+    /// Terminates the current group of triples, if any.
+    fn end_group(&mut self) -> Result<()> {
+        if self.previous_subject.take().is_some() {
+            self.output.write_all(b" .\n")?;
+        }
+
+        self.previous_predicate = None;
+        Ok(())
     }
 
     /// Converts a single node to its corresponding Turtle representation.
@@ -173,45 +816,92 @@ impl<'a> TurtleWriter<'a> {
     /// # Failures
     ///
     /// - The node type is invalid for the triple segment.
+    /// - The node contains an invalid IRI.
     ///
     fn node_to_turtle(&self, node: &Node, segment: &TripleSegment) -> Result<String> {
-        match *node {
-            Node::BlankNode { .. } =>
-            // blank nodes are not allowed as predicates
-            {
-                if *segment == TripleSegment::Predicate {
-                    return Err(Error::new(
-                        ErrorType::InvalidWriterOutput,
-                        "Blank nodes are not allowed as predicates in Turtle.",
-                    ));
-                }
+        check_node(node, segment)?;
+
+        // use the formatter to get the corresponding N-Triple syntax
+        let formatter = TurtleFormatter::new(&self.namespaces);
+
+        Ok(match self.base_uri {
+            Some(ref base_uri) => formatter.with_base_uri(base_uri).format_node(node),
+            None => formatter.format_node(node),
+        })
+    }
+}
+
+/// Checks if the node type is valid considering the triple segment.
+///
+/// # Failures
+///
+/// - The node type is invalid for the triple segment.
+/// - The node contains an invalid IRI.
+///
+fn check_node(node: &Node, segment: &TripleSegment) -> Result<()> {
+    match *node {
+        Node::UriNode { ref uri } => check_uri(uri)?,
+        // blank nodes are not allowed as predicates
+        Node::BlankNode { .. } if *segment == TripleSegment::Predicate => {
+            return Err(Error::new(
+                ErrorType::InvalidWriterOutput,
+                "Blank nodes are not allowed as predicates in Turtle.",
+            ));
+        }
+        Node::LiteralNode {
+            data_type: ref dt,
+            language: ref lang,
+            ..
+        } => {
+            // literal nodes are only allowed as objects
+            if *segment != TripleSegment::Object {
+                return Err(Error::new(
+                    ErrorType::InvalidWriterOutput,
+                    "Literals are not allowed as subjects or predicates in Turtle.",
+                ));
             }
-            Node::LiteralNode {
-                data_type: ref dt,
-                language: ref lang,
-                ..
-            } => {
-                // literal nodes are only allowed as objects
-                if *segment != TripleSegment::Object {
-                    return Err(Error::new(
-                        ErrorType::InvalidWriterOutput,
-                        "Literals are not allowed as subjects or predicates in Turtle.",
-                    ));
-                }
 
-                // either language or data type could be defined, but not both
-                if *lang != None && *dt != None {
-                    return Err(Error::new(
-                        ErrorType::InvalidWriterOutput,
-                        "Literal has data type and language.",
-                    ));
-                }
+            // either language or data type could be defined, but not both
+            if lang.is_some() && dt.is_some() {
+                return Err(Error::new(
+                    ErrorType::InvalidWriterOutput,
+                    "Literal has data type and language.",
+                ));
+            }
+
+            if let Some(ref dt) = *dt {
+                check_uri(dt)?;
             }
-            _ => {}
         }
+        _ => {}
+    }
 
-        // use the formatter to get the corresponding N-Triple syntax
-        Ok(self.formatter.format_node(node))
+    Ok(())
+}
+
+/// Checks that a URI is a valid IRI before it is written.
+fn check_uri(uri: &Uri) -> Result<()> {
+    if uri.is_valid_iri_ref() {
+        Ok(())
+    } else {
+        Err(Error::new(
+            ErrorType::InvalidWriterOutput,
+            "Invalid IRI: ".to_string() + uri.to_string(),
+        ))
+    }
+}
+
+impl<W: Write> RdfSink for TurtleStreamWriter<W> {
+    fn base(&mut self, base_uri: &Uri) -> Result<()> {
+        self.write_base(base_uri)
+    }
+
+    fn prefix(&mut self, namespace: &Namespace) -> Result<()> {
+        self.write_prefix(namespace)
+    }
+
+    fn triple(&mut self, triple: Triple) -> Result<()> {
+        self.write_triple(&triple)
     }
 }
 
@@ -219,10 +909,13 @@ impl<'a> TurtleWriter<'a> {
 mod tests {
     use crate::graph::Graph;
     use crate::namespace::Namespace;
+    use crate::node::Node;
+    use crate::reader::rdf_parser::{RdfParser, RdfStreamParser};
+    use crate::reader::turtle_parser::TurtleParser;
     use crate::triple::*;
     use crate::uri::Uri;
     use crate::writer::rdf_writer::RdfWriter;
-    use crate::writer::turtle_writer::TurtleWriter;
+    use crate::writer::turtle_writer::{TurtleStreamWriter, TurtleWriter};
 
     #[test]
     fn test_turtle_writer() {
@@ -231,7 +924,7 @@ mod tests {
         let subject = graph.create_blank_node();
         let object = graph.create_blank_node();
         let predicate =
-            graph.create_uri_node(&Uri::new("http://example.org/show/localName".to_string()));
+            graph.create_uri_node(&Uri::parse("http://example.org/show/localName").unwrap());
 
         let trip = Triple::new(&subject, &predicate, &object);
         graph.add_triple(&trip);
@@ -252,11 +945,11 @@ mod tests {
         let subject1 = graph.create_blank_node();
         let object1 = graph.create_blank_node();
         let predicate1 =
-            graph.create_uri_node(&Uri::new("http://example.org/show/localName".to_string()));
+            graph.create_uri_node(&Uri::parse("http://example.org/show/localName").unwrap());
 
         let subject2 = graph.create_blank_node();
         let object2 = graph.create_blank_node();
-        let predicate2 = graph.create_uri_node(&Uri::new("http://example.org/test".to_string()));
+        let predicate2 = graph.create_uri_node(&Uri::parse("http://example.org/test").unwrap());
 
         graph.add_triple(&Triple::new(&subject1, &predicate1, &object1));
         graph.add_triple(&Triple::new(&subject2, &predicate1, &object1));
@@ -283,7 +976,7 @@ _:auto2 <http://example.org/show/localName> _:auto1 ;
         let subject1 = graph.create_blank_node();
         let object1 = graph.create_blank_node();
         let predicate1 =
-            graph.create_uri_node(&Uri::new("http://example.org/show/localName".to_string()));
+            graph.create_uri_node(&Uri::parse("http://example.org/show/localName").unwrap());
 
         let subject2 = graph.create_blank_node();
         let object2 = graph.create_blank_node();
@@ -308,7 +1001,7 @@ _:auto2 <http://example.org/show/localName> _:auto1 ,
 
     #[test]
     fn test_turtle_writer_base_uri() {
-        let graph = Graph::new(Some(&Uri::new("http://example.org/".to_string())));
+        let graph = Graph::new(Some(&Uri::parse("http://example.org/").unwrap()));
 
         let result = "@base <http://example.org/> .\n".to_string();
 
@@ -325,7 +1018,7 @@ _:auto2 <http://example.org/show/localName> _:auto1 ,
 
         graph.add_namespace(&Namespace::new(
             "example".to_string(),
-            Uri::new("http://example.org/".to_string()),
+            Uri::parse("http://example.org/").unwrap(),
         ));
 
         let result = "@prefix example: <http://example.org/> .\n".to_string();
@@ -343,19 +1036,273 @@ _:auto2 <http://example.org/show/localName> _:auto1 ,
 
         graph.add_namespace(&Namespace::new(
             "example".to_string(),
-            Uri::new("http://example.org/".to_string()),
+            Uri::parse("http://example.org/").unwrap(),
         ));
-        
-        let result = "@prefix example: <http://example.org/> .\n _:auto0 example.localName 1".to_string();
+
+        let result = "@prefix example: <http://example.org/> .\n_:auto0 example:localName \"1\"^^<http://www.w3.org/2001/XMLSchema#integer> .".to_string();
         let subject1 = graph.create_blank_node();
-        let predicate1 = graph.create_uri_node(&Uri::new("http://example.org/show/localName".to_string()));
+        let predicate1 =
+            graph.create_uri_node(&Uri::parse("http://example.org/localName").unwrap());
         let object1 = graph.create_integer_node(1);
         graph.add_triple(&Triple::new(&subject1, &predicate1, &object1));
-        
+
         let writer = TurtleWriter::new(graph.namespaces());
         match writer.write_to_string(&graph) {
             Ok(str) => assert_eq!(result, str),
             Err(_) => assert!(false),
         }
     }
+
+    #[test]
+    fn test_turtle_stream_writer_from_parser() {
+        let input = "@prefix foaf: <http://xmlns.com/foaf/0.1/> .
+                     _:a foaf:name \"Alice\" ;
+                         foaf:knows _:b , _:c .
+                     _:b foaf:name \"Bob\" .";
+
+        let mut writer = TurtleStreamWriter::new(Vec::new());
+        let mut reader = TurtleParser::from_string(input);
+        reader.parse_all(&mut writer).unwrap();
+
+        let output = String::from_utf8(writer.finish().unwrap()).unwrap();
+
+        assert_eq!(
+            output,
+            "@prefix foaf: <http://xmlns.com/foaf/0.1/> .\n\
+             _:a foaf:name \"Alice\" ;\n    foaf:knows _:b ,\n               _:c .\n\
+             _:b foaf:name \"Bob\" ."
+        );
+    }
+
+    #[test]
+    fn test_turtle_writer_write_to() {
+        let mut graph = Graph::new(None);
+
+        let subject = graph.create_blank_node();
+        let predicate = graph.create_uri_node(&Uri::parse("http://example.org/p").unwrap());
+        let object = graph.create_literal_node("o".to_string());
+        graph.add_triple(&Triple::new(&subject, &predicate, &object));
+
+        let writer = TurtleWriter::new(graph.namespaces());
+        let mut output = Vec::new();
+        writer.write_to(&graph, &mut output).unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            writer.write_to_string(&graph).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_turtle_writer_escaped_literal_round_trip() {
+        let mut graph = Graph::new(None);
+        let literal = "quote \" backslash \\ tab \t newline \n bell \u{7} 'single'";
+
+        let subject = graph.create_uri_node(&Uri::parse("http://example.org/a").unwrap());
+        let predicate = graph.create_uri_node(&Uri::parse("http://example.org/p").unwrap());
+        let object = graph.create_literal_node(literal.to_string());
+        graph.add_triple(&Triple::new(&subject, &predicate, &object));
+
+        let output = TurtleWriter::new(graph.namespaces())
+            .write_to_string(&graph)
+            .unwrap();
+        let parsed = TurtleParser::from_string(output).decode().unwrap();
+
+        assert_eq!(
+            parsed.triples_iter().collect::<Vec<_>>(),
+            graph.triples_iter().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_turtle_writer_invalid_uri() {
+        let mut graph = Graph::new(None);
+
+        let subject =
+            graph.create_uri_node(&Uri::new_unchecked("http://example.org/a b".to_string()));
+        let predicate = graph.create_uri_node(&Uri::parse("http://example.org/p").unwrap());
+        graph.add_triple(&Triple::new(&subject, &predicate, &subject));
+
+        assert!(TurtleWriter::new(graph.namespaces())
+            .write_to_string(&graph)
+            .is_err());
+
+        // IRIs that would need escapes are rejected instead of escaped
+        let mut writer = TurtleStreamWriter::new(Vec::new());
+        let object = Node::LiteralNode {
+            literal: "a".to_string(),
+            data_type: Some(Uri::new_unchecked("http://example.org/a{b}".to_string())),
+            language: None,
+        };
+
+        assert!(writer
+            .write_base(&Uri::new_unchecked("http://example.org/a\\b/".to_string()))
+            .is_err());
+        assert!(writer
+            .write_triple(&Triple::new(&predicate, &predicate, &object))
+            .is_err());
+        assert!(writer.finish().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_turtle_stream_writer_invalid_prefix() {
+        let namespace = Uri::parse("http://example.org/").unwrap();
+
+        for prefix in &["1a", "a b", "a.", "_a", "a>"] {
+            let mut writer = TurtleStreamWriter::new(Vec::new());
+
+            assert!(writer
+                .write_prefix(&Namespace::new(prefix.to_string(), namespace.clone()))
+                .is_err());
+            assert!(
+                writer.finish().unwrap().is_empty(),
+                "{} was written",
+                prefix
+            );
+        }
+
+        let mut writer = TurtleStreamWriter::new(Vec::new());
+        let invalid = Uri::new_unchecked("http://example.org/a> . <b".to_string());
+
+        assert!(writer
+            .write_prefix(&Namespace::new("ex".to_string(), invalid))
+            .is_err());
+        writer
+            .write_prefix(&Namespace::new("ex.a:".to_string(), namespace))
+            .unwrap();
+        assert_eq!(
+            String::from_utf8(writer.finish().unwrap()).unwrap(),
+            "@prefix ex.a: <http://example.org/> .\n"
+        );
+    }
+
+    #[test]
+    fn test_turtle_writer_relative_and_qname_round_trip() {
+        let mut graph = Graph::new(Some(&Uri::parse("http://example.org/a/").unwrap()));
+
+        graph.add_namespace(&Namespace::new(
+            "ex".to_string(),
+            Uri::parse("http://example.com/").unwrap(),
+        ));
+        graph.add_namespace(&Namespace::new(
+            "show".to_string(),
+            Uri::parse("http://example.com/show/").unwrap(),
+        ));
+
+        let subject = graph.create_uri_node(&Uri::parse("http://example.org/a/b#c").unwrap());
+        let predicate =
+            graph.create_uri_node(&Uri::parse("http://example.com/show/local.").unwrap());
+        let object = graph.create_uri_node(&Uri::parse("http://example.com/x/y").unwrap());
+        graph.add_triple(&Triple::new(&subject, &predicate, &object));
+
+        let output = TurtleWriter::new(graph.namespaces())
+            .write_to_string(&graph)
+            .unwrap();
+
+        assert!(output.ends_with("<b#c> show:local\\. ex:x\\/y ."));
+
+        let parsed = TurtleParser::from_string(output).decode().unwrap();
+
+        assert_eq!(
+            parsed.triples_iter().collect::<Vec<_>>(),
+            graph.triples_iter().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_turtle_writer_blank_node_nesting() {
+        let input = "_:a <http://example.org/p> _:b , _:c .
+                     _:b <http://example.org/q> <http://example.org/r> , _:d .
+                     _:e <http://example.org/p> _:f .
+                     _:f <http://example.org/p> _:e .";
+        let graph = TurtleParser::from_string(input.to_string())
+            .decode()
+            .unwrap();
+
+        let output = TurtleWriter::new(graph.namespaces())
+            .with_blank_node_nesting(true)
+            .write_to_string(&graph)
+            .unwrap();
+
+        assert_eq!(
+            output,
+            "_:a <http://example.org/p> [ <http://example.org/q> <http://example.org/r> , [] ] ,\n\
+             \x20                          [] .\n\
+             _:e <http://example.org/p> [ <http://example.org/p> _:e ] ."
+        );
+    }
+
+    #[test]
+    fn test_turtle_writer_collections() {
+        let input = "@prefix ex: <http://example.org/> .
+                     ex:a ex:p ( ex:b ( \"c\" ) [ ex:q ex:r ] ) , () .
+                     ex:a ex:q _:l .
+                     _:l <http://www.w3.org/1999/02/22-rdf-syntax-ns#first> ex:b ;
+                         <http://www.w3.org/1999/02/22-rdf-syntax-ns#rest> ex:tail .";
+        let graph = TurtleParser::from_string(input.to_string())
+            .decode()
+            .unwrap();
+
+        let output = TurtleWriter::new(graph.namespaces())
+            .with_blank_node_nesting(true)
+            .with_collections(true)
+            .write_to_string(&graph)
+            .unwrap();
+
+        assert_eq!(
+            output,
+            "@prefix ex: <http://example.org/> .\n\
+             ex:a ex:p () ,\n\
+             \x20         ( ex:b ( \"c\" ) [ ex:q ex:r ] ) ;\n\
+             \x20    ex:q [ <http://www.w3.org/1999/02/22-rdf-syntax-ns#first> ex:b ; \
+             <http://www.w3.org/1999/02/22-rdf-syntax-ns#rest> ex:tail ] ."
+        );
+    }
+
+    #[test]
+    fn test_turtle_writer_rdf_type() {
+        let input = "@prefix ex: <http://example.org/> .
+                     ex:a ex:b ex:c ; a ex:D , ex:E .";
+        let graph = TurtleParser::from_string(input.to_string())
+            .decode()
+            .unwrap();
+
+        let output = TurtleWriter::new(graph.namespaces())
+            .with_indentation(2)
+            .with_rdf_type_abbreviation(true)
+            .with_rdf_type_first(true)
+            .write_to_string(&graph)
+            .unwrap();
+
+        assert_eq!(
+            output,
+            "@prefix ex: <http://example.org/> .\n\nex:a a ex:D , ex:E ;\n  ex:b ex:c ."
+        );
+    }
+
+    #[test]
+    fn test_turtle_writer_pretty_round_trip() {
+        let input = "@prefix ex: <http://example.org/> .
+                     ex:a a ex:C ; ex:p [ ex:q ( 1 [ ex:r ( ) ] ( ) ) ] , _:b .
+                     _:b ex:p _:b .
+                     ex:d ex:p ( _:c _:c ) , [ ex:s \"t\" ] .";
+        let graph = TurtleParser::from_string(input.to_string())
+            .decode()
+            .unwrap();
+
+        let output = TurtleWriter::new(graph.namespaces())
+            .pretty()
+            .write_to_string(&graph)
+            .unwrap();
+        let parsed = TurtleParser::from_string(output.clone()).decode().unwrap();
+
+        assert_eq!(parsed.count(), graph.count());
+        assert_eq!(
+            TurtleWriter::new(parsed.namespaces())
+                .pretty()
+                .write_to_string(&parsed)
+                .unwrap(),
+            output
+        );
+    }
 }
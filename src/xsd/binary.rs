@@ -0,0 +1,183 @@
+use crate::error::{Error, ErrorType};
+use crate::Result;
+
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Decodes the lexical form of an `xsd:hexBinary`.
+///
+/// # Examples
+///
+/// ```
+/// use rdf::xsd::binary::decode_hex;
+///
+/// assert_eq!(decode_hex("0fB7").unwrap(), vec![0x0f, 0xb7]);
+/// assert!(decode_hex("0fB").is_err());
+/// ```
+///
+/// # Failures
+///
+/// - The lexical form has an odd length or contains characters other than hex digits.
+///
+pub fn decode_hex(lexical: &str) -> Result<Vec<u8>> {
+    if !lexical.len().is_multiple_of(2) || !lexical.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(Error::new(
+            ErrorType::InvalidLiteral,
+            "Invalid hexBinary: ".to_string() + lexical,
+        ));
+    }
+
+    Ok((0..lexical.len())
+        .step_by(2)
+        .map(|index| u8::from_str_radix(&lexical[index..index + 2], 16).unwrap_or(0))
+        .collect())
+}
+
+/// Encodes bytes in the canonical lexical form of an `xsd:hexBinary` with upper case digits.
+///
+/// # Examples
+///
+/// ```
+/// use rdf::xsd::binary::encode_hex;
+///
+/// assert_eq!(encode_hex(&[0x0f, 0xb7]), "0FB7");
+/// ```
+pub fn encode_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02X}", byte)).collect()
+}
+
+/// Decodes the lexical form of an `xsd:base64Binary`, spaces are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use rdf::xsd::binary::decode_base64;
+///
+/// assert_eq!(decode_base64("SGVs bG8=").unwrap(), b"Hello".to_vec());
+/// assert!(decode_base64("SGVsbG8").is_err());
+/// ```
+///
+/// # Failures
+///
+/// - The lexical form contains characters that are not in the base64 alphabet.
+/// - The length or padding of the lexical form is invalid.
+///
+pub fn decode_base64(lexical: &str) -> Result<Vec<u8>> {
+    let invalid = || {
+        Error::new(
+            ErrorType::InvalidLiteral,
+            "Invalid base64Binary: ".to_string() + lexical,
+        )
+    };
+
+    let characters: Vec<u8> = lexical.bytes().filter(|&c| c != b' ').collect();
+
+    if !characters.len().is_multiple_of(4) {
+        return Err(invalid());
+    }
+
+    let padding = characters.iter().rev().take_while(|&&c| c == b'=').count();
+
+    if padding > 2 {
+        return Err(invalid());
+    }
+
+    let mut bytes = Vec::with_capacity(characters.len() / 4 * 3);
+    let mut buffer: u32 = 0;
+
+    for (index, &c) in characters[..characters.len() - padding].iter().enumerate() {
+        let sextet = BASE64_ALPHABET
+            .iter()
+            .position(|&a| a == c)
+            .ok_or_else(invalid)?;
+
+        buffer = (buffer << 6) | sextet as u32;
+
+        if index % 4 == 3 {
+            bytes.extend_from_slice(&[(buffer >> 16) as u8, (buffer >> 8) as u8, buffer as u8]);
+            buffer = 0;
+        }
+    }
+
+    // the unused bits of the last character before the padding must be zero
+    match padding {
+        1 if buffer & 0x3 == 0 => {
+            bytes.extend_from_slice(&[(buffer >> 10) as u8, (buffer >> 2) as u8])
+        }
+        2 if buffer & 0xf == 0 => bytes.push((buffer >> 4) as u8),
+        0 => {}
+        _ => return Err(invalid()),
+    }
+
+    Ok(bytes)
+}
+
+/// Encodes bytes in the canonical lexical form of an `xsd:base64Binary` without spaces.
+///
+/// # Examples
+///
+/// ```
+/// use rdf::xsd::binary::encode_base64;
+///
+/// assert_eq!(encode_base64(b"Hello"), "SGVsbG8=");
+/// ```
+pub fn encode_base64(bytes: &[u8]) -> String {
+    let mut output_string = String::with_capacity(bytes.len().div_ceil(3) * 4);
+
+    for chunk in bytes.chunks(3) {
+        let buffer = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |buffer, (index, &byte)| {
+                buffer | (byte as u32) << (16 - 8 * index)
+            });
+
+        for index in 0..4 {
+            if index <= chunk.len() {
+                let sextet = (buffer >> (18 - 6 * index)) & 0x3f;
+                output_string.push(BASE64_ALPHABET[sextet as usize] as char);
+            } else {
+                output_string.push('=');
+            }
+        }
+    }
+
+    output_string
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::xsd::binary::*;
+
+    #[test]
+    fn base64_round_trip() {
+        let examples: [(&[u8], &str); 5] = [
+            (b"", ""),
+            (b"f", "Zg=="),
+            (b"fo", "Zm8="),
+            (b"foo", "Zm9v"),
+            (b"foobar", "Zm9vYmFy"),
+        ];
+
+        for &(bytes, lexical) in examples.iter() {
+            assert_eq!(encode_base64(bytes), lexical);
+            assert_eq!(decode_base64(lexical).unwrap(), bytes.to_vec());
+        }
+    }
+
+    #[test]
+    fn reject_invalid_base64() {
+        let invalid = ["Zg=", "Zh==", "Z===", "Zm9v!A==", "=Zm9"];
+
+        for lexical in invalid.iter() {
+            assert!(decode_base64(lexical).is_err(), "{} is valid", lexical);
+        }
+    }
+
+    #[test]
+    fn hex_round_trip() {
+        assert_eq!(decode_hex("").unwrap(), Vec::<u8>::new());
+        assert_eq!(encode_hex(&decode_hex("00ff10aB").unwrap()), "00FF10AB");
+        assert!(decode_hex("0g").is_err());
+    }
+}
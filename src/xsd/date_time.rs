@@ -0,0 +1,629 @@
+use crate::error::{Error, ErrorType};
+use crate::xsd::decimal::Decimal;
+use crate::Result;
+use std::fmt;
+
+/// Date and time of day with optional timezone offset, as defined by `xsd:dateTime`.
+///
+/// A time of 24:00:00 is represented as 00:00:00 of the following day.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct DateTime {
+    date: Date,
+    time: Time,
+}
+
+/// Calendar date with optional timezone offset, as defined by `xsd:date`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Date {
+    year: i64,
+    month: u8,
+    day: u8,
+    timezone: Option<i16>,
+}
+
+/// Time of day with optional timezone offset, as defined by `xsd:time`.
+///
+/// A time of 24:00:00 is represented as 00:00:00.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Time {
+    hour: u8,
+    minute: u8,
+    second: Decimal,
+    timezone: Option<i16>,
+}
+
+/// Year with optional timezone offset, as defined by `xsd:gYear`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct GYear {
+    year: i64,
+    timezone: Option<i16>,
+}
+
+impl DateTime {
+    /// Parses the lexical form of an `xsd:dateTime`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::xsd::date_time::DateTime;
+    ///
+    /// let date_time = DateTime::parse("2002-10-10T12:00:00.500-05:00").unwrap();
+    ///
+    /// assert_eq!(date_time.date().year(), 2002);
+    /// assert_eq!(date_time.time().timezone(), Some(-300));
+    /// assert_eq!(DateTime::parse("1999-12-31T24:00:00Z").unwrap().to_string(),
+    ///            "2000-01-01T00:00:00Z");
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - The lexical form is not a date and time.
+    /// - A field is out of range, e.g. February 30.
+    ///
+    pub fn parse(lexical: &str) -> Result<DateTime> {
+        let invalid = || invalid_literal("dateTime", lexical);
+
+        let index = lexical.find('T').ok_or_else(invalid)?;
+        let ((year, month, day), rest) = parse_date(&lexical[..index]).ok_or_else(invalid)?;
+
+        if !rest.is_empty() {
+            return Err(invalid());
+        }
+
+        let (hour, minute, second, rest) = parse_time(&lexical[index + 1..]).ok_or_else(invalid)?;
+        let timezone = parse_timezone(rest).ok_or_else(invalid)?;
+
+        let mut date = Date::from_fields(year, month, day, timezone).ok_or_else(invalid)?;
+
+        if hour == 24 {
+            date = date.next_day();
+        }
+
+        Ok(DateTime {
+            date,
+            time: Time {
+                hour: hour % 24,
+                minute,
+                second,
+                timezone,
+            },
+        })
+    }
+
+    /// Returns the date.
+    pub fn date(&self) -> &Date {
+        &self.date
+    }
+
+    /// Returns the time of day.
+    pub fn time(&self) -> &Time {
+        &self.time
+    }
+
+    /// Returns the number of seconds since 1970-01-01T00:00:00Z.
+    ///
+    /// Values without timezone are treated as UTC. Returns `None` if the number cannot be
+    /// represented.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::xsd::date_time::DateTime;
+    ///
+    /// let utc = DateTime::parse("2002-10-10T17:00:00Z").unwrap();
+    /// let local = DateTime::parse("2002-10-10T12:00:00-05:00").unwrap();
+    ///
+    /// assert_eq!(utc.timestamp(), local.timestamp());
+    /// assert_eq!(utc.timestamp().unwrap().to_string(), "1034269200");
+    /// ```
+    pub fn timestamp(&self) -> Option<Decimal> {
+        let seconds = self.date.days_since_epoch() * 86400 + self.time.seconds_of_day();
+
+        Decimal::new(seconds, 0).checked_add(&self.time.second)
+    }
+}
+
+impl Date {
+    /// Parses the lexical form of an `xsd:date`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::xsd::date_time::Date;
+    ///
+    /// assert_eq!(Date::parse("-0044-03-15").unwrap().year(), -44);
+    /// assert!(Date::parse("2001-02-29").is_err());
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - The lexical form is not a date.
+    /// - A field is out of range, e.g. February 30.
+    ///
+    pub fn parse(lexical: &str) -> Result<Date> {
+        let invalid = || invalid_literal("date", lexical);
+
+        let ((year, month, day), rest) = parse_date(lexical).ok_or_else(invalid)?;
+        let timezone = parse_timezone(rest).ok_or_else(invalid)?;
+
+        Date::from_fields(year, month, day, timezone).ok_or_else(invalid)
+    }
+
+    /// Creates a date if the fields are in range.
+    fn from_fields(year: i64, month: u8, day: u8, timezone: Option<i16>) -> Option<Date> {
+        if month == 0 || month > 12 || day == 0 || day > days_in_month(year, month) {
+            return None;
+        }
+
+        Some(Date {
+            year,
+            month,
+            day,
+            timezone,
+        })
+    }
+
+    /// Returns the year, negative years are before the year 1 (year 0 is 1 BCE).
+    pub fn year(&self) -> i64 {
+        self.year
+    }
+
+    /// Returns the month, starting with 1.
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    /// Returns the day of the month, starting with 1.
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    /// Returns the timezone offset in minutes, if there is one.
+    pub fn timezone(&self) -> Option<i16> {
+        self.timezone
+    }
+
+    /// Returns the number of seconds from 1970-01-01T00:00:00Z to the start of the date.
+    ///
+    /// Dates without timezone are treated as UTC.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::xsd::date_time::Date;
+    ///
+    /// assert_eq!(Date::parse("1970-01-02").unwrap().timestamp(), 86400);
+    /// assert_eq!(Date::parse("1970-01-01+01:00").unwrap().timestamp(), -3600);
+    /// ```
+    pub fn timestamp(&self) -> i128 {
+        self.days_since_epoch() * 86400 - i128::from(self.timezone.unwrap_or(0)) * 60
+    }
+
+    /// Returns the number of days since 1970-01-01 in the proleptic Gregorian calendar.
+    fn days_since_epoch(&self) -> i128 {
+        let month = i128::from(self.month);
+        let year = i128::from(self.year) - if month <= 2 { 1 } else { 0 };
+
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let day_of_year = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5
+            + i128::from(self.day)
+            - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    fn next_day(self) -> Date {
+        if self.day < days_in_month(self.year, self.month) {
+            Date {
+                day: self.day + 1,
+                ..self
+            }
+        } else if self.month < 12 {
+            Date {
+                month: self.month + 1,
+                day: 1,
+                ..self
+            }
+        } else {
+            Date {
+                year: self.year + 1,
+                month: 1,
+                day: 1,
+                ..self
+            }
+        }
+    }
+}
+
+impl Time {
+    /// Parses the lexical form of an `xsd:time`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::xsd::date_time::Time;
+    ///
+    /// let time = Time::parse("13:20:00.250+01:00").unwrap();
+    ///
+    /// assert_eq!(time.hour(), 13);
+    /// assert_eq!(time.second().to_string(), "0.25");
+    /// assert_eq!(Time::parse("24:00:00").unwrap().to_string(), "00:00:00");
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - The lexical form is not a time of day.
+    /// - A field is out of range, e.g. 25 hours.
+    ///
+    pub fn parse(lexical: &str) -> Result<Time> {
+        let invalid = || invalid_literal("time", lexical);
+
+        let (hour, minute, second, rest) = parse_time(lexical).ok_or_else(invalid)?;
+        let timezone = parse_timezone(rest).ok_or_else(invalid)?;
+
+        Ok(Time {
+            hour: hour % 24,
+            minute,
+            second,
+            timezone,
+        })
+    }
+
+    /// Returns the hour, from 0 to 23.
+    pub fn hour(&self) -> u8 {
+        self.hour
+    }
+
+    /// Returns the minute.
+    pub fn minute(&self) -> u8 {
+        self.minute
+    }
+
+    /// Returns the second including its fraction.
+    pub fn second(&self) -> &Decimal {
+        &self.second
+    }
+
+    /// Returns the timezone offset in minutes, if there is one.
+    pub fn timezone(&self) -> Option<i16> {
+        self.timezone
+    }
+
+    /// Returns the number of seconds since midnight UTC, negative if the time is on the day
+    /// before in UTC.
+    ///
+    /// Times without timezone are treated as UTC. Returns `None` if the number cannot be
+    /// represented.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::xsd::date_time::Time;
+    ///
+    /// assert_eq!(Time::parse("13:00:00+01:00").unwrap().timestamp(),
+    ///            Time::parse("12:00:00Z").unwrap().timestamp());
+    /// ```
+    pub fn timestamp(&self) -> Option<Decimal> {
+        Decimal::new(self.seconds_of_day(), 0).checked_add(&self.second)
+    }
+
+    /// Returns the seconds of the hours and minutes since midnight UTC, without the seconds.
+    fn seconds_of_day(&self) -> i128 {
+        i128::from(self.hour) * 3600 + i128::from(self.minute) * 60
+            - i128::from(self.timezone.unwrap_or(0)) * 60
+    }
+}
+
+impl GYear {
+    /// Parses the lexical form of an `xsd:gYear`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::xsd::date_time::GYear;
+    ///
+    /// assert_eq!(GYear::parse("12345Z").unwrap().year(), 12345);
+    /// assert!(GYear::parse("01999").is_err());
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - The lexical form is not a year.
+    ///
+    pub fn parse(lexical: &str) -> Result<GYear> {
+        let invalid = || invalid_literal("gYear", lexical);
+
+        let (year, rest) = parse_year(lexical).ok_or_else(invalid)?;
+        let timezone = parse_timezone(rest).ok_or_else(invalid)?;
+
+        Ok(GYear { year, timezone })
+    }
+
+    /// Returns the year, negative years are before the year 1 (year 0 is 1 BCE).
+    pub fn year(&self) -> i64 {
+        self.year
+    }
+
+    /// Returns the timezone offset in minutes, if there is one.
+    pub fn timezone(&self) -> Option<i16> {
+        self.timezone
+    }
+}
+
+impl fmt::Display for DateTime {
+    /// Writes the canonical lexical form, a timezone offset of zero is written as 'Z'.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_year(f, self.date.year)?;
+        write!(f, "-{:02}-{:02}T", self.date.month, self.date.day)?;
+        write_time(f, self.time.hour, self.time.minute, &self.time.second)?;
+        write_timezone(f, self.time.timezone)
+    }
+}
+
+impl fmt::Display for Date {
+    /// Writes the canonical lexical form, a timezone offset of zero is written as 'Z'.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_year(f, self.year)?;
+        write!(f, "-{:02}-{:02}", self.month, self.day)?;
+        write_timezone(f, self.timezone)
+    }
+}
+
+impl fmt::Display for Time {
+    /// Writes the canonical lexical form, a timezone offset of zero is written as 'Z'.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_time(f, self.hour, self.minute, &self.second)?;
+        write_timezone(f, self.timezone)
+    }
+}
+
+impl fmt::Display for GYear {
+    /// Writes the canonical lexical form, a timezone offset of zero is written as 'Z'.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_year(f, self.year)?;
+        write_timezone(f, self.timezone)
+    }
+}
+
+fn invalid_literal(data_type: &str, lexical: &str) -> Error {
+    Error::new(
+        ErrorType::InvalidLiteral,
+        format!("Invalid {}: {}", data_type, lexical),
+    )
+}
+
+fn is_leap_year(year: i64) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+fn days_in_month(year: i64, month: u8) -> u8 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Parses a number with exactly the given count of digits.
+fn parse_digits(input: &str, count: usize) -> Option<(u8, &str)> {
+    if input.len() < count || !input[..count].bytes().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+
+    Some((input[..count].parse().ok()?, &input[count..]))
+}
+
+/// Parses `-? yyyy`, years with more than four digits must not start with '0'.
+fn parse_year(input: &str) -> Option<(i64, &str)> {
+    let (negative, unsigned) = match input.strip_prefix('-') {
+        Some(unsigned) => (true, unsigned),
+        None => (false, input),
+    };
+
+    let length = unsigned
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(unsigned.len());
+
+    if length < 4 || length > 4 && unsigned.starts_with('0') {
+        return None;
+    }
+
+    let year: i64 = unsigned[..length].parse().ok()?;
+
+    // there is no negative year zero
+    if negative && year == 0 {
+        return None;
+    }
+
+    Some((if negative { -year } else { year }, &unsigned[length..]))
+}
+
+/// Parses `yyyy-mm-dd` without validating the day of the month.
+fn parse_date(input: &str) -> Option<((i64, u8, u8), &str)> {
+    let (year, rest) = parse_year(input)?;
+    let (month, rest) = parse_digits(rest.strip_prefix('-')?, 2)?;
+    let (day, rest) = parse_digits(rest.strip_prefix('-')?, 2)?;
+
+    Some(((year, month, day), rest))
+}
+
+/// Parses `hh:mm:ss(.s+)?` and validates the fields, 24:00:00 is allowed.
+fn parse_time(input: &str) -> Option<(u8, u8, Decimal, &str)> {
+    let (hour, rest) = parse_digits(input, 2)?;
+    let (minute, rest) = parse_digits(rest.strip_prefix(':')?, 2)?;
+    let (second, rest) = parse_digits(rest.strip_prefix(':')?, 2)?;
+
+    let (fraction, rest) = match rest.strip_prefix('.') {
+        Some(rest) => {
+            let length = rest
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(rest.len());
+
+            if length == 0 {
+                return None;
+            }
+
+            (&rest[..length], &rest[length..])
+        }
+        None => ("", rest),
+    };
+
+    let second = Decimal::parse(&format!("{}.{}", second, fraction)).ok()?;
+
+    if minute > 59 || second >= Decimal::from(60) {
+        return None;
+    }
+
+    if hour > 24 || hour == 24 && (minute > 0 || second != Decimal::from(0)) {
+        return None;
+    }
+
+    Some((hour, minute, second, rest))
+}
+
+/// Parses an optional timezone `Z` or `(+|-)hh:mm` that must end the input.
+///
+/// Returns `None` if the input is invalid and `Some(None)` if there is no timezone.
+fn parse_timezone(input: &str) -> Option<Option<i16>> {
+    if input.is_empty() {
+        return Some(None);
+    }
+
+    if input == "Z" {
+        return Some(Some(0));
+    }
+
+    let sign = match input.as_bytes()[0] {
+        b'+' => 1,
+        b'-' => -1,
+        _ => return None,
+    };
+
+    let (hours, rest) = parse_digits(&input[1..], 2)?;
+    let (minutes, rest) = parse_digits(rest.strip_prefix(':')?, 2)?;
+
+    if !rest.is_empty() || minutes > 59 || hours > 14 || hours == 14 && minutes > 0 {
+        return None;
+    }
+
+    Some(Some(sign * (i16::from(hours) * 60 + i16::from(minutes))))
+}
+
+fn write_year(f: &mut fmt::Formatter, year: i64) -> fmt::Result {
+    if year < 0 {
+        write!(f, "-{:04}", -year)
+    } else {
+        write!(f, "{:04}", year)
+    }
+}
+
+fn write_time(f: &mut fmt::Formatter, hour: u8, minute: u8, second: &Decimal) -> fmt::Result {
+    write!(f, "{:02}:{:02}:", hour, minute)?;
+
+    if second.trunc() < 10 {
+        f.write_str("0")?;
+    }
+
+    write!(f, "{}", second)
+}
+
+fn write_timezone(f: &mut fmt::Formatter, timezone: Option<i16>) -> fmt::Result {
+    match timezone {
+        None => Ok(()),
+        Some(0) => f.write_str("Z"),
+        Some(offset) => write!(
+            f,
+            "{}{:02}:{:02}",
+            if offset < 0 { '-' } else { '+' },
+            offset.abs() / 60,
+            offset.abs() % 60
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::xsd::date_time::*;
+
+    #[test]
+    fn parse_date_times() {
+        let examples = [
+            ("2001-10-26T21:32:52", "2001-10-26T21:32:52"),
+            ("2001-10-26T21:32:52+02:00", "2001-10-26T21:32:52+02:00"),
+            ("2001-10-26T19:32:52Z", "2001-10-26T19:32:52Z"),
+            ("2001-10-26T19:32:52+00:00", "2001-10-26T19:32:52Z"),
+            ("-2001-10-26T21:32:52", "-2001-10-26T21:32:52"),
+            ("2001-10-26T21:32:52.12679000", "2001-10-26T21:32:52.12679"),
+            ("2001-10-26T21:32:05.0", "2001-10-26T21:32:05"),
+            ("2000-02-29T24:00:00-14:00", "2000-03-01T00:00:00-14:00"),
+            ("0000-01-01T00:00:00", "0000-01-01T00:00:00"),
+        ];
+
+        for &(lexical, canonical) in examples.iter() {
+            assert_eq!(DateTime::parse(lexical).unwrap().to_string(), canonical);
+        }
+    }
+
+    #[test]
+    fn date_time_timestamps() {
+        let examples = [
+            ("1970-01-01T00:00:00Z", "0"),
+            ("1969-12-31T23:59:59.5", "-0.5"),
+            ("2000-03-01T00:00:00+01:00", "951865200"),
+            ("0000-01-01T00:00:00Z", "-62167219200"),
+        ];
+
+        for &(lexical, timestamp) in examples.iter() {
+            let date_time = DateTime::parse(lexical).unwrap();
+            assert_eq!(date_time.timestamp().unwrap().to_string(), timestamp);
+        }
+
+        assert_eq!(
+            Date::parse("-0001-12-31Z").unwrap().timestamp(),
+            -62167305600
+        );
+    }
+
+    #[test]
+    fn reject_invalid_date_times() {
+        let invalid = [
+            "2001-10-26",
+            "2001-10-26T21:32",
+            "2001-10-26T25:32:52+02:00",
+            "01-10-26T21:32:52",
+            "2001-10-26T21:32:60",
+            "2001-10-26T24:00:01",
+            "2001-10-26T21:32:52.",
+            "2001-13-26T21:32:52",
+            "1900-02-29T00:00:00",
+            "2001-10-26T21:32:52+15:00",
+            "2001-10-26T21:32:52 ",
+            "2001-10-26t21:32:52",
+        ];
+
+        for lexical in invalid.iter() {
+            assert!(DateTime::parse(lexical).is_err(), "{} is valid", lexical);
+        }
+    }
+
+    #[test]
+    fn parse_dates_times_and_years() {
+        assert_eq!(
+            Date::parse("2004-02-29Z").unwrap().to_string(),
+            "2004-02-29Z"
+        );
+        assert_eq!(
+            Time::parse("09:05:00-00:30").unwrap().to_string(),
+            "09:05:00-00:30"
+        );
+        assert_eq!(GYear::parse("-0001").unwrap().to_string(), "-0001");
+
+        assert!(Date::parse("2004-02-29T00:00:00").is_err());
+        assert!(Time::parse("9:05:00").is_err());
+        assert!(GYear::parse("999").is_err());
+        assert!(GYear::parse("-0000").is_err());
+    }
+}
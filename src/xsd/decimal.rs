@@ -0,0 +1,305 @@
+use crate::error::{Error, ErrorType};
+use crate::Result;
+use std::cmp::Ordering;
+use std::fmt;
+
+/// Maximum number of significant digits of a decimal.
+const MAX_DIGITS: usize = 38;
+
+/// Decimal number as defined by `xsd:decimal`.
+///
+/// The number is stored as an integer value scaled by a power of ten, so up to 38
+/// significant digits are represented exactly. The representation is normalized, which
+/// means that equal numbers have equal fields.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Decimal {
+    value: i128,
+    scale: u32,
+}
+
+impl Decimal {
+    /// Constructor of `Decimal` for the number `value * 10^-scale`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::xsd::decimal::Decimal;
+    ///
+    /// assert_eq!(Decimal::new(1250, 3).to_string(), "1.25");
+    /// assert_eq!(Decimal::new(1250, 3), Decimal::new(125, 2));
+    /// ```
+    pub fn new(value: i128, scale: u32) -> Decimal {
+        let mut decimal = Decimal { value, scale };
+
+        // remove trailing zeros of the fraction
+        while decimal.scale > 0 && decimal.value % 10 == 0 {
+            decimal.value /= 10;
+            decimal.scale -= 1;
+        }
+
+        decimal
+    }
+
+    /// Parses the lexical form of an `xsd:decimal`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::xsd::decimal::Decimal;
+    ///
+    /// assert_eq!(Decimal::parse("+001.500").unwrap(), Decimal::new(15, 1));
+    /// assert_eq!(Decimal::parse("-.5").unwrap(), Decimal::new(-5, 1));
+    /// assert!(Decimal::parse("1e5").is_err());
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - The lexical form is not a decimal number.
+    /// - The number has more than 38 significant digits, which is reported as
+    ///   `ErrorType::UnsupportedPrecision`.
+    ///
+    pub fn parse(lexical: &str) -> Result<Decimal> {
+        let (negative, unsigned) = split_sign(lexical);
+
+        let (integer, fraction) = match unsigned.find('.') {
+            Some(index) => (&unsigned[..index], &unsigned[index + 1..]),
+            None => (unsigned, ""),
+        };
+
+        if integer.is_empty() && fraction.is_empty()
+            || !integer
+                .chars()
+                .chain(fraction.chars())
+                .all(|c| c.is_ascii_digit())
+        {
+            return Err(Error::new(
+                ErrorType::InvalidLiteral,
+                "Invalid decimal: ".to_string() + lexical,
+            ));
+        }
+
+        let fraction = fraction.trim_end_matches('0');
+        let digits = integer.to_string() + fraction;
+        let digits = digits.trim_start_matches('0');
+
+        let too_many_digits = || {
+            Error::new(
+                ErrorType::UnsupportedPrecision,
+                "Decimal has too many digits: ".to_string() + lexical,
+            )
+        };
+
+        if digits.len() > MAX_DIGITS {
+            return Err(too_many_digits());
+        }
+
+        let value = if digits.is_empty() {
+            0
+        } else {
+            digits.parse::<i128>().map_err(|_| too_many_digits())?
+        };
+
+        Ok(Decimal::new(
+            if negative { -value } else { value },
+            fraction.len() as u32,
+        ))
+    }
+
+    /// Returns the unscaled integer value.
+    pub fn value(&self) -> i128 {
+        self.value
+    }
+
+    /// Returns the number of digits of the fraction.
+    pub fn scale(&self) -> u32 {
+        self.scale
+    }
+
+    /// Checks if the decimal has no fraction.
+    pub fn is_integer(&self) -> bool {
+        self.scale == 0
+    }
+
+    /// Returns the integer part of the decimal, rounded towards zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::xsd::decimal::Decimal;
+    ///
+    /// assert_eq!(Decimal::new(-125, 1).trunc(), -12);
+    /// ```
+    pub fn trunc(&self) -> i128 {
+        self.value / 10i128.pow(self.scale)
+    }
+
+    /// Adds two decimals, returns `None` if the result cannot be represented.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::xsd::decimal::Decimal;
+    ///
+    /// let sum = Decimal::new(15, 1).checked_add(&Decimal::from(2)).unwrap();
+    ///
+    /// assert_eq!(sum, Decimal::new(35, 1));
+    /// ```
+    pub fn checked_add(&self, other: &Decimal) -> Option<Decimal> {
+        let scale = self.scale.max(other.scale);
+        let value = self.rescale(scale)?.checked_add(other.rescale(scale)?)?;
+
+        Some(Decimal::new(value, scale))
+    }
+
+    /// Returns the negated decimal.
+    pub fn negate(&self) -> Decimal {
+        Decimal::new(-self.value, self.scale)
+    }
+
+    /// Converts the decimal to the nearest `f64`.
+    pub fn to_f64(&self) -> f64 {
+        self.to_string().parse::<f64>().unwrap_or(0.0)
+    }
+
+    /// Returns the unscaled value for a larger scale, `None` if the value overflows.
+    fn rescale(&self, scale: u32) -> Option<i128> {
+        10i128
+            .checked_pow(scale - self.scale)?
+            .checked_mul(self.value)
+    }
+}
+
+impl From<i64> for Decimal {
+    fn from(value: i64) -> Decimal {
+        Decimal::new(i128::from(value), 0)
+    }
+}
+
+impl Ord for Decimal {
+    fn cmp(&self, other: &Decimal) -> Ordering {
+        let scale = self.scale.max(other.scale);
+
+        match (self.rescale(scale), other.rescale(scale)) {
+            (Some(a), Some(b)) => a.cmp(&b),
+            // the value that overflows has the larger magnitude
+            (None, _) => self.value.cmp(&0),
+            (_, None) => 0.cmp(&other.value),
+        }
+    }
+}
+
+impl PartialOrd for Decimal {
+    fn partial_cmp(&self, other: &Decimal) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for Decimal {
+    /// Writes the canonical lexical form of the decimal.
+    ///
+    /// Integers are written without decimal point, other numbers without leading and
+    /// trailing zeros.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let digits = self.value.unsigned_abs().to_string();
+
+        if self.value < 0 {
+            f.write_str("-")?;
+        }
+
+        if self.scale == 0 {
+            return f.write_str(&digits);
+        }
+
+        let scale = self.scale as usize;
+
+        if digits.len() > scale {
+            let (integer, fraction) = digits.split_at(digits.len() - scale);
+            write!(f, "{}.{}", integer, fraction)
+        } else {
+            write!(f, "0.{}{}", "0".repeat(scale - digits.len()), digits)
+        }
+    }
+}
+
+/// Splits the optional sign from a number, returns if the number is negative.
+pub(crate) fn split_sign(lexical: &str) -> (bool, &str) {
+    if let Some(unsigned) = lexical.strip_prefix('-') {
+        (true, unsigned)
+    } else {
+        (false, lexical.strip_prefix('+').unwrap_or(lexical))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::ErrorType;
+    use crate::xsd::decimal::Decimal;
+
+    #[test]
+    fn parse_decimals() {
+        let examples = [
+            ("0", "0"),
+            ("-0.0", "0"),
+            ("+1", "1"),
+            ("1.", "1"),
+            (".25", "0.25"),
+            ("-000123.4500", "-123.45"),
+            ("0.001", "0.001"),
+            (
+                "12345678901234567890.123456789012345678",
+                "12345678901234567890.123456789012345678",
+            ),
+        ];
+
+        for &(lexical, canonical) in examples.iter() {
+            assert_eq!(Decimal::parse(lexical).unwrap().to_string(), canonical);
+        }
+    }
+
+    #[test]
+    fn reject_invalid_decimals() {
+        let invalid = [
+            "",
+            ".",
+            "+",
+            "1.2.3",
+            "1e2",
+            " 1",
+            "INF",
+        ];
+
+        for lexical in invalid.iter() {
+            assert!(Decimal::parse(lexical).is_err(), "{} is valid", lexical);
+        }
+    }
+
+    #[test]
+    fn report_unsupported_precision() {
+        for lexical in [
+            "123456789012345678901234567890123456789",
+            "-0.123456789012345678901234567890123456789",
+        ]
+        .iter()
+        {
+            match Decimal::parse(lexical) {
+                Err(err) => match err.error_type() {
+                    ErrorType::UnsupportedPrecision => {}
+                    _ => panic!("Unexpected error type"),
+                },
+                Ok(_) => panic!("{} is supported", lexical),
+            }
+        }
+    }
+
+    #[test]
+    fn compare_decimals() {
+        let small = Decimal::parse("-1.5").unwrap();
+        let large = Decimal::parse("99999999999999999999999999999999999999").unwrap();
+        let fraction = Decimal::parse("0.00000000000000000000000000000000000001").unwrap();
+
+        assert!(small < Decimal::from(0));
+        assert!(fraction < large);
+        assert!(small < fraction);
+        assert!(Decimal::new(10, 1) == Decimal::from(1));
+    }
+}
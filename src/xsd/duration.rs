@@ -0,0 +1,256 @@
+use crate::error::{Error, ErrorType};
+use crate::xsd::decimal::Decimal;
+use crate::Result;
+use std::fmt;
+
+/// Duration as defined by `xsd:duration`, a number of months and a number of seconds.
+///
+/// Both parts have the same sign, e.g. `-P1MT1S` is minus one month and minus one second.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Duration {
+    months: i64,
+    seconds: Decimal,
+}
+
+impl Duration {
+    /// Parses the lexical form of an `xsd:duration`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::xsd::duration::Duration;
+    ///
+    /// let duration = Duration::parse("P1Y2M3DT10H30M").unwrap();
+    ///
+    /// assert_eq!(duration.months(), 14);
+    /// assert_eq!(duration.seconds().to_string(), "297000");
+    /// assert_eq!(Duration::parse("PT36H").unwrap().to_string(), "P1DT12H");
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - The lexical form is not a duration, e.g. it has no fields.
+    /// - The duration is too large to be represented.
+    ///
+    pub fn parse(lexical: &str) -> Result<Duration> {
+        Duration::parse_fields(lexical).ok_or_else(|| {
+            Error::new(
+                ErrorType::InvalidLiteral,
+                "Invalid duration: ".to_string() + lexical,
+            )
+        })
+    }
+
+    fn parse_fields(lexical: &str) -> Option<Duration> {
+        let (negative, rest) = match lexical.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, lexical),
+        };
+
+        let rest = rest.strip_prefix('P')?;
+
+        let (date, time) = match rest.find('T') {
+            Some(index) if index + 1 < rest.len() => (&rest[..index], Some(&rest[index + 1..])),
+            Some(_) => return None,
+            None => (rest, None),
+        };
+
+        if date.is_empty() && time.is_none() {
+            return None;
+        }
+
+        let mut months: i64 = 0;
+        let mut seconds = Decimal::from(0);
+
+        // fields are only allowed once and in this order
+        let date_fields = [('Y', 12), ('M', 1), ('D', 0)];
+        let mut input = date;
+
+        for &(designator, factor) in date_fields.iter() {
+            if let Some((value, rest)) = parse_field(input, designator) {
+                let value: i64 = value.parse().ok()?;
+
+                if factor == 0 {
+                    let day_seconds = Decimal::from(value.checked_mul(86400)?);
+                    seconds = seconds.checked_add(&day_seconds)?;
+                } else {
+                    months = months.checked_add(value.checked_mul(factor)?)?;
+                }
+
+                input = rest;
+            }
+        }
+
+        if !input.is_empty() {
+            return None;
+        }
+
+        if let Some(time) = time {
+            let mut input = time;
+
+            for &(designator, factor) in [('H', 3600), ('M', 60)].iter() {
+                if let Some((value, rest)) = parse_field(input, designator) {
+                    let value: i64 = value.parse().ok()?;
+                    seconds = seconds.checked_add(&Decimal::from(value.checked_mul(factor)?))?;
+                    input = rest;
+                }
+            }
+
+            if let Some((value, rest)) = parse_field(input, 'S') {
+                if value.ends_with('.') {
+                    return None;
+                }
+
+                seconds = seconds.checked_add(&Decimal::parse(value).ok()?)?;
+                input = rest;
+            }
+
+            if !input.is_empty() {
+                return None;
+            }
+        }
+
+        if negative {
+            Some(Duration {
+                months: -months,
+                seconds: seconds.negate(),
+            })
+        } else {
+            Some(Duration { months, seconds })
+        }
+    }
+
+    /// Returns the number of months, negative for negative durations.
+    pub fn months(&self) -> i64 {
+        self.months
+    }
+
+    /// Returns the number of seconds, negative for negative durations.
+    pub fn seconds(&self) -> &Decimal {
+        &self.seconds
+    }
+}
+
+impl fmt::Display for Duration {
+    /// Writes the canonical lexical form.
+    ///
+    /// Months are written as years and months, seconds as days, hours, minutes and seconds.
+    /// Fields with a value of zero are left out, an empty duration is written as `PT0S`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let negative = self.months < 0 || self.seconds < Decimal::from(0);
+
+        if negative {
+            f.write_str("-")?;
+        }
+
+        f.write_str("P")?;
+
+        let months = self.months.unsigned_abs();
+        let seconds = if negative {
+            self.seconds.negate()
+        } else {
+            self.seconds
+        };
+
+        if months == 0 && seconds == Decimal::from(0) {
+            return f.write_str("T0S");
+        }
+
+        if months / 12 > 0 {
+            write!(f, "{}Y", months / 12)?;
+        }
+
+        if !months.is_multiple_of(12) {
+            write!(f, "{}M", months % 12)?;
+        }
+
+        let whole_seconds = seconds.trunc();
+        let fraction = seconds.checked_add(&Decimal::new(-whole_seconds, 0));
+
+        if whole_seconds / 86400 > 0 {
+            write!(f, "{}D", whole_seconds / 86400)?;
+        }
+
+        let hours = whole_seconds % 86400 / 3600;
+        let minutes = whole_seconds % 3600 / 60;
+        let seconds = Decimal::new(whole_seconds % 60, 0)
+            .checked_add(&fraction.unwrap_or_else(|| Decimal::from(0)))
+            .unwrap_or_else(|| Decimal::from(0));
+
+        if hours > 0 || minutes > 0 || seconds != Decimal::from(0) {
+            f.write_str("T")?;
+        }
+
+        if hours > 0 {
+            write!(f, "{}H", hours)?;
+        }
+
+        if minutes > 0 {
+            write!(f, "{}M", minutes)?;
+        }
+
+        if seconds != Decimal::from(0) {
+            write!(f, "{}S", seconds)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Parses a number followed by the designator at the start of the input.
+///
+/// Returns the number and the rest of the input, or `None` if the input does not start
+/// with the field.
+fn parse_field(input: &str, designator: char) -> Option<(&str, &str)> {
+    let length = input
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(input.len());
+
+    if length == 0 || !input[length..].starts_with(designator) {
+        return None;
+    }
+
+    if designator != 'S' && input[..length].contains('.') {
+        return None;
+    }
+
+    Some((&input[..length], &input[length + 1..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::xsd::duration::Duration;
+
+    #[test]
+    fn parse_durations() {
+        let examples = [
+            ("P2Y6M5DT12H35M30S", "P2Y6M5DT12H35M30S"),
+            ("P1DT2H", "P1DT2H"),
+            ("P20M", "P1Y8M"),
+            ("PT20M", "PT20M"),
+            ("P0Y20M0D", "P1Y8M"),
+            ("P0Y", "PT0S"),
+            ("-P60D", "-P60D"),
+            ("PT1M30.5S", "PT1M30.5S"),
+            ("PT0.010S", "PT0.01S"),
+            ("PT90M", "PT1H30M"),
+            ("-P1Y1MT1.5S", "-P1Y1MT1.5S"),
+        ];
+
+        for &(lexical, canonical) in examples.iter() {
+            assert_eq!(Duration::parse(lexical).unwrap().to_string(), canonical);
+        }
+    }
+
+    #[test]
+    fn reject_invalid_durations() {
+        let invalid = [
+            "P", "PT", "P1YT", "P-1Y", "1Y", "P1M1Y", "P1.5Y", "PT1.S", "P1D1H", "PT1H1D", "P1Y1Y",
+            "+P1Y", "P 1Y",
+        ];
+
+        for lexical in invalid.iter() {
+            assert!(Duration::parse(lexical).is_err(), "{} is valid", lexical);
+        }
+    }
+}
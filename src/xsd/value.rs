@@ -0,0 +1,681 @@
+use crate::error::{Error, ErrorType};
+use crate::node::Node;
+use crate::specs::xml_specs::XmlDataTypes;
+use crate::vocab::xsd;
+use crate::xsd::binary;
+use crate::xsd::date_time::{Date, DateTime, GYear, Time};
+use crate::xsd::decimal::{split_sign, Decimal};
+use crate::xsd::duration::Duration;
+use crate::Result;
+use std::fmt;
+
+/// Value of a literal with an XML schema data type.
+///
+/// Values are parsed from the lexical form of literals and written in canonical lexical
+/// form, so two literals with the same value have the same canonical form.
+///
+/// # Examples
+///
+/// ```
+/// use rdf::node::Node;
+/// use rdf::specs::xml_specs::XmlDataTypes;
+/// use rdf::xsd::value::XsdValue;
+///
+/// let node = Node::LiteralNode {
+///   literal: "+0042".to_string(),
+///   data_type: Some(XmlDataTypes::Int.to_uri()),
+///   language: None,
+/// };
+///
+/// let value = node.literal_value().unwrap();
+///
+/// assert_eq!(value.as_integer(), Some(42));
+/// assert_eq!(value.to_string(), "42");
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+pub enum XsdValue {
+    /// Value of `xsd:string`, also used for literals without data type.
+    String(String),
+
+    /// Value of `xsd:boolean`.
+    Boolean(bool),
+
+    /// Value of `xsd:integer` or a data type derived from it.
+    Integer {
+        value: i128,
+        data_type: XmlDataTypes,
+    },
+
+    /// Value of `xsd:decimal`.
+    Decimal(Decimal),
+
+    /// Value of `xsd:float`.
+    Float(f32),
+
+    /// Value of `xsd:double`.
+    Double(f64),
+
+    /// Value of `xsd:dateTime`.
+    DateTime(DateTime),
+
+    /// Value of `xsd:date`.
+    Date(Date),
+
+    /// Value of `xsd:time`.
+    Time(Time),
+
+    /// Value of `xsd:duration`.
+    Duration(Duration),
+
+    /// Value of `xsd:gYear`.
+    GYear(GYear),
+
+    /// Value of `xsd:hexBinary`.
+    HexBinary(Vec<u8>),
+
+    /// Value of `xsd:base64Binary`.
+    Base64Binary(Vec<u8>),
+}
+
+impl XsdValue {
+    /// Parses the lexical form of a literal with the provided data type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::specs::xml_specs::XmlDataTypes;
+    /// use rdf::xsd::value::XsdValue;
+    ///
+    /// assert_eq!(XsdValue::parse("1", XmlDataTypes::Boolean).unwrap(), XsdValue::Boolean(true));
+    /// assert_eq!(XsdValue::parse("1.5e2", XmlDataTypes::Double).unwrap().to_string(), "1.5E2");
+    /// assert!(XsdValue::parse("256", XmlDataTypes::UnsignedByte).is_err());
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - The lexical form is not valid for the data type.
+    /// - The value is out of the range of the data type.
+    /// - The value is valid, but integers beyond the range of `i128` and decimals with more
+    ///   than 38 digits cannot be represented, which is reported as
+    ///   `ErrorType::UnsupportedPrecision`.
+    ///
+    pub fn parse(lexical: &str, data_type: XmlDataTypes) -> Result<XsdValue> {
+        // all data types but string collapse white space, so the lexical form is trimmed
+        let lexical = match data_type {
+            XmlDataTypes::String => lexical,
+            _ => lexical.trim_matches(|c| matches!(c, ' ' | '\t' | '\n' | '\r')),
+        };
+        let invalid = || invalid_literal(ErrorType::InvalidLiteral, data_type, lexical);
+
+        if data_type.integer_range().is_some() {
+            return XsdValue::parse_integer(lexical, data_type);
+        }
+
+        Ok(match data_type {
+            XmlDataTypes::String if lexical.chars().all(is_xml_char) => {
+                XsdValue::String(lexical.to_string())
+            }
+            XmlDataTypes::String => return Err(invalid()),
+            XmlDataTypes::Boolean => match lexical {
+                "true" | "1" => XsdValue::Boolean(true),
+                "false" | "0" => XsdValue::Boolean(false),
+                _ => return Err(invalid()),
+            },
+            XmlDataTypes::Decimal => XsdValue::Decimal(Decimal::parse(lexical)?),
+            XmlDataTypes::Float => XsdValue::Float(parse_float(lexical).ok_or_else(invalid)?),
+            XmlDataTypes::Double => XsdValue::Double(parse_float(lexical).ok_or_else(invalid)?),
+            XmlDataTypes::DateTime => XsdValue::DateTime(DateTime::parse(lexical)?),
+            XmlDataTypes::Date => XsdValue::Date(Date::parse(lexical)?),
+            XmlDataTypes::Time => XsdValue::Time(Time::parse(lexical)?),
+            XmlDataTypes::Duration => XsdValue::Duration(Duration::parse(lexical)?),
+            XmlDataTypes::GYear => XsdValue::GYear(GYear::parse(lexical)?),
+            XmlDataTypes::HexBinary => XsdValue::HexBinary(binary::decode_hex(lexical)?),
+            XmlDataTypes::Base64Binary => XsdValue::Base64Binary(binary::decode_base64(lexical)?),
+            _ => return Err(invalid()),
+        })
+    }
+
+    /// Parses the value of a literal node.
+    ///
+    /// Literals without data type, including literals with language, are strings.
+    ///
+    /// # Failures
+    ///
+    /// - The node is not a literal.
+    /// - The data type of the literal is not supported.
+    /// - The lexical form is not valid for the data type.
+    ///
+    pub fn from_node(node: &Node) -> Result<XsdValue> {
+        match *node {
+            Node::LiteralNode {
+                ref literal,
+                data_type: None,
+                ..
+            } => Ok(XsdValue::String(literal.clone())),
+            Node::LiteralNode {
+                ref literal,
+                data_type: Some(ref data_type),
+                ..
+            } => match XmlDataTypes::from_uri(data_type) {
+                Some(data_type) => XsdValue::parse(literal, data_type),
+                None => Err(Error::new(
+                    ErrorType::InvalidLiteral,
+                    "Unsupported literal data type: ".to_string() + data_type.to_string(),
+                )),
+            },
+            _ => Err(Error::new(
+                ErrorType::InvalidLiteral,
+                "Node is not a literal.",
+            )),
+        }
+    }
+
+    /// Checks if the lexical form is valid for the data type.
+    ///
+    /// Values that are valid but exceed the supported precision are valid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::specs::xml_specs::XmlDataTypes;
+    /// use rdf::xsd::value::XsdValue;
+    ///
+    /// assert!(XsdValue::is_valid("2004-02-29", XmlDataTypes::Date));
+    /// assert_eq!(XsdValue::is_valid("2005-02-29", XmlDataTypes::Date), false);
+    /// assert!(XsdValue::is_valid("170141183460469231731687303715884105728", XmlDataTypes::Integer));
+    /// ```
+    pub fn is_valid(lexical: &str, data_type: XmlDataTypes) -> bool {
+        match XsdValue::parse(lexical, data_type) {
+            Ok(_) => true,
+            Err(err) => matches!(err.error_type(), ErrorType::UnsupportedPrecision),
+        }
+    }
+
+    /// Returns the canonical lexical form of a literal with the data type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::specs::xml_specs::XmlDataTypes;
+    /// use rdf::xsd::value::XsdValue;
+    ///
+    /// assert_eq!(XsdValue::canonicalize("-0.50", XmlDataTypes::Decimal).unwrap(), "-0.5");
+    /// assert_eq!(XsdValue::canonicalize("0", XmlDataTypes::Boolean).unwrap(), "false");
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - The lexical form is not valid for the data type.
+    ///
+    pub fn canonicalize(lexical: &str, data_type: XmlDataTypes) -> Result<String> {
+        Ok(XsdValue::parse(lexical, data_type)?.to_string())
+    }
+
+    /// Returns the data type of the value.
+    pub fn data_type(&self) -> XmlDataTypes {
+        match *self {
+            XsdValue::String(_) => XmlDataTypes::String,
+            XsdValue::Boolean(_) => XmlDataTypes::Boolean,
+            XsdValue::Integer { data_type, .. } => data_type,
+            XsdValue::Decimal(_) => XmlDataTypes::Decimal,
+            XsdValue::Float(_) => XmlDataTypes::Float,
+            XsdValue::Double(_) => XmlDataTypes::Double,
+            XsdValue::DateTime(_) => XmlDataTypes::DateTime,
+            XsdValue::Date(_) => XmlDataTypes::Date,
+            XsdValue::Time(_) => XmlDataTypes::Time,
+            XsdValue::Duration(_) => XmlDataTypes::Duration,
+            XsdValue::GYear(_) => XmlDataTypes::GYear,
+            XsdValue::HexBinary(_) => XmlDataTypes::HexBinary,
+            XsdValue::Base64Binary(_) => XmlDataTypes::Base64Binary,
+        }
+    }
+
+    /// Returns a literal node with the canonical lexical form and the data type of the value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::node::Node;
+    /// use rdf::specs::xml_specs::XmlDataTypes;
+    /// use rdf::xsd::value::XsdValue;
+    ///
+    /// assert_eq!(XsdValue::from(true).to_node(), Node::LiteralNode {
+    ///   literal: "true".to_string(),
+    ///   data_type: Some(XmlDataTypes::Boolean.to_uri()),
+    ///   language: None,
+    /// });
+    /// ```
+    pub fn to_node(&self) -> Node {
+        Node::LiteralNode {
+            literal: self.to_string(),
+            data_type: Some(self.data_type().to_uri()),
+            language: None,
+        }
+    }
+
+    /// Returns the value of a boolean.
+    pub fn as_bool(&self) -> Option<bool> {
+        match *self {
+            XsdValue::Boolean(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of an integer of any integer data type.
+    pub fn as_integer(&self) -> Option<i128> {
+        match *self {
+            XsdValue::Integer { value, .. } => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of a decimal or integer as decimal.
+    pub fn as_decimal(&self) -> Option<Decimal> {
+        match *self {
+            XsdValue::Decimal(value) => Some(value),
+            XsdValue::Integer { value, .. } => Some(Decimal::new(value, 0)),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of a numeric literal as nearest `f64`.
+    pub fn as_f64(&self) -> Option<f64> {
+        match *self {
+            XsdValue::Integer { value, .. } => Some(value as f64),
+            XsdValue::Decimal(ref value) => Some(value.to_f64()),
+            XsdValue::Float(value) => Some(f64::from(value)),
+            XsdValue::Double(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of a string.
+    pub fn as_str(&self) -> Option<&str> {
+        match *self {
+            XsdValue::String(ref value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of a hexBinary or base64Binary.
+    pub fn as_bytes(&self) -> Option<&[u8]> {
+        match *self {
+            XsdValue::HexBinary(ref value) | XsdValue::Base64Binary(ref value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Checks if two values are equal, numbers of different data types are compared by value.
+    ///
+    /// Integers and decimals are compared exactly, other numbers as `f64`. Date and time values
+    /// are compared as points in time, values with timezone are never equal to values without
+    /// timezone. Values of other data types are only equal to values of the same data type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::specs::xml_specs::XmlDataTypes;
+    /// use rdf::xsd::value::XsdValue;
+    ///
+    /// let integer = XsdValue::parse("1", XmlDataTypes::Byte).unwrap();
+    /// let double = XsdValue::parse("1.0E0", XmlDataTypes::Double).unwrap();
+    /// let utc = XsdValue::parse("12:00:00Z", XmlDataTypes::Time).unwrap();
+    /// let local = XsdValue::parse("13:00:00+01:00", XmlDataTypes::Time).unwrap();
+    ///
+    /// assert!(integer.value_eq(&double));
+    /// assert!(utc.value_eq(&local));
+    /// assert_eq!(integer.value_eq(&XsdValue::from("1")), false);
+    /// ```
+    pub fn value_eq(&self, other: &XsdValue) -> bool {
+        if let (Some(a), Some(b)) = (self.as_decimal(), other.as_decimal()) {
+            return a == b;
+        }
+
+        if let (Some(a), Some(b)) = (self.as_f64(), other.as_f64()) {
+            return a == b;
+        }
+
+        match (self, other) {
+            (XsdValue::DateTime(a), XsdValue::DateTime(b)) => {
+                a.time().timezone().is_some() == b.time().timezone().is_some()
+                    && a.timestamp().is_some()
+                    && a.timestamp() == b.timestamp()
+            }
+            (XsdValue::Date(a), XsdValue::Date(b)) => {
+                a.timezone().is_some() == b.timezone().is_some() && a.timestamp() == b.timestamp()
+            }
+            (XsdValue::Time(a), XsdValue::Time(b)) => {
+                a.timezone().is_some() == b.timezone().is_some()
+                    && a.timestamp().is_some()
+                    && a.timestamp() == b.timestamp()
+            }
+            _ => self == other,
+        }
+    }
+
+    /// Parses an integer of any integer data type.
+    ///
+    /// # Failures
+    ///
+    /// - The lexical form is not an integer or the value is out of the range of the data type.
+    /// - The value is beyond the range of `i128` for a data type without this bound.
+    ///
+    fn parse_integer(lexical: &str, data_type: XmlDataTypes) -> Result<XsdValue> {
+        let error = |error_type| invalid_literal(error_type, data_type, lexical);
+
+        let (negative, unsigned) = split_sign(lexical);
+        let (min, max) = data_type.integer_range().unwrap();
+
+        if unsigned.is_empty() || !unsigned.bytes().all(|c| c.is_ascii_digit()) {
+            return Err(error(ErrorType::InvalidLiteral));
+        }
+
+        let value: i128 = match lexical.parse() {
+            Ok(value) => value,
+            // the value space of the unbounded data types is larger than i128
+            Err(_) if negative && min == i128::MIN || !negative && max == i128::MAX => {
+                return Err(error(ErrorType::UnsupportedPrecision))
+            }
+            Err(_) => return Err(error(ErrorType::InvalidLiteral)),
+        };
+
+        if value < min || value > max {
+            return Err(error(ErrorType::InvalidLiteral));
+        }
+
+        Ok(XsdValue::Integer { value, data_type })
+    }
+}
+
+impl fmt::Display for XsdValue {
+    /// Writes the canonical lexical form of the value.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            XsdValue::String(ref value) => f.write_str(value),
+            XsdValue::Boolean(value) => write!(f, "{}", value),
+            XsdValue::Integer { value, .. } => write!(f, "{}", value),
+            XsdValue::Decimal(ref value) => write!(f, "{}", value),
+            XsdValue::Float(value) => write_float(f, value.is_nan(), format!("{:E}", value)),
+            XsdValue::Double(value) => write_float(f, value.is_nan(), format!("{:E}", value)),
+            XsdValue::DateTime(ref value) => write!(f, "{}", value),
+            XsdValue::Date(ref value) => write!(f, "{}", value),
+            XsdValue::Time(ref value) => write!(f, "{}", value),
+            XsdValue::Duration(ref value) => write!(f, "{}", value),
+            XsdValue::GYear(ref value) => write!(f, "{}", value),
+            XsdValue::HexBinary(ref value) => f.write_str(&binary::encode_hex(value)),
+            XsdValue::Base64Binary(ref value) => f.write_str(&binary::encode_base64(value)),
+        }
+    }
+}
+
+impl From<bool> for XsdValue {
+    fn from(value: bool) -> XsdValue {
+        XsdValue::Boolean(value)
+    }
+}
+
+impl From<i64> for XsdValue {
+    fn from(value: i64) -> XsdValue {
+        XsdValue::Integer {
+            value: i128::from(value),
+            data_type: XmlDataTypes::Integer,
+        }
+    }
+}
+
+impl From<f64> for XsdValue {
+    fn from(value: f64) -> XsdValue {
+        XsdValue::Double(value)
+    }
+}
+
+impl From<Decimal> for XsdValue {
+    fn from(value: Decimal) -> XsdValue {
+        XsdValue::Decimal(value)
+    }
+}
+
+impl<'a> From<&'a str> for XsdValue {
+    fn from(value: &'a str) -> XsdValue {
+        XsdValue::String(value.to_string())
+    }
+}
+
+impl From<String> for XsdValue {
+    fn from(value: String) -> XsdValue {
+        XsdValue::String(value)
+    }
+}
+
+/// Checks if the character is allowed in XML documents.
+fn is_xml_char(c: char) -> bool {
+    match c {
+        '\t' | '\n' | '\r' => true,
+        '\u{FFFE}' | '\u{FFFF}' => false,
+        c => c >= ' ',
+    }
+}
+
+fn invalid_literal(error_type: ErrorType, data_type: XmlDataTypes, lexical: &str) -> Error {
+    let name = data_type.as_str().trim_start_matches(xsd::NAMESPACE_URI);
+
+    Error::new(error_type, format!("Invalid {}: {}", name, lexical))
+}
+
+/// Parses the lexical form of an `xsd:float` or `xsd:double`.
+fn parse_float<F: std::str::FromStr + std::ops::Neg<Output = F>>(lexical: &str) -> Option<F> {
+    match lexical {
+        "INF" | "+INF" => return "inf".parse().ok(),
+        "-INF" => return "-inf".parse().ok(),
+        "NaN" => return "NaN".parse().ok(),
+        _ => {}
+    }
+
+    let (_, unsigned) = split_sign(lexical);
+
+    let (mantissa, exponent) = match unsigned.find(['e', 'E']) {
+        Some(index) => (&unsigned[..index], Some(&unsigned[index + 1..])),
+        None => (unsigned, None),
+    };
+
+    let is_digits = |s: &str| s.bytes().all(|c| c.is_ascii_digit());
+
+    let valid_mantissa = match mantissa.find('.') {
+        Some(index) => {
+            mantissa.len() > 1 && is_digits(&mantissa[..index]) && is_digits(&mantissa[index + 1..])
+        }
+        None => !mantissa.is_empty() && is_digits(mantissa),
+    };
+
+    let valid_exponent = match exponent.map(|exponent| split_sign(exponent).1) {
+        Some(exponent) => !exponent.is_empty() && is_digits(exponent),
+        None => true,
+    };
+
+    if valid_mantissa && valid_exponent {
+        lexical.parse().ok()
+    } else {
+        None
+    }
+}
+
+/// Writes the canonical form of a float from its scientific notation, e.g. `1.0E0`.
+fn write_float(f: &mut fmt::Formatter, is_nan: bool, scientific: String) -> fmt::Result {
+    match scientific.as_str() {
+        _ if is_nan => f.write_str("NaN"),
+        "inf" => f.write_str("INF"),
+        "-inf" => f.write_str("-INF"),
+        _ => match scientific.find('E') {
+            // the mantissa of the canonical form always contains a decimal point
+            Some(index) if !scientific[..index].contains('.') => {
+                write!(f, "{}.0{}", &scientific[..index], &scientific[index..])
+            }
+            _ => f.write_str(&scientific),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::ErrorType;
+    use crate::node::Node;
+    use crate::specs::xml_specs::XmlDataTypes;
+    use crate::uri::Uri;
+    use crate::xsd::value::XsdValue;
+
+    #[test]
+    fn canonicalize_literals() {
+        let examples = [
+            ("-0", XmlDataTypes::Integer, "0"),
+            ("+0100", XmlDataTypes::PositiveInteger, "100"),
+            (
+                "-9223372036854775808",
+                XmlDataTypes::Long,
+                "-9223372036854775808",
+            ),
+            (
+                "18446744073709551615",
+                XmlDataTypes::UnsignedLong,
+                "18446744073709551615",
+            ),
+            ("1", XmlDataTypes::Boolean, "true"),
+            ("01.10", XmlDataTypes::Decimal, "1.1"),
+            ("100", XmlDataTypes::Double, "1.0E2"),
+            ("-0", XmlDataTypes::Double, "-0.0E0"),
+            (".5e-3", XmlDataTypes::Double, "5.0E-4"),
+            ("+INF", XmlDataTypes::Double, "INF"),
+            ("NaN", XmlDataTypes::Float, "NaN"),
+            ("0.1", XmlDataTypes::Float, "1.0E-1"),
+            ("3.4028235E38", XmlDataTypes::Float, "3.4028235E38"),
+            (" 1 ", XmlDataTypes::Integer, "1"),
+            ("\n true\t", XmlDataTypes::Boolean, "true"),
+            ("1.0\r\n", XmlDataTypes::Float, "1.0E0"),
+            ("a\tb", XmlDataTypes::String, "a\tb"),
+            (" a ", XmlDataTypes::String, " a "),
+            ("0fb7", XmlDataTypes::HexBinary, "0FB7"),
+            ("Zm9v YmFy", XmlDataTypes::Base64Binary, "Zm9vYmFy"),
+            (
+                "2001-10-26T21:32:52+00:00",
+                XmlDataTypes::DateTime,
+                "2001-10-26T21:32:52Z",
+            ),
+            ("P0D", XmlDataTypes::Duration, "PT0S"),
+        ];
+
+        for &(lexical, data_type, canonical) in examples.iter() {
+            assert_eq!(
+                XsdValue::canonicalize(lexical, data_type).unwrap(),
+                canonical,
+                "canonicalizing {}",
+                lexical
+            );
+        }
+    }
+
+    #[test]
+    fn reject_invalid_literals() {
+        let invalid = [
+            ("1.0", XmlDataTypes::Integer),
+            ("", XmlDataTypes::Integer),
+            ("+", XmlDataTypes::Int),
+            ("2147483648", XmlDataTypes::Int),
+            ("-1", XmlDataTypes::NonNegativeInteger),
+            ("0", XmlDataTypes::NegativeInteger),
+            ("128", XmlDataTypes::Byte),
+            ("TRUE", XmlDataTypes::Boolean),
+            ("inf", XmlDataTypes::Double),
+            ("1e", XmlDataTypes::Double),
+            ("e1", XmlDataTypes::Double),
+            (".", XmlDataTypes::Float),
+            ("1. 0", XmlDataTypes::Float),
+            ("a\u{1}", XmlDataTypes::String),
+            ("0g", XmlDataTypes::HexBinary),
+            (
+                "-170141183460469231731687303715884105729",
+                XmlDataTypes::PositiveInteger,
+            ),
+            (
+                "170141183460469231731687303715884105728",
+                XmlDataTypes::UnsignedLong,
+            ),
+        ];
+
+        for &(lexical, data_type) in invalid.iter() {
+            assert!(
+                !XsdValue::is_valid(lexical, data_type),
+                "{} is valid {}",
+                lexical,
+                data_type.to_string()
+            );
+        }
+
+        let err = XsdValue::parse(" 1.0 ", XmlDataTypes::Integer).unwrap_err();
+        assert_eq!(err.to_string(), "Invalid integer: 1.0");
+
+        let err = XsdValue::parse("256", XmlDataTypes::UnsignedByte).unwrap_err();
+        assert_eq!(err.to_string(), "Invalid unsignedByte: 256");
+    }
+
+    #[test]
+    fn accept_values_beyond_supported_precision() {
+        let unsupported = [
+            (
+                "170141183460469231731687303715884105728",
+                XmlDataTypes::Integer,
+            ),
+            (
+                "+170141183460469231731687303715884105728",
+                XmlDataTypes::NonNegativeInteger,
+            ),
+            (
+                "-170141183460469231731687303715884105729",
+                XmlDataTypes::NegativeInteger,
+            ),
+            (
+                "170141183460469231731687303715884105728",
+                XmlDataTypes::Decimal,
+            ),
+            (
+                "1.00000000000000000000000000000000000001",
+                XmlDataTypes::Decimal,
+            ),
+        ];
+
+        for &(lexical, data_type) in unsupported.iter() {
+            assert!(XsdValue::is_valid(lexical, data_type));
+
+            match XsdValue::parse(lexical, data_type) {
+                Err(err) => match err.error_type() {
+                    ErrorType::UnsupportedPrecision => {}
+                    _ => panic!("Unexpected error type"),
+                },
+                Ok(_) => panic!("{} is supported", lexical),
+            }
+        }
+    }
+
+    #[test]
+    fn literal_values_of_nodes() {
+        let literal = |literal: &str, data_type: Option<&str>| Node::LiteralNode {
+            literal: literal.to_string(),
+            data_type: data_type.map(|uri| Uri::parse(uri).unwrap()),
+            language: None,
+        };
+
+        let integer = literal("12", Some("http://www.w3.org/2001/XMLSchema#short"));
+        let plain = literal("12", None);
+        let unknown = literal("12", Some("http://example.org/type"));
+
+        assert_eq!(integer.literal_value().unwrap().as_integer(), Some(12));
+        assert_eq!(plain.literal_value().unwrap().as_str(), Some("12"));
+        assert!(unknown.literal_value().is_err());
+        assert!(Node::BlankNode {
+            id: "a".to_string()
+        }
+        .literal_value()
+        .is_err());
+
+        let value = XsdValue::from(2.5);
+        assert_eq!(value.to_node().literal_value().unwrap(), value);
+    }
+}
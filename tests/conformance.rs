@@ -0,0 +1,373 @@
+//! Runs test manifests in the layout of the W3C RDF 1.1 Turtle and N-Triples test suites and
+//! of the RDF Dataset Canonicalization test suite.
+//!
+//! The suites in `tests/conformance/turtle` and `tests/conformance/ntriples` are local subsets.
+//! The official suites are vendored in `tests/conformance/w3c` by
+//! `tests/conformance/fetch-w3c-suites.sh`, `RDF_TESTS_DIR` can point to another checkout of
+//! <https://github.com/w3c/rdf-tests> and `RDF_CANON_TESTS_DIR` to another checkout of
+//! <https://github.com/w3c/rdf-canon>. The `w3c_*` tests are skipped with a notice while the
+//! official suites are missing.
+//!
+//! The manifests are parsed with the crate's own `TurtleParser`. Evaluation tests compare the
+//! parsed graph to the expected N-Triples output modulo blank node labels, canonicalization
+//! tests compare the canonical N-Quads or the issued identifiers exactly.
+
+use rdf::canonicalization::rdfc::{CanonicalDataset, Canonicalizer, HashAlgorithm};
+use rdf::graph::Graph;
+use rdf::isomorphism::is_isomorphic;
+use rdf::json::JsonValue;
+use rdf::node::Node;
+use rdf::reader::n_triples_parser::NTriplesParser;
+use rdf::reader::rdf_parser::RdfParser;
+use rdf::reader::turtle_parser::TurtleParser;
+use rdf::uri::Uri;
+use std::env;
+use std::fs::{self, File};
+use std::path::{Path, PathBuf};
+
+const RDF: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#";
+const MF: &str = "http://www.w3.org/2001/sw/DataAccess/tests/test-manifest#";
+const RDFT: &str = "http://www.w3.org/ns/rdftest#";
+const RDFC: &str = "https://w3c.github.io/rdf-canon/tests/vocab#";
+
+/// Location of the Turtle test suite that the local suites are resolved against.
+const TURTLE_TESTS: &str = "http://www.w3.org/2013/TurtleTests/";
+
+/// Location of the RDF 1.1 suites of the official test repository.
+const RDF_TESTS: &str = "https://w3c.github.io/rdf-tests/";
+
+/// Number of canonicalization steps after which the poison graphs of the negative
+/// canonicalization tests are rejected, far more than the evaluation tests take.
+const WORK_LIMIT: usize = 100_000;
+
+/// Location of the official RDF Dataset Canonicalization test suite.
+const RDF_CANON_TESTS: &str = "https://w3c.github.io/rdf-canon/tests/";
+
+/// Test suite with the directory of the manifest and the IRI the manifest is published at.
+struct Suite {
+    dir: PathBuf,
+    base: String,
+}
+
+impl Suite {
+    /// Suite of `tests/conformance` that uses the location of the Turtle test suite as base.
+    fn local(name: &str) -> Suite {
+        Suite {
+            dir: Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("tests")
+                .join("conformance")
+                .join(name),
+            base: TURTLE_TESTS.to_string(),
+        }
+    }
+
+    /// Suite of the official test repository, vendored in `tests/conformance/w3c/rdf-tests`
+    /// unless `RDF_TESTS_DIR` is set. Returns `None` if the suite is missing.
+    fn official(path: &str) -> Option<Suite> {
+        let root = match env::var_os("RDF_TESTS_DIR") {
+            Some(root) => PathBuf::from(root),
+            None => vendored("rdf-tests"),
+        };
+
+        Suite::existing(Suite {
+            dir: root.join(path),
+            base: format!("{}{}/", RDF_TESTS, path),
+        })
+    }
+
+    /// Suite of the canonicalization test repository, vendored in
+    /// `tests/conformance/w3c/rdf-canon` unless `RDF_CANON_TESTS_DIR` is set. Returns `None` if
+    /// the suite is missing.
+    fn official_canon() -> Option<Suite> {
+        let root = match env::var_os("RDF_CANON_TESTS_DIR") {
+            Some(root) => PathBuf::from(root),
+            None => vendored("rdf-canon"),
+        };
+
+        Suite::existing(Suite {
+            dir: root.join("tests"),
+            base: RDF_CANON_TESTS.to_string(),
+        })
+    }
+
+    /// Returns the suite if its manifest exists, otherwise prints how to fetch it.
+    fn existing(suite: Suite) -> Option<Suite> {
+        if suite.dir.join("manifest.ttl").is_file() {
+            return Some(suite);
+        }
+
+        println!(
+            "Skipping {}, {} is missing. Run tests/conformance/fetch-w3c-suites.sh to vendor it.",
+            suite.base,
+            suite.dir.display()
+        );
+        None
+    }
+}
+
+/// Directory of a vendored test repository.
+fn vendored(repository: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("conformance")
+        .join("w3c")
+        .join(repository)
+}
+
+/// Entry of a test manifest.
+struct TestEntry {
+    name: String,
+    test_type: String,
+    action: PathBuf,
+
+    /// IRI of the action, which is the base of the action document.
+    action_uri: String,
+    result: Option<PathBuf>,
+
+    /// Hash algorithm of canonicalization tests, SHA-256 if not given.
+    hash_algorithm: Option<String>,
+}
+
+fn uri_node(uri: &str) -> Node {
+    Node::UriNode {
+        uri: Uri::parse(uri).unwrap(),
+    }
+}
+
+/// Returns the object of the first triple with the given subject and predicate.
+fn object<'a>(graph: &'a Graph, subject: &Node, predicate: &str) -> Option<&'a Node> {
+    graph
+        .get_triples_with_subject_and_predicate(subject, &uri_node(predicate))
+        .first()
+        .map(|triple| triple.object())
+}
+
+/// Returns the IRI of a manifest reference.
+fn uri_of(node: &Node) -> String {
+    match node {
+        Node::UriNode { uri } => uri.to_string().to_string(),
+        node => panic!("Invalid file reference in manifest: {:?}", node),
+    }
+}
+
+/// Resolves the IRI of a manifest reference to a file in the suite directory.
+fn file_of(suite: &Suite, uri: &str) -> PathBuf {
+    match uri.strip_prefix(&suite.base) {
+        Some(path) => suite.dir.join(path),
+        None => panic!("File reference {} is outside of {}.", uri, suite.base),
+    }
+}
+
+/// Reads the entries of the `mf:entries` list of a manifest, rejected tests are skipped.
+fn read_manifest(suite: &Suite) -> Vec<TestEntry> {
+    let graph = parse_turtle(
+        &suite.dir.join("manifest.ttl"),
+        &(suite.base.clone() + "manifest.ttl"),
+    )
+    .unwrap();
+
+    let entries_predicate = uri_node(&(MF.to_string() + "entries"));
+    let mut list = graph
+        .get_triples_with_predicate(&entries_predicate)
+        .first()
+        .map(|triple| triple.object().clone())
+        .expect("Manifest has no mf:entries.");
+
+    let nil = uri_node(&(RDF.to_string() + "nil"));
+    let rejected = uri_node(&(RDFT.to_string() + "Rejected"));
+    let mut entries = Vec::new();
+
+    while list != nil {
+        let test = object(&graph, &list, &(RDF.to_string() + "first"))
+            .unwrap()
+            .clone();
+
+        list = object(&graph, &list, &(RDF.to_string() + "rest"))
+            .unwrap()
+            .clone();
+
+        if object(&graph, &test, &(RDFT.to_string() + "approval")) == Some(&rejected) {
+            continue;
+        }
+
+        let test_type = match object(&graph, &test, &(RDF.to_string() + "type")) {
+            Some(Node::UriNode { uri }) => uri.to_string().replace(RDFT, "").replace(RDFC, ""),
+            node => panic!("Invalid test type: {:?}", node),
+        };
+
+        let name = match object(&graph, &test, &(MF.to_string() + "name")) {
+            Some(Node::LiteralNode { literal, .. }) => literal.clone(),
+            node => panic!("Invalid test name: {:?}", node),
+        };
+
+        let action_uri = uri_of(object(&graph, &test, &(MF.to_string() + "action")).unwrap());
+
+        entries.push(TestEntry {
+            name,
+            test_type,
+            action: file_of(suite, &action_uri),
+            action_uri,
+            result: object(&graph, &test, &(MF.to_string() + "result"))
+                .map(|node| file_of(suite, &uri_of(node))),
+            hash_algorithm: match object(&graph, &test, &(RDFC.to_string() + "hashAlgorithm")) {
+                Some(Node::LiteralNode { literal, .. }) => Some(literal.clone()),
+                _ => None,
+            },
+        });
+    }
+
+    entries
+}
+
+/// Parses a Turtle file with the location of the file in the test suite as base URI.
+fn parse_turtle(path: &Path, base_uri: &str) -> rdf::Result<Graph> {
+    TurtleParser::from_reader(File::open(path).unwrap())
+        .with_base_uri(Uri::parse(base_uri)?)
+        .decode()
+}
+
+fn parse_n_triples(path: &Path) -> rdf::Result<Graph> {
+    NTriplesParser::from_reader(File::open(path).unwrap()).decode()
+}
+
+/// Canonicalizes the N-Quads of the action with the hash algorithm of the entry.
+fn canonicalize(entry: &TestEntry) -> rdf::Result<CanonicalDataset> {
+    let hash_algorithm = match entry.hash_algorithm.as_deref() {
+        None | Some("SHA256") => HashAlgorithm::Sha256,
+        Some("SHA384") => HashAlgorithm::Sha384,
+        Some(name) => panic!("Unsupported hash algorithm {}.", name),
+    };
+
+    NTriplesParser::from_reader(File::open(&entry.action).unwrap())
+        .decode_dataset()
+        .and_then(|dataset| {
+            Canonicalizer::new()
+                .work_limit(WORK_LIMIT)
+                .hash_algorithm(hash_algorithm)
+                .canonicalize_dataset(&dataset)
+        })
+}
+
+/// Runs a single manifest entry and returns a description of the failure.
+fn run_entry(entry: &TestEntry) -> Option<String> {
+    match entry.test_type.as_str() {
+        "TestTurtlePositiveSyntax" => parse_turtle(&entry.action, &entry.action_uri)
+            .err()
+            .map(|err| err.to_string()),
+        "TestNTriplesPositiveSyntax" => parse_n_triples(&entry.action)
+            .err()
+            .map(|err| err.to_string()),
+        "TestTurtleNegativeSyntax" | "TestTurtleNegativeEval" => {
+            parse_turtle(&entry.action, &entry.action_uri)
+                .ok()
+                .map(|_| "Invalid input was accepted.".to_string())
+        }
+        "TestNTriplesNegativeSyntax" => parse_n_triples(&entry.action)
+            .ok()
+            .map(|_| "Invalid input was accepted.".to_string()),
+        "TestTurtleEval" => {
+            let expected = parse_n_triples(entry.result.as_ref().unwrap()).unwrap();
+
+            match parse_turtle(&entry.action, &entry.action_uri) {
+                Ok(ref graph) => is_isomorphic(graph, &expected)
+                    .err()
+                    .map(|mismatch| mismatch.to_string()),
+                Err(err) => Some(err.to_string()),
+            }
+        }
+        "RDFC10EvalTest" => {
+            let expected = fs::read_to_string(entry.result.as_ref().unwrap()).unwrap();
+
+            match canonicalize(entry) {
+                Ok(ref canonical) if canonical.to_n_quads() == expected => None,
+                Ok(canonical) => Some(format!(
+                    "Canonical N-Quads differ from the expected result:\n{}",
+                    canonical.to_n_quads()
+                )),
+                Err(err) => Some(err.to_string()),
+            }
+        }
+        "RDFC10MapTest" => {
+            let map = fs::read_to_string(entry.result.as_ref().unwrap()).unwrap();
+            let expected: Vec<(String, String)> = match JsonValue::parse(&map).unwrap() {
+                JsonValue::Object(members) => members
+                    .iter()
+                    .map(|(existing, issued)| {
+                        (existing.clone(), issued.as_str().unwrap().to_string())
+                    })
+                    .collect(),
+                value => panic!("Invalid identifier map: {:?}", value),
+            };
+
+            match canonicalize(entry) {
+                Ok(canonical) => {
+                    let issued: Vec<(String, String)> = canonical
+                        .issued_identifiers()
+                        .iter()
+                        .map(|(existing, issued)| (existing.clone(), issued.clone()))
+                        .collect();
+
+                    if issued == expected {
+                        None
+                    } else {
+                        Some(format!("Issued identifiers differ: {:?}", issued))
+                    }
+                }
+                Err(err) => Some(err.to_string()),
+            }
+        }
+        "RDFC10NegativeEvalTest" => canonicalize(entry)
+            .ok()
+            .map(|_| "Canonicalization did not fail.".to_string()),
+        test_type => Some(format!("Unsupported test type {}.", test_type)),
+    }
+}
+
+fn run_suite(suite: &Suite) {
+    let entries = read_manifest(suite);
+    assert!(!entries.is_empty());
+
+    let failures: Vec<String> = entries
+        .iter()
+        .filter_map(|entry| run_entry(entry).map(|failure| format!("{}: {}", entry.name, failure)))
+        .collect();
+
+    assert!(
+        failures.is_empty(),
+        "{} of {} tests failed:\n{}",
+        failures.len(),
+        entries.len(),
+        failures.join("\n")
+    );
+}
+
+#[test]
+fn turtle_test_suite() {
+    run_suite(&Suite::local("turtle"));
+}
+
+#[test]
+fn n_triples_test_suite() {
+    run_suite(&Suite::local("ntriples"));
+}
+
+#[test]
+fn w3c_turtle_test_suite() {
+    if let Some(suite) = Suite::official("rdf/rdf11/rdf-turtle") {
+        run_suite(&suite);
+    }
+}
+
+#[test]
+fn w3c_n_triples_test_suite() {
+    if let Some(suite) = Suite::official("rdf/rdf11/rdf-n-triples") {
+        run_suite(&suite);
+    }
+}
+
+#[test]
+fn w3c_rdfc10_test_suite() {
+    if let Some(suite) = Suite::official_canon() {
+        run_suite(&suite);
+    }
+}
@@ -0,0 +1,34 @@
+#!/bin/sh
+# Vendors the official W3C test suites that tests/conformance.rs runs into tests/conformance/w3c.
+#
+# Usage: tests/conformance/fetch-w3c-suites.sh [<rdf-tests revision> [<rdf-canon revision>]]
+#
+# The suites are copied without the git history of their repositories, commit them together
+# with the revision they were fetched at.
+
+set -e
+
+dir="$(cd "$(dirname "$0")" && pwd)/w3c"
+
+# fetch <repository> <revision> <path>...
+fetch() {
+    repository="$1"
+    revision="$2"
+    shift 2
+
+    checkout="$(mktemp -d)"
+    git clone --quiet "https://github.com/w3c/$repository" "$checkout"
+    git -C "$checkout" checkout --quiet "$revision"
+
+    rm -rf "$dir/$repository"
+    for path in "$@"; do
+        mkdir -p "$dir/$repository/$(dirname "$path")"
+        cp -R "$checkout/$path" "$dir/$repository/$path"
+    done
+    echo "$repository $(git -C "$checkout" rev-parse HEAD)" > "$dir/$repository/REVISION"
+
+    rm -rf "$checkout"
+}
+
+fetch rdf-tests "${1:-origin/HEAD}" rdf/rdf11/rdf-turtle rdf/rdf11/rdf-n-triples
+fetch rdf-canon "${2:-origin/HEAD}" tests